@@ -9,10 +9,21 @@
 /// - QuicEndpoint: QUIC endpoint management
 /// - QuicConnection: Connection handling
 /// - QuicSendStream/QuicRecvStream: Stream operations
+/// - quicRecvStreamData/QuicSendStreamSink: Dart Stream and StreamSink adapters for QUIC streams
 ///
 /// ## Convenience API
 /// Simplified wrappers built on Core API:
 /// - QuicClient: High-level client operations
+/// - SimpleQuicServer: Handler-based request/response server
+/// - QuicConnectionPool: Managed per-authority connection pool
+/// - Http3Client: HTTP/3 requests with headers, status codes and trailers
+/// - Http3Router: Method and path routing for SimpleQuicServer's HTTP/3 mode
+/// - WebTransportSession: WebTransport streams and datagrams over HTTP/3
+/// - QuicDatagramMux: Datagram channels multiplexed by flow ID
+/// - QuicRpcClient/QuicRpcRegistry: Multiplexed RPC calls with deadlines and cancellation
+/// - QuicPubSubBroker/QuicPubSubClient: Topic subscriptions with resumable sequence numbers
+/// - QuicFileUpload/QuicFileReceiver: Resumable, SHA-256 verified file uploads
+/// - QuicStreamReassembler: Contiguous data from unordered stream reads
 /// - Connection pooling and automatic retry logic
 library;
 // Flutter QUIC package exports
@@ -27,9 +38,38 @@ export 'src/rust/frb_generated.dart' show RustLib;
 export 'src/rust/core/endpoint.dart';
 export 'src/rust/core/connection.dart';
 export 'src/rust/core/stream.dart';
+export 'src/stream_adapters.dart';
+export 'src/rust/core/keylog.dart';
+export 'src/rust/core/cert_resolver.dart';
+export 'src/rust/core/datagram.dart';
+export 'src/datagram_stream.dart';
+
+// Export certificate types
+export 'src/rust/models/certificate.dart';
 
 // Export convenience types
 export 'src/rust/convenience/client.dart';
+export 'src/rust/convenience/server.dart';
+export 'src/rust/convenience/pool.dart';
+export 'src/rust/convenience/streaming.dart';
+export 'src/streaming_request.dart';
+export 'src/rust/convenience/http3.dart';
+export 'src/rust/convenience/http3_server.dart';
+export 'src/rust/convenience/webtransport.dart';
+export 'src/rust/convenience/datagram_channel.dart';
+export 'src/rust/convenience/framing.dart';
+export 'src/framed_stream.dart';
+export 'src/rust/convenience/rpc.dart';
+export 'src/rpc.dart';
+export 'src/rust/convenience/pubsub.dart';
+export 'src/pubsub.dart';
+export 'src/rust/convenience/file_transfer.dart';
+export 'src/file_transfer.dart';
+export 'src/rust/convenience/reassembly.dart';
+export 'src/http3_request.dart';
 
 // Export error types
 export 'src/rust/errors.dart';
+
+// Export logging types
+export 'src/rust/logging.dart';
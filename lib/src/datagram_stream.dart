@@ -0,0 +1,102 @@
+import 'dart:async';
+import 'dart:typed_data';
+
+import 'rust/api/bridge.dart';
+import 'rust/core/connection.dart';
+import 'rust/convenience/datagram_channel.dart';
+import 'rust/core/datagram.dart';
+
+/// An event of a stream created by [quicDatagramStream]
+sealed class QuicDatagramEvent {
+  const QuicDatagramEvent();
+}
+
+/// A datagram received from the peer
+final class QuicDatagramReceived extends QuicDatagramEvent {
+  const QuicDatagramReceived(this.data, {this.droppedBefore = 0});
+
+  final Uint8List data;
+
+  /// Datagrams discarded by the drop policy just before this one
+  final int droppedBefore;
+}
+
+/// The last event of the stream, reporting why the connection ended
+final class QuicDatagramStreamEnded extends QuicDatagramEvent {
+  const QuicDatagramStreamEnded(this.end);
+
+  final QuicConnectionEnd end;
+}
+
+/// Deliver datagrams received on [connection] as a stream
+///
+/// Datagrams are queued in the background up to `config.capacity`, after which
+/// `config.drop_policy` discards datagrams until the listener catches up;
+/// pausing the subscription lets the queue fill. All queued datagrams are
+/// taken with a single call across the bridge. The stream ends with a
+/// [QuicDatagramStreamEnded] event once the connection closes, and cancelling
+/// it stops receiving. Do not call `connectionReadDatagram` on the same
+/// connection while the stream is listened to.
+///
+/// ```dart
+/// await for (final event in quicDatagramStream(connection)) {
+///   switch (event) {
+///     case QuicDatagramReceived(:final data):
+///       handle(data);
+///     case QuicDatagramStreamEnded(:final end):
+///       print('connection ended: ${end.kind} ${end.reason}');
+///   }
+/// }
+/// ```
+Stream<QuicDatagramEvent> quicDatagramStream(
+  QuicConnection connection, {
+  QuicDatagramSubscriptionConfig? config,
+}) async* {
+  final subscription = await connectionSubscribeDatagrams(
+    connection: connection,
+    config: config ?? await datagramSubscriptionConfigNew(),
+  );
+
+  try {
+    yield* _batchEvents(
+      () => datagramSubscriptionNextBatch(subscription: subscription),
+    );
+  } finally {
+    await datagramSubscriptionClose(subscription: subscription);
+    subscription.dispose();
+  }
+}
+
+/// Deliver the payloads received on [channel] as a stream
+///
+/// Behaves like [quicDatagramStream] with the queue settings of the channel's
+/// mux. Cancelling the stream closes the channel.
+Stream<QuicDatagramEvent> quicDatagramChannelStream(
+  QuicDatagramChannel channel,
+) async* {
+  try {
+    yield* _batchEvents(() => datagramChannelNextBatch(channel: channel));
+  } finally {
+    await datagramChannelClose(channel: channel);
+  }
+}
+
+Stream<QuicDatagramEvent> _batchEvents(
+  Future<QuicDatagramBatch> Function() nextBatch,
+) async* {
+  while (true) {
+    final batch = await nextBatch();
+
+    var dropped = batch.dropped.toInt();
+    for (final datagram in batch.datagrams) {
+      yield QuicDatagramReceived(datagram, droppedBefore: dropped);
+      dropped = 0;
+    }
+
+    final end = batch.end;
+    if (end != null) {
+      yield QuicDatagramStreamEnded(end);
+      return;
+    }
+  }
+}
@@ -0,0 +1,59 @@
+import 'dart:async';
+
+import 'rust/api/bridge.dart';
+import 'rust/core/connection.dart';
+import 'rust/convenience/file_transfer.dart';
+
+/// Upload the file at [path] and report its progress as a stream
+///
+/// The stream emits progress whenever the receiver acknowledges a chunk and
+/// closes once the receiver verified the file's SHA-256 digest. A failed
+/// upload is delivered as a `QuicFileTransferException` error; after a
+/// `Connection` error, uploading the same file again on a new connection
+/// resumes it. Cancelling the subscription cancels the upload.
+///
+/// ```dart
+/// await for (final progress in quicUploadFile(connection, '/storage/video.mp4')) {
+///   bar.value = progress.bytesTransferred / progress.totalBytes;
+/// }
+/// ```
+Stream<QuicFileTransferProgress> quicUploadFile(
+  QuicConnection connection,
+  String path, {
+  QuicFileTransferConfig? config,
+}) {
+  late final StreamController<QuicFileTransferProgress> controller;
+  QuicFileUpload? upload;
+  var done = false;
+
+  controller = StreamController(
+    onListen: () async {
+      try {
+        final started = await fileUploadStart(
+          connection: connection,
+          path: path,
+          config: config ?? await fileTransferConfigNew(),
+          onProgress: (progress) {
+            if (!controller.isClosed) controller.add(progress);
+          },
+        );
+        upload = started;
+        await fileUploadWait(upload: started);
+      } catch (error, stackTrace) {
+        if (!controller.isClosed) controller.addError(error, stackTrace);
+      } finally {
+        done = true;
+        upload?.dispose();
+        await controller.close();
+      }
+    },
+    onCancel: () async {
+      final started = upload;
+      if (started != null && !done) {
+        await fileUploadCancel(upload: started);
+      }
+    },
+  );
+
+  return controller.stream;
+}
@@ -0,0 +1,24 @@
+import 'dart:async';
+import 'dart:typed_data';
+
+import 'rust/api/bridge.dart';
+import 'rust/convenience/framing.dart';
+
+/// Receive the messages of [stream] as a Dart stream
+///
+/// The stream closes once the peer finishes its send half. A truncated or
+/// oversized frame is delivered as a `QuicFrameException` error, after which
+/// the stream closes. Messages are only read while the subscription is active.
+///
+/// ```dart
+/// await for (final message in quicFramedMessages(framed)) {
+///   handle(utf8.decode(message));
+/// }
+/// ```
+Stream<Uint8List> quicFramedMessages(QuicFramedStream stream) async* {
+  while (true) {
+    final message = await framedStreamRecv(stream: stream);
+    if (message == null) return;
+    yield message;
+  }
+}
@@ -0,0 +1,87 @@
+import 'dart:async';
+import 'dart:typed_data';
+
+import 'rust/api/bridge.dart';
+import 'rust/convenience/http3.dart';
+
+/// A response to a request started with [sendHttp3StreamingRequest]
+class Http3StreamingResponse {
+  Http3StreamingResponse._(this.client, this.stream, this.status, this.headers);
+
+  /// The client that was passed in; use this one for further requests
+  final Http3Client client;
+
+  /// Handle to the response stream
+  final Http3ResponseStream stream;
+
+  /// Response status code
+  final int status;
+
+  /// Response header fields
+  final List<Http3Header> headers;
+
+  /// Response body chunks
+  ///
+  /// Chunks are only read from the QUIC stream while the subscription is
+  /// active, and cancelling it cancels the request. Listen at most once.
+  Stream<Uint8List> get body async* {
+    var done = false;
+    try {
+      while (true) {
+        final chunk = await http3ResponseStreamNextChunk(response: stream);
+        if (chunk == null) break;
+        yield chunk;
+      }
+      done = true;
+    } finally {
+      if (!done) await cancel();
+    }
+  }
+
+  /// Trailer fields, available once [body] has been read to the end
+  Future<List<Http3Header>> trailers() =>
+      http3ResponseStreamTrailers(response: stream);
+
+  /// Cancel the request and reset the QUIC stream
+  Future<void> cancel() => http3ResponseStreamCancel(response: stream);
+}
+
+/// Send [request] with [body] as its streamed request body
+///
+/// Completes once the response headers arrive; the body is uploaded in the
+/// background, pulling chunks only as fast as the connection accepts them.
+/// `request.body` is ignored.
+///
+/// ```dart
+/// final response = await sendHttp3StreamingRequest(
+///   client: client,
+///   request: await http3RequestNew(method: 'PUT', url: 'https://127.0.0.1:4433/upload'),
+///   body: file.openRead().map(Uint8List.fromList),
+/// );
+/// await response.body.pipe(output);
+/// ```
+Future<Http3StreamingResponse> sendHttp3StreamingRequest({
+  required Http3Client client,
+  required Http3Request request,
+  required Stream<Uint8List> body,
+}) async {
+  final chunks = StreamIterator<Uint8List>(body);
+
+  final (Http3Client nextClient, Http3ResponseStream stream) =
+      await http3ClientSendStreaming(
+        client: client,
+        request: request,
+        nextBodyChunk: () async {
+          if (await chunks.moveNext()) return chunks.current;
+          await chunks.cancel();
+          return null;
+        },
+      );
+
+  return Http3StreamingResponse._(
+    nextClient,
+    stream,
+    await http3ResponseStreamStatus(response: stream),
+    await http3ResponseStreamHeaders(response: stream),
+  );
+}
@@ -0,0 +1,34 @@
+import 'dart:async';
+
+import 'rust/api/bridge.dart';
+import 'rust/convenience/pubsub.dart';
+
+/// Receive the messages of [subscription] as a Dart stream
+///
+/// The stream closes once the subscription ends, either by unsubscribing or
+/// because the connection closed. Cancelling the stream unsubscribes. To
+/// resume after a reconnect, subscribe again with `resumeAfter` set to
+/// `pubSubSubscriptionLastSequence` of the old subscription.
+///
+/// ```dart
+/// await for (final message in quicPubSubMessages(subscription)) {
+///   updateChart(message.sequence, message.payload);
+/// }
+/// ```
+Stream<QuicPubSubMessage> quicPubSubMessages(
+  QuicPubSubSubscription subscription,
+) async* {
+  var done = false;
+  try {
+    while (true) {
+      final message = await pubSubSubscriptionNextMessage(
+        subscription: subscription,
+      );
+      if (message == null) break;
+      yield message;
+    }
+    done = true;
+  } finally {
+    if (!done) await pubSubSubscriptionUnsubscribe(subscription: subscription);
+  }
+}
@@ -0,0 +1,47 @@
+import 'dart:async';
+import 'dart:typed_data';
+
+import 'rust/api/bridge.dart';
+import 'rust/convenience/rpc.dart';
+
+/// Make a server-streaming call and receive its response messages as a stream
+///
+/// The stream closes once the server completes the call successfully; a
+/// failed call is delivered as a [QuicRpcStatus] error. Messages are only
+/// read while the subscription is active, and cancelling it cancels the call.
+///
+/// ```dart
+/// await for (final update in quicRpcServerStream(
+///   client: client,
+///   method: 'prices.watch',
+///   payload: utf8.encode('ACME'),
+/// )) {
+///   handle(update);
+/// }
+/// ```
+Stream<Uint8List> quicRpcServerStream({
+  required QuicRpcClient client,
+  required String method,
+  required List<int> payload,
+  QuicRpcCallOptions? options,
+}) async* {
+  final call = await rpcClientCallStreaming(
+    client: client,
+    method: method,
+    payload: payload,
+    options: options ?? await rpcCallOptionsNew(),
+  );
+
+  var done = false;
+  try {
+    while (true) {
+      final message = await rpcCallNextMessage(call: call);
+      if (message == null) break;
+      yield message;
+    }
+    done = true;
+  } finally {
+    if (!done) await rpcCallCancel(call: call);
+    call.dispose();
+  }
+}
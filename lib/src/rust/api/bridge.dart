@@ -4,20 +4,61 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../convenience/client.dart';
+import '../convenience/datagram_channel.dart';
+import '../convenience/file_transfer.dart';
+import '../convenience/framing.dart';
+import '../convenience/http3.dart';
+import '../convenience/http3_server.dart';
+import '../convenience/pool.dart';
+import '../convenience/pubsub.dart';
+import '../convenience/reassembly.dart';
+import '../convenience/rpc.dart';
+import '../convenience/server.dart';
+import '../convenience/streaming.dart';
+import '../convenience/webtransport.dart';
+import '../core/cert_resolver.dart';
 import '../core/config.dart';
 import '../core/connection.dart';
+import '../core/datagram.dart';
 import '../core/endpoint.dart';
+import '../core/keylog.dart';
 import '../core/stream.dart';
 import '../errors.dart';
 import '../frb_generated.dart';
+import '../logging.dart';
+import '../models/certificate.dart';
 import '../models/types.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
+// These functions are ignored because they are not marked as `pub`: `forward_to_dart`
+
 /// Create a new QUIC client endpoint
 Future<QuicEndpoint> createClientEndpoint() =>
     RustLib.instance.api.crateApiBridgeCreateClientEndpoint();
 
+/// Create a new QUIC client endpoint that logs TLS secrets to `key_log`
+///
+/// The endpoint is driven by the bridge's runtime, so it stays usable after
+/// this call returns.
+Future<QuicEndpoint> createClientEndpointWithKeyLog({
+  required QuicKeyLog keyLog,
+}) => RustLib.instance.api.crateApiBridgeCreateClientEndpointWithKeyLog(
+  keyLog: keyLog,
+);
+
+/// Create a new QUIC client endpoint that offers `alpn_protocols` during the handshake
+///
+/// Use `[utf8.encode('h3')]` for WebTransport sessions.
+Future<QuicEndpoint> createClientEndpointWithAlpn({
+  required List<Uint8List> alpnProtocols,
+}) => RustLib.instance.api.crateApiBridgeCreateClientEndpointWithAlpn(
+  alpnProtocols: alpnProtocols,
+);
+
 /// Create a new QUIC server endpoint
+///
+/// The endpoint is driven by the bridge's runtime, so it keeps accepting
+/// connections after this call returns.
 Future<QuicEndpoint> createServerEndpoint({
   required QuicServerConfig config,
   required String addr,
@@ -46,11 +87,47 @@ Future<QuicSendStream> sendStreamWriteAll({
   data: data,
 );
 
+/// Write several buffers to a QUIC send stream without concatenating them
+/// This exposes the QuicSendStream.write_chunks() method to flutter_rust_bridge
+Future<(QuicSendStream, QuicChunksWritten)> sendStreamWriteChunks({
+  required QuicSendStream stream,
+  required List<Uint8List> chunks,
+}) => RustLib.instance.api.crateApiBridgeSendStreamWriteChunks(
+  stream: stream,
+  chunks: chunks,
+);
+
+/// Write several buffers to a QUIC send stream in their entirety
+/// This exposes the QuicSendStream.write_all_chunks() method to flutter_rust_bridge
+Future<QuicSendStream> sendStreamWriteAllChunks({
+  required QuicSendStream stream,
+  required List<Uint8List> chunks,
+}) => RustLib.instance.api.crateApiBridgeSendStreamWriteAllChunks(
+  stream: stream,
+  chunks: chunks,
+);
+
+/// Get write-side statistics of a QUIC send stream
+/// This exposes the QuicSendStream.stats() method to flutter_rust_bridge
+Future<(QuicSendStream, QuicSendStreamStats)> sendStreamStats({
+  required QuicSendStream stream,
+}) => RustLib.instance.api.crateApiBridgeSendStreamStats(stream: stream);
+
 /// Finish a QUIC send stream
 /// This exposes the QuicSendStream.finish() method to flutter_rust_bridge
 Future<QuicSendStream> sendStreamFinish({required QuicSendStream stream}) =>
     RustLib.instance.api.crateApiBridgeSendStreamFinish(stream: stream);
 
+/// Reset a QUIC send stream, abandoning any unsent data
+/// This exposes the QuicSendStream.reset() method to flutter_rust_bridge
+Future<QuicSendStream> sendStreamReset({
+  required QuicSendStream stream,
+  required int errorCode,
+}) => RustLib.instance.api.crateApiBridgeSendStreamReset(
+  stream: stream,
+  errorCode: errorCode,
+);
+
 /// Read data from a QUIC recv stream
 /// This exposes the QuicRecvStream.read() method to flutter_rust_bridge
 Future<(QuicRecvStream, Uint8List?)> recvStreamRead({
@@ -61,6 +138,28 @@ Future<(QuicRecvStream, Uint8List?)> recvStreamRead({
   maxLength: maxLength,
 );
 
+/// Read the next chunk of a QUIC recv stream together with its stream offset
+/// This exposes the QuicRecvStream.read_chunk() method to flutter_rust_bridge
+Future<(QuicRecvStream, QuicStreamChunk?)> recvStreamReadChunk({
+  required QuicRecvStream stream,
+  required BigInt maxLength,
+  required bool ordered,
+}) => RustLib.instance.api.crateApiBridgeRecvStreamReadChunk(
+  stream: stream,
+  maxLength: maxLength,
+  ordered: ordered,
+);
+
+/// Read every chunk already available on a QUIC recv stream, in stream order
+/// This exposes the QuicRecvStream.read_chunks() method to flutter_rust_bridge
+Future<(QuicRecvStream, List<QuicStreamChunk>?)> recvStreamReadChunks({
+  required QuicRecvStream stream,
+  required BigInt maxChunks,
+}) => RustLib.instance.api.crateApiBridgeRecvStreamReadChunks(
+  stream: stream,
+  maxChunks: maxChunks,
+);
+
 /// Read all remaining data from a QUIC recv stream
 /// This exposes the QuicRecvStream.read_to_end() method to flutter_rust_bridge
 Future<(QuicRecvStream, Uint8List)> recvStreamReadToEnd({
@@ -86,6 +185,22 @@ Future<(QuicConnection, QuicSendStream)> connectionOpenUni({
   connection: connection,
 );
 
+/// Accept a bidirectional stream opened by the peer
+/// This exposes the QuicConnection.accept_bi() method to flutter_rust_bridge
+Future<(QuicConnection, QuicSendStream, QuicRecvStream)> connectionAcceptBi({
+  required QuicConnection connection,
+}) => RustLib.instance.api.crateApiBridgeConnectionAcceptBi(
+  connection: connection,
+);
+
+/// Accept a unidirectional stream opened by the peer
+/// This exposes the QuicConnection.accept_uni() method to flutter_rust_bridge
+Future<(QuicConnection, QuicRecvStream)> connectionAcceptUni({
+  required QuicConnection connection,
+}) => RustLib.instance.api.crateApiBridgeConnectionAcceptUni(
+  connection: connection,
+);
+
 /// Connect to a server using a QUIC endpoint
 /// This exposes the QuicEndpoint.connect() method to flutter_rust_bridge
 Future<(QuicEndpoint, QuicConnection)> endpointConnect({
@@ -98,6 +213,22 @@ Future<(QuicEndpoint, QuicConnection)> endpointConnect({
   serverName: serverName,
 );
 
+/// Accept the next incoming connection on a server endpoint
+/// This exposes the QuicEndpoint.accept() method to flutter_rust_bridge
+Future<(QuicEndpoint, QuicConnection?)> endpointAccept({
+  required QuicEndpoint endpoint,
+}) => RustLib.instance.api.crateApiBridgeEndpointAccept(endpoint: endpoint);
+
+/// Replace the server config used for new incoming connections
+/// This exposes the QuicEndpoint.set_server_config() method to flutter_rust_bridge
+Future<QuicEndpoint> endpointSetServerConfig({
+  required QuicEndpoint endpoint,
+  required QuicServerConfig config,
+}) => RustLib.instance.api.crateApiBridgeEndpointSetServerConfig(
+  endpoint: endpoint,
+  config: config,
+);
+
 /// Send a datagram on a QUIC connection
 /// This exposes the QuicConnection.send_datagram() method to flutter_rust_bridge
 Future<QuicConnection> connectionSendDatagram({
@@ -126,6 +257,144 @@ Future<(QuicConnection, Uint8List?)> connectionReadDatagram({
   connection: connection,
 );
 
+/// Create a new QuicDatagramSubscriptionConfig with default values
+Future<QuicDatagramSubscriptionConfig> datagramSubscriptionConfigNew() =>
+    RustLib.instance.api.crateApiBridgeDatagramSubscriptionConfigNew();
+
+/// Receive datagrams in the background into a bounded queue
+/// This exposes the QuicConnection.subscribe_datagrams() method to flutter_rust_bridge
+///
+/// The connection is borrowed, so the caller keeps using it while subscribed.
+Future<QuicDatagramSubscription> connectionSubscribeDatagrams({
+  required QuicConnection connection,
+  required QuicDatagramSubscriptionConfig config,
+}) => RustLib.instance.api.crateApiBridgeConnectionSubscribeDatagrams(
+  connection: connection,
+  config: config,
+);
+
+/// Wait for datagrams and take everything a subscription has queued
+Future<QuicDatagramBatch> datagramSubscriptionNextBatch({
+  required QuicDatagramSubscription subscription,
+}) => RustLib.instance.api.crateApiBridgeDatagramSubscriptionNextBatch(
+  subscription: subscription,
+);
+
+/// Stop a datagram subscription
+Future<void> datagramSubscriptionClose({
+  required QuicDatagramSubscription subscription,
+}) => RustLib.instance.api.crateApiBridgeDatagramSubscriptionClose(
+  subscription: subscription,
+);
+
+/// Create a new QuicDatagramChannelConfig with default values
+Future<QuicDatagramChannelConfig> datagramChannelConfigNew() =>
+    RustLib.instance.api.crateApiBridgeDatagramChannelConfigNew();
+
+/// Route a connection's datagrams to channels by flow ID
+Future<(QuicConnection, QuicDatagramMux)> connectionDatagramMux({
+  required QuicConnection connection,
+  required QuicDatagramChannelConfig config,
+}) => RustLib.instance.api.crateApiBridgeConnectionDatagramMux(
+  connection: connection,
+  config: config,
+);
+
+/// Open the datagram channel for a flow ID
+Future<QuicDatagramChannel> datagramMuxOpenChannel({
+  required QuicDatagramMux mux,
+  required BigInt flowId,
+}) => RustLib.instance.api.crateApiBridgeDatagramMuxOpenChannel(
+  mux: mux,
+  flowId: flowId,
+);
+
+/// Get the flow ID of a datagram channel
+Future<BigInt> datagramChannelFlowId({required QuicDatagramChannel channel}) =>
+    RustLib.instance.api.crateApiBridgeDatagramChannelFlowId(channel: channel);
+
+/// Get the largest payload that fits a single datagram on a channel
+Future<BigInt?> datagramChannelMaxPayloadSize({
+  required QuicDatagramChannel channel,
+}) => RustLib.instance.api.crateApiBridgeDatagramChannelMaxPayloadSize(
+  channel: channel,
+);
+
+/// Send a payload on a datagram channel, fragmenting it if enabled
+Future<void> datagramChannelSend({
+  required QuicDatagramChannel channel,
+  required List<int> data,
+}) => RustLib.instance.api.crateApiBridgeDatagramChannelSend(
+  channel: channel,
+  data: data,
+);
+
+/// Wait for payloads and take everything queued on a datagram channel
+Future<QuicDatagramBatch> datagramChannelNextBatch({
+  required QuicDatagramChannel channel,
+}) => RustLib.instance.api.crateApiBridgeDatagramChannelNextBatch(
+  channel: channel,
+);
+
+/// Close a datagram channel and free its flow ID
+Future<void> datagramChannelClose({required QuicDatagramChannel channel}) =>
+    RustLib.instance.api.crateApiBridgeDatagramChannelClose(channel: channel);
+
+/// Wrap a stream pair to send and receive length-prefixed messages
+Future<QuicFramedStream> framedStreamNew({
+  QuicSendStream? send,
+  QuicRecvStream? recv,
+  required BigInt maxMessageSize,
+}) => RustLib.instance.api.crateApiBridgeFramedStreamNew(
+  send: send,
+  recv: recv,
+  maxMessageSize: maxMessageSize,
+);
+
+/// Send one message on a framed stream
+Future<void> framedStreamSend({
+  required QuicFramedStream stream,
+  required List<int> message,
+}) => RustLib.instance.api.crateApiBridgeFramedStreamSend(
+  stream: stream,
+  message: message,
+);
+
+/// Receive the next message from a framed stream, or `None` at the end of the stream
+Future<Uint8List?> framedStreamRecv({required QuicFramedStream stream}) =>
+    RustLib.instance.api.crateApiBridgeFramedStreamRecv(stream: stream);
+
+/// Finish the send half of a framed stream
+Future<void> framedStreamFinish({required QuicFramedStream stream}) =>
+    RustLib.instance.api.crateApiBridgeFramedStreamFinish(stream: stream);
+
+/// Create a reassembler that puts unordered stream chunks back in order
+Future<QuicStreamReassembler> streamReassemblerNew() =>
+    RustLib.instance.api.crateApiBridgeStreamReassemblerNew();
+
+/// Add an unordered chunk and get the data that became contiguous
+Future<Uint8List> streamReassemblerPush({
+  required QuicStreamReassembler reassembler,
+  required QuicStreamChunk chunk,
+}) => RustLib.instance.api.crateApiBridgeStreamReassemblerPush(
+  reassembler: reassembler,
+  chunk: chunk,
+);
+
+/// Get the stream offset up to which a reassembler has released data
+Future<BigInt> streamReassemblerContiguousOffset({
+  required QuicStreamReassembler reassembler,
+}) => RustLib.instance.api.crateApiBridgeStreamReassemblerContiguousOffset(
+  reassembler: reassembler,
+);
+
+/// Get the number of bytes a reassembler holds until a gap is filled
+Future<BigInt> streamReassemblerBufferedBytes({
+  required QuicStreamReassembler reassembler,
+}) => RustLib.instance.api.crateApiBridgeStreamReassemblerBufferedBytes(
+  reassembler: reassembler,
+);
+
 /// Get datagram send buffer space
 /// This exposes the QuicConnection.datagram_send_buffer_space() method to flutter_rust_bridge
 Future<(QuicConnection, BigInt)> connectionDatagramSendBufferSpace({
@@ -198,6 +467,209 @@ Future<QuicServerConfig> serverConfigWithSingleCert({
   key: key,
 );
 
+/// Create a new server config from a loaded certificate chain and private key
+Future<QuicServerConfig> serverConfigWithCertificate({
+  required CertificateChain certChain,
+  required PrivateKey key,
+}) => RustLib.instance.api.crateApiBridgeServerConfigWithCertificate(
+  certChain: certChain,
+  key: key,
+);
+
+/// Load a certificate chain from PEM data
+Future<CertificateChain> certificateChainFromPem({required List<int> pem}) =>
+    RustLib.instance.api.crateApiBridgeCertificateChainFromPem(pem: pem);
+
+/// Load a PKCS#8, SEC1 or RSA private key from PEM data
+Future<PrivateKey> privateKeyFromPem({required List<int> pem}) =>
+    RustLib.instance.api.crateApiBridgePrivateKeyFromPem(pem: pem);
+
+/// Load the certificate chain and private key from a password protected PKCS#12 bundle
+Future<(CertificateChain, PrivateKey)> loadPkcs12({
+  required List<int> data,
+  required String password,
+}) => RustLib.instance.api.crateApiBridgeLoadPkcs12(
+  data: data,
+  password: password,
+);
+
+/// Create a new server config that picks certificates by SNI from `resolver`
+Future<QuicServerConfig> serverConfigWithCertResolver({
+  required QuicCertificateResolver resolver,
+  required List<Uint8List> alpnProtocols,
+}) => RustLib.instance.api.crateApiBridgeServerConfigWithCertResolver(
+  resolver: resolver,
+  alpnProtocols: alpnProtocols,
+);
+
+/// Set the transport config used for incoming connections
+Future<QuicServerConfig> serverConfigTransportConfig({
+  required QuicServerConfig config,
+  required QuicTransportConfig transportConfig,
+}) => RustLib.instance.api.crateApiBridgeServerConfigTransportConfig(
+  config: config,
+  transportConfig: transportConfig,
+);
+
+/// Set the maximum number of incoming connections buffered before they are accepted
+Future<QuicServerConfig> serverConfigMaxIncoming({
+  required QuicServerConfig config,
+  required BigInt maxIncoming,
+}) => RustLib.instance.api.crateApiBridgeServerConfigMaxIncoming(
+  config: config,
+  maxIncoming: maxIncoming,
+);
+
+/// Set how long retry tokens remain valid
+Future<QuicServerConfig> serverConfigRetryTokenLifetime({
+  required QuicServerConfig config,
+  required BigInt lifetimeMs,
+}) => RustLib.instance.api.crateApiBridgeServerConfigRetryTokenLifetime(
+  config: config,
+  lifetimeMs: lifetimeMs,
+);
+
+/// Allow or forbid clients to migrate to a new address
+Future<QuicServerConfig> serverConfigMigration({
+  required QuicServerConfig config,
+  required bool allow,
+}) => RustLib.instance.api.crateApiBridgeServerConfigMigration(
+  config: config,
+  allow: allow,
+);
+
+/// Set the IPv4 preferred address advertised to clients, e.g. "203.0.113.1:443"
+Future<QuicServerConfig> serverConfigPreferredAddressV4({
+  required QuicServerConfig config,
+  String? addr,
+}) => RustLib.instance.api.crateApiBridgeServerConfigPreferredAddressV4(
+  config: config,
+  addr: addr,
+);
+
+/// Set the IPv6 preferred address advertised to clients, e.g. "[2001:db8::1]:443"
+Future<QuicServerConfig> serverConfigPreferredAddressV6({
+  required QuicServerConfig config,
+  String? addr,
+}) => RustLib.instance.api.crateApiBridgeServerConfigPreferredAddressV6(
+  config: config,
+  addr: addr,
+);
+
+/// Set the maximum number of bytes buffered for a single incoming connection
+Future<QuicServerConfig> serverConfigIncomingBufferSize({
+  required QuicServerConfig config,
+  required BigInt size,
+}) => RustLib.instance.api.crateApiBridgeServerConfigIncomingBufferSize(
+  config: config,
+  size: size,
+);
+
+/// Set the maximum number of bytes buffered across all incoming connections
+Future<QuicServerConfig> serverConfigIncomingBufferSizeTotal({
+  required QuicServerConfig config,
+  required BigInt size,
+}) => RustLib.instance.api.crateApiBridgeServerConfigIncomingBufferSizeTotal(
+  config: config,
+  size: size,
+);
+
+/// Require clients to prove their address with a Retry before they are accepted
+Future<QuicServerConfig> serverConfigUseRetry({
+  required QuicServerConfig config,
+  required bool enabled,
+}) => RustLib.instance.api.crateApiBridgeServerConfigUseRetry(
+  config: config,
+  enabled: enabled,
+);
+
+/// Whether endpoints created from the config validate client addresses with a Retry
+Future<(QuicServerConfig, bool)> serverConfigRetryEnabled({
+  required QuicServerConfig config,
+}) =>
+    RustLib.instance.api.crateApiBridgeServerConfigRetryEnabled(config: config);
+
+/// Create an empty SNI certificate resolver
+Future<QuicCertificateResolver> certResolverNew() =>
+    RustLib.instance.api.crateApiBridgeCertResolverNew();
+
+/// Add or replace the certificate served for `server_name`
+Future<QuicCertificateResolver> certResolverAdd({
+  required QuicCertificateResolver resolver,
+  required String serverName,
+  required CertificateChain certChain,
+  required PrivateKey key,
+}) => RustLib.instance.api.crateApiBridgeCertResolverAdd(
+  resolver: resolver,
+  serverName: serverName,
+  certChain: certChain,
+  key: key,
+);
+
+/// Stop serving a certificate for `server_name`
+Future<(QuicCertificateResolver, bool)> certResolverRemove({
+  required QuicCertificateResolver resolver,
+  required String serverName,
+}) => RustLib.instance.api.crateApiBridgeCertResolverRemove(
+  resolver: resolver,
+  serverName: serverName,
+);
+
+/// Set the certificate used when no registered server name matches
+Future<QuicCertificateResolver> certResolverSetDefault({
+  required QuicCertificateResolver resolver,
+  required CertificateChain certChain,
+  required PrivateKey key,
+}) => RustLib.instance.api.crateApiBridgeCertResolverSetDefault(
+  resolver: resolver,
+  certChain: certChain,
+  key: key,
+);
+
+/// Generate a self-signed certificate for the given DNS names and IP addresses
+Future<SelfSignedCertificate> generateSelfSignedCertificate({
+  required List<String> subjectAltNames,
+  required int validityDays,
+  required CertificateKeyAlgorithm algorithm,
+}) => RustLib.instance.api.crateApiBridgeGenerateSelfSignedCertificate(
+  subjectAltNames: subjectAltNames,
+  validityDays: validityDays,
+  algorithm: algorithm,
+);
+
+/// Create a new server config with ALPN protocols that logs TLS secrets to `key_log`
+Future<QuicServerConfig> serverConfigWithCryptoAndKeyLog({
+  required List<Uint8List> certChain,
+  required List<int> key,
+  required List<Uint8List> alpnProtocols,
+  required QuicKeyLog keyLog,
+}) => RustLib.instance.api.crateApiBridgeServerConfigWithCryptoAndKeyLog(
+  certChain: certChain,
+  key: key,
+  alpnProtocols: alpnProtocols,
+  keyLog: keyLog,
+);
+
+/// Create a new server config that picks certificates by SNI from `resolver` and logs TLS secrets to `key_log`
+Future<QuicServerConfig> serverConfigWithCertResolverAndKeyLog({
+  required QuicCertificateResolver resolver,
+  required List<Uint8List> alpnProtocols,
+  required QuicKeyLog keyLog,
+}) => RustLib.instance.api.crateApiBridgeServerConfigWithCertResolverAndKeyLog(
+  resolver: resolver,
+  alpnProtocols: alpnProtocols,
+  keyLog: keyLog,
+);
+
+/// Create a key log that appends NSS key log lines to a file (Wireshark compatible)
+Future<QuicKeyLog> keyLogFile({required String path}) =>
+    RustLib.instance.api.crateApiBridgeKeyLogFile(path: path);
+
+/// Create a key log that delivers each NSS key log line to a Dart callback
+Future<QuicKeyLog> keyLogCallback({
+  required FutureOr<void> Function(String) onLine,
+}) => RustLib.instance.api.crateApiBridgeKeyLogCallback(onLine: onLine);
+
 /// Create a new transport config
 Future<QuicTransportConfig> transportConfigNew() =>
     RustLib.instance.api.crateApiBridgeTransportConfigNew();
@@ -206,6 +678,17 @@ Future<QuicTransportConfig> transportConfigNew() =>
 Future<QuicEndpointConfig> endpointConfigNew() =>
     RustLib.instance.api.crateApiBridgeEndpointConfigNew();
 
+/// Forward tracing output from quinn and flutter_quic to a Dart stream
+///
+/// `filter` uses `EnvFilter` directive syntax, e.g. "info,quinn=debug".
+/// Calling this again replaces the stream and the filter.
+Stream<QuicLogRecord> initLogging({required String filter}) =>
+    RustLib.instance.api.crateApiBridgeInitLogging(filter: filter);
+
+/// Change the log filter directives at runtime
+Future<void> setLogFilter({required String filter}) =>
+    RustLib.instance.api.crateApiBridgeSetLogFilter(filter: filter);
+
 Future<void> exposeTypesForFrbGeneration() =>
     RustLib.instance.api.crateApiBridgeExposeTypesForFrbGeneration();
 
@@ -254,6 +737,93 @@ Future<(QuicClient, String)> quicClientSendWithTimeout({
   data: data,
 );
 
+/// Send binary data using QuicClient and return the binary response
+Future<(QuicClient, Uint8List)> quicClientSendBytes({
+  required QuicClient client,
+  required String url,
+  required List<int> data,
+}) => RustLib.instance.api.crateApiBridgeQuicClientSendBytes(
+  client: client,
+  url: url,
+  data: data,
+);
+
+/// Send binary data with timeout using QuicClient
+Future<(QuicClient, Uint8List)> quicClientSendBytesWithTimeout({
+  required QuicClient client,
+  required String url,
+  required List<int> data,
+}) => RustLib.instance.api.crateApiBridgeQuicClientSendBytesWithTimeout(
+  client: client,
+  url: url,
+  data: data,
+);
+
+/// Send a GET request using QuicClient and return the binary response
+Future<(QuicClient, Uint8List)> quicClientGetBytes({
+  required QuicClient client,
+  required String url,
+}) => RustLib.instance.api.crateApiBridgeQuicClientGetBytes(
+  client: client,
+  url: url,
+);
+
+/// Send a POST request with a binary body using QuicClient
+Future<(QuicClient, Uint8List)> quicClientPostBytes({
+  required QuicClient client,
+  required String url,
+  required List<int> data,
+}) => RustLib.instance.api.crateApiBridgeQuicClientPostBytes(
+  client: client,
+  url: url,
+  data: data,
+);
+
+/// Start a streaming request using QuicClient
+///
+/// `next_body_chunk` is called whenever the stream can take more data and returns
+/// `null` at the end of the body. `on_response_chunk` is awaited before the next
+/// response chunk is read, so a slow consumer slows the server down.
+/// `on_progress` events are delivered in order without holding up the transfer.
+Future<(QuicClient, QuicStreamingTransfer)> quicClientSendStreaming({
+  required QuicClient client,
+  required String url,
+  BigInt? uploadLength,
+  BigInt? responseLength,
+  required FutureOr<Uint8List?> Function() nextBodyChunk,
+  required FutureOr<void> Function(Uint8List) onResponseChunk,
+  required FutureOr<void> Function(QuicTransferProgress) onProgress,
+}) => RustLib.instance.api.crateApiBridgeQuicClientSendStreaming(
+  client: client,
+  url: url,
+  uploadLength: uploadLength,
+  responseLength: responseLength,
+  nextBodyChunk: nextBodyChunk,
+  onResponseChunk: onResponseChunk,
+  onProgress: onProgress,
+);
+
+/// Wait for a streaming transfer to finish and return its final progress
+Future<QuicTransferProgress> streamingTransferWait({
+  required QuicStreamingTransfer transfer,
+}) => RustLib.instance.api.crateApiBridgeStreamingTransferWait(
+  transfer: transfer,
+);
+
+/// Cancel a streaming transfer, resetting its QUIC stream
+Future<void> streamingTransferCancel({
+  required QuicStreamingTransfer transfer,
+}) => RustLib.instance.api.crateApiBridgeStreamingTransferCancel(
+  transfer: transfer,
+);
+
+/// Get the current progress of a streaming transfer
+Future<QuicTransferProgress> streamingTransferProgress({
+  required QuicStreamingTransfer transfer,
+}) => RustLib.instance.api.crateApiBridgeStreamingTransferProgress(
+  transfer: transfer,
+);
+
 /// Send a GET request using QuicClient
 Future<(QuicClient, String)> quicClientGet({
   required QuicClient client,
@@ -314,3 +884,649 @@ Future<QuicClientConfig> exposeQuicClientConfigType({
 }) => RustLib.instance.api.crateApiBridgeExposeQuicClientConfigType(
   config: config,
 );
+
+/// Create a new SimpleQuicServerConfig with default values
+Future<SimpleQuicServerConfig> simpleQuicServerConfigNew() =>
+    RustLib.instance.api.crateApiBridgeSimpleQuicServerConfigNew();
+
+/// Bind a SimpleQuicServer with the given TLS configuration
+Future<SimpleQuicServer> simpleQuicServerBind({
+  required QuicServerConfig serverConfig,
+  required String addr,
+  required SimpleQuicServerConfig config,
+}) => RustLib.instance.api.crateApiBridgeSimpleQuicServerBind(
+  serverConfig: serverConfig,
+  addr: addr,
+  config: config,
+);
+
+/// Bind a SimpleQuicServer with a generated self-signed certificate for development
+Future<(SimpleQuicServer, SelfSignedCertificate)>
+simpleQuicServerBindSelfSigned({
+  required String addr,
+  required List<String> subjectAltNames,
+  required SimpleQuicServerConfig config,
+}) => RustLib.instance.api.crateApiBridgeSimpleQuicServerBindSelfSigned(
+  addr: addr,
+  subjectAltNames: subjectAltNames,
+  config: config,
+);
+
+/// Start answering requests with a Dart handler
+///
+/// A handler that throws resets the request stream instead of answering it.
+Future<SimpleQuicServer> simpleQuicServerServe({
+  required SimpleQuicServer server,
+  required FutureOr<Uint8List> Function(Uint8List) handler,
+}) => RustLib.instance.api.crateApiBridgeSimpleQuicServerServe(
+  server: server,
+  handler: handler,
+);
+
+/// Bind an HTTP/3 SimpleQuicServer with a generated self-signed certificate for development
+Future<(SimpleQuicServer, SelfSignedCertificate)>
+simpleQuicServerBindHttp3SelfSigned({
+  required String addr,
+  required List<String> subjectAltNames,
+  required SimpleQuicServerConfig config,
+}) => RustLib.instance.api.crateApiBridgeSimpleQuicServerBindHttp3SelfSigned(
+  addr: addr,
+  subjectAltNames: subjectAltNames,
+  config: config,
+);
+
+/// Start answering HTTP/3 requests with the routes of `router`
+Future<SimpleQuicServer> simpleQuicServerServeHttp3({
+  required SimpleQuicServer server,
+  required Http3Router router,
+}) => RustLib.instance.api.crateApiBridgeSimpleQuicServerServeHttp3(
+  server: server,
+  router: router,
+);
+
+/// Start answering RPC calls with the handlers of `registry`
+Future<SimpleQuicServer> simpleQuicServerServeRpc({
+  required SimpleQuicServer server,
+  required QuicRpcRegistry registry,
+}) => RustLib.instance.api.crateApiBridgeSimpleQuicServerServeRpc(
+  server: server,
+  registry: registry,
+);
+
+/// Start delivering the topics of `broker` to subscribing clients
+///
+/// The broker stays usable for publishing.
+Future<SimpleQuicServer> simpleQuicServerServePubsub({
+  required SimpleQuicServer server,
+  required QuicPubSubBroker broker,
+}) => RustLib.instance.api.crateApiBridgeSimpleQuicServerServePubsub(
+  server: server,
+  broker: broker,
+);
+
+/// Start receiving file uploads into the directory of `receiver`
+Future<SimpleQuicServer> simpleQuicServerServeFiles({
+  required SimpleQuicServer server,
+  required QuicFileReceiver receiver,
+}) => RustLib.instance.api.crateApiBridgeSimpleQuicServerServeFiles(
+  server: server,
+  receiver: receiver,
+);
+
+/// Get the address a SimpleQuicServer is listening on
+Future<(SimpleQuicServer, String)> simpleQuicServerLocalAddr({
+  required SimpleQuicServer server,
+}) => RustLib.instance.api.crateApiBridgeSimpleQuicServerLocalAddr(
+  server: server,
+);
+
+/// Get a snapshot of the SimpleQuicServer counters
+Future<(SimpleQuicServer, SimpleQuicServerStats)> simpleQuicServerStats({
+  required SimpleQuicServer server,
+}) => RustLib.instance.api.crateApiBridgeSimpleQuicServerStats(server: server);
+
+/// Gracefully shut down a SimpleQuicServer
+///
+/// Returns the number of requests abandoned when the shutdown timeout expired.
+Future<(SimpleQuicServer, BigInt)> simpleQuicServerShutdown({
+  required SimpleQuicServer server,
+}) =>
+    RustLib.instance.api.crateApiBridgeSimpleQuicServerShutdown(server: server);
+
+/// Create a new QuicConnectionPoolConfig with default values
+Future<QuicConnectionPoolConfig> connectionPoolConfigNew() =>
+    RustLib.instance.api.crateApiBridgeConnectionPoolConfigNew();
+
+/// Create a connection pool with background idle eviction
+Future<QuicConnectionPool> connectionPoolNew({
+  required QuicConnectionPoolConfig config,
+}) => RustLib.instance.api.crateApiBridgeConnectionPoolNew(config: config);
+
+/// Acquire a pooled connection to `addr`, connecting through `endpoint` if needed
+Future<(QuicConnectionPool, QuicEndpoint, QuicConnection)>
+connectionPoolAcquire({
+  required QuicConnectionPool pool,
+  required QuicEndpoint endpoint,
+  required String addr,
+  required String serverName,
+}) => RustLib.instance.api.crateApiBridgeConnectionPoolAcquire(
+  pool: pool,
+  endpoint: endpoint,
+  addr: addr,
+  serverName: serverName,
+);
+
+/// Return a connection obtained from `connection_pool_acquire`
+Future<(QuicConnectionPool, QuicConnection)> connectionPoolRelease({
+  required QuicConnectionPool pool,
+  required QuicConnection connection,
+}) => RustLib.instance.api.crateApiBridgeConnectionPoolRelease(
+  pool: pool,
+  connection: connection,
+);
+
+/// Remove a connection from the pool without closing it
+Future<(QuicConnectionPool, QuicConnection, bool)> connectionPoolRemove({
+  required QuicConnectionPool pool,
+  required QuicConnection connection,
+}) => RustLib.instance.api.crateApiBridgeConnectionPoolRemove(
+  pool: pool,
+  connection: connection,
+);
+
+/// Close and remove idle connections now, returning how many were evicted
+Future<(QuicConnectionPool, BigInt)> connectionPoolEvictIdle({
+  required QuicConnectionPool pool,
+}) => RustLib.instance.api.crateApiBridgeConnectionPoolEvictIdle(pool: pool);
+
+/// Get connection pool statistics
+Future<(QuicConnectionPool, QuicConnectionPoolStats)> connectionPoolStats({
+  required QuicConnectionPool pool,
+}) => RustLib.instance.api.crateApiBridgeConnectionPoolStats(pool: pool);
+
+/// Remove every connection from the pool
+Future<QuicConnectionPool> connectionPoolClear({
+  required QuicConnectionPool pool,
+}) => RustLib.instance.api.crateApiBridgeConnectionPoolClear(pool: pool);
+
+/// Create a new Http3ClientConfig with default values
+Future<Http3ClientConfig> http3ClientConfigNew() =>
+    RustLib.instance.api.crateApiBridgeHttp3ClientConfigNew();
+
+/// Create an HTTP/3 client
+Future<Http3Client> http3ClientCreate({required Http3ClientConfig config}) =>
+    RustLib.instance.api.crateApiBridgeHttp3ClientCreate(config: config);
+
+/// Create a request without headers, body or trailers
+Future<Http3Request> http3RequestNew({
+  required String method,
+  required String url,
+}) => RustLib.instance.api.crateApiBridgeHttp3RequestNew(
+  method: method,
+  url: url,
+);
+
+/// Send an HTTP/3 request and buffer the whole response
+Future<(Http3Client, Http3Response)> http3ClientSend({
+  required Http3Client client,
+  required Http3Request request,
+}) => RustLib.instance.api.crateApiBridgeHttp3ClientSend(
+  client: client,
+  request: request,
+);
+
+/// Send an HTTP/3 request with a streamed body, returning once the response headers arrive
+///
+/// `next_body_chunk` is called whenever the stream can take more data and returns
+/// `null` at the end of the body.
+Future<(Http3Client, Http3ResponseStream)> http3ClientSendStreaming({
+  required Http3Client client,
+  required Http3Request request,
+  required FutureOr<Uint8List?> Function() nextBodyChunk,
+}) => RustLib.instance.api.crateApiBridgeHttp3ClientSendStreaming(
+  client: client,
+  request: request,
+  nextBodyChunk: nextBodyChunk,
+);
+
+/// Close every connection of an HTTP/3 client
+Future<Http3Client> http3ClientClose({required Http3Client client}) =>
+    RustLib.instance.api.crateApiBridgeHttp3ClientClose(client: client);
+
+/// Get the status code of a streaming HTTP/3 response
+Future<int> http3ResponseStreamStatus({
+  required Http3ResponseStream response,
+}) => RustLib.instance.api.crateApiBridgeHttp3ResponseStreamStatus(
+  response: response,
+);
+
+/// Get the header fields of a streaming HTTP/3 response
+Future<List<Http3Header>> http3ResponseStreamHeaders({
+  required Http3ResponseStream response,
+}) => RustLib.instance.api.crateApiBridgeHttp3ResponseStreamHeaders(
+  response: response,
+);
+
+/// Read the next body chunk of a streaming HTTP/3 response, or `null` at the end
+Future<Uint8List?> http3ResponseStreamNextChunk({
+  required Http3ResponseStream response,
+}) => RustLib.instance.api.crateApiBridgeHttp3ResponseStreamNextChunk(
+  response: response,
+);
+
+/// Read the trailer fields of a streaming HTTP/3 response after its body
+Future<List<Http3Header>> http3ResponseStreamTrailers({
+  required Http3ResponseStream response,
+}) => RustLib.instance.api.crateApiBridgeHttp3ResponseStreamTrailers(
+  response: response,
+);
+
+/// Cancel a streaming HTTP/3 request
+Future<void> http3ResponseStreamCancel({
+  required Http3ResponseStream response,
+}) => RustLib.instance.api.crateApiBridgeHttp3ResponseStreamCancel(
+  response: response,
+);
+
+/// Create an HTTP/3 router without routes
+Future<Http3Router> http3RouterNew() =>
+    RustLib.instance.api.crateApiBridgeHttp3RouterNew();
+
+/// Add a route handled by a Dart callback
+///
+/// `path` matches exactly, or as a prefix when it ends in `*`. A handler that
+/// throws before responding is answered with 500.
+Future<Http3Router> http3RouterRoute({
+  required Http3Router router,
+  required String method,
+  required String path,
+  required FutureOr<void> Function(Http3ServerRequest, Http3ServerStream) handler,
+}) => RustLib.instance.api.crateApiBridgeHttp3RouterRoute(
+  router: router,
+  method: method,
+  path: path,
+  handler: handler,
+);
+
+/// Read the next request body chunk, or `null` at the end of the body
+Future<Uint8List?> http3ServerStreamNextChunk({
+  required Http3ServerStream stream,
+}) => RustLib.instance.api.crateApiBridgeHttp3ServerStreamNextChunk(
+  stream: stream,
+);
+
+/// Read the whole request body, answering 413 if it exceeds `max_request_size`
+Future<Uint8List> http3ServerStreamReadBody({
+  required Http3ServerStream stream,
+}) => RustLib.instance.api.crateApiBridgeHttp3ServerStreamReadBody(
+  stream: stream,
+);
+
+/// Read the request trailer fields after the body
+Future<List<Http3Header>> http3ServerStreamTrailers({
+  required Http3ServerStream stream,
+}) => RustLib.instance.api.crateApiBridgeHttp3ServerStreamTrailers(
+  stream: stream,
+);
+
+/// Send the response status and header fields
+Future<void> http3ServerStreamSendResponse({
+  required Http3ServerStream stream,
+  required int status,
+  required List<Http3Header> headers,
+}) => RustLib.instance.api.crateApiBridgeHttp3ServerStreamSendResponse(
+  stream: stream,
+  status: status,
+  headers: headers,
+);
+
+/// Send a response body chunk
+Future<void> http3ServerStreamSendData({
+  required Http3ServerStream stream,
+  required List<int> chunk,
+}) => RustLib.instance.api.crateApiBridgeHttp3ServerStreamSendData(
+  stream: stream,
+  chunk: chunk,
+);
+
+/// Send the trailer fields, if any, and finish the response
+Future<void> http3ServerStreamFinish({
+  required Http3ServerStream stream,
+  required List<Http3Header> trailers,
+}) => RustLib.instance.api.crateApiBridgeHttp3ServerStreamFinish(
+  stream: stream,
+  trailers: trailers,
+);
+
+/// Send a complete response with a buffered body
+Future<void> http3ServerStreamRespond({
+  required Http3ServerStream stream,
+  required int status,
+  required List<Http3Header> headers,
+  required List<int> body,
+}) => RustLib.instance.api.crateApiBridgeHttp3ServerStreamRespond(
+  stream: stream,
+  status: status,
+  headers: headers,
+  body: body,
+);
+
+/// Open a WebTransport session to an https URL
+Future<(QuicEndpoint, WebTransportSession)> webtransportSessionConnect({
+  required QuicEndpoint endpoint,
+  required String url,
+  required List<Http3Header> headers,
+}) => RustLib.instance.api.crateApiBridgeWebtransportSessionConnect(
+  endpoint: endpoint,
+  url: url,
+  headers: headers,
+);
+
+/// Get the session ID of a WebTransport session
+Future<BigInt> webtransportSessionId({required WebTransportSession session}) =>
+    RustLib.instance.api.crateApiBridgeWebtransportSessionId(session: session);
+
+/// Get the QUIC connection carrying a WebTransport session
+Future<QuicConnection> webtransportSessionConnection({
+  required WebTransportSession session,
+}) => RustLib.instance.api.crateApiBridgeWebtransportSessionConnection(
+  session: session,
+);
+
+/// Open a bidirectional stream within a WebTransport session
+Future<(QuicSendStream, QuicRecvStream)> webtransportSessionOpenBi({
+  required WebTransportSession session,
+}) => RustLib.instance.api.crateApiBridgeWebtransportSessionOpenBi(
+  session: session,
+);
+
+/// Open a unidirectional stream within a WebTransport session
+Future<QuicSendStream> webtransportSessionOpenUni({
+  required WebTransportSession session,
+}) => RustLib.instance.api.crateApiBridgeWebtransportSessionOpenUni(
+  session: session,
+);
+
+/// Accept the next bidirectional stream opened by the server within a WebTransport session
+Future<(QuicSendStream, QuicRecvStream)> webtransportSessionAcceptBi({
+  required WebTransportSession session,
+}) => RustLib.instance.api.crateApiBridgeWebtransportSessionAcceptBi(
+  session: session,
+);
+
+/// Accept the next unidirectional stream opened by the server within a WebTransport session
+Future<QuicRecvStream> webtransportSessionAcceptUni({
+  required WebTransportSession session,
+}) => RustLib.instance.api.crateApiBridgeWebtransportSessionAcceptUni(
+  session: session,
+);
+
+/// Send a datagram within a WebTransport session
+Future<void> webtransportSessionSendDatagram({
+  required WebTransportSession session,
+  required List<int> data,
+}) => RustLib.instance.api.crateApiBridgeWebtransportSessionSendDatagram(
+  session: session,
+  data: data,
+);
+
+/// Receive the next datagram sent within a WebTransport session
+Future<Uint8List> webtransportSessionReadDatagram({
+  required WebTransportSession session,
+}) => RustLib.instance.api.crateApiBridgeWebtransportSessionReadDatagram(
+  session: session,
+);
+
+/// Get the largest datagram payload a WebTransport session can currently send
+Future<BigInt?> webtransportSessionMaxDatagramSize({
+  required WebTransportSession session,
+}) => RustLib.instance.api.crateApiBridgeWebtransportSessionMaxDatagramSize(
+  session: session,
+);
+
+/// Close a WebTransport session with an application error code and reason
+Future<void> webtransportSessionClose({
+  required WebTransportSession session,
+  required int code,
+  required String reason,
+}) => RustLib.instance.api.crateApiBridgeWebtransportSessionClose(
+  session: session,
+  code: code,
+  reason: reason,
+);
+
+/// Wait until a WebTransport session is closed by either side
+Future<WebTransportCloseInfo> webtransportSessionClosed({
+  required WebTransportSession session,
+}) => RustLib.instance.api.crateApiBridgeWebtransportSessionClosed(
+  session: session,
+);
+
+/// Create the default RPC client configuration (1 MiB messages, 30 second deadline)
+Future<QuicRpcClientConfig> rpcClientConfigNew() =>
+    RustLib.instance.api.crateApiBridgeRpcClientConfigNew();
+
+/// Create call options without a deadline override or metadata
+Future<QuicRpcCallOptions> rpcCallOptionsNew() =>
+    RustLib.instance.api.crateApiBridgeRpcCallOptionsNew();
+
+/// Create an RPC client calling the server on the other end of `connection`
+Future<QuicRpcClient> rpcClientNew({
+  required QuicConnection connection,
+  required QuicRpcClientConfig config,
+}) => RustLib.instance.api.crateApiBridgeRpcClientNew(
+  connection: connection,
+  config: config,
+);
+
+/// Make a call expecting exactly one response message
+Future<Uint8List> rpcClientCall({
+  required QuicRpcClient client,
+  required String method,
+  required List<int> payload,
+  required QuicRpcCallOptions options,
+}) => RustLib.instance.api.crateApiBridgeRpcClientCall(
+  client: client,
+  method: method,
+  payload: payload,
+  options: options,
+);
+
+/// Make a call whose response messages are read with `rpc_call_next_message`
+Future<QuicRpcCall> rpcClientCallStreaming({
+  required QuicRpcClient client,
+  required String method,
+  required List<int> payload,
+  required QuicRpcCallOptions options,
+}) => RustLib.instance.api.crateApiBridgeRpcClientCallStreaming(
+  client: client,
+  method: method,
+  payload: payload,
+  options: options,
+);
+
+/// Read the next response message, or `null` once the call completed successfully
+Future<Uint8List?> rpcCallNextMessage({required QuicRpcCall call}) =>
+    RustLib.instance.api.crateApiBridgeRpcCallNextMessage(call: call);
+
+/// Cancel a call, resetting its stream
+Future<void> rpcCallCancel({required QuicRpcCall call}) =>
+    RustLib.instance.api.crateApiBridgeRpcCallCancel(call: call);
+
+/// Create an RPC registry without handlers
+Future<QuicRpcRegistry> rpcRegistryNew() =>
+    RustLib.instance.api.crateApiBridgeRpcRegistryNew();
+
+/// Register a Dart handler that sends responses with `rpc_responder_send`
+///
+/// The returned status ends the call. A handler that throws fails the call
+/// with `Internal`.
+Future<QuicRpcRegistry> rpcRegistryRegister({
+  required QuicRpcRegistry registry,
+  required String method,
+  required FutureOr<QuicRpcStatus> Function(QuicRpcRequest, QuicRpcResponder) handler,
+}) => RustLib.instance.api.crateApiBridgeRpcRegistryRegister(
+  registry: registry,
+  method: method,
+  handler: handler,
+);
+
+/// Register a Dart handler answering with a single response message
+///
+/// A handler that throws fails the call with `Internal`.
+Future<QuicRpcRegistry> rpcRegistryRegisterUnary({
+  required QuicRpcRegistry registry,
+  required String method,
+  required FutureOr<Uint8List> Function(QuicRpcRequest) handler,
+}) => RustLib.instance.api.crateApiBridgeRpcRegistryRegisterUnary(
+  registry: registry,
+  method: method,
+  handler: handler,
+);
+
+/// Send one response message of a call
+Future<void> rpcResponderSend({
+  required QuicRpcResponder responder,
+  required List<int> message,
+}) => RustLib.instance.api.crateApiBridgeRpcResponderSend(
+  responder: responder,
+  message: message,
+);
+
+/// Create the default broker configuration (1024 retained messages per topic, 1 MiB messages)
+Future<QuicPubSubBrokerConfig> pubSubBrokerConfigNew() =>
+    RustLib.instance.api.crateApiBridgePubSubBrokerConfigNew();
+
+/// Create a pub/sub broker without topics
+Future<QuicPubSubBroker> pubSubBrokerNew({
+  required QuicPubSubBrokerConfig config,
+}) => RustLib.instance.api.crateApiBridgePubSubBrokerNew(config: config);
+
+/// Publish a message to the subscribers of `topic`, returning its sequence number
+Future<BigInt> pubSubBrokerPublish({
+  required QuicPubSubBroker broker,
+  required String topic,
+  required List<int> payload,
+}) => RustLib.instance.api.crateApiBridgePubSubBrokerPublish(
+  broker: broker,
+  topic: topic,
+  payload: payload,
+);
+
+/// Get the sequence number of the newest message of `topic`, or 0 if none was published
+Future<BigInt> pubSubBrokerLatestSequence({
+  required QuicPubSubBroker broker,
+  required String topic,
+}) => RustLib.instance.api.crateApiBridgePubSubBrokerLatestSequence(
+  broker: broker,
+  topic: topic,
+);
+
+/// Create default subscribe options: stream delivery of new messages only
+Future<QuicPubSubSubscribeOptions> pubSubSubscribeOptionsNew() =>
+    RustLib.instance.api.crateApiBridgePubSubSubscribeOptionsNew();
+
+/// Open a pub/sub client on a connection to a broker
+Future<(QuicConnection, QuicPubSubClient)> pubSubClientConnect({
+  required QuicConnection connection,
+}) => RustLib.instance.api.crateApiBridgePubSubClientConnect(
+  connection: connection,
+);
+
+/// Subscribe to `topic`
+Future<QuicPubSubSubscription> pubSubClientSubscribe({
+  required QuicPubSubClient client,
+  required String topic,
+  required QuicPubSubSubscribeOptions options,
+}) => RustLib.instance.api.crateApiBridgePubSubClientSubscribe(
+  client: client,
+  topic: topic,
+  options: options,
+);
+
+/// Wait for the next message, or `null` once unsubscribed or the connection closed
+Future<QuicPubSubMessage?> pubSubSubscriptionNextMessage({
+  required QuicPubSubSubscription subscription,
+}) => RustLib.instance.api.crateApiBridgePubSubSubscriptionNextMessage(
+  subscription: subscription,
+);
+
+/// Get the sequence number to resume the subscription after on a new connection
+Future<BigInt> pubSubSubscriptionLastSequence({
+  required QuicPubSubSubscription subscription,
+}) => RustLib.instance.api.crateApiBridgePubSubSubscriptionLastSequence(
+  subscription: subscription,
+);
+
+/// Get the number of requested messages the broker no longer retained when subscribing
+Future<BigInt> pubSubSubscriptionMissed({
+  required QuicPubSubSubscription subscription,
+}) => RustLib.instance.api.crateApiBridgePubSubSubscriptionMissed(
+  subscription: subscription,
+);
+
+/// Get the subscribed topic
+Future<String> pubSubSubscriptionTopic({
+  required QuicPubSubSubscription subscription,
+}) => RustLib.instance.api.crateApiBridgePubSubSubscriptionTopic(
+  subscription: subscription,
+);
+
+/// Stop receiving messages of a subscription's topic
+Future<void> pubSubSubscriptionUnsubscribe({
+  required QuicPubSubSubscription subscription,
+}) => RustLib.instance.api.crateApiBridgePubSubSubscriptionUnsubscribe(
+  subscription: subscription,
+);
+
+/// Create the default file transfer configuration (1 MiB chunks on 4 parallel streams)
+Future<QuicFileTransferConfig> fileTransferConfigNew() =>
+    RustLib.instance.api.crateApiBridgeFileTransferConfigNew();
+
+/// Start uploading the file at `path` to the receiver on the other end of `connection`
+///
+/// `on_progress` is called whenever the receiver acknowledges a chunk. The
+/// connection is borrowed, so the caller keeps using it during the upload.
+Future<QuicFileUpload> fileUploadStart({
+  required QuicConnection connection,
+  required String path,
+  required QuicFileTransferConfig config,
+  required FutureOr<void> Function(QuicFileTransferProgress) onProgress,
+}) => RustLib.instance.api.crateApiBridgeFileUploadStart(
+  connection: connection,
+  path: path,
+  config: config,
+  onProgress: onProgress,
+);
+
+/// Wait for the receiver to verify an uploaded file and return the final progress
+Future<QuicFileTransferProgress> fileUploadWait({
+  required QuicFileUpload upload,
+}) => RustLib.instance.api.crateApiBridgeFileUploadWait(upload: upload);
+
+/// Cancel an upload; the receiver keeps what it acknowledged for a later resume
+Future<void> fileUploadCancel({required QuicFileUpload upload}) =>
+    RustLib.instance.api.crateApiBridgeFileUploadCancel(upload: upload);
+
+/// Get the current progress of an upload
+Future<QuicFileTransferProgress> fileUploadProgress({
+  required QuicFileUpload upload,
+}) => RustLib.instance.api.crateApiBridgeFileUploadProgress(upload: upload);
+
+/// Create a receiver storing uploaded files in `directory`
+Future<QuicFileReceiver> fileReceiverNew({
+  required String directory,
+  required BigInt maxFileSize,
+}) => RustLib.instance.api.crateApiBridgeFileReceiverNew(
+  directory: directory,
+  maxFileSize: maxFileSize,
+);
+
+/// Receive files uploaded on `connection` until it closes
+Future<QuicConnection> fileReceiverServeConnection({
+  required QuicFileReceiver receiver,
+  required QuicConnection connection,
+}) => RustLib.instance.api.crateApiBridgeFileReceiverServeConnection(
+  receiver: receiver,
+  connection: connection,
+);
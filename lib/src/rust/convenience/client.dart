@@ -29,6 +29,9 @@ class QuicClientConfig {
   /// Keep-alive timeout for connections in milliseconds
   final BigInt keepAliveTimeoutMs;
 
+  /// Maximum response size in bytes; larger responses fail the request
+  final BigInt maxResponseSize;
+
   const QuicClientConfig({
     required this.maxConnectionsPerHost,
     required this.connectTimeoutMs,
@@ -36,6 +39,7 @@ class QuicClientConfig {
     required this.retryAttempts,
     required this.retryDelayMs,
     required this.keepAliveTimeoutMs,
+    required this.maxResponseSize,
   });
 
   @override
@@ -45,7 +49,8 @@ class QuicClientConfig {
       requestTimeoutMs.hashCode ^
       retryAttempts.hashCode ^
       retryDelayMs.hashCode ^
-      keepAliveTimeoutMs.hashCode;
+      keepAliveTimeoutMs.hashCode ^
+      maxResponseSize.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -57,5 +62,6 @@ class QuicClientConfig {
           requestTimeoutMs == other.requestTimeoutMs &&
           retryAttempts == other.retryAttempts &&
           retryDelayMs == other.retryDelayMs &&
-          keepAliveTimeoutMs == other.keepAliveTimeoutMs;
+          keepAliveTimeoutMs == other.keepAliveTimeoutMs &&
+          maxResponseSize == other.maxResponseSize;
 }
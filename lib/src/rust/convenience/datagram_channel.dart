@@ -0,0 +1,57 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../core/datagram.dart';
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<QuicDatagramChannel>>
+abstract class QuicDatagramChannel implements RustOpaqueInterface {}
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<QuicDatagramMux>>
+abstract class QuicDatagramMux implements RustOpaqueInterface {}
+
+/// Configuration for datagram channels
+class QuicDatagramChannelConfig {
+  /// Split payloads above the datagram size limit instead of rejecting them
+  final bool fragmentation;
+
+  /// Largest payload accepted for sending or reassembly when fragmenting
+  final BigInt maxMessageSize;
+
+  /// Time after which an incomplete fragmented message is discarded
+  final BigInt reassemblyTimeoutMs;
+
+  /// Maximum number of datagrams held per channel until the application takes them
+  final BigInt channelCapacity;
+  final QuicDatagramDropPolicy dropPolicy;
+
+  const QuicDatagramChannelConfig({
+    required this.fragmentation,
+    required this.maxMessageSize,
+    required this.reassemblyTimeoutMs,
+    required this.channelCapacity,
+    required this.dropPolicy,
+  });
+
+  @override
+  int get hashCode =>
+      fragmentation.hashCode ^
+      maxMessageSize.hashCode ^
+      reassemblyTimeoutMs.hashCode ^
+      channelCapacity.hashCode ^
+      dropPolicy.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QuicDatagramChannelConfig &&
+          runtimeType == other.runtimeType &&
+          fragmentation == other.fragmentation &&
+          maxMessageSize == other.maxMessageSize &&
+          reassemblyTimeoutMs == other.reassemblyTimeoutMs &&
+          channelCapacity == other.channelCapacity &&
+          dropPolicy == other.dropPolicy;
+}
@@ -0,0 +1,69 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<QuicFileReceiver>>
+abstract class QuicFileReceiver implements RustOpaqueInterface {}
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<QuicFileUpload>>
+abstract class QuicFileUpload implements RustOpaqueInterface {}
+
+/// Configuration for QuicFileUpload
+class QuicFileTransferConfig {
+  /// Bytes sent per chunk stream, at most 16 MiB
+  final BigInt chunkSize;
+
+  /// Chunk streams in flight at once
+  final BigInt parallelStreams;
+
+  const QuicFileTransferConfig({
+    required this.chunkSize,
+    required this.parallelStreams,
+  });
+
+  @override
+  int get hashCode => chunkSize.hashCode ^ parallelStreams.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QuicFileTransferConfig &&
+          runtimeType == other.runtimeType &&
+          chunkSize == other.chunkSize &&
+          parallelStreams == other.parallelStreams;
+}
+
+/// Progress of a file upload
+class QuicFileTransferProgress {
+  /// Bytes the receiver holds, including those from earlier attempts
+  final BigInt bytesTransferred;
+
+  /// Size of the file
+  final BigInt totalBytes;
+
+  /// Offset the upload resumed from, 0 for a fresh upload
+  final BigInt resumedFrom;
+
+  const QuicFileTransferProgress({
+    required this.bytesTransferred,
+    required this.totalBytes,
+    required this.resumedFrom,
+  });
+
+  @override
+  int get hashCode =>
+      bytesTransferred.hashCode ^ totalBytes.hashCode ^ resumedFrom.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QuicFileTransferProgress &&
+          runtimeType == other.runtimeType &&
+          bytesTransferred == other.bytesTransferred &&
+          totalBytes == other.totalBytes &&
+          resumedFrom == other.resumedFrom;
+}
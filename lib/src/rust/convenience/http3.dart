@@ -0,0 +1,148 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Http3Client>>
+abstract class Http3Client implements RustOpaqueInterface {}
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Http3ResponseStream>>
+abstract class Http3ResponseStream implements RustOpaqueInterface {}
+
+/// Configuration for Http3Client
+class Http3ClientConfig {
+  /// Connection timeout in milliseconds
+  final BigInt connectTimeoutMs;
+
+  /// Request timeout in milliseconds, covering the whole buffered exchange
+  final BigInt requestTimeoutMs;
+
+  /// Maximum response body size in bytes for buffered requests
+  final BigInt maxResponseSize;
+
+  /// Maximum size of a received header or trailer section in bytes
+  final BigInt maxFieldSectionSize;
+
+  const Http3ClientConfig({
+    required this.connectTimeoutMs,
+    required this.requestTimeoutMs,
+    required this.maxResponseSize,
+    required this.maxFieldSectionSize,
+  });
+
+  @override
+  int get hashCode =>
+      connectTimeoutMs.hashCode ^
+      requestTimeoutMs.hashCode ^
+      maxResponseSize.hashCode ^
+      maxFieldSectionSize.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Http3ClientConfig &&
+          runtimeType == other.runtimeType &&
+          connectTimeoutMs == other.connectTimeoutMs &&
+          requestTimeoutMs == other.requestTimeoutMs &&
+          maxResponseSize == other.maxResponseSize &&
+          maxFieldSectionSize == other.maxFieldSectionSize;
+}
+
+/// A single HTTP header or trailer field
+///
+/// Fields are kept as an ordered list so repeated names such as `set-cookie`
+/// survive the round trip.
+class Http3Header {
+  final String name;
+  final String value;
+
+  const Http3Header({required this.name, required this.value});
+
+  @override
+  int get hashCode => name.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Http3Header &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          value == other.value;
+}
+
+/// An HTTP/3 request
+class Http3Request {
+  /// Request method (e.g., "GET", "POST")
+  final String method;
+
+  /// Absolute https URL (e.g., "https://127.0.0.1:4433/api/items")
+  final String url;
+  final List<Http3Header> headers;
+
+  /// Request body; ignored by `send_streaming`, which takes the body as a stream
+  final Uint8List body;
+
+  /// Trailer fields sent after the body
+  final List<Http3Header> trailers;
+
+  const Http3Request({
+    required this.method,
+    required this.url,
+    required this.headers,
+    required this.body,
+    required this.trailers,
+  });
+
+  @override
+  int get hashCode =>
+      method.hashCode ^
+      url.hashCode ^
+      headers.hashCode ^
+      body.hashCode ^
+      trailers.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Http3Request &&
+          runtimeType == other.runtimeType &&
+          method == other.method &&
+          url == other.url &&
+          headers == other.headers &&
+          body == other.body &&
+          trailers == other.trailers;
+}
+
+/// A buffered HTTP/3 response
+class Http3Response {
+  final int status;
+  final List<Http3Header> headers;
+  final Uint8List body;
+
+  /// Trailer fields, empty if the server sent none
+  final List<Http3Header> trailers;
+
+  const Http3Response({
+    required this.status,
+    required this.headers,
+    required this.body,
+    required this.trailers,
+  });
+
+  @override
+  int get hashCode =>
+      status.hashCode ^ headers.hashCode ^ body.hashCode ^ trailers.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Http3Response &&
+          runtimeType == other.runtimeType &&
+          status == other.status &&
+          headers == other.headers &&
+          body == other.body &&
+          trailers == other.trailers;
+}
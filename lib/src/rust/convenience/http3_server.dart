@@ -0,0 +1,47 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'http3.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Http3Router>>
+abstract class Http3Router implements RustOpaqueInterface {}
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<Http3ServerStream>>
+abstract class Http3ServerStream implements RustOpaqueInterface {}
+
+/// Head of a request received by the HTTP/3 server
+class Http3ServerRequest {
+  final String method;
+
+  /// Request path without the query (e.g., "/api/items")
+  final String path;
+
+  /// Query string without the leading `?`, if any
+  final String? query;
+  final List<Http3Header> headers;
+
+  const Http3ServerRequest({
+    required this.method,
+    required this.path,
+    this.query,
+    required this.headers,
+  });
+
+  @override
+  int get hashCode =>
+      method.hashCode ^ path.hashCode ^ query.hashCode ^ headers.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Http3ServerRequest &&
+          runtimeType == other.runtimeType &&
+          method == other.method &&
+          path == other.path &&
+          query == other.query &&
+          headers == other.headers;
+}
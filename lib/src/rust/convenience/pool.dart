@@ -0,0 +1,125 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<QuicConnectionPool>>
+abstract class QuicConnectionPool implements RustOpaqueInterface {}
+
+/// Pool statistics for a single authority
+class QuicAuthorityPoolStats {
+  final String authority;
+
+  /// Pooled connections, live or not yet pruned
+  final BigInt connections;
+
+  /// Acquired connections not yet released, summed over all connections
+  final BigInt activeStreams;
+
+  const QuicAuthorityPoolStats({
+    required this.authority,
+    required this.connections,
+    required this.activeStreams,
+  });
+
+  @override
+  int get hashCode =>
+      authority.hashCode ^ connections.hashCode ^ activeStreams.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QuicAuthorityPoolStats &&
+          runtimeType == other.runtimeType &&
+          authority == other.authority &&
+          connections == other.connections &&
+          activeStreams == other.activeStreams;
+}
+
+/// Configuration for QuicConnectionPool
+class QuicConnectionPoolConfig {
+  /// Maximum number of connections kept per authority (host:port)
+  final BigInt maxConnectionsPerAuthority;
+
+  /// Number of concurrent users after which a connection counts as saturated
+  ///
+  /// Keep this at or below the server's `max_concurrent_bidi_streams`.
+  final int maxStreamsPerConnection;
+
+  /// Time an unused connection stays pooled, in milliseconds
+  final BigInt idleTimeoutMs;
+
+  /// Interval of the background idle eviction, in milliseconds (0 disables it)
+  final BigInt evictionIntervalMs;
+
+  const QuicConnectionPoolConfig({
+    required this.maxConnectionsPerAuthority,
+    required this.maxStreamsPerConnection,
+    required this.idleTimeoutMs,
+    required this.evictionIntervalMs,
+  });
+
+  @override
+  int get hashCode =>
+      maxConnectionsPerAuthority.hashCode ^
+      maxStreamsPerConnection.hashCode ^
+      idleTimeoutMs.hashCode ^
+      evictionIntervalMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QuicConnectionPoolConfig &&
+          runtimeType == other.runtimeType &&
+          maxConnectionsPerAuthority == other.maxConnectionsPerAuthority &&
+          maxStreamsPerConnection == other.maxStreamsPerConnection &&
+          idleTimeoutMs == other.idleTimeoutMs &&
+          evictionIntervalMs == other.evictionIntervalMs;
+}
+
+/// Snapshot of QuicConnectionPool state and counters
+class QuicConnectionPoolStats {
+  final List<QuicAuthorityPoolStats> authorities;
+
+  /// Acquisitions served by a pooled connection
+  final BigInt hits;
+
+  /// Acquisitions that opened a new connection
+  final BigInt misses;
+
+  /// Connections closed after staying unused for `idle_timeout_ms`
+  final BigInt evictedIdle;
+
+  /// Connections dropped because the peer or the transport had closed them
+  final BigInt evictedDead;
+
+  const QuicConnectionPoolStats({
+    required this.authorities,
+    required this.hits,
+    required this.misses,
+    required this.evictedIdle,
+    required this.evictedDead,
+  });
+
+  @override
+  int get hashCode =>
+      authorities.hashCode ^
+      hits.hashCode ^
+      misses.hashCode ^
+      evictedIdle.hashCode ^
+      evictedDead.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QuicConnectionPoolStats &&
+          runtimeType == other.runtimeType &&
+          authorities == other.authorities &&
+          hits == other.hits &&
+          misses == other.misses &&
+          evictedIdle == other.evictedIdle &&
+          evictedDead == other.evictedDead;
+}
@@ -0,0 +1,113 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<QuicPubSubBroker>>
+abstract class QuicPubSubBroker implements RustOpaqueInterface {}
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<QuicPubSubClient>>
+abstract class QuicPubSubClient implements RustOpaqueInterface {}
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<QuicPubSubSubscription>>
+abstract class QuicPubSubSubscription implements RustOpaqueInterface {}
+
+/// Configuration for QuicPubSubBroker
+class QuicPubSubBrokerConfig {
+  /// Messages kept per topic for resuming subscribers and slow readers
+  final BigInt historySize;
+
+  /// Largest message accepted by `publish`, in bytes
+  final BigInt maxMessageSize;
+
+  const QuicPubSubBrokerConfig({
+    required this.historySize,
+    required this.maxMessageSize,
+  });
+
+  @override
+  int get hashCode => historySize.hashCode ^ maxMessageSize.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QuicPubSubBrokerConfig &&
+          runtimeType == other.runtimeType &&
+          historySize == other.historySize &&
+          maxMessageSize == other.maxMessageSize;
+}
+
+/// How the messages of a subscription are delivered
+enum QuicPubSubDelivery {
+  /// In order on a dedicated stream; a slow subscriber holds up only its own stream
+  stream,
+  /// As datagrams, which may be lost or reordered and must fit a single datagram
+  datagram,
+}
+
+/// A message received on a subscription
+class QuicPubSubMessage {
+  /// Position of the message in its topic, starting at 1
+  ///
+  /// A gap means messages were missed, e.g. because the subscriber fell more
+  /// than `history_size` messages behind or a datagram was lost.
+  final BigInt sequence;
+  final Uint8List payload;
+
+  const QuicPubSubMessage({required this.sequence, required this.payload});
+
+  @override
+  int get hashCode => sequence.hashCode ^ payload.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QuicPubSubMessage &&
+          runtimeType == other.runtimeType &&
+          sequence == other.sequence &&
+          payload == other.payload;
+}
+
+/// Options for `QuicPubSubClient::subscribe`
+class QuicPubSubSubscribeOptions {
+  final QuicPubSubDelivery delivery;
+
+  /// Replay retained messages published after this sequence number;
+  /// `None` delivers only messages published from now on
+  final BigInt? resumeAfter;
+
+  /// Messages held until the application takes them
+  ///
+  /// Stream deliveries then wait for the application, datagrams are dropped.
+  final BigInt bufferCapacity;
+
+  /// Largest message accepted from the broker, in bytes
+  final BigInt maxMessageSize;
+
+  const QuicPubSubSubscribeOptions({
+    required this.delivery,
+    this.resumeAfter,
+    required this.bufferCapacity,
+    required this.maxMessageSize,
+  });
+
+  @override
+  int get hashCode =>
+      delivery.hashCode ^
+      resumeAfter.hashCode ^
+      bufferCapacity.hashCode ^
+      maxMessageSize.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QuicPubSubSubscribeOptions &&
+          runtimeType == other.runtimeType &&
+          delivery == other.delivery &&
+          resumeAfter == other.resumeAfter &&
+          bufferCapacity == other.bufferCapacity &&
+          maxMessageSize == other.maxMessageSize;
+}
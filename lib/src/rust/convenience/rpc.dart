@@ -0,0 +1,157 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<QuicRpcCall>>
+abstract class QuicRpcCall implements RustOpaqueInterface {}
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<QuicRpcClient>>
+abstract class QuicRpcClient implements RustOpaqueInterface {}
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<QuicRpcRegistry>>
+abstract class QuicRpcRegistry implements RustOpaqueInterface {}
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<QuicRpcResponder>>
+abstract class QuicRpcResponder implements RustOpaqueInterface {}
+
+/// Per-call settings
+class QuicRpcCallOptions {
+  /// Time allowed for the whole call in milliseconds, overriding the client default
+  final BigInt? deadlineMs;
+  final List<QuicRpcMetadata> metadata;
+
+  const QuicRpcCallOptions({this.deadlineMs, required this.metadata});
+
+  @override
+  int get hashCode => deadlineMs.hashCode ^ metadata.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QuicRpcCallOptions &&
+          runtimeType == other.runtimeType &&
+          deadlineMs == other.deadlineMs &&
+          metadata == other.metadata;
+}
+
+/// Configuration for QuicRpcClient
+class QuicRpcClientConfig {
+  /// Largest request or response message in bytes
+  final BigInt maxMessageSize;
+
+  /// Deadline for calls without one of their own, in milliseconds
+  final BigInt? defaultDeadlineMs;
+
+  const QuicRpcClientConfig({
+    required this.maxMessageSize,
+    this.defaultDeadlineMs,
+  });
+
+  @override
+  int get hashCode => maxMessageSize.hashCode ^ defaultDeadlineMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QuicRpcClientConfig &&
+          runtimeType == other.runtimeType &&
+          maxMessageSize == other.maxMessageSize &&
+          defaultDeadlineMs == other.defaultDeadlineMs;
+}
+
+/// Outcome of a call, numbered like gRPC status codes
+enum QuicRpcCode {
+  ok,
+  cancelled,
+  unknown,
+  invalidArgument,
+  deadlineExceeded,
+  notFound,
+  alreadyExists,
+  permissionDenied,
+  resourceExhausted,
+  failedPrecondition,
+  aborted,
+  outOfRange,
+  unimplemented,
+  internal,
+  unavailable,
+  dataLoss,
+  unauthenticated,
+}
+
+/// A metadata entry sent with a call
+class QuicRpcMetadata {
+  final String key;
+  final String value;
+
+  const QuicRpcMetadata({required this.key, required this.value});
+
+  @override
+  int get hashCode => key.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QuicRpcMetadata &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          value == other.value;
+}
+
+/// A call received by the server
+class QuicRpcRequest {
+  final String method;
+  final List<QuicRpcMetadata> metadata;
+  final Uint8List payload;
+
+  /// Time left until the caller's deadline when the call arrived, in milliseconds
+  final BigInt? deadlineMs;
+
+  const QuicRpcRequest({
+    required this.method,
+    required this.metadata,
+    required this.payload,
+    this.deadlineMs,
+  });
+
+  @override
+  int get hashCode =>
+      method.hashCode ^
+      metadata.hashCode ^
+      payload.hashCode ^
+      deadlineMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QuicRpcRequest &&
+          runtimeType == other.runtimeType &&
+          method == other.method &&
+          metadata == other.metadata &&
+          payload == other.payload &&
+          deadlineMs == other.deadlineMs;
+}
+
+/// Status a call ended with; every failed call reports one
+class QuicRpcStatus implements FrbException {
+  final QuicRpcCode code;
+  final String message;
+
+  const QuicRpcStatus({required this.code, required this.message});
+
+  @override
+  int get hashCode => code.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QuicRpcStatus &&
+          runtimeType == other.runtimeType &&
+          code == other.code &&
+          message == other.message;
+}
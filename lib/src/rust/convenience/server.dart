@@ -0,0 +1,117 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SimpleQuicServer>>
+abstract class SimpleQuicServer implements RustOpaqueInterface {}
+
+/// Configuration for SimpleQuicServer
+class SimpleQuicServerConfig {
+  /// Maximum number of requests handled at once on a single connection
+  final int maxConcurrentStreamsPerConnection;
+
+  /// Maximum number of requests handled at once across all connections
+  final int maxConcurrentRequests;
+
+  /// Maximum request size in bytes; larger requests are rejected
+  ///
+  /// In HTTP/3 mode this applies to bodies read with `read_body`.
+  final BigInt maxRequestSize;
+
+  /// Time allowed for reading a request and running the handler, in milliseconds
+  ///
+  /// In HTTP/3 mode this covers the whole handler, including streamed bodies.
+  final BigInt requestTimeoutMs;
+
+  /// Time `shutdown` waits for in-flight requests, in milliseconds
+  final BigInt shutdownTimeoutMs;
+
+  const SimpleQuicServerConfig({
+    required this.maxConcurrentStreamsPerConnection,
+    required this.maxConcurrentRequests,
+    required this.maxRequestSize,
+    required this.requestTimeoutMs,
+    required this.shutdownTimeoutMs,
+  });
+
+  @override
+  int get hashCode =>
+      maxConcurrentStreamsPerConnection.hashCode ^
+      maxConcurrentRequests.hashCode ^
+      maxRequestSize.hashCode ^
+      requestTimeoutMs.hashCode ^
+      shutdownTimeoutMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SimpleQuicServerConfig &&
+          runtimeType == other.runtimeType &&
+          maxConcurrentStreamsPerConnection ==
+              other.maxConcurrentStreamsPerConnection &&
+          maxConcurrentRequests == other.maxConcurrentRequests &&
+          maxRequestSize == other.maxRequestSize &&
+          requestTimeoutMs == other.requestTimeoutMs &&
+          shutdownTimeoutMs == other.shutdownTimeoutMs;
+}
+
+/// Snapshot of SimpleQuicServer counters
+class SimpleQuicServerStats {
+  /// Connections accepted since the server was bound
+  final BigInt connectionsAccepted;
+
+  /// Connections currently open
+  final BigInt activeConnections;
+
+  /// Requests answered with a response
+  final BigInt requestsHandled;
+
+  /// Requests rejected, timed out or failed in the handler
+  final BigInt requestsFailed;
+
+  /// Requests currently being read or handled
+  final BigInt activeRequests;
+
+  /// Request bytes received
+  final BigInt bytesReceived;
+
+  /// Response bytes sent
+  final BigInt bytesSent;
+
+  const SimpleQuicServerStats({
+    required this.connectionsAccepted,
+    required this.activeConnections,
+    required this.requestsHandled,
+    required this.requestsFailed,
+    required this.activeRequests,
+    required this.bytesReceived,
+    required this.bytesSent,
+  });
+
+  @override
+  int get hashCode =>
+      connectionsAccepted.hashCode ^
+      activeConnections.hashCode ^
+      requestsHandled.hashCode ^
+      requestsFailed.hashCode ^
+      activeRequests.hashCode ^
+      bytesReceived.hashCode ^
+      bytesSent.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SimpleQuicServerStats &&
+          runtimeType == other.runtimeType &&
+          connectionsAccepted == other.connectionsAccepted &&
+          activeConnections == other.activeConnections &&
+          requestsHandled == other.requestsHandled &&
+          requestsFailed == other.requestsFailed &&
+          activeRequests == other.activeRequests &&
+          bytesReceived == other.bytesReceived &&
+          bytesSent == other.bytesSent;
+}
@@ -0,0 +1,49 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<QuicStreamingTransfer>>
+abstract class QuicStreamingTransfer implements RustOpaqueInterface {}
+
+/// Progress of a streaming transfer
+class QuicTransferProgress {
+  /// Request body bytes written to the stream
+  final BigInt bytesSent;
+
+  /// Response body bytes received so far
+  final BigInt bytesReceived;
+
+  /// Declared request body length, if the caller knew it up front
+  final BigInt? totalToSend;
+
+  /// Response body length, if the caller knew it up front or the response has ended
+  final BigInt? expectedTotalReceived;
+
+  const QuicTransferProgress({
+    required this.bytesSent,
+    required this.bytesReceived,
+    this.totalToSend,
+    this.expectedTotalReceived,
+  });
+
+  @override
+  int get hashCode =>
+      bytesSent.hashCode ^
+      bytesReceived.hashCode ^
+      totalToSend.hashCode ^
+      expectedTotalReceived.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QuicTransferProgress &&
+          runtimeType == other.runtimeType &&
+          bytesSent == other.bytesSent &&
+          bytesReceived == other.bytesReceived &&
+          totalToSend == other.totalToSend &&
+          expectedTotalReceived == other.expectedTotalReceived;
+}
@@ -0,0 +1,30 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<WebTransportSession>>
+abstract class WebTransportSession implements RustOpaqueInterface {}
+
+/// Why a WebTransport session ended
+class WebTransportCloseInfo {
+  /// Application error code from the close capsule, 0 if none was sent
+  final int code;
+  final String reason;
+
+  const WebTransportCloseInfo({required this.code, required this.reason});
+
+  @override
+  int get hashCode => code.hashCode ^ reason.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WebTransportCloseInfo &&
+          runtimeType == other.runtimeType &&
+          code == other.code &&
+          reason == other.reason;
+}
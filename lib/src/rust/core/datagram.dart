@@ -0,0 +1,118 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<QuicDatagramSubscription>>
+abstract class QuicDatagramSubscription implements RustOpaqueInterface {}
+
+/// Why a connection ended
+class QuicConnectionEnd {
+  final QuicConnectionEndKind kind;
+
+  /// Error code sent with the close, for transport and application closes
+  final BigInt? errorCode;
+  final String reason;
+
+  const QuicConnectionEnd({
+    required this.kind,
+    this.errorCode,
+    required this.reason,
+  });
+
+  @override
+  int get hashCode => kind.hashCode ^ errorCode.hashCode ^ reason.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QuicConnectionEnd &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          errorCode == other.errorCode &&
+          reason == other.reason;
+}
+
+/// How a connection ended, mirroring `quinn::ConnectionError`
+enum QuicConnectionEndKind {
+  /// The peer's QUIC version is not supported
+  versionMismatch,
+  /// The peer violated the QUIC specification
+  transportError,
+  /// The peer closed the connection at the transport level
+  connectionClosed,
+  /// The peer's application closed the connection
+  applicationClosed,
+  /// The peer reset the connection
+  reset,
+  /// No packets were received within the idle timeout
+  timedOut,
+  /// The connection was closed locally
+  locallyClosed,
+  /// No connection IDs were left to use
+  cidsExhausted,
+}
+
+/// Datagrams taken from a subscription in one call
+class QuicDatagramBatch {
+  /// Received datagrams, oldest first
+  final List<Uint8List> datagrams;
+
+  /// Datagrams discarded by the drop policy since the previous batch
+  final BigInt dropped;
+
+  /// Why the connection ended, once it has; no datagrams follow this batch
+  final QuicConnectionEnd? end;
+
+  const QuicDatagramBatch({
+    required this.datagrams,
+    required this.dropped,
+    this.end,
+  });
+
+  @override
+  int get hashCode => datagrams.hashCode ^ dropped.hashCode ^ end.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QuicDatagramBatch &&
+          runtimeType == other.runtimeType &&
+          datagrams == other.datagrams &&
+          dropped == other.dropped &&
+          end == other.end;
+}
+
+/// What a datagram subscription does when its queue is full
+enum QuicDatagramDropPolicy {
+  /// Discard the oldest queued datagram to make room for the new one
+  dropOldest,
+  /// Discard the newly received datagram
+  dropNewest,
+}
+
+/// Configuration for a datagram subscription
+class QuicDatagramSubscriptionConfig {
+  /// Maximum number of datagrams held until the application takes them
+  final BigInt capacity;
+  final QuicDatagramDropPolicy dropPolicy;
+
+  const QuicDatagramSubscriptionConfig({
+    required this.capacity,
+    required this.dropPolicy,
+  });
+
+  @override
+  int get hashCode => capacity.hashCode ^ dropPolicy.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QuicDatagramSubscriptionConfig &&
+          runtimeType == other.runtimeType &&
+          capacity == other.capacity &&
+          dropPolicy == other.dropPolicy;
+}
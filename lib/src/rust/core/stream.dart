@@ -11,3 +11,79 @@ abstract class QuicRecvStream implements RustOpaqueInterface {}
 
 // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<QuicSendStream>>
 abstract class QuicSendStream implements RustOpaqueInterface {}
+
+/// How much of a multi-buffer write was accepted by the stream
+class QuicChunksWritten {
+  /// Number of bytes written
+  final BigInt bytes;
+
+  /// Number of buffers written in full
+  final BigInt chunks;
+
+  const QuicChunksWritten({required this.bytes, required this.chunks});
+
+  @override
+  int get hashCode => bytes.hashCode ^ chunks.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QuicChunksWritten &&
+          runtimeType == other.runtimeType &&
+          bytes == other.bytes &&
+          chunks == other.chunks;
+}
+
+/// Write-side statistics of a send stream
+class QuicSendStreamStats {
+  /// Bytes handed to the stream through this wrapper, whether sent yet or not
+  final BigInt bytesQueued;
+
+  /// Whether the stream was finished and the peer acknowledged all of its data
+  ///
+  /// QUIC implementations track acknowledgement per packet, and quinn
+  /// reports it for a stream only as a whole once it is finished.
+  final bool acknowledged;
+
+  /// Error code the peer stopped the stream with, if it did
+  final BigInt? stopErrorCode;
+
+  const QuicSendStreamStats({
+    required this.bytesQueued,
+    required this.acknowledged,
+    this.stopErrorCode,
+  });
+
+  @override
+  int get hashCode =>
+      bytesQueued.hashCode ^ acknowledged.hashCode ^ stopErrorCode.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QuicSendStreamStats &&
+          runtimeType == other.runtimeType &&
+          bytesQueued == other.bytesQueued &&
+          acknowledged == other.acknowledged &&
+          stopErrorCode == other.stopErrorCode;
+}
+
+/// Data received on a stream, with its position in the stream
+class QuicStreamChunk {
+  /// Stream offset of the first byte of `data`
+  final BigInt offset;
+  final Uint8List data;
+
+  const QuicStreamChunk({required this.offset, required this.data});
+
+  @override
+  int get hashCode => offset.hashCode ^ data.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QuicStreamChunk &&
+          runtimeType == other.runtimeType &&
+          offset == other.offset &&
+          data == other.data;
+}
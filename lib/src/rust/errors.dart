@@ -33,6 +33,72 @@ sealed class QuicError with _$QuicError implements FrbException {
   const factory QuicError.config(String field0) = QuicError_Config;
   const factory QuicError.network(String field0) = QuicError_Network;
   const factory QuicError.write(String field0) = QuicError_Write;
+  const factory QuicError.responseTooLong({required BigInt limit}) =
+      QuicError_ResponseTooLong;
+}
+
+/// Kind of a file transfer error
+enum QuicFileTransferErrorKind {
+  /// Reading or writing a local file failed
+  io,
+  /// The connection or one of its streams failed; the transfer can be resumed
+  connection,
+  /// The receiver refused the file, e.g. because of its name or size
+  rejected,
+  /// The received file does not match the SHA-256 digest of the manifest
+  checksumMismatch,
+  /// The transfer was cancelled
+  cancelled,
+}
+
+/// File transfer errors when sending or receiving files
+class QuicFileTransferException implements FrbException {
+  final QuicFileTransferErrorKind kind;
+  final String message;
+
+  const QuicFileTransferException({required this.kind, required this.message});
+
+  @override
+  int get hashCode => kind.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QuicFileTransferException &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          message == other.message;
+}
+
+/// Kind of a framed message error
+enum QuicFrameErrorKind {
+  /// A message exceeds the maximum message size
+  tooLarge,
+  /// The stream ended in the middle of a frame
+  truncated,
+  /// Reading the underlying stream failed
+  read,
+  /// Writing the underlying stream failed
+  write,
+}
+
+/// Framed message errors when sending or receiving length-prefixed messages
+class QuicFrameException implements FrbException {
+  final QuicFrameErrorKind kind;
+  final String message;
+
+  const QuicFrameException({required this.kind, required this.message});
+
+  @override
+  int get hashCode => kind.hashCode ^ message.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QuicFrameException &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          message == other.message;
 }
 
 @freezed
@@ -344,61 +344,30 @@ as String,
 /// @nodoc
 mixin _$QuicError {
 
- String get field0;
-/// Create a copy of QuicError
-/// with the given fields replaced by the non-null parameter values.
-@JsonKey(includeFromJson: false, includeToJson: false)
-@pragma('vm:prefer-inline')
-$QuicErrorCopyWith<QuicError> get copyWith => _$QuicErrorCopyWithImpl<QuicError>(this as QuicError, _$identity);
+
 
 
 
 @override
 bool operator ==(Object other) {
-  return identical(this, other) || (other.runtimeType == runtimeType&&other is QuicError&&(identical(other.field0, field0) || other.field0 == field0));
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is QuicError);
 }
 
 
 @override
-int get hashCode => Object.hash(runtimeType,field0);
+int get hashCode => runtimeType.hashCode;
 
 @override
 String toString() {
-  return 'QuicError(field0: $field0)';
+  return 'QuicError()';
 }
 
 
 }
 
 /// @nodoc
-abstract mixin class $QuicErrorCopyWith<$Res>  {
-  factory $QuicErrorCopyWith(QuicError value, $Res Function(QuicError) _then) = _$QuicErrorCopyWithImpl;
-@useResult
-$Res call({
- String field0
-});
-
-
-
-
-}
-/// @nodoc
-class _$QuicErrorCopyWithImpl<$Res>
-    implements $QuicErrorCopyWith<$Res> {
-  _$QuicErrorCopyWithImpl(this._self, this._then);
-
-  final QuicError _self;
-  final $Res Function(QuicError) _then;
-
-/// Create a copy of QuicError
-/// with the given fields replaced by the non-null parameter values.
-@pragma('vm:prefer-inline') @override $Res call({Object? field0 = null,}) {
-  return _then(_self.copyWith(
-field0: null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
-as String,
-  ));
-}
-
+class $QuicErrorCopyWith<$Res>  {
+$QuicErrorCopyWith(QuicError _, $Res Function(QuicError) __);
 }
 
 
@@ -416,7 +385,7 @@ extension QuicErrorPatterns on QuicError {
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( QuicError_Connection value)?  connection,TResult Function( QuicError_Endpoint value)?  endpoint,TResult Function( QuicError_Stream value)?  stream,TResult Function( QuicError_Tls value)?  tls,TResult Function( QuicError_Config value)?  config,TResult Function( QuicError_Network value)?  network,TResult Function( QuicError_Write value)?  write,required TResult orElse(),}){
+@optionalTypeArgs TResult maybeMap<TResult extends Object?>({TResult Function( QuicError_Connection value)?  connection,TResult Function( QuicError_Endpoint value)?  endpoint,TResult Function( QuicError_Stream value)?  stream,TResult Function( QuicError_Tls value)?  tls,TResult Function( QuicError_Config value)?  config,TResult Function( QuicError_Network value)?  network,TResult Function( QuicError_Write value)?  write,TResult Function( QuicError_ResponseTooLong value)?  responseTooLong,required TResult orElse(),}){
 final _that = this;
 switch (_that) {
 case QuicError_Connection() when connection != null:
@@ -426,7 +395,8 @@ return stream(_that);case QuicError_Tls() when tls != null:
 return tls(_that);case QuicError_Config() when config != null:
 return config(_that);case QuicError_Network() when network != null:
 return network(_that);case QuicError_Write() when write != null:
-return write(_that);case _:
+return write(_that);case QuicError_ResponseTooLong() when responseTooLong != null:
+return responseTooLong(_that);case _:
   return orElse();
 
 }
@@ -444,7 +414,7 @@ return write(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( QuicError_Connection value)  connection,required TResult Function( QuicError_Endpoint value)  endpoint,required TResult Function( QuicError_Stream value)  stream,required TResult Function( QuicError_Tls value)  tls,required TResult Function( QuicError_Config value)  config,required TResult Function( QuicError_Network value)  network,required TResult Function( QuicError_Write value)  write,}){
+@optionalTypeArgs TResult map<TResult extends Object?>({required TResult Function( QuicError_Connection value)  connection,required TResult Function( QuicError_Endpoint value)  endpoint,required TResult Function( QuicError_Stream value)  stream,required TResult Function( QuicError_Tls value)  tls,required TResult Function( QuicError_Config value)  config,required TResult Function( QuicError_Network value)  network,required TResult Function( QuicError_Write value)  write,required TResult Function( QuicError_ResponseTooLong value)  responseTooLong,}){
 final _that = this;
 switch (_that) {
 case QuicError_Connection():
@@ -454,7 +424,8 @@ return stream(_that);case QuicError_Tls():
 return tls(_that);case QuicError_Config():
 return config(_that);case QuicError_Network():
 return network(_that);case QuicError_Write():
-return write(_that);}
+return write(_that);case QuicError_ResponseTooLong():
+return responseTooLong(_that);}
 }
 /// A variant of `map` that fallback to returning `null`.
 ///
@@ -468,7 +439,7 @@ return write(_that);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( QuicError_Connection value)?  connection,TResult? Function( QuicError_Endpoint value)?  endpoint,TResult? Function( QuicError_Stream value)?  stream,TResult? Function( QuicError_Tls value)?  tls,TResult? Function( QuicError_Config value)?  config,TResult? Function( QuicError_Network value)?  network,TResult? Function( QuicError_Write value)?  write,}){
+@optionalTypeArgs TResult? mapOrNull<TResult extends Object?>({TResult? Function( QuicError_Connection value)?  connection,TResult? Function( QuicError_Endpoint value)?  endpoint,TResult? Function( QuicError_Stream value)?  stream,TResult? Function( QuicError_Tls value)?  tls,TResult? Function( QuicError_Config value)?  config,TResult? Function( QuicError_Network value)?  network,TResult? Function( QuicError_Write value)?  write,TResult? Function( QuicError_ResponseTooLong value)?  responseTooLong,}){
 final _that = this;
 switch (_that) {
 case QuicError_Connection() when connection != null:
@@ -478,7 +449,8 @@ return stream(_that);case QuicError_Tls() when tls != null:
 return tls(_that);case QuicError_Config() when config != null:
 return config(_that);case QuicError_Network() when network != null:
 return network(_that);case QuicError_Write() when write != null:
-return write(_that);case _:
+return write(_that);case QuicError_ResponseTooLong() when responseTooLong != null:
+return responseTooLong(_that);case _:
   return null;
 
 }
@@ -495,7 +467,7 @@ return write(_that);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( String field0)?  connection,TResult Function( String field0)?  endpoint,TResult Function( String field0)?  stream,TResult Function( String field0)?  tls,TResult Function( String field0)?  config,TResult Function( String field0)?  network,TResult Function( String field0)?  write,required TResult orElse(),}) {final _that = this;
+@optionalTypeArgs TResult maybeWhen<TResult extends Object?>({TResult Function( String field0)?  connection,TResult Function( String field0)?  endpoint,TResult Function( String field0)?  stream,TResult Function( String field0)?  tls,TResult Function( String field0)?  config,TResult Function( String field0)?  network,TResult Function( String field0)?  write,TResult Function( BigInt limit)?  responseTooLong,required TResult orElse(),}) {final _that = this;
 switch (_that) {
 case QuicError_Connection() when connection != null:
 return connection(_that.field0);case QuicError_Endpoint() when endpoint != null:
@@ -504,7 +476,8 @@ return stream(_that.field0);case QuicError_Tls() when tls != null:
 return tls(_that.field0);case QuicError_Config() when config != null:
 return config(_that.field0);case QuicError_Network() when network != null:
 return network(_that.field0);case QuicError_Write() when write != null:
-return write(_that.field0);case _:
+return write(_that.field0);case QuicError_ResponseTooLong() when responseTooLong != null:
+return responseTooLong(_that.limit);case _:
   return orElse();
 
 }
@@ -522,7 +495,7 @@ return write(_that.field0);case _:
 /// }
 /// ```
 
-@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( String field0)  connection,required TResult Function( String field0)  endpoint,required TResult Function( String field0)  stream,required TResult Function( String field0)  tls,required TResult Function( String field0)  config,required TResult Function( String field0)  network,required TResult Function( String field0)  write,}) {final _that = this;
+@optionalTypeArgs TResult when<TResult extends Object?>({required TResult Function( String field0)  connection,required TResult Function( String field0)  endpoint,required TResult Function( String field0)  stream,required TResult Function( String field0)  tls,required TResult Function( String field0)  config,required TResult Function( String field0)  network,required TResult Function( String field0)  write,required TResult Function( BigInt limit)  responseTooLong,}) {final _that = this;
 switch (_that) {
 case QuicError_Connection():
 return connection(_that.field0);case QuicError_Endpoint():
@@ -531,7 +504,8 @@ return stream(_that.field0);case QuicError_Tls():
 return tls(_that.field0);case QuicError_Config():
 return config(_that.field0);case QuicError_Network():
 return network(_that.field0);case QuicError_Write():
-return write(_that.field0);}
+return write(_that.field0);case QuicError_ResponseTooLong():
+return responseTooLong(_that.limit);}
 }
 /// A variant of `when` that fallback to returning `null`
 ///
@@ -545,7 +519,7 @@ return write(_that.field0);}
 /// }
 /// ```
 
-@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( String field0)?  connection,TResult? Function( String field0)?  endpoint,TResult? Function( String field0)?  stream,TResult? Function( String field0)?  tls,TResult? Function( String field0)?  config,TResult? Function( String field0)?  network,TResult? Function( String field0)?  write,}) {final _that = this;
+@optionalTypeArgs TResult? whenOrNull<TResult extends Object?>({TResult? Function( String field0)?  connection,TResult? Function( String field0)?  endpoint,TResult? Function( String field0)?  stream,TResult? Function( String field0)?  tls,TResult? Function( String field0)?  config,TResult? Function( String field0)?  network,TResult? Function( String field0)?  write,TResult? Function( BigInt limit)?  responseTooLong,}) {final _that = this;
 switch (_that) {
 case QuicError_Connection() when connection != null:
 return connection(_that.field0);case QuicError_Endpoint() when endpoint != null:
@@ -554,7 +528,8 @@ return stream(_that.field0);case QuicError_Tls() when tls != null:
 return tls(_that.field0);case QuicError_Config() when config != null:
 return config(_that.field0);case QuicError_Network() when network != null:
 return network(_that.field0);case QuicError_Write() when write != null:
-return write(_that.field0);case _:
+return write(_that.field0);case QuicError_ResponseTooLong() when responseTooLong != null:
+return responseTooLong(_that.limit);case _:
   return null;
 
 }
@@ -569,11 +544,11 @@ class QuicError_Connection extends QuicError {
   const QuicError_Connection(this.field0): super._();
   
 
-@override final  String field0;
+ final  String field0;
 
 /// Create a copy of QuicError
 /// with the given fields replaced by the non-null parameter values.
-@override @JsonKey(includeFromJson: false, includeToJson: false)
+@JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
 $QuicError_ConnectionCopyWith<QuicError_Connection> get copyWith => _$QuicError_ConnectionCopyWithImpl<QuicError_Connection>(this, _$identity);
 
@@ -599,7 +574,7 @@ String toString() {
 /// @nodoc
 abstract mixin class $QuicError_ConnectionCopyWith<$Res> implements $QuicErrorCopyWith<$Res> {
   factory $QuicError_ConnectionCopyWith(QuicError_Connection value, $Res Function(QuicError_Connection) _then) = _$QuicError_ConnectionCopyWithImpl;
-@override @useResult
+@useResult
 $Res call({
  String field0
 });
@@ -618,7 +593,7 @@ class _$QuicError_ConnectionCopyWithImpl<$Res>
 
 /// Create a copy of QuicError
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
   return _then(QuicError_Connection(
 null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
 as String,
@@ -635,11 +610,11 @@ class QuicError_Endpoint extends QuicError {
   const QuicError_Endpoint(this.field0): super._();
   
 
-@override final  String field0;
+ final  String field0;
 
 /// Create a copy of QuicError
 /// with the given fields replaced by the non-null parameter values.
-@override @JsonKey(includeFromJson: false, includeToJson: false)
+@JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
 $QuicError_EndpointCopyWith<QuicError_Endpoint> get copyWith => _$QuicError_EndpointCopyWithImpl<QuicError_Endpoint>(this, _$identity);
 
@@ -665,7 +640,7 @@ String toString() {
 /// @nodoc
 abstract mixin class $QuicError_EndpointCopyWith<$Res> implements $QuicErrorCopyWith<$Res> {
   factory $QuicError_EndpointCopyWith(QuicError_Endpoint value, $Res Function(QuicError_Endpoint) _then) = _$QuicError_EndpointCopyWithImpl;
-@override @useResult
+@useResult
 $Res call({
  String field0
 });
@@ -684,7 +659,7 @@ class _$QuicError_EndpointCopyWithImpl<$Res>
 
 /// Create a copy of QuicError
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
   return _then(QuicError_Endpoint(
 null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
 as String,
@@ -701,11 +676,11 @@ class QuicError_Stream extends QuicError {
   const QuicError_Stream(this.field0): super._();
   
 
-@override final  String field0;
+ final  String field0;
 
 /// Create a copy of QuicError
 /// with the given fields replaced by the non-null parameter values.
-@override @JsonKey(includeFromJson: false, includeToJson: false)
+@JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
 $QuicError_StreamCopyWith<QuicError_Stream> get copyWith => _$QuicError_StreamCopyWithImpl<QuicError_Stream>(this, _$identity);
 
@@ -731,7 +706,7 @@ String toString() {
 /// @nodoc
 abstract mixin class $QuicError_StreamCopyWith<$Res> implements $QuicErrorCopyWith<$Res> {
   factory $QuicError_StreamCopyWith(QuicError_Stream value, $Res Function(QuicError_Stream) _then) = _$QuicError_StreamCopyWithImpl;
-@override @useResult
+@useResult
 $Res call({
  String field0
 });
@@ -750,7 +725,7 @@ class _$QuicError_StreamCopyWithImpl<$Res>
 
 /// Create a copy of QuicError
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
   return _then(QuicError_Stream(
 null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
 as String,
@@ -767,11 +742,11 @@ class QuicError_Tls extends QuicError {
   const QuicError_Tls(this.field0): super._();
   
 
-@override final  String field0;
+ final  String field0;
 
 /// Create a copy of QuicError
 /// with the given fields replaced by the non-null parameter values.
-@override @JsonKey(includeFromJson: false, includeToJson: false)
+@JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
 $QuicError_TlsCopyWith<QuicError_Tls> get copyWith => _$QuicError_TlsCopyWithImpl<QuicError_Tls>(this, _$identity);
 
@@ -797,7 +772,7 @@ String toString() {
 /// @nodoc
 abstract mixin class $QuicError_TlsCopyWith<$Res> implements $QuicErrorCopyWith<$Res> {
   factory $QuicError_TlsCopyWith(QuicError_Tls value, $Res Function(QuicError_Tls) _then) = _$QuicError_TlsCopyWithImpl;
-@override @useResult
+@useResult
 $Res call({
  String field0
 });
@@ -816,7 +791,7 @@ class _$QuicError_TlsCopyWithImpl<$Res>
 
 /// Create a copy of QuicError
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
   return _then(QuicError_Tls(
 null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
 as String,
@@ -833,11 +808,11 @@ class QuicError_Config extends QuicError {
   const QuicError_Config(this.field0): super._();
   
 
-@override final  String field0;
+ final  String field0;
 
 /// Create a copy of QuicError
 /// with the given fields replaced by the non-null parameter values.
-@override @JsonKey(includeFromJson: false, includeToJson: false)
+@JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
 $QuicError_ConfigCopyWith<QuicError_Config> get copyWith => _$QuicError_ConfigCopyWithImpl<QuicError_Config>(this, _$identity);
 
@@ -863,7 +838,7 @@ String toString() {
 /// @nodoc
 abstract mixin class $QuicError_ConfigCopyWith<$Res> implements $QuicErrorCopyWith<$Res> {
   factory $QuicError_ConfigCopyWith(QuicError_Config value, $Res Function(QuicError_Config) _then) = _$QuicError_ConfigCopyWithImpl;
-@override @useResult
+@useResult
 $Res call({
  String field0
 });
@@ -882,7 +857,7 @@ class _$QuicError_ConfigCopyWithImpl<$Res>
 
 /// Create a copy of QuicError
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
   return _then(QuicError_Config(
 null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
 as String,
@@ -899,11 +874,11 @@ class QuicError_Network extends QuicError {
   const QuicError_Network(this.field0): super._();
   
 
-@override final  String field0;
+ final  String field0;
 
 /// Create a copy of QuicError
 /// with the given fields replaced by the non-null parameter values.
-@override @JsonKey(includeFromJson: false, includeToJson: false)
+@JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
 $QuicError_NetworkCopyWith<QuicError_Network> get copyWith => _$QuicError_NetworkCopyWithImpl<QuicError_Network>(this, _$identity);
 
@@ -929,7 +904,7 @@ String toString() {
 /// @nodoc
 abstract mixin class $QuicError_NetworkCopyWith<$Res> implements $QuicErrorCopyWith<$Res> {
   factory $QuicError_NetworkCopyWith(QuicError_Network value, $Res Function(QuicError_Network) _then) = _$QuicError_NetworkCopyWithImpl;
-@override @useResult
+@useResult
 $Res call({
  String field0
 });
@@ -948,7 +923,7 @@ class _$QuicError_NetworkCopyWithImpl<$Res>
 
 /// Create a copy of QuicError
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
   return _then(QuicError_Network(
 null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
 as String,
@@ -965,11 +940,11 @@ class QuicError_Write extends QuicError {
   const QuicError_Write(this.field0): super._();
   
 
-@override final  String field0;
+ final  String field0;
 
 /// Create a copy of QuicError
 /// with the given fields replaced by the non-null parameter values.
-@override @JsonKey(includeFromJson: false, includeToJson: false)
+@JsonKey(includeFromJson: false, includeToJson: false)
 @pragma('vm:prefer-inline')
 $QuicError_WriteCopyWith<QuicError_Write> get copyWith => _$QuicError_WriteCopyWithImpl<QuicError_Write>(this, _$identity);
 
@@ -995,7 +970,7 @@ String toString() {
 /// @nodoc
 abstract mixin class $QuicError_WriteCopyWith<$Res> implements $QuicErrorCopyWith<$Res> {
   factory $QuicError_WriteCopyWith(QuicError_Write value, $Res Function(QuicError_Write) _then) = _$QuicError_WriteCopyWithImpl;
-@override @useResult
+@useResult
 $Res call({
  String field0
 });
@@ -1014,7 +989,7 @@ class _$QuicError_WriteCopyWithImpl<$Res>
 
 /// Create a copy of QuicError
 /// with the given fields replaced by the non-null parameter values.
-@override @pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
+@pragma('vm:prefer-inline') $Res call({Object? field0 = null,}) {
   return _then(QuicError_Write(
 null == field0 ? _self.field0 : field0 // ignore: cast_nullable_to_non_nullable
 as String,
@@ -1022,6 +997,72 @@ as String,
 }
 
 
+}
+
+/// @nodoc
+
+
+class QuicError_ResponseTooLong extends QuicError {
+  const QuicError_ResponseTooLong({required this.limit}): super._();
+  
+
+ final  BigInt limit;
+
+/// Create a copy of QuicError
+/// with the given fields replaced by the non-null parameter values.
+@JsonKey(includeFromJson: false, includeToJson: false)
+@pragma('vm:prefer-inline')
+$QuicError_ResponseTooLongCopyWith<QuicError_ResponseTooLong> get copyWith => _$QuicError_ResponseTooLongCopyWithImpl<QuicError_ResponseTooLong>(this, _$identity);
+
+
+
+@override
+bool operator ==(Object other) {
+  return identical(this, other) || (other.runtimeType == runtimeType&&other is QuicError_ResponseTooLong&&(identical(other.limit, limit) || other.limit == limit));
+}
+
+
+@override
+int get hashCode => Object.hash(runtimeType,limit);
+
+@override
+String toString() {
+  return 'QuicError.responseTooLong(limit: $limit)';
+}
+
+
+}
+
+/// @nodoc
+abstract mixin class $QuicError_ResponseTooLongCopyWith<$Res> implements $QuicErrorCopyWith<$Res> {
+  factory $QuicError_ResponseTooLongCopyWith(QuicError_ResponseTooLong value, $Res Function(QuicError_ResponseTooLong) _then) = _$QuicError_ResponseTooLongCopyWithImpl;
+@useResult
+$Res call({
+ BigInt limit
+});
+
+
+
+
+}
+/// @nodoc
+class _$QuicError_ResponseTooLongCopyWithImpl<$Res>
+    implements $QuicError_ResponseTooLongCopyWith<$Res> {
+  _$QuicError_ResponseTooLongCopyWithImpl(this._self, this._then);
+
+  final QuicError_ResponseTooLong _self;
+  final $Res Function(QuicError_ResponseTooLong) _then;
+
+/// Create a copy of QuicError
+/// with the given fields replaced by the non-null parameter values.
+@pragma('vm:prefer-inline') $Res call({Object? limit = null,}) {
+  return _then(QuicError_ResponseTooLong(
+limit: null == limit ? _self.limit : limit // ignore: cast_nullable_to_non_nullable
+as BigInt,
+  ));
+}
+
+
 }
 
 /// @nodoc
@@ -5,9 +5,24 @@
 
 import 'api/bridge.dart';
 import 'convenience/client.dart';
+import 'convenience/datagram_channel.dart';
+import 'convenience/file_transfer.dart';
+import 'convenience/framing.dart';
+import 'convenience/http3.dart';
+import 'convenience/http3_server.dart';
+import 'convenience/pool.dart';
+import 'convenience/pubsub.dart';
+import 'convenience/reassembly.dart';
+import 'convenience/rpc.dart';
+import 'convenience/server.dart';
+import 'convenience/streaming.dart';
+import 'convenience/webtransport.dart';
+import 'core/cert_resolver.dart';
 import 'core/config.dart';
 import 'core/connection.dart';
+import 'core/datagram.dart';
 import 'core/endpoint.dart';
+import 'core/keylog.dart';
 import 'core/stream.dart';
 import 'dart:async';
 import 'dart:convert';
@@ -15,6 +30,8 @@ import 'errors.dart';
 import 'frb_generated.dart';
 import 'frb_generated.io.dart'
     if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'logging.dart';
+import 'models/certificate.dart';
 import 'models/types.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
@@ -73,7 +90,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => -1226864594;
+  int get rustContentHash => 1238592798;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -106,10 +123,47 @@ abstract class RustLibApi extends BaseApi {
 
   Future<void> crateApiBridgeExposeTypesForFrbGeneration();
 
+  Future<QuicCertificateResolver> crateApiBridgeCertResolverAdd({
+    required QuicCertificateResolver resolver,
+    required String serverName,
+    required CertificateChain certChain,
+    required PrivateKey key,
+  });
+
+  Future<QuicCertificateResolver> crateApiBridgeCertResolverNew();
+
+  Future<(QuicCertificateResolver, bool)> crateApiBridgeCertResolverRemove({
+    required QuicCertificateResolver resolver,
+    required String serverName,
+  });
+
+  Future<QuicCertificateResolver> crateApiBridgeCertResolverSetDefault({
+    required QuicCertificateResolver resolver,
+    required CertificateChain certChain,
+    required PrivateKey key,
+  });
+
+  Future<CertificateChain> crateApiBridgeCertificateChainFromPem({
+    required List<int> pem,
+  });
+
+  Future<(QuicConnection, QuicSendStream, QuicRecvStream)>
+  crateApiBridgeConnectionAcceptBi({required QuicConnection connection});
+
+  Future<(QuicConnection, QuicRecvStream)> crateApiBridgeConnectionAcceptUni({
+    required QuicConnection connection,
+  });
+
   Future<(QuicConnection, String?)> crateApiBridgeConnectionCloseReason({
     required QuicConnection connection,
   });
 
+  Future<(QuicConnection, QuicDatagramMux)>
+  crateApiBridgeConnectionDatagramMux({
+    required QuicConnection connection,
+    required QuicDatagramChannelConfig config,
+  });
+
   Future<(QuicConnection, BigInt)>
   crateApiBridgeConnectionDatagramSendBufferSpace({
     required QuicConnection connection,
@@ -130,6 +184,43 @@ abstract class RustLibApi extends BaseApi {
     required QuicConnection connection,
   });
 
+  Future<(QuicConnectionPool, QuicEndpoint, QuicConnection)>
+  crateApiBridgeConnectionPoolAcquire({
+    required QuicConnectionPool pool,
+    required QuicEndpoint endpoint,
+    required String addr,
+    required String serverName,
+  });
+
+  Future<QuicConnectionPool> crateApiBridgeConnectionPoolClear({
+    required QuicConnectionPool pool,
+  });
+
+  Future<QuicConnectionPoolConfig> crateApiBridgeConnectionPoolConfigNew();
+
+  Future<(QuicConnectionPool, BigInt)> crateApiBridgeConnectionPoolEvictIdle({
+    required QuicConnectionPool pool,
+  });
+
+  Future<QuicConnectionPool> crateApiBridgeConnectionPoolNew({
+    required QuicConnectionPoolConfig config,
+  });
+
+  Future<(QuicConnectionPool, QuicConnection)>
+  crateApiBridgeConnectionPoolRelease({
+    required QuicConnectionPool pool,
+    required QuicConnection connection,
+  });
+
+  Future<(QuicConnectionPool, QuicConnection, bool)>
+  crateApiBridgeConnectionPoolRemove({
+    required QuicConnectionPool pool,
+    required QuicConnection connection,
+  });
+
+  Future<(QuicConnectionPool, QuicConnectionPoolStats)>
+  crateApiBridgeConnectionPoolStats({required QuicConnectionPool pool});
+
   Future<(QuicConnection, Uint8List?)> crateApiBridgeConnectionReadDatagram({
     required QuicConnection connection,
   });
@@ -159,13 +250,69 @@ abstract class RustLibApi extends BaseApi {
     required QuicConnection connection,
   });
 
+  Future<QuicDatagramSubscription> crateApiBridgeConnectionSubscribeDatagrams({
+    required QuicConnection connection,
+    required QuicDatagramSubscriptionConfig config,
+  });
+
   Future<QuicEndpoint> crateApiBridgeCreateClientEndpoint();
 
+  Future<QuicEndpoint> crateApiBridgeCreateClientEndpointWithAlpn({
+    required List<Uint8List> alpnProtocols,
+  });
+
+  Future<QuicEndpoint> crateApiBridgeCreateClientEndpointWithKeyLog({
+    required QuicKeyLog keyLog,
+  });
+
   Future<QuicEndpoint> crateApiBridgeCreateServerEndpoint({
     required QuicServerConfig config,
     required String addr,
   });
 
+  Future<void> crateApiBridgeDatagramChannelClose({
+    required QuicDatagramChannel channel,
+  });
+
+  Future<QuicDatagramChannelConfig> crateApiBridgeDatagramChannelConfigNew();
+
+  Future<BigInt> crateApiBridgeDatagramChannelFlowId({
+    required QuicDatagramChannel channel,
+  });
+
+  Future<BigInt?> crateApiBridgeDatagramChannelMaxPayloadSize({
+    required QuicDatagramChannel channel,
+  });
+
+  Future<QuicDatagramBatch> crateApiBridgeDatagramChannelNextBatch({
+    required QuicDatagramChannel channel,
+  });
+
+  Future<void> crateApiBridgeDatagramChannelSend({
+    required QuicDatagramChannel channel,
+    required List<int> data,
+  });
+
+  Future<QuicDatagramChannel> crateApiBridgeDatagramMuxOpenChannel({
+    required QuicDatagramMux mux,
+    required BigInt flowId,
+  });
+
+  Future<void> crateApiBridgeDatagramSubscriptionClose({
+    required QuicDatagramSubscription subscription,
+  });
+
+  Future<QuicDatagramSubscriptionConfig>
+  crateApiBridgeDatagramSubscriptionConfigNew();
+
+  Future<QuicDatagramBatch> crateApiBridgeDatagramSubscriptionNextBatch({
+    required QuicDatagramSubscription subscription,
+  });
+
+  Future<(QuicEndpoint, QuicConnection?)> crateApiBridgeEndpointAccept({
+    required QuicEndpoint endpoint,
+  });
+
   Future<QuicEndpointConfig> crateApiBridgeEndpointConfigNew();
 
   Future<(QuicEndpoint, QuicConnection)> crateApiBridgeEndpointConnect({
@@ -174,8 +321,222 @@ abstract class RustLibApi extends BaseApi {
     required String serverName,
   });
 
+  Future<QuicEndpoint> crateApiBridgeEndpointSetServerConfig({
+    required QuicEndpoint endpoint,
+    required QuicServerConfig config,
+  });
+
+  Future<QuicFileReceiver> crateApiBridgeFileReceiverNew({
+    required String directory,
+    required BigInt maxFileSize,
+  });
+
+  Future<QuicConnection> crateApiBridgeFileReceiverServeConnection({
+    required QuicFileReceiver receiver,
+    required QuicConnection connection,
+  });
+
+  Future<QuicFileTransferConfig> crateApiBridgeFileTransferConfigNew();
+
+  Future<void> crateApiBridgeFileUploadCancel({required QuicFileUpload upload});
+
+  Future<QuicFileTransferProgress> crateApiBridgeFileUploadProgress({
+    required QuicFileUpload upload,
+  });
+
+  Future<QuicFileUpload> crateApiBridgeFileUploadStart({
+    required QuicConnection connection,
+    required String path,
+    required QuicFileTransferConfig config,
+    required FutureOr<void> Function(QuicFileTransferProgress) onProgress,
+  });
+
+  Future<QuicFileTransferProgress> crateApiBridgeFileUploadWait({
+    required QuicFileUpload upload,
+  });
+
+  Future<void> crateApiBridgeFramedStreamFinish({
+    required QuicFramedStream stream,
+  });
+
+  Future<QuicFramedStream> crateApiBridgeFramedStreamNew({
+    QuicSendStream? send,
+    QuicRecvStream? recv,
+    required BigInt maxMessageSize,
+  });
+
+  Future<Uint8List?> crateApiBridgeFramedStreamRecv({
+    required QuicFramedStream stream,
+  });
+
+  Future<void> crateApiBridgeFramedStreamSend({
+    required QuicFramedStream stream,
+    required List<int> message,
+  });
+
+  Future<SelfSignedCertificate> crateApiBridgeGenerateSelfSignedCertificate({
+    required List<String> subjectAltNames,
+    required int validityDays,
+    required CertificateKeyAlgorithm algorithm,
+  });
+
+  Future<Http3Client> crateApiBridgeHttp3ClientClose({
+    required Http3Client client,
+  });
+
+  Future<Http3ClientConfig> crateApiBridgeHttp3ClientConfigNew();
+
+  Future<Http3Client> crateApiBridgeHttp3ClientCreate({
+    required Http3ClientConfig config,
+  });
+
+  Future<(Http3Client, Http3Response)> crateApiBridgeHttp3ClientSend({
+    required Http3Client client,
+    required Http3Request request,
+  });
+
+  Future<(Http3Client, Http3ResponseStream)>
+  crateApiBridgeHttp3ClientSendStreaming({
+    required Http3Client client,
+    required Http3Request request,
+    required FutureOr<Uint8List?> Function() nextBodyChunk,
+  });
+
+  Future<Http3Request> crateApiBridgeHttp3RequestNew({
+    required String method,
+    required String url,
+  });
+
+  Future<void> crateApiBridgeHttp3ResponseStreamCancel({
+    required Http3ResponseStream response,
+  });
+
+  Future<List<Http3Header>> crateApiBridgeHttp3ResponseStreamHeaders({
+    required Http3ResponseStream response,
+  });
+
+  Future<Uint8List?> crateApiBridgeHttp3ResponseStreamNextChunk({
+    required Http3ResponseStream response,
+  });
+
+  Future<int> crateApiBridgeHttp3ResponseStreamStatus({
+    required Http3ResponseStream response,
+  });
+
+  Future<List<Http3Header>> crateApiBridgeHttp3ResponseStreamTrailers({
+    required Http3ResponseStream response,
+  });
+
+  Future<Http3Router> crateApiBridgeHttp3RouterNew();
+
+  Future<Http3Router> crateApiBridgeHttp3RouterRoute({
+    required Http3Router router,
+    required String method,
+    required String path,
+    required FutureOr<void> Function(Http3ServerRequest, Http3ServerStream) handler,
+  });
+
+  Future<void> crateApiBridgeHttp3ServerStreamFinish({
+    required Http3ServerStream stream,
+    required List<Http3Header> trailers,
+  });
+
+  Future<Uint8List?> crateApiBridgeHttp3ServerStreamNextChunk({
+    required Http3ServerStream stream,
+  });
+
+  Future<Uint8List> crateApiBridgeHttp3ServerStreamReadBody({
+    required Http3ServerStream stream,
+  });
+
+  Future<void> crateApiBridgeHttp3ServerStreamRespond({
+    required Http3ServerStream stream,
+    required int status,
+    required List<Http3Header> headers,
+    required List<int> body,
+  });
+
+  Future<void> crateApiBridgeHttp3ServerStreamSendData({
+    required Http3ServerStream stream,
+    required List<int> chunk,
+  });
+
+  Future<void> crateApiBridgeHttp3ServerStreamSendResponse({
+    required Http3ServerStream stream,
+    required int status,
+    required List<Http3Header> headers,
+  });
+
+  Future<List<Http3Header>> crateApiBridgeHttp3ServerStreamTrailers({
+    required Http3ServerStream stream,
+  });
+
   Future<void> crateApiBridgeInitApp();
 
+  Stream<QuicLogRecord> crateApiBridgeInitLogging({required String filter});
+
+  Future<QuicKeyLog> crateApiBridgeKeyLogCallback({
+    required FutureOr<void> Function(String) onLine,
+  });
+
+  Future<QuicKeyLog> crateApiBridgeKeyLogFile({required String path});
+
+  Future<(CertificateChain, PrivateKey)> crateApiBridgeLoadPkcs12({
+    required List<int> data,
+    required String password,
+  });
+
+  Future<PrivateKey> crateApiBridgePrivateKeyFromPem({required List<int> pem});
+
+  Future<QuicPubSubBrokerConfig> crateApiBridgePubSubBrokerConfigNew();
+
+  Future<BigInt> crateApiBridgePubSubBrokerLatestSequence({
+    required QuicPubSubBroker broker,
+    required String topic,
+  });
+
+  Future<QuicPubSubBroker> crateApiBridgePubSubBrokerNew({
+    required QuicPubSubBrokerConfig config,
+  });
+
+  Future<BigInt> crateApiBridgePubSubBrokerPublish({
+    required QuicPubSubBroker broker,
+    required String topic,
+    required List<int> payload,
+  });
+
+  Future<(QuicConnection, QuicPubSubClient)> crateApiBridgePubSubClientConnect({
+    required QuicConnection connection,
+  });
+
+  Future<QuicPubSubSubscription> crateApiBridgePubSubClientSubscribe({
+    required QuicPubSubClient client,
+    required String topic,
+    required QuicPubSubSubscribeOptions options,
+  });
+
+  Future<QuicPubSubSubscribeOptions> crateApiBridgePubSubSubscribeOptionsNew();
+
+  Future<BigInt> crateApiBridgePubSubSubscriptionLastSequence({
+    required QuicPubSubSubscription subscription,
+  });
+
+  Future<BigInt> crateApiBridgePubSubSubscriptionMissed({
+    required QuicPubSubSubscription subscription,
+  });
+
+  Future<QuicPubSubMessage?> crateApiBridgePubSubSubscriptionNextMessage({
+    required QuicPubSubSubscription subscription,
+  });
+
+  Future<String> crateApiBridgePubSubSubscriptionTopic({
+    required QuicPubSubSubscription subscription,
+  });
+
+  Future<void> crateApiBridgePubSubSubscriptionUnsubscribe({
+    required QuicPubSubSubscription subscription,
+  });
+
   Future<QuicClient> crateApiBridgeQuicClientClearPool({
     required QuicClient client,
   });
@@ -197,6 +558,11 @@ abstract class RustLibApi extends BaseApi {
     required String url,
   });
 
+  Future<(QuicClient, Uint8List)> crateApiBridgeQuicClientGetBytes({
+    required QuicClient client,
+    required String url,
+  });
+
   Future<(QuicClient, String)> crateApiBridgeQuicClientGetWithTimeout({
     required QuicClient client,
     required String url,
@@ -208,6 +574,12 @@ abstract class RustLibApi extends BaseApi {
     required String data,
   });
 
+  Future<(QuicClient, Uint8List)> crateApiBridgeQuicClientPostBytes({
+    required QuicClient client,
+    required String url,
+    required List<int> data,
+  });
+
   Future<(QuicClient, String)> crateApiBridgeQuicClientPostWithTimeout({
     required QuicClient client,
     required String url,
@@ -220,6 +592,29 @@ abstract class RustLibApi extends BaseApi {
     required String data,
   });
 
+  Future<(QuicClient, Uint8List)> crateApiBridgeQuicClientSendBytes({
+    required QuicClient client,
+    required String url,
+    required List<int> data,
+  });
+
+  Future<(QuicClient, Uint8List)> crateApiBridgeQuicClientSendBytesWithTimeout({
+    required QuicClient client,
+    required String url,
+    required List<int> data,
+  });
+
+  Future<(QuicClient, QuicStreamingTransfer)>
+  crateApiBridgeQuicClientSendStreaming({
+    required QuicClient client,
+    required String url,
+    BigInt? uploadLength,
+    BigInt? responseLength,
+    required FutureOr<Uint8List?> Function() nextBodyChunk,
+    required FutureOr<void> Function(Uint8List) onResponseChunk,
+    required FutureOr<void> Function(QuicTransferProgress) onProgress,
+  });
+
   Future<(QuicClient, String)> crateApiBridgeQuicClientSendWithTimeout({
     required QuicClient client,
     required String url,
@@ -231,15 +626,84 @@ abstract class RustLibApi extends BaseApi {
     required BigInt maxLength,
   });
 
+  Future<(QuicRecvStream, QuicStreamChunk?)> crateApiBridgeRecvStreamReadChunk({
+    required QuicRecvStream stream,
+    required BigInt maxLength,
+    required bool ordered,
+  });
+
+  Future<(QuicRecvStream, List<QuicStreamChunk>?)>
+  crateApiBridgeRecvStreamReadChunks({
+    required QuicRecvStream stream,
+    required BigInt maxChunks,
+  });
+
   Future<(QuicRecvStream, Uint8List)> crateApiBridgeRecvStreamReadToEnd({
     required QuicRecvStream stream,
     required BigInt maxLength,
   });
 
+  Future<void> crateApiBridgeRpcCallCancel({required QuicRpcCall call});
+
+  Future<Uint8List?> crateApiBridgeRpcCallNextMessage({
+    required QuicRpcCall call,
+  });
+
+  Future<QuicRpcCallOptions> crateApiBridgeRpcCallOptionsNew();
+
+  Future<Uint8List> crateApiBridgeRpcClientCall({
+    required QuicRpcClient client,
+    required String method,
+    required List<int> payload,
+    required QuicRpcCallOptions options,
+  });
+
+  Future<QuicRpcCall> crateApiBridgeRpcClientCallStreaming({
+    required QuicRpcClient client,
+    required String method,
+    required List<int> payload,
+    required QuicRpcCallOptions options,
+  });
+
+  Future<QuicRpcClientConfig> crateApiBridgeRpcClientConfigNew();
+
+  Future<QuicRpcClient> crateApiBridgeRpcClientNew({
+    required QuicConnection connection,
+    required QuicRpcClientConfig config,
+  });
+
+  Future<QuicRpcRegistry> crateApiBridgeRpcRegistryNew();
+
+  Future<QuicRpcRegistry> crateApiBridgeRpcRegistryRegister({
+    required QuicRpcRegistry registry,
+    required String method,
+    required FutureOr<QuicRpcStatus> Function(QuicRpcRequest, QuicRpcResponder) handler,
+  });
+
+  Future<QuicRpcRegistry> crateApiBridgeRpcRegistryRegisterUnary({
+    required QuicRpcRegistry registry,
+    required String method,
+    required FutureOr<Uint8List> Function(QuicRpcRequest) handler,
+  });
+
+  Future<void> crateApiBridgeRpcResponderSend({
+    required QuicRpcResponder responder,
+    required List<int> message,
+  });
+
   Future<QuicSendStream> crateApiBridgeSendStreamFinish({
     required QuicSendStream stream,
   });
 
+  Future<QuicSendStream> crateApiBridgeSendStreamReset({
+    required QuicSendStream stream,
+    required int errorCode,
+  });
+
+  Future<(QuicSendStream, QuicSendStreamStats)> crateApiBridgeSendStreamStats({
+    required QuicSendStream stream,
+  });
+
   Future<(QuicSendStream, BigInt)> crateApiBridgeSendStreamWrite({
     required QuicSendStream stream,
     required List<int> data,
@@ -250,35 +714,340 @@ abstract class RustLibApi extends BaseApi {
     required List<int> data,
   });
 
-  Future<QuicServerConfig> crateApiBridgeServerConfigWithSingleCert({
-    required List<Uint8List> certChain,
-    required List<int> key,
+  Future<QuicSendStream> crateApiBridgeSendStreamWriteAllChunks({
+    required QuicSendStream stream,
+    required List<Uint8List> chunks,
   });
 
-  Future<QuicTransportConfig> crateApiBridgeTransportConfigNew();
+  Future<(QuicSendStream, QuicChunksWritten)>
+  crateApiBridgeSendStreamWriteChunks({
+    required QuicSendStream stream,
+    required List<Uint8List> chunks,
+  });
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_QuicClient;
+  Future<QuicServerConfig> crateApiBridgeServerConfigIncomingBufferSize({
+    required QuicServerConfig config,
+    required BigInt size,
+  });
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_QuicClient;
+  Future<QuicServerConfig> crateApiBridgeServerConfigIncomingBufferSizeTotal({
+    required QuicServerConfig config,
+    required BigInt size,
+  });
 
-  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_QuicClientPtr;
+  Future<QuicServerConfig> crateApiBridgeServerConfigMaxIncoming({
+    required QuicServerConfig config,
+    required BigInt maxIncoming,
+  });
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_QuicConnection;
+  Future<QuicServerConfig> crateApiBridgeServerConfigMigration({
+    required QuicServerConfig config,
+    required bool allow,
+  });
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_QuicConnection;
+  Future<QuicServerConfig> crateApiBridgeServerConfigPreferredAddressV4({
+    required QuicServerConfig config,
+    String? addr,
+  });
 
-  CrossPlatformFinalizerArg
-  get rust_arc_decrement_strong_count_QuicConnectionPtr;
+  Future<QuicServerConfig> crateApiBridgeServerConfigPreferredAddressV6({
+    required QuicServerConfig config,
+    String? addr,
+  });
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_QuicEndpoint;
+  Future<(QuicServerConfig, bool)> crateApiBridgeServerConfigRetryEnabled({
+    required QuicServerConfig config,
+  });
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_QuicEndpoint;
+  Future<QuicServerConfig> crateApiBridgeServerConfigRetryTokenLifetime({
+    required QuicServerConfig config,
+    required BigInt lifetimeMs,
+  });
+
+  Future<QuicServerConfig> crateApiBridgeServerConfigTransportConfig({
+    required QuicServerConfig config,
+    required QuicTransportConfig transportConfig,
+  });
+
+  Future<QuicServerConfig> crateApiBridgeServerConfigUseRetry({
+    required QuicServerConfig config,
+    required bool enabled,
+  });
+
+  Future<QuicServerConfig> crateApiBridgeServerConfigWithCertResolver({
+    required QuicCertificateResolver resolver,
+    required List<Uint8List> alpnProtocols,
+  });
+
+  Future<QuicServerConfig> crateApiBridgeServerConfigWithCertResolverAndKeyLog({
+    required QuicCertificateResolver resolver,
+    required List<Uint8List> alpnProtocols,
+    required QuicKeyLog keyLog,
+  });
+
+  Future<QuicServerConfig> crateApiBridgeServerConfigWithCertificate({
+    required CertificateChain certChain,
+    required PrivateKey key,
+  });
+
+  Future<QuicServerConfig> crateApiBridgeServerConfigWithCryptoAndKeyLog({
+    required List<Uint8List> certChain,
+    required List<int> key,
+    required List<Uint8List> alpnProtocols,
+    required QuicKeyLog keyLog,
+  });
+
+  Future<QuicServerConfig> crateApiBridgeServerConfigWithSingleCert({
+    required List<Uint8List> certChain,
+    required List<int> key,
+  });
+
+  Future<void> crateApiBridgeSetLogFilter({required String filter});
+
+  Future<SimpleQuicServer> crateApiBridgeSimpleQuicServerBind({
+    required QuicServerConfig serverConfig,
+    required String addr,
+    required SimpleQuicServerConfig config,
+  });
+
+  Future<(SimpleQuicServer, SelfSignedCertificate)>
+  crateApiBridgeSimpleQuicServerBindHttp3SelfSigned({
+    required String addr,
+    required List<String> subjectAltNames,
+    required SimpleQuicServerConfig config,
+  });
+
+  Future<(SimpleQuicServer, SelfSignedCertificate)>
+  crateApiBridgeSimpleQuicServerBindSelfSigned({
+    required String addr,
+    required List<String> subjectAltNames,
+    required SimpleQuicServerConfig config,
+  });
+
+  Future<SimpleQuicServerConfig> crateApiBridgeSimpleQuicServerConfigNew();
+
+  Future<(SimpleQuicServer, String)> crateApiBridgeSimpleQuicServerLocalAddr({
+    required SimpleQuicServer server,
+  });
+
+  Future<SimpleQuicServer> crateApiBridgeSimpleQuicServerServe({
+    required SimpleQuicServer server,
+    required FutureOr<Uint8List> Function(Uint8List) handler,
+  });
+
+  Future<SimpleQuicServer> crateApiBridgeSimpleQuicServerServeFiles({
+    required SimpleQuicServer server,
+    required QuicFileReceiver receiver,
+  });
+
+  Future<SimpleQuicServer> crateApiBridgeSimpleQuicServerServeHttp3({
+    required SimpleQuicServer server,
+    required Http3Router router,
+  });
+
+  Future<SimpleQuicServer> crateApiBridgeSimpleQuicServerServePubsub({
+    required SimpleQuicServer server,
+    required QuicPubSubBroker broker,
+  });
+
+  Future<SimpleQuicServer> crateApiBridgeSimpleQuicServerServeRpc({
+    required SimpleQuicServer server,
+    required QuicRpcRegistry registry,
+  });
+
+  Future<(SimpleQuicServer, BigInt)> crateApiBridgeSimpleQuicServerShutdown({
+    required SimpleQuicServer server,
+  });
+
+  Future<(SimpleQuicServer, SimpleQuicServerStats)>
+  crateApiBridgeSimpleQuicServerStats({required SimpleQuicServer server});
+
+  Future<BigInt> crateApiBridgeStreamReassemblerBufferedBytes({
+    required QuicStreamReassembler reassembler,
+  });
+
+  Future<BigInt> crateApiBridgeStreamReassemblerContiguousOffset({
+    required QuicStreamReassembler reassembler,
+  });
+
+  Future<QuicStreamReassembler> crateApiBridgeStreamReassemblerNew();
+
+  Future<Uint8List> crateApiBridgeStreamReassemblerPush({
+    required QuicStreamReassembler reassembler,
+    required QuicStreamChunk chunk,
+  });
+
+  Future<void> crateApiBridgeStreamingTransferCancel({
+    required QuicStreamingTransfer transfer,
+  });
+
+  Future<QuicTransferProgress> crateApiBridgeStreamingTransferProgress({
+    required QuicStreamingTransfer transfer,
+  });
+
+  Future<QuicTransferProgress> crateApiBridgeStreamingTransferWait({
+    required QuicStreamingTransfer transfer,
+  });
+
+  Future<QuicTransportConfig> crateApiBridgeTransportConfigNew();
+
+  Future<(QuicSendStream, QuicRecvStream)>
+  crateApiBridgeWebtransportSessionAcceptBi({
+    required WebTransportSession session,
+  });
+
+  Future<QuicRecvStream> crateApiBridgeWebtransportSessionAcceptUni({
+    required WebTransportSession session,
+  });
+
+  Future<void> crateApiBridgeWebtransportSessionClose({
+    required WebTransportSession session,
+    required int code,
+    required String reason,
+  });
+
+  Future<WebTransportCloseInfo> crateApiBridgeWebtransportSessionClosed({
+    required WebTransportSession session,
+  });
+
+  Future<(QuicEndpoint, WebTransportSession)>
+  crateApiBridgeWebtransportSessionConnect({
+    required QuicEndpoint endpoint,
+    required String url,
+    required List<Http3Header> headers,
+  });
+
+  Future<QuicConnection> crateApiBridgeWebtransportSessionConnection({
+    required WebTransportSession session,
+  });
+
+  Future<BigInt> crateApiBridgeWebtransportSessionId({
+    required WebTransportSession session,
+  });
+
+  Future<BigInt?> crateApiBridgeWebtransportSessionMaxDatagramSize({
+    required WebTransportSession session,
+  });
+
+  Future<(QuicSendStream, QuicRecvStream)>
+  crateApiBridgeWebtransportSessionOpenBi({
+    required WebTransportSession session,
+  });
+
+  Future<QuicSendStream> crateApiBridgeWebtransportSessionOpenUni({
+    required WebTransportSession session,
+  });
+
+  Future<Uint8List> crateApiBridgeWebtransportSessionReadDatagram({
+    required WebTransportSession session,
+  });
+
+  Future<void> crateApiBridgeWebtransportSessionSendDatagram({
+    required WebTransportSession session,
+    required List<int> data,
+  });
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_Http3Client;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_Http3Client;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Http3ClientPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_Http3ResponseStream;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_Http3ResponseStream;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_Http3ResponseStreamPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_Http3Router;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_Http3Router;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_Http3RouterPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_Http3ServerStream;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_Http3ServerStream;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_Http3ServerStreamPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicCertificateResolver;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicCertificateResolver;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicCertificateResolverPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicClient;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicClient;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_QuicClientPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicConnection;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicConnection;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicConnectionPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicConnectionPool;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicConnectionPool;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicConnectionPoolPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicDatagramChannel;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicDatagramChannel;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicDatagramChannelPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicDatagramMux;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicDatagramMux;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicDatagramMuxPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicDatagramSubscription;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicDatagramSubscription;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicDatagramSubscriptionPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicEndpoint;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicEndpoint;
 
   CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_QuicEndpointPtr;
 
@@ -291,6 +1060,68 @@ abstract class RustLibApi extends BaseApi {
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_QuicEndpointConfigPtr;
 
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicFileReceiver;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicFileReceiver;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicFileReceiverPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicFileUpload;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicFileUpload;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicFileUploadPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicFramedStream;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicFramedStream;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicFramedStreamPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicKeyLog;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicKeyLog;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_QuicKeyLogPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicPubSubBroker;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicPubSubBroker;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicPubSubBrokerPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicPubSubClient;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicPubSubClient;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicPubSubClientPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicPubSubSubscription;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicPubSubSubscription;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicPubSubSubscriptionPtr;
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_QuicRecvStream;
 
@@ -300,6 +1131,41 @@ abstract class RustLibApi extends BaseApi {
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_QuicRecvStreamPtr;
 
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicRpcCall;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicRpcCall;
+
+  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_QuicRpcCallPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicRpcClient;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicRpcClient;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicRpcClientPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicRpcRegistry;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicRpcRegistry;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicRpcRegistryPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicRpcResponder;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicRpcResponder;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicRpcResponderPtr;
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_QuicSendStream;
 
@@ -318,6 +1184,24 @@ abstract class RustLibApi extends BaseApi {
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_QuicServerConfigPtr;
 
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicStreamReassembler;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicStreamReassembler;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicStreamReassemblerPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicStreamingTransfer;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicStreamingTransfer;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicStreamingTransferPtr;
+
   RustArcIncrementStrongCountFnType
   get rust_arc_increment_strong_count_QuicTransportConfig;
 
@@ -326,6 +1210,24 @@ abstract class RustLibApi extends BaseApi {
 
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_QuicTransportConfigPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_SimpleQuicServer;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_SimpleQuicServer;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_SimpleQuicServerPtr;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_WebTransportSession;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_WebTransportSession;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_WebTransportSessionPtr;
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -548,17 +1450,23 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       );
 
   @override
-  Future<(QuicConnection, String?)> crateApiBridgeConnectionCloseReason({
-    required QuicConnection connection,
+  Future<QuicCertificateResolver> crateApiBridgeCertResolverAdd({
+    required QuicCertificateResolver resolver,
+    required String serverName,
+    required CertificateChain certChain,
+    required PrivateKey key,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-            connection,
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver(
+            resolver,
             serializer,
           );
+          sse_encode_String(serverName, serializer);
+          sse_encode_box_autoadd_certificate_chain(certChain, serializer);
+          sse_encode_box_autoadd_private_key(key, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -568,35 +1476,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_string,
-          decodeErrorData: null,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver,
+          decodeErrorData: sse_decode_quic_error,
         ),
-        constMeta: kCrateApiBridgeConnectionCloseReasonConstMeta,
-        argValues: [connection],
+        constMeta: kCrateApiBridgeCertResolverAddConstMeta,
+        argValues: [resolver, serverName, certChain, key],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeConnectionCloseReasonConstMeta =>
+  TaskConstMeta get kCrateApiBridgeCertResolverAddConstMeta =>
       const TaskConstMeta(
-        debugName: "connection_close_reason",
-        argNames: ["connection"],
+        debugName: "cert_resolver_add",
+        argNames: ["resolver", "serverName", "certChain", "key"],
       );
 
   @override
-  Future<(QuicConnection, BigInt)>
-  crateApiBridgeConnectionDatagramSendBufferSpace({
-    required QuicConnection connection,
-  }) {
+  Future<QuicCertificateResolver> crateApiBridgeCertResolverNew() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-            connection,
-            serializer,
-          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -606,34 +1507,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_usize,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiBridgeConnectionDatagramSendBufferSpaceConstMeta,
-        argValues: [connection],
+        constMeta: kCrateApiBridgeCertResolverNewConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeConnectionDatagramSendBufferSpaceConstMeta =>
-      const TaskConstMeta(
-        debugName: "connection_datagram_send_buffer_space",
-        argNames: ["connection"],
-      );
+  TaskConstMeta get kCrateApiBridgeCertResolverNewConstMeta =>
+      const TaskConstMeta(debugName: "cert_resolver_new", argNames: []);
 
   @override
-  Future<(QuicConnection, String?)> crateApiBridgeConnectionLocalIp({
-    required QuicConnection connection,
+  Future<(QuicCertificateResolver, bool)> crateApiBridgeCertResolverRemove({
+    required QuicCertificateResolver resolver,
+    required String serverName,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-            connection,
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver(
+            resolver,
             serializer,
           );
+          sse_encode_String(serverName, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -643,34 +1543,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_string,
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_certificate_resolver_bool,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiBridgeConnectionLocalIpConstMeta,
-        argValues: [connection],
+        constMeta: kCrateApiBridgeCertResolverRemoveConstMeta,
+        argValues: [resolver, serverName],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeConnectionLocalIpConstMeta =>
+  TaskConstMeta get kCrateApiBridgeCertResolverRemoveConstMeta =>
       const TaskConstMeta(
-        debugName: "connection_local_ip",
-        argNames: ["connection"],
+        debugName: "cert_resolver_remove",
+        argNames: ["resolver", "serverName"],
       );
 
   @override
-  Future<(QuicConnection, BigInt?)> crateApiBridgeConnectionMaxDatagramSize({
-    required QuicConnection connection,
+  Future<QuicCertificateResolver> crateApiBridgeCertResolverSetDefault({
+    required QuicCertificateResolver resolver,
+    required CertificateChain certChain,
+    required PrivateKey key,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-            connection,
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver(
+            resolver,
             serializer,
           );
+          sse_encode_box_autoadd_certificate_chain(certChain, serializer);
+          sse_encode_box_autoadd_private_key(key, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -680,33 +1584,31 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_box_autoadd_usize,
-          decodeErrorData: null,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver,
+          decodeErrorData: sse_decode_quic_error,
         ),
-        constMeta: kCrateApiBridgeConnectionMaxDatagramSizeConstMeta,
-        argValues: [connection],
+        constMeta: kCrateApiBridgeCertResolverSetDefaultConstMeta,
+        argValues: [resolver, certChain, key],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeConnectionMaxDatagramSizeConstMeta =>
+  TaskConstMeta get kCrateApiBridgeCertResolverSetDefaultConstMeta =>
       const TaskConstMeta(
-        debugName: "connection_max_datagram_size",
-        argNames: ["connection"],
+        debugName: "cert_resolver_set_default",
+        argNames: ["resolver", "certChain", "key"],
       );
 
   @override
-  Future<(QuicConnection, QuicSendStream, QuicRecvStream)>
-  crateApiBridgeConnectionOpenBi({required QuicConnection connection}) {
+  Future<CertificateChain> crateApiBridgeCertificateChainFromPem({
+    required List<int> pem,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-            connection,
-            serializer,
-          );
+          sse_encode_list_prim_u_8_loose(pem, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -715,27 +1617,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream,
+          decodeSuccessData: sse_decode_certificate_chain,
           decodeErrorData: sse_decode_quic_error,
         ),
-        constMeta: kCrateApiBridgeConnectionOpenBiConstMeta,
-        argValues: [connection],
+        constMeta: kCrateApiBridgeCertificateChainFromPemConstMeta,
+        argValues: [pem],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeConnectionOpenBiConstMeta =>
+  TaskConstMeta get kCrateApiBridgeCertificateChainFromPemConstMeta =>
       const TaskConstMeta(
-        debugName: "connection_open_bi",
-        argNames: ["connection"],
+        debugName: "certificate_chain_from_pem",
+        argNames: ["pem"],
       );
 
   @override
-  Future<(QuicConnection, QuicSendStream)> crateApiBridgeConnectionOpenUni({
-    required QuicConnection connection,
-  }) {
+  Future<(QuicConnection, QuicSendStream, QuicRecvStream)>
+  crateApiBridgeConnectionAcceptBi({required QuicConnection connection}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -753,24 +1653,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream,
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream,
           decodeErrorData: sse_decode_quic_error,
         ),
-        constMeta: kCrateApiBridgeConnectionOpenUniConstMeta,
+        constMeta: kCrateApiBridgeConnectionAcceptBiConstMeta,
         argValues: [connection],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeConnectionOpenUniConstMeta =>
+  TaskConstMeta get kCrateApiBridgeConnectionAcceptBiConstMeta =>
       const TaskConstMeta(
-        debugName: "connection_open_uni",
+        debugName: "connection_accept_bi",
         argNames: ["connection"],
       );
 
   @override
-  Future<(QuicConnection, Uint8List?)> crateApiBridgeConnectionReadDatagram({
+  Future<(QuicConnection, QuicRecvStream)> crateApiBridgeConnectionAcceptUni({
     required QuicConnection connection,
   }) {
     return handler.executeNormal(
@@ -790,25 +1690,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_list_prim_u_8_strict,
-          decodeErrorData: null,
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream,
+          decodeErrorData: sse_decode_quic_error,
         ),
-        constMeta: kCrateApiBridgeConnectionReadDatagramConstMeta,
+        constMeta: kCrateApiBridgeConnectionAcceptUniConstMeta,
         argValues: [connection],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeConnectionReadDatagramConstMeta =>
+  TaskConstMeta get kCrateApiBridgeConnectionAcceptUniConstMeta =>
       const TaskConstMeta(
-        debugName: "connection_read_datagram",
+        debugName: "connection_accept_uni",
         argNames: ["connection"],
       );
 
   @override
-  Future<(QuicConnection, SocketAddress)>
-  crateApiBridgeConnectionRemoteAddress({required QuicConnection connection}) {
+  Future<(QuicConnection, String?)> crateApiBridgeConnectionCloseReason({
+    required QuicConnection connection,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -826,25 +1727,27 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_socket_address,
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_string,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiBridgeConnectionRemoteAddressConstMeta,
+        constMeta: kCrateApiBridgeConnectionCloseReasonConstMeta,
         argValues: [connection],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeConnectionRemoteAddressConstMeta =>
+  TaskConstMeta get kCrateApiBridgeConnectionCloseReasonConstMeta =>
       const TaskConstMeta(
-        debugName: "connection_remote_address",
+        debugName: "connection_close_reason",
         argNames: ["connection"],
       );
 
   @override
-  Future<(QuicConnection, BigInt)> crateApiBridgeConnectionRttMillis({
+  Future<(QuicConnection, QuicDatagramMux)>
+  crateApiBridgeConnectionDatagramMux({
     required QuicConnection connection,
+    required QuicDatagramChannelConfig config,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -854,6 +1757,10 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             connection,
             serializer,
           );
+          sse_encode_box_autoadd_quic_datagram_channel_config(
+            config,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -863,26 +1770,26 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_u_64,
-          decodeErrorData: null,
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_datagram_mux,
+          decodeErrorData: sse_decode_quic_error,
         ),
-        constMeta: kCrateApiBridgeConnectionRttMillisConstMeta,
-        argValues: [connection],
+        constMeta: kCrateApiBridgeConnectionDatagramMuxConstMeta,
+        argValues: [connection, config],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeConnectionRttMillisConstMeta =>
+  TaskConstMeta get kCrateApiBridgeConnectionDatagramMuxConstMeta =>
       const TaskConstMeta(
-        debugName: "connection_rtt_millis",
-        argNames: ["connection"],
+        debugName: "connection_datagram_mux",
+        argNames: ["connection", "config"],
       );
 
   @override
-  Future<QuicConnection> crateApiBridgeConnectionSendDatagram({
+  Future<(QuicConnection, BigInt)>
+  crateApiBridgeConnectionDatagramSendBufferSpace({
     required QuicConnection connection,
-    required List<int> data,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -892,7 +1799,6 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             connection,
             serializer,
           );
-          sse_encode_list_prim_u_8_loose(data, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -902,26 +1808,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection,
-          decodeErrorData: sse_decode_quic_datagram_exception,
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_usize,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiBridgeConnectionSendDatagramConstMeta,
-        argValues: [connection, data],
+        constMeta: kCrateApiBridgeConnectionDatagramSendBufferSpaceConstMeta,
+        argValues: [connection],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeConnectionSendDatagramConstMeta =>
+  TaskConstMeta get kCrateApiBridgeConnectionDatagramSendBufferSpaceConstMeta =>
       const TaskConstMeta(
-        debugName: "connection_send_datagram",
-        argNames: ["connection", "data"],
+        debugName: "connection_datagram_send_buffer_space",
+        argNames: ["connection"],
       );
 
   @override
-  Future<QuicConnection> crateApiBridgeConnectionSendDatagramWait({
+  Future<(QuicConnection, String?)> crateApiBridgeConnectionLocalIp({
     required QuicConnection connection,
-    required List<int> data,
   }) {
     return handler.executeNormal(
       NormalTask(
@@ -931,7 +1836,6 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
             connection,
             serializer,
           );
-          sse_encode_list_prim_u_8_loose(data, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -941,24 +1845,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection,
-          decodeErrorData: sse_decode_quic_datagram_exception,
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_string,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiBridgeConnectionSendDatagramWaitConstMeta,
-        argValues: [connection, data],
+        constMeta: kCrateApiBridgeConnectionLocalIpConstMeta,
+        argValues: [connection],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeConnectionSendDatagramWaitConstMeta =>
+  TaskConstMeta get kCrateApiBridgeConnectionLocalIpConstMeta =>
       const TaskConstMeta(
-        debugName: "connection_send_datagram_wait",
-        argNames: ["connection", "data"],
+        debugName: "connection_local_ip",
+        argNames: ["connection"],
       );
 
   @override
-  Future<(QuicConnection, BigInt)> crateApiBridgeConnectionStableId({
+  Future<(QuicConnection, BigInt?)> crateApiBridgeConnectionMaxDatagramSize({
     required QuicConnection connection,
   }) {
     return handler.executeNormal(
@@ -978,26 +1882,25 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_usize,
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_box_autoadd_usize,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiBridgeConnectionStableIdConstMeta,
+        constMeta: kCrateApiBridgeConnectionMaxDatagramSizeConstMeta,
         argValues: [connection],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeConnectionStableIdConstMeta =>
+  TaskConstMeta get kCrateApiBridgeConnectionMaxDatagramSizeConstMeta =>
       const TaskConstMeta(
-        debugName: "connection_stable_id",
+        debugName: "connection_max_datagram_size",
         argNames: ["connection"],
       );
 
   @override
-  Future<(QuicConnection, QuicConnectionStats)> crateApiBridgeConnectionStats({
-    required QuicConnection connection,
-  }) {
+  Future<(QuicConnection, QuicSendStream, QuicRecvStream)>
+  crateApiBridgeConnectionOpenBi({required QuicConnection connection}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -1015,28 +1918,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_quic_connection_stats,
-          decodeErrorData: null,
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream,
+          decodeErrorData: sse_decode_quic_error,
         ),
-        constMeta: kCrateApiBridgeConnectionStatsConstMeta,
+        constMeta: kCrateApiBridgeConnectionOpenBiConstMeta,
         argValues: [connection],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeConnectionStatsConstMeta =>
+  TaskConstMeta get kCrateApiBridgeConnectionOpenBiConstMeta =>
       const TaskConstMeta(
-        debugName: "connection_stats",
+        debugName: "connection_open_bi",
         argNames: ["connection"],
       );
 
   @override
-  Future<QuicEndpoint> crateApiBridgeCreateClientEndpoint() {
+  Future<(QuicConnection, QuicSendStream)> crateApiBridgeConnectionOpenUni({
+    required QuicConnection connection,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+            connection,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1046,33 +1955,44 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint,
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream,
           decodeErrorData: sse_decode_quic_error,
         ),
-        constMeta: kCrateApiBridgeCreateClientEndpointConstMeta,
-        argValues: [],
+        constMeta: kCrateApiBridgeConnectionOpenUniConstMeta,
+        argValues: [connection],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeCreateClientEndpointConstMeta =>
-      const TaskConstMeta(debugName: "create_client_endpoint", argNames: []);
+  TaskConstMeta get kCrateApiBridgeConnectionOpenUniConstMeta =>
+      const TaskConstMeta(
+        debugName: "connection_open_uni",
+        argNames: ["connection"],
+      );
 
   @override
-  Future<QuicEndpoint> crateApiBridgeCreateServerEndpoint({
-    required QuicServerConfig config,
+  Future<(QuicConnectionPool, QuicEndpoint, QuicConnection)>
+  crateApiBridgeConnectionPoolAcquire({
+    required QuicConnectionPool pool,
+    required QuicEndpoint endpoint,
     required String addr,
+    required String serverName,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
-            config,
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+            pool,
+            serializer,
+          );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+            endpoint,
             serializer,
           );
           sse_encode_String(addr, serializer);
+          sse_encode_String(serverName, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1082,28 +2002,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint,
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection,
           decodeErrorData: sse_decode_quic_error,
         ),
-        constMeta: kCrateApiBridgeCreateServerEndpointConstMeta,
-        argValues: [config, addr],
+        constMeta: kCrateApiBridgeConnectionPoolAcquireConstMeta,
+        argValues: [pool, endpoint, addr, serverName],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeCreateServerEndpointConstMeta =>
+  TaskConstMeta get kCrateApiBridgeConnectionPoolAcquireConstMeta =>
       const TaskConstMeta(
-        debugName: "create_server_endpoint",
-        argNames: ["config", "addr"],
+        debugName: "connection_pool_acquire",
+        argNames: ["pool", "endpoint", "addr", "serverName"],
       );
 
   @override
-  Future<QuicEndpointConfig> crateApiBridgeEndpointConfigNew() {
+  Future<QuicConnectionPool> crateApiBridgeConnectionPoolClear({
+    required QuicConnectionPool pool,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+            pool,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1113,35 +2039,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiBridgeEndpointConfigNewConstMeta,
-        argValues: [],
+        constMeta: kCrateApiBridgeConnectionPoolClearConstMeta,
+        argValues: [pool],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeEndpointConfigNewConstMeta =>
-      const TaskConstMeta(debugName: "endpoint_config_new", argNames: []);
+  TaskConstMeta get kCrateApiBridgeConnectionPoolClearConstMeta =>
+      const TaskConstMeta(
+        debugName: "connection_pool_clear",
+        argNames: ["pool"],
+      );
 
   @override
-  Future<(QuicEndpoint, QuicConnection)> crateApiBridgeEndpointConnect({
-    required QuicEndpoint endpoint,
-    required String addr,
-    required String serverName,
-  }) {
+  Future<QuicConnectionPoolConfig> crateApiBridgeConnectionPoolConfigNew() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
-            endpoint,
-            serializer,
-          );
-          sse_encode_String(addr, serializer);
-          sse_encode_String(serverName, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1150,29 +2069,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection,
-          decodeErrorData: sse_decode_quic_error,
+          decodeSuccessData: sse_decode_quic_connection_pool_config,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiBridgeEndpointConnectConstMeta,
-        argValues: [endpoint, addr, serverName],
+        constMeta: kCrateApiBridgeConnectionPoolConfigNewConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeEndpointConnectConstMeta =>
+  TaskConstMeta get kCrateApiBridgeConnectionPoolConfigNewConstMeta =>
       const TaskConstMeta(
-        debugName: "endpoint_connect",
-        argNames: ["endpoint", "addr", "serverName"],
+        debugName: "connection_pool_config_new",
+        argNames: [],
       );
 
   @override
-  Future<void> crateApiBridgeInitApp() {
+  Future<(QuicConnectionPool, BigInt)> crateApiBridgeConnectionPoolEvictIdle({
+    required QuicConnectionPool pool,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+            pool,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1181,29 +2105,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_usize,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiBridgeInitAppConstMeta,
-        argValues: [],
+        constMeta: kCrateApiBridgeConnectionPoolEvictIdleConstMeta,
+        argValues: [pool],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateApiBridgeConnectionPoolEvictIdleConstMeta =>
+      const TaskConstMeta(
+        debugName: "connection_pool_evict_idle",
+        argNames: ["pool"],
+      );
 
   @override
-  Future<QuicClient> crateApiBridgeQuicClientClearPool({
-    required QuicClient client,
+  Future<QuicConnectionPool> crateApiBridgeConnectionPoolNew({
+    required QuicConnectionPoolConfig config,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
-            client,
+          sse_encode_box_autoadd_quic_connection_pool_config(
+            config,
             serializer,
           );
           pdeCallFfi(
@@ -1215,32 +2143,38 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiBridgeQuicClientClearPoolConstMeta,
-        argValues: [client],
+        constMeta: kCrateApiBridgeConnectionPoolNewConstMeta,
+        argValues: [config],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeQuicClientClearPoolConstMeta =>
+  TaskConstMeta get kCrateApiBridgeConnectionPoolNewConstMeta =>
       const TaskConstMeta(
-        debugName: "quic_client_clear_pool",
-        argNames: ["client"],
+        debugName: "connection_pool_new",
+        argNames: ["config"],
       );
 
   @override
-  Future<(QuicClient, QuicClientConfig)> crateApiBridgeQuicClientConfig({
-    required QuicClient client,
+  Future<(QuicConnectionPool, QuicConnection)>
+  crateApiBridgeConnectionPoolRelease({
+    required QuicConnectionPool pool,
+    required QuicConnection connection,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
-            client,
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+            pool,
+            serializer,
+          );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+            connection,
             serializer,
           );
           pdeCallFfi(
@@ -1252,28 +2186,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_quic_client_config,
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiBridgeQuicClientConfigConstMeta,
-        argValues: [client],
+        constMeta: kCrateApiBridgeConnectionPoolReleaseConstMeta,
+        argValues: [pool, connection],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeQuicClientConfigConstMeta =>
+  TaskConstMeta get kCrateApiBridgeConnectionPoolReleaseConstMeta =>
       const TaskConstMeta(
-        debugName: "quic_client_config",
-        argNames: ["client"],
+        debugName: "connection_pool_release",
+        argNames: ["pool", "connection"],
       );
 
   @override
-  Future<QuicClientConfig> crateApiBridgeQuicClientConfigNew() {
+  Future<(QuicConnectionPool, QuicConnection, bool)>
+  crateApiBridgeConnectionPoolRemove({
+    required QuicConnectionPool pool,
+    required QuicConnection connection,
+  }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+            pool,
+            serializer,
+          );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+            connection,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1282,25 +2228,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_quic_client_config,
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_bool,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiBridgeQuicClientConfigNewConstMeta,
-        argValues: [],
+        constMeta: kCrateApiBridgeConnectionPoolRemoveConstMeta,
+        argValues: [pool, connection],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeQuicClientConfigNewConstMeta =>
-      const TaskConstMeta(debugName: "quic_client_config_new", argNames: []);
+  TaskConstMeta get kCrateApiBridgeConnectionPoolRemoveConstMeta =>
+      const TaskConstMeta(
+        debugName: "connection_pool_remove",
+        argNames: ["pool", "connection"],
+      );
 
   @override
-  Future<QuicClient> crateApiBridgeQuicClientCreate() {
+  Future<(QuicConnectionPool, QuicConnectionPoolStats)>
+  crateApiBridgeConnectionPoolStats({required QuicConnectionPool pool}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+            pool,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1310,28 +2265,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient,
-          decodeErrorData: sse_decode_quic_error,
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_quic_connection_pool_stats,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiBridgeQuicClientCreateConstMeta,
-        argValues: [],
+        constMeta: kCrateApiBridgeConnectionPoolStatsConstMeta,
+        argValues: [pool],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeQuicClientCreateConstMeta =>
-      const TaskConstMeta(debugName: "quic_client_create", argNames: []);
+  TaskConstMeta get kCrateApiBridgeConnectionPoolStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "connection_pool_stats",
+        argNames: ["pool"],
+      );
 
   @override
-  Future<QuicClient> crateApiBridgeQuicClientCreateWithConfig({
-    required QuicClientConfig config,
+  Future<(QuicConnection, Uint8List?)> crateApiBridgeConnectionReadDatagram({
+    required QuicConnection connection,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_quic_client_config(config, serializer);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+            connection,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1341,36 +2302,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient,
-          decodeErrorData: sse_decode_quic_error,
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_list_prim_u_8_strict,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiBridgeQuicClientCreateWithConfigConstMeta,
-        argValues: [config],
+        constMeta: kCrateApiBridgeConnectionReadDatagramConstMeta,
+        argValues: [connection],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeQuicClientCreateWithConfigConstMeta =>
+  TaskConstMeta get kCrateApiBridgeConnectionReadDatagramConstMeta =>
       const TaskConstMeta(
-        debugName: "quic_client_create_with_config",
-        argNames: ["config"],
+        debugName: "connection_read_datagram",
+        argNames: ["connection"],
       );
 
   @override
-  Future<(QuicClient, String)> crateApiBridgeQuicClientGet({
-    required QuicClient client,
-    required String url,
-  }) {
+  Future<(QuicConnection, SocketAddress)>
+  crateApiBridgeConnectionRemoteAddress({required QuicConnection connection}) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
-            client,
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+            connection,
             serializer,
           );
-          sse_encode_String(url, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1380,36 +2338,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_string,
-          decodeErrorData: sse_decode_quic_error,
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_socket_address,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiBridgeQuicClientGetConstMeta,
-        argValues: [client, url],
+        constMeta: kCrateApiBridgeConnectionRemoteAddressConstMeta,
+        argValues: [connection],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeQuicClientGetConstMeta =>
+  TaskConstMeta get kCrateApiBridgeConnectionRemoteAddressConstMeta =>
       const TaskConstMeta(
-        debugName: "quic_client_get",
-        argNames: ["client", "url"],
+        debugName: "connection_remote_address",
+        argNames: ["connection"],
       );
 
   @override
-  Future<(QuicClient, String)> crateApiBridgeQuicClientGetWithTimeout({
-    required QuicClient client,
-    required String url,
+  Future<(QuicConnection, BigInt)> crateApiBridgeConnectionRttMillis({
+    required QuicConnection connection,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
-            client,
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+            connection,
             serializer,
           );
-          sse_encode_String(url, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1419,38 +2375,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_string,
-          decodeErrorData: sse_decode_quic_error,
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_u_64,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiBridgeQuicClientGetWithTimeoutConstMeta,
-        argValues: [client, url],
+        constMeta: kCrateApiBridgeConnectionRttMillisConstMeta,
+        argValues: [connection],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeQuicClientGetWithTimeoutConstMeta =>
+  TaskConstMeta get kCrateApiBridgeConnectionRttMillisConstMeta =>
       const TaskConstMeta(
-        debugName: "quic_client_get_with_timeout",
-        argNames: ["client", "url"],
+        debugName: "connection_rtt_millis",
+        argNames: ["connection"],
       );
 
   @override
-  Future<(QuicClient, String)> crateApiBridgeQuicClientPost({
-    required QuicClient client,
-    required String url,
-    required String data,
+  Future<QuicConnection> crateApiBridgeConnectionSendDatagram({
+    required QuicConnection connection,
+    required List<int> data,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
-            client,
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+            connection,
             serializer,
           );
-          sse_encode_String(url, serializer);
-          sse_encode_String(data, serializer);
+          sse_encode_list_prim_u_8_loose(data, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1460,38 +2414,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_string,
-          decodeErrorData: sse_decode_quic_error,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection,
+          decodeErrorData: sse_decode_quic_datagram_exception,
         ),
-        constMeta: kCrateApiBridgeQuicClientPostConstMeta,
-        argValues: [client, url, data],
+        constMeta: kCrateApiBridgeConnectionSendDatagramConstMeta,
+        argValues: [connection, data],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeQuicClientPostConstMeta =>
+  TaskConstMeta get kCrateApiBridgeConnectionSendDatagramConstMeta =>
       const TaskConstMeta(
-        debugName: "quic_client_post",
-        argNames: ["client", "url", "data"],
+        debugName: "connection_send_datagram",
+        argNames: ["connection", "data"],
       );
 
   @override
-  Future<(QuicClient, String)> crateApiBridgeQuicClientPostWithTimeout({
-    required QuicClient client,
-    required String url,
-    required String data,
+  Future<QuicConnection> crateApiBridgeConnectionSendDatagramWait({
+    required QuicConnection connection,
+    required List<int> data,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
-            client,
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+            connection,
             serializer,
           );
-          sse_encode_String(url, serializer);
-          sse_encode_String(data, serializer);
+          sse_encode_list_prim_u_8_loose(data, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1501,38 +2453,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_string,
-          decodeErrorData: sse_decode_quic_error,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection,
+          decodeErrorData: sse_decode_quic_datagram_exception,
         ),
-        constMeta: kCrateApiBridgeQuicClientPostWithTimeoutConstMeta,
-        argValues: [client, url, data],
+        constMeta: kCrateApiBridgeConnectionSendDatagramWaitConstMeta,
+        argValues: [connection, data],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeQuicClientPostWithTimeoutConstMeta =>
+  TaskConstMeta get kCrateApiBridgeConnectionSendDatagramWaitConstMeta =>
       const TaskConstMeta(
-        debugName: "quic_client_post_with_timeout",
-        argNames: ["client", "url", "data"],
+        debugName: "connection_send_datagram_wait",
+        argNames: ["connection", "data"],
       );
 
   @override
-  Future<(QuicClient, String)> crateApiBridgeQuicClientSend({
-    required QuicClient client,
-    required String url,
-    required String data,
+  Future<(QuicConnection, BigInt)> crateApiBridgeConnectionStableId({
+    required QuicConnection connection,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
-            client,
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+            connection,
             serializer,
           );
-          sse_encode_String(url, serializer);
-          sse_encode_String(data, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1542,38 +2490,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_string,
-          decodeErrorData: sse_decode_quic_error,
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_usize,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiBridgeQuicClientSendConstMeta,
-        argValues: [client, url, data],
+        constMeta: kCrateApiBridgeConnectionStableIdConstMeta,
+        argValues: [connection],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeQuicClientSendConstMeta =>
+  TaskConstMeta get kCrateApiBridgeConnectionStableIdConstMeta =>
       const TaskConstMeta(
-        debugName: "quic_client_send",
-        argNames: ["client", "url", "data"],
+        debugName: "connection_stable_id",
+        argNames: ["connection"],
       );
 
   @override
-  Future<(QuicClient, String)> crateApiBridgeQuicClientSendWithTimeout({
-    required QuicClient client,
-    required String url,
-    required String data,
+  Future<(QuicConnection, QuicConnectionStats)> crateApiBridgeConnectionStats({
+    required QuicConnection connection,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
-            client,
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+            connection,
             serializer,
           );
-          sse_encode_String(url, serializer);
-          sse_encode_String(data, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1583,36 +2527,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_string,
-          decodeErrorData: sse_decode_quic_error,
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_quic_connection_stats,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiBridgeQuicClientSendWithTimeoutConstMeta,
-        argValues: [client, url, data],
+        constMeta: kCrateApiBridgeConnectionStatsConstMeta,
+        argValues: [connection],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeQuicClientSendWithTimeoutConstMeta =>
+  TaskConstMeta get kCrateApiBridgeConnectionStatsConstMeta =>
       const TaskConstMeta(
-        debugName: "quic_client_send_with_timeout",
-        argNames: ["client", "url", "data"],
+        debugName: "connection_stats",
+        argNames: ["connection"],
       );
 
   @override
-  Future<(QuicRecvStream, Uint8List?)> crateApiBridgeRecvStreamRead({
-    required QuicRecvStream stream,
-    required BigInt maxLength,
+  Future<QuicDatagramSubscription> crateApiBridgeConnectionSubscribeDatagrams({
+    required QuicConnection connection,
+    required QuicDatagramSubscriptionConfig config,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
-            stream,
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+            connection,
+            serializer,
+          );
+          sse_encode_box_autoadd_quic_datagram_subscription_config(
+            config,
             serializer,
           );
-          sse_encode_usize(maxLength, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1622,36 +2569,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_opt_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_quic_read_exception,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription,
+          decodeErrorData: sse_decode_quic_error,
         ),
-        constMeta: kCrateApiBridgeRecvStreamReadConstMeta,
-        argValues: [stream, maxLength],
+        constMeta: kCrateApiBridgeConnectionSubscribeDatagramsConstMeta,
+        argValues: [connection, config],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeRecvStreamReadConstMeta =>
+  TaskConstMeta get kCrateApiBridgeConnectionSubscribeDatagramsConstMeta =>
       const TaskConstMeta(
-        debugName: "recv_stream_read",
-        argNames: ["stream", "maxLength"],
+        debugName: "connection_subscribe_datagrams",
+        argNames: ["connection", "config"],
       );
 
   @override
-  Future<(QuicRecvStream, Uint8List)> crateApiBridgeRecvStreamReadToEnd({
-    required QuicRecvStream stream,
-    required BigInt maxLength,
-  }) {
+  Future<QuicEndpoint> crateApiBridgeCreateClientEndpoint() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
-            stream,
-            serializer,
-          );
-          sse_encode_usize(maxLength, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1661,34 +2600,28 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_list_prim_u_8_strict,
-          decodeErrorData: sse_decode_quic_read_to_end_exception,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint,
+          decodeErrorData: sse_decode_quic_error,
         ),
-        constMeta: kCrateApiBridgeRecvStreamReadToEndConstMeta,
-        argValues: [stream, maxLength],
+        constMeta: kCrateApiBridgeCreateClientEndpointConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeRecvStreamReadToEndConstMeta =>
-      const TaskConstMeta(
-        debugName: "recv_stream_read_to_end",
-        argNames: ["stream", "maxLength"],
-      );
+  TaskConstMeta get kCrateApiBridgeCreateClientEndpointConstMeta =>
+      const TaskConstMeta(debugName: "create_client_endpoint", argNames: []);
 
   @override
-  Future<QuicSendStream> crateApiBridgeSendStreamFinish({
-    required QuicSendStream stream,
+  Future<QuicEndpoint> crateApiBridgeCreateClientEndpointWithAlpn({
+    required List<Uint8List> alpnProtocols,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
-            stream,
-            serializer,
-          );
+          sse_encode_list_list_prim_u_8_strict(alpnProtocols, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1698,36 +2631,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream,
-          decodeErrorData: sse_decode_quic_write_exception,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint,
+          decodeErrorData: sse_decode_quic_error,
         ),
-        constMeta: kCrateApiBridgeSendStreamFinishConstMeta,
-        argValues: [stream],
+        constMeta: kCrateApiBridgeCreateClientEndpointWithAlpnConstMeta,
+        argValues: [alpnProtocols],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeSendStreamFinishConstMeta =>
+  TaskConstMeta get kCrateApiBridgeCreateClientEndpointWithAlpnConstMeta =>
       const TaskConstMeta(
-        debugName: "send_stream_finish",
-        argNames: ["stream"],
+        debugName: "create_client_endpoint_with_alpn",
+        argNames: ["alpnProtocols"],
       );
 
   @override
-  Future<(QuicSendStream, BigInt)> crateApiBridgeSendStreamWrite({
-    required QuicSendStream stream,
-    required List<int> data,
+  Future<QuicEndpoint> crateApiBridgeCreateClientEndpointWithKeyLog({
+    required QuicKeyLog keyLog,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
-            stream,
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicKeyLog(
+            keyLog,
             serializer,
           );
-          sse_encode_list_prim_u_8_loose(data, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1737,36 +2668,36 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_usize,
-          decodeErrorData: sse_decode_quic_write_exception,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint,
+          decodeErrorData: sse_decode_quic_error,
         ),
-        constMeta: kCrateApiBridgeSendStreamWriteConstMeta,
-        argValues: [stream, data],
+        constMeta: kCrateApiBridgeCreateClientEndpointWithKeyLogConstMeta,
+        argValues: [keyLog],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeSendStreamWriteConstMeta =>
+  TaskConstMeta get kCrateApiBridgeCreateClientEndpointWithKeyLogConstMeta =>
       const TaskConstMeta(
-        debugName: "send_stream_write",
-        argNames: ["stream", "data"],
+        debugName: "create_client_endpoint_with_key_log",
+        argNames: ["keyLog"],
       );
 
   @override
-  Future<QuicSendStream> crateApiBridgeSendStreamWriteAll({
-    required QuicSendStream stream,
-    required List<int> data,
+  Future<QuicEndpoint> crateApiBridgeCreateServerEndpoint({
+    required QuicServerConfig config,
+    required String addr,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
-            stream,
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+            config,
             serializer,
           );
-          sse_encode_list_prim_u_8_loose(data, serializer);
+          sse_encode_String(addr, serializer);
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1776,33 +2707,34 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
         },
         codec: SseCodec(
           decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream,
-          decodeErrorData: sse_decode_quic_write_exception,
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint,
+          decodeErrorData: sse_decode_quic_error,
         ),
-        constMeta: kCrateApiBridgeSendStreamWriteAllConstMeta,
-        argValues: [stream, data],
+        constMeta: kCrateApiBridgeCreateServerEndpointConstMeta,
+        argValues: [config, addr],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeSendStreamWriteAllConstMeta =>
+  TaskConstMeta get kCrateApiBridgeCreateServerEndpointConstMeta =>
       const TaskConstMeta(
-        debugName: "send_stream_write_all",
-        argNames: ["stream", "data"],
+        debugName: "create_server_endpoint",
+        argNames: ["config", "addr"],
       );
 
   @override
-  Future<QuicServerConfig> crateApiBridgeServerConfigWithSingleCert({
-    required List<Uint8List> certChain,
-    required List<int> key,
+  Future<void> crateApiBridgeDatagramChannelClose({
+    required QuicDatagramChannel channel,
   }) {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_list_prim_u_8_strict(certChain, serializer);
-          sse_encode_list_prim_u_8_loose(key, serializer);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
+            channel,
+            serializer,
+          );
           pdeCallFfi(
             generalizedFrbRustBinding,
             serializer,
@@ -1811,25 +2743,24 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig,
-          decodeErrorData: sse_decode_String,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
         ),
-        constMeta: kCrateApiBridgeServerConfigWithSingleCertConstMeta,
-        argValues: [certChain, key],
+        constMeta: kCrateApiBridgeDatagramChannelCloseConstMeta,
+        argValues: [channel],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeServerConfigWithSingleCertConstMeta =>
+  TaskConstMeta get kCrateApiBridgeDatagramChannelCloseConstMeta =>
       const TaskConstMeta(
-        debugName: "server_config_with_single_cert",
-        argNames: ["certChain", "key"],
+        debugName: "datagram_channel_close",
+        argNames: ["channel"],
       );
 
   @override
-  Future<QuicTransportConfig> crateApiBridgeTransportConfigNew() {
+  Future<QuicDatagramChannelConfig> crateApiBridgeDatagramChannelConfigNew() {
     return handler.executeNormal(
       NormalTask(
         callFfi: (port_) {
@@ -1842,1912 +2773,13708 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           );
         },
         codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig,
+          decodeSuccessData: sse_decode_quic_datagram_channel_config,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiBridgeTransportConfigNewConstMeta,
+        constMeta: kCrateApiBridgeDatagramChannelConfigNewConstMeta,
         argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiBridgeTransportConfigNewConstMeta =>
-      const TaskConstMeta(debugName: "transport_config_new", argNames: []);
+  TaskConstMeta get kCrateApiBridgeDatagramChannelConfigNewConstMeta =>
+      const TaskConstMeta(
+        debugName: "datagram_channel_config_new",
+        argNames: [],
+      );
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_QuicClient => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient;
+  @override
+  Future<BigInt> crateApiBridgeDatagramChannelFlowId({
+    required QuicDatagramChannel channel,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
+            channel,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 43,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeDatagramChannelFlowIdConstMeta,
+        argValues: [channel],
+        apiImpl: this,
+      ),
+    );
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_QuicClient => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient;
+  TaskConstMeta get kCrateApiBridgeDatagramChannelFlowIdConstMeta =>
+      const TaskConstMeta(
+        debugName: "datagram_channel_flow_id",
+        argNames: ["channel"],
+      );
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_QuicConnection => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection;
+  @override
+  Future<BigInt?> crateApiBridgeDatagramChannelMaxPayloadSize({
+    required QuicDatagramChannel channel,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
+            channel,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 44,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_usize,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeDatagramChannelMaxPayloadSizeConstMeta,
+        argValues: [channel],
+        apiImpl: this,
+      ),
+    );
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_QuicConnection => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection;
+  TaskConstMeta get kCrateApiBridgeDatagramChannelMaxPayloadSizeConstMeta =>
+      const TaskConstMeta(
+        debugName: "datagram_channel_max_payload_size",
+        argNames: ["channel"],
+      );
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_QuicEndpoint => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint;
+  @override
+  Future<QuicDatagramBatch> crateApiBridgeDatagramChannelNextBatch({
+    required QuicDatagramChannel channel,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
+            channel,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 45,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_quic_datagram_batch,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeDatagramChannelNextBatchConstMeta,
+        argValues: [channel],
+        apiImpl: this,
+      ),
+    );
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_QuicEndpoint => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint;
+  TaskConstMeta get kCrateApiBridgeDatagramChannelNextBatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "datagram_channel_next_batch",
+        argNames: ["channel"],
+      );
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_QuicEndpointConfig => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig;
+  @override
+  Future<void> crateApiBridgeDatagramChannelSend({
+    required QuicDatagramChannel channel,
+    required List<int> data,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
+            channel,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 46,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_quic_datagram_exception,
+        ),
+        constMeta: kCrateApiBridgeDatagramChannelSendConstMeta,
+        argValues: [channel, data],
+        apiImpl: this,
+      ),
+    );
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_QuicEndpointConfig => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig;
+  TaskConstMeta get kCrateApiBridgeDatagramChannelSendConstMeta =>
+      const TaskConstMeta(
+        debugName: "datagram_channel_send",
+        argNames: ["channel", "data"],
+      );
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_QuicRecvStream => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream;
+  @override
+  Future<QuicDatagramChannel> crateApiBridgeDatagramMuxOpenChannel({
+    required QuicDatagramMux mux,
+    required BigInt flowId,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
+            mux,
+            serializer,
+          );
+          sse_encode_u_64(flowId, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 47,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeDatagramMuxOpenChannelConstMeta,
+        argValues: [mux, flowId],
+        apiImpl: this,
+      ),
+    );
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_QuicRecvStream => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream;
+  TaskConstMeta get kCrateApiBridgeDatagramMuxOpenChannelConstMeta =>
+      const TaskConstMeta(
+        debugName: "datagram_mux_open_channel",
+        argNames: ["mux", "flowId"],
+      );
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_QuicSendStream => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream;
+  @override
+  Future<void> crateApiBridgeDatagramSubscriptionClose({
+    required QuicDatagramSubscription subscription,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription(
+            subscription,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 48,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeDatagramSubscriptionCloseConstMeta,
+        argValues: [subscription],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeDatagramSubscriptionCloseConstMeta =>
+      const TaskConstMeta(
+        debugName: "datagram_subscription_close",
+        argNames: ["subscription"],
+      );
+
+  @override
+  Future<QuicDatagramSubscriptionConfig>
+  crateApiBridgeDatagramSubscriptionConfigNew() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 49,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_quic_datagram_subscription_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeDatagramSubscriptionConfigNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeDatagramSubscriptionConfigNewConstMeta =>
+      const TaskConstMeta(
+        debugName: "datagram_subscription_config_new",
+        argNames: [],
+      );
+
+  @override
+  Future<QuicDatagramBatch> crateApiBridgeDatagramSubscriptionNextBatch({
+    required QuicDatagramSubscription subscription,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription(
+            subscription,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 50,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_quic_datagram_batch,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeDatagramSubscriptionNextBatchConstMeta,
+        argValues: [subscription],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeDatagramSubscriptionNextBatchConstMeta =>
+      const TaskConstMeta(
+        debugName: "datagram_subscription_next_batch",
+        argNames: ["subscription"],
+      );
+
+  @override
+  Future<(QuicEndpoint, QuicConnection?)> crateApiBridgeEndpointAccept({
+    required QuicEndpoint endpoint,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+            endpoint,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 51,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_opt_box_autoadd_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeEndpointAcceptConstMeta,
+        argValues: [endpoint],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeEndpointAcceptConstMeta =>
+      const TaskConstMeta(debugName: "endpoint_accept", argNames: ["endpoint"]);
+
+  @override
+  Future<QuicEndpointConfig> crateApiBridgeEndpointConfigNew() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 52,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeEndpointConfigNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeEndpointConfigNewConstMeta =>
+      const TaskConstMeta(debugName: "endpoint_config_new", argNames: []);
+
+  @override
+  Future<(QuicEndpoint, QuicConnection)> crateApiBridgeEndpointConnect({
+    required QuicEndpoint endpoint,
+    required String addr,
+    required String serverName,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+            endpoint,
+            serializer,
+          );
+          sse_encode_String(addr, serializer);
+          sse_encode_String(serverName, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 53,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeEndpointConnectConstMeta,
+        argValues: [endpoint, addr, serverName],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeEndpointConnectConstMeta =>
+      const TaskConstMeta(
+        debugName: "endpoint_connect",
+        argNames: ["endpoint", "addr", "serverName"],
+      );
+
+  @override
+  Future<QuicEndpoint> crateApiBridgeEndpointSetServerConfig({
+    required QuicEndpoint endpoint,
+    required QuicServerConfig config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+            endpoint,
+            serializer,
+          );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+            config,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 54,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeEndpointSetServerConfigConstMeta,
+        argValues: [endpoint, config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeEndpointSetServerConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "endpoint_set_server_config",
+        argNames: ["endpoint", "config"],
+      );
+
+  @override
+  Future<QuicFileReceiver> crateApiBridgeFileReceiverNew({
+    required String directory,
+    required BigInt maxFileSize,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(directory, serializer);
+          sse_encode_u_64(maxFileSize, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 55,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver,
+          decodeErrorData: sse_decode_quic_file_transfer_exception,
+        ),
+        constMeta: kCrateApiBridgeFileReceiverNewConstMeta,
+        argValues: [directory, maxFileSize],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeFileReceiverNewConstMeta =>
+      const TaskConstMeta(
+        debugName: "file_receiver_new",
+        argNames: ["directory", "maxFileSize"],
+      );
+
+  @override
+  Future<QuicConnection> crateApiBridgeFileReceiverServeConnection({
+    required QuicFileReceiver receiver,
+    required QuicConnection connection,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver(
+            receiver,
+            serializer,
+          );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+            connection,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 56,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeFileReceiverServeConnectionConstMeta,
+        argValues: [receiver, connection],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeFileReceiverServeConnectionConstMeta =>
+      const TaskConstMeta(
+        debugName: "file_receiver_serve_connection",
+        argNames: ["receiver", "connection"],
+      );
+
+  @override
+  Future<QuicFileTransferConfig> crateApiBridgeFileTransferConfigNew() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 57,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_quic_file_transfer_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeFileTransferConfigNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeFileTransferConfigNewConstMeta =>
+      const TaskConstMeta(debugName: "file_transfer_config_new", argNames: []);
+
+  @override
+  Future<void> crateApiBridgeFileUploadCancel({
+    required QuicFileUpload upload,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload(
+            upload,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 58,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeFileUploadCancelConstMeta,
+        argValues: [upload],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeFileUploadCancelConstMeta =>
+      const TaskConstMeta(
+        debugName: "file_upload_cancel",
+        argNames: ["upload"],
+      );
+
+  @override
+  Future<QuicFileTransferProgress> crateApiBridgeFileUploadProgress({
+    required QuicFileUpload upload,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload(
+            upload,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 59,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_quic_file_transfer_progress,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeFileUploadProgressConstMeta,
+        argValues: [upload],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeFileUploadProgressConstMeta =>
+      const TaskConstMeta(
+        debugName: "file_upload_progress",
+        argNames: ["upload"],
+      );
+
+  @override
+  Future<QuicFileUpload> crateApiBridgeFileUploadStart({
+    required QuicConnection connection,
+    required String path,
+    required QuicFileTransferConfig config,
+    required FutureOr<void> Function(QuicFileTransferProgress) onProgress,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+            connection,
+            serializer,
+          );
+          sse_encode_String(path, serializer);
+          sse_encode_box_autoadd_quic_file_transfer_config(config, serializer);
+          sse_encode_DartFn_Inputs_quic_file_transfer_progress_Output_unit_AnyhowException(
+            onProgress,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 60,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload,
+          decodeErrorData: sse_decode_quic_file_transfer_exception,
+        ),
+        constMeta: kCrateApiBridgeFileUploadStartConstMeta,
+        argValues: [connection, path, config, onProgress],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeFileUploadStartConstMeta =>
+      const TaskConstMeta(
+        debugName: "file_upload_start",
+        argNames: ["connection", "path", "config", "onProgress"],
+      );
+
+  @override
+  Future<QuicFileTransferProgress> crateApiBridgeFileUploadWait({
+    required QuicFileUpload upload,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload(
+            upload,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 61,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_quic_file_transfer_progress,
+          decodeErrorData: sse_decode_quic_file_transfer_exception,
+        ),
+        constMeta: kCrateApiBridgeFileUploadWaitConstMeta,
+        argValues: [upload],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeFileUploadWaitConstMeta =>
+      const TaskConstMeta(debugName: "file_upload_wait", argNames: ["upload"]);
+
+  @override
+  Future<void> crateApiBridgeFramedStreamFinish({
+    required QuicFramedStream stream,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream(
+            stream,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 62,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_quic_frame_exception,
+        ),
+        constMeta: kCrateApiBridgeFramedStreamFinishConstMeta,
+        argValues: [stream],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeFramedStreamFinishConstMeta =>
+      const TaskConstMeta(
+        debugName: "framed_stream_finish",
+        argNames: ["stream"],
+      );
+
+  @override
+  Future<QuicFramedStream> crateApiBridgeFramedStreamNew({
+    QuicSendStream? send,
+    QuicRecvStream? recv,
+    required BigInt maxMessageSize,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+            send,
+            serializer,
+          );
+          sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+            recv,
+            serializer,
+          );
+          sse_encode_usize(maxMessageSize, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 63,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeFramedStreamNewConstMeta,
+        argValues: [send, recv, maxMessageSize],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeFramedStreamNewConstMeta =>
+      const TaskConstMeta(
+        debugName: "framed_stream_new",
+        argNames: ["send", "recv", "maxMessageSize"],
+      );
+
+  @override
+  Future<Uint8List?> crateApiBridgeFramedStreamRecv({
+    required QuicFramedStream stream,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream(
+            stream,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 64,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_quic_frame_exception,
+        ),
+        constMeta: kCrateApiBridgeFramedStreamRecvConstMeta,
+        argValues: [stream],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeFramedStreamRecvConstMeta =>
+      const TaskConstMeta(
+        debugName: "framed_stream_recv",
+        argNames: ["stream"],
+      );
+
+  @override
+  Future<void> crateApiBridgeFramedStreamSend({
+    required QuicFramedStream stream,
+    required List<int> message,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream(
+            stream,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(message, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 65,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_quic_frame_exception,
+        ),
+        constMeta: kCrateApiBridgeFramedStreamSendConstMeta,
+        argValues: [stream, message],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeFramedStreamSendConstMeta =>
+      const TaskConstMeta(
+        debugName: "framed_stream_send",
+        argNames: ["stream", "message"],
+      );
+
+  @override
+  Future<SelfSignedCertificate> crateApiBridgeGenerateSelfSignedCertificate({
+    required List<String> subjectAltNames,
+    required int validityDays,
+    required CertificateKeyAlgorithm algorithm,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(subjectAltNames, serializer);
+          sse_encode_u_32(validityDays, serializer);
+          sse_encode_certificate_key_algorithm(algorithm, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 66,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_self_signed_certificate,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeGenerateSelfSignedCertificateConstMeta,
+        argValues: [subjectAltNames, validityDays, algorithm],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeGenerateSelfSignedCertificateConstMeta =>
+      const TaskConstMeta(
+        debugName: "generate_self_signed_certificate",
+        argNames: ["subjectAltNames", "validityDays", "algorithm"],
+      );
+
+  @override
+  Future<Http3Client> crateApiBridgeHttp3ClientClose({
+    required Http3Client client,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client(
+            client,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 67,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeHttp3ClientCloseConstMeta,
+        argValues: [client],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeHttp3ClientCloseConstMeta =>
+      const TaskConstMeta(
+        debugName: "http3_client_close",
+        argNames: ["client"],
+      );
+
+  @override
+  Future<Http3ClientConfig> crateApiBridgeHttp3ClientConfigNew() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 68,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_http_3_client_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeHttp3ClientConfigNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeHttp3ClientConfigNewConstMeta =>
+      const TaskConstMeta(debugName: "http3_client_config_new", argNames: []);
+
+  @override
+  Future<Http3Client> crateApiBridgeHttp3ClientCreate({
+    required Http3ClientConfig config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_http_3_client_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 69,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeHttp3ClientCreateConstMeta,
+        argValues: [config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeHttp3ClientCreateConstMeta =>
+      const TaskConstMeta(
+        debugName: "http3_client_create",
+        argNames: ["config"],
+      );
+
+  @override
+  Future<(Http3Client, Http3Response)> crateApiBridgeHttp3ClientSend({
+    required Http3Client client,
+    required Http3Request request,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client(
+            client,
+            serializer,
+          );
+          sse_encode_box_autoadd_http_3_request(request, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 70,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_http_3_client_http_3_response,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeHttp3ClientSendConstMeta,
+        argValues: [client, request],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeHttp3ClientSendConstMeta =>
+      const TaskConstMeta(
+        debugName: "http3_client_send",
+        argNames: ["client", "request"],
+      );
+
+  @override
+  Future<(Http3Client, Http3ResponseStream)>
+  crateApiBridgeHttp3ClientSendStreaming({
+    required Http3Client client,
+    required Http3Request request,
+    required FutureOr<Uint8List?> Function() nextBodyChunk,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client(
+            client,
+            serializer,
+          );
+          sse_encode_box_autoadd_http_3_request(request, serializer);
+          sse_encode_DartFn_Inputs__Output_opt_list_prim_u_8_strict_AnyhowException(
+            nextBodyChunk,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 71,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_http_3_client_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_http_3_response_stream,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeHttp3ClientSendStreamingConstMeta,
+        argValues: [client, request, nextBodyChunk],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeHttp3ClientSendStreamingConstMeta =>
+      const TaskConstMeta(
+        debugName: "http3_client_send_streaming",
+        argNames: ["client", "request", "nextBodyChunk"],
+      );
+
+  @override
+  Future<Http3Request> crateApiBridgeHttp3RequestNew({
+    required String method,
+    required String url,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(method, serializer);
+          sse_encode_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 72,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_http_3_request,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeHttp3RequestNewConstMeta,
+        argValues: [method, url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeHttp3RequestNewConstMeta =>
+      const TaskConstMeta(
+        debugName: "http3_request_new",
+        argNames: ["method", "url"],
+      );
+
+  @override
+  Future<void> crateApiBridgeHttp3ResponseStreamCancel({
+    required Http3ResponseStream response,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+            response,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 73,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeHttp3ResponseStreamCancelConstMeta,
+        argValues: [response],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeHttp3ResponseStreamCancelConstMeta =>
+      const TaskConstMeta(
+        debugName: "http3_response_stream_cancel",
+        argNames: ["response"],
+      );
+
+  @override
+  Future<List<Http3Header>> crateApiBridgeHttp3ResponseStreamHeaders({
+    required Http3ResponseStream response,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+            response,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 74,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_http_3_header,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeHttp3ResponseStreamHeadersConstMeta,
+        argValues: [response],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeHttp3ResponseStreamHeadersConstMeta =>
+      const TaskConstMeta(
+        debugName: "http3_response_stream_headers",
+        argNames: ["response"],
+      );
+
+  @override
+  Future<Uint8List?> crateApiBridgeHttp3ResponseStreamNextChunk({
+    required Http3ResponseStream response,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+            response,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 75,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeHttp3ResponseStreamNextChunkConstMeta,
+        argValues: [response],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeHttp3ResponseStreamNextChunkConstMeta =>
+      const TaskConstMeta(
+        debugName: "http3_response_stream_next_chunk",
+        argNames: ["response"],
+      );
+
+  @override
+  Future<int> crateApiBridgeHttp3ResponseStreamStatus({
+    required Http3ResponseStream response,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+            response,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 76,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_16,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeHttp3ResponseStreamStatusConstMeta,
+        argValues: [response],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeHttp3ResponseStreamStatusConstMeta =>
+      const TaskConstMeta(
+        debugName: "http3_response_stream_status",
+        argNames: ["response"],
+      );
+
+  @override
+  Future<List<Http3Header>> crateApiBridgeHttp3ResponseStreamTrailers({
+    required Http3ResponseStream response,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+            response,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 77,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_http_3_header,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeHttp3ResponseStreamTrailersConstMeta,
+        argValues: [response],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeHttp3ResponseStreamTrailersConstMeta =>
+      const TaskConstMeta(
+        debugName: "http3_response_stream_trailers",
+        argNames: ["response"],
+      );
+
+  @override
+  Future<Http3Router> crateApiBridgeHttp3RouterNew() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 78,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Router,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeHttp3RouterNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeHttp3RouterNewConstMeta =>
+      const TaskConstMeta(debugName: "http3_router_new", argNames: []);
+
+  @override
+  Future<Http3Router> crateApiBridgeHttp3RouterRoute({
+    required Http3Router router,
+    required String method,
+    required String path,
+    required FutureOr<void> Function(Http3ServerRequest, Http3ServerStream) handler,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Router(
+            router,
+            serializer,
+          );
+          sse_encode_String(method, serializer);
+          sse_encode_String(path, serializer);
+          sse_encode_DartFn_Inputs_http_3_server_request_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream_Output_unit_AnyhowException(
+            handler,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 79,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Router,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeHttp3RouterRouteConstMeta,
+        argValues: [router, method, path, handler],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeHttp3RouterRouteConstMeta =>
+      const TaskConstMeta(
+        debugName: "http3_router_route",
+        argNames: ["router", "method", "path", "handler"],
+      );
+
+  @override
+  Future<void> crateApiBridgeHttp3ServerStreamFinish({
+    required Http3ServerStream stream,
+    required List<Http3Header> trailers,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+            stream,
+            serializer,
+          );
+          sse_encode_list_http_3_header(trailers, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 80,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeHttp3ServerStreamFinishConstMeta,
+        argValues: [stream, trailers],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeHttp3ServerStreamFinishConstMeta =>
+      const TaskConstMeta(
+        debugName: "http3_server_stream_finish",
+        argNames: ["stream", "trailers"],
+      );
+
+  @override
+  Future<Uint8List?> crateApiBridgeHttp3ServerStreamNextChunk({
+    required Http3ServerStream stream,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+            stream,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 81,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeHttp3ServerStreamNextChunkConstMeta,
+        argValues: [stream],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeHttp3ServerStreamNextChunkConstMeta =>
+      const TaskConstMeta(
+        debugName: "http3_server_stream_next_chunk",
+        argNames: ["stream"],
+      );
+
+  @override
+  Future<Uint8List> crateApiBridgeHttp3ServerStreamReadBody({
+    required Http3ServerStream stream,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+            stream,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 82,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeHttp3ServerStreamReadBodyConstMeta,
+        argValues: [stream],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeHttp3ServerStreamReadBodyConstMeta =>
+      const TaskConstMeta(
+        debugName: "http3_server_stream_read_body",
+        argNames: ["stream"],
+      );
+
+  @override
+  Future<void> crateApiBridgeHttp3ServerStreamRespond({
+    required Http3ServerStream stream,
+    required int status,
+    required List<Http3Header> headers,
+    required List<int> body,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+            stream,
+            serializer,
+          );
+          sse_encode_u_16(status, serializer);
+          sse_encode_list_http_3_header(headers, serializer);
+          sse_encode_list_prim_u_8_loose(body, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 83,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeHttp3ServerStreamRespondConstMeta,
+        argValues: [stream, status, headers, body],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeHttp3ServerStreamRespondConstMeta =>
+      const TaskConstMeta(
+        debugName: "http3_server_stream_respond",
+        argNames: ["stream", "status", "headers", "body"],
+      );
+
+  @override
+  Future<void> crateApiBridgeHttp3ServerStreamSendData({
+    required Http3ServerStream stream,
+    required List<int> chunk,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+            stream,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(chunk, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 84,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeHttp3ServerStreamSendDataConstMeta,
+        argValues: [stream, chunk],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeHttp3ServerStreamSendDataConstMeta =>
+      const TaskConstMeta(
+        debugName: "http3_server_stream_send_data",
+        argNames: ["stream", "chunk"],
+      );
+
+  @override
+  Future<void> crateApiBridgeHttp3ServerStreamSendResponse({
+    required Http3ServerStream stream,
+    required int status,
+    required List<Http3Header> headers,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+            stream,
+            serializer,
+          );
+          sse_encode_u_16(status, serializer);
+          sse_encode_list_http_3_header(headers, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 85,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeHttp3ServerStreamSendResponseConstMeta,
+        argValues: [stream, status, headers],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeHttp3ServerStreamSendResponseConstMeta =>
+      const TaskConstMeta(
+        debugName: "http3_server_stream_send_response",
+        argNames: ["stream", "status", "headers"],
+      );
+
+  @override
+  Future<List<Http3Header>> crateApiBridgeHttp3ServerStreamTrailers({
+    required Http3ServerStream stream,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+            stream,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 86,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_http_3_header,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeHttp3ServerStreamTrailersConstMeta,
+        argValues: [stream],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeHttp3ServerStreamTrailersConstMeta =>
+      const TaskConstMeta(
+        debugName: "http3_server_stream_trailers",
+        argNames: ["stream"],
+      );
+
+  @override
+  Future<void> crateApiBridgeInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 87,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
+
+  @override
+  Stream<QuicLogRecord> crateApiBridgeInitLogging({required String filter}) {
+    final sink = RustStreamSink<QuicLogRecord>();
+    unawaited(
+      handler.executeNormal(
+        NormalTask(
+          callFfi: (port_) {
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            sse_encode_String(filter, serializer);
+            sse_encode_StreamSink_quic_log_record_Sse(sink, serializer);
+            pdeCallFfi(
+              generalizedFrbRustBinding,
+              serializer,
+              funcId: 88,
+              port: port_,
+            );
+          },
+          codec: SseCodec(
+            decodeSuccessData: sse_decode_unit,
+            decodeErrorData: sse_decode_quic_error,
+          ),
+          constMeta: kCrateApiBridgeInitLoggingConstMeta,
+          argValues: [filter, sink],
+          apiImpl: this,
+        ),
+      ),
+    );
+    return sink.stream;
+  }
+
+  TaskConstMeta get kCrateApiBridgeInitLoggingConstMeta => const TaskConstMeta(
+    debugName: "init_logging",
+    argNames: ["filter", "sink"],
+  );
+
+  @override
+  Future<QuicKeyLog> crateApiBridgeKeyLogCallback({
+    required FutureOr<void> Function(String) onLine,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
+            onLine,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 89,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicKeyLog,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeKeyLogCallbackConstMeta,
+        argValues: [onLine],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeKeyLogCallbackConstMeta =>
+      const TaskConstMeta(debugName: "key_log_callback", argNames: ["onLine"]);
+
+  @override
+  Future<QuicKeyLog> crateApiBridgeKeyLogFile({required String path}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 90,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicKeyLog,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeKeyLogFileConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeKeyLogFileConstMeta =>
+      const TaskConstMeta(debugName: "key_log_file", argNames: ["path"]);
+
+  @override
+  Future<(CertificateChain, PrivateKey)> crateApiBridgeLoadPkcs12({
+    required List<int> data,
+    required String password,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          sse_encode_String(password, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 91,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_record_certificate_chain_private_key,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeLoadPkcs12ConstMeta,
+        argValues: [data, password],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeLoadPkcs12ConstMeta => const TaskConstMeta(
+    debugName: "load_pkcs12",
+    argNames: ["data", "password"],
+  );
+
+  @override
+  Future<PrivateKey> crateApiBridgePrivateKeyFromPem({required List<int> pem}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_prim_u_8_loose(pem, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 92,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_private_key,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgePrivateKeyFromPemConstMeta,
+        argValues: [pem],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgePrivateKeyFromPemConstMeta =>
+      const TaskConstMeta(debugName: "private_key_from_pem", argNames: ["pem"]);
+
+  @override
+  Future<QuicPubSubBrokerConfig> crateApiBridgePubSubBrokerConfigNew() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 93,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_quic_pub_sub_broker_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgePubSubBrokerConfigNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgePubSubBrokerConfigNewConstMeta =>
+      const TaskConstMeta(debugName: "pub_sub_broker_config_new", argNames: []);
+
+  @override
+  Future<BigInt> crateApiBridgePubSubBrokerLatestSequence({
+    required QuicPubSubBroker broker,
+    required String topic,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker(
+            broker,
+            serializer,
+          );
+          sse_encode_String(topic, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 94,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgePubSubBrokerLatestSequenceConstMeta,
+        argValues: [broker, topic],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgePubSubBrokerLatestSequenceConstMeta =>
+      const TaskConstMeta(
+        debugName: "pub_sub_broker_latest_sequence",
+        argNames: ["broker", "topic"],
+      );
+
+  @override
+  Future<QuicPubSubBroker> crateApiBridgePubSubBrokerNew({
+    required QuicPubSubBrokerConfig config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_quic_pub_sub_broker_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 95,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgePubSubBrokerNewConstMeta,
+        argValues: [config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgePubSubBrokerNewConstMeta =>
+      const TaskConstMeta(
+        debugName: "pub_sub_broker_new",
+        argNames: ["config"],
+      );
+
+  @override
+  Future<BigInt> crateApiBridgePubSubBrokerPublish({
+    required QuicPubSubBroker broker,
+    required String topic,
+    required List<int> payload,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker(
+            broker,
+            serializer,
+          );
+          sse_encode_String(topic, serializer);
+          sse_encode_list_prim_u_8_loose(payload, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 96,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgePubSubBrokerPublishConstMeta,
+        argValues: [broker, topic, payload],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgePubSubBrokerPublishConstMeta =>
+      const TaskConstMeta(
+        debugName: "pub_sub_broker_publish",
+        argNames: ["broker", "topic", "payload"],
+      );
+
+  @override
+  Future<(QuicConnection, QuicPubSubClient)> crateApiBridgePubSubClientConnect({
+    required QuicConnection connection,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+            connection,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 97,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_pub_sub_client,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgePubSubClientConnectConstMeta,
+        argValues: [connection],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgePubSubClientConnectConstMeta =>
+      const TaskConstMeta(
+        debugName: "pub_sub_client_connect",
+        argNames: ["connection"],
+      );
+
+  @override
+  Future<QuicPubSubSubscription> crateApiBridgePubSubClientSubscribe({
+    required QuicPubSubClient client,
+    required String topic,
+    required QuicPubSubSubscribeOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
+            client,
+            serializer,
+          );
+          sse_encode_String(topic, serializer);
+          sse_encode_box_autoadd_quic_pub_sub_subscribe_options(
+            options,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 98,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgePubSubClientSubscribeConstMeta,
+        argValues: [client, topic, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgePubSubClientSubscribeConstMeta =>
+      const TaskConstMeta(
+        debugName: "pub_sub_client_subscribe",
+        argNames: ["client", "topic", "options"],
+      );
+
+  @override
+  Future<QuicPubSubSubscribeOptions> crateApiBridgePubSubSubscribeOptionsNew() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 99,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_quic_pub_sub_subscribe_options,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgePubSubSubscribeOptionsNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgePubSubSubscribeOptionsNewConstMeta =>
+      const TaskConstMeta(
+        debugName: "pub_sub_subscribe_options_new",
+        argNames: [],
+      );
+
+  @override
+  Future<BigInt> crateApiBridgePubSubSubscriptionLastSequence({
+    required QuicPubSubSubscription subscription,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+            subscription,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 100,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgePubSubSubscriptionLastSequenceConstMeta,
+        argValues: [subscription],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgePubSubSubscriptionLastSequenceConstMeta =>
+      const TaskConstMeta(
+        debugName: "pub_sub_subscription_last_sequence",
+        argNames: ["subscription"],
+      );
+
+  @override
+  Future<BigInt> crateApiBridgePubSubSubscriptionMissed({
+    required QuicPubSubSubscription subscription,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+            subscription,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 101,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgePubSubSubscriptionMissedConstMeta,
+        argValues: [subscription],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgePubSubSubscriptionMissedConstMeta =>
+      const TaskConstMeta(
+        debugName: "pub_sub_subscription_missed",
+        argNames: ["subscription"],
+      );
+
+  @override
+  Future<QuicPubSubMessage?> crateApiBridgePubSubSubscriptionNextMessage({
+    required QuicPubSubSubscription subscription,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+            subscription,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 102,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_quic_pub_sub_message,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgePubSubSubscriptionNextMessageConstMeta,
+        argValues: [subscription],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgePubSubSubscriptionNextMessageConstMeta =>
+      const TaskConstMeta(
+        debugName: "pub_sub_subscription_next_message",
+        argNames: ["subscription"],
+      );
+
+  @override
+  Future<String> crateApiBridgePubSubSubscriptionTopic({
+    required QuicPubSubSubscription subscription,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+            subscription,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 103,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgePubSubSubscriptionTopicConstMeta,
+        argValues: [subscription],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgePubSubSubscriptionTopicConstMeta =>
+      const TaskConstMeta(
+        debugName: "pub_sub_subscription_topic",
+        argNames: ["subscription"],
+      );
+
+  @override
+  Future<void> crateApiBridgePubSubSubscriptionUnsubscribe({
+    required QuicPubSubSubscription subscription,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+            subscription,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 104,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgePubSubSubscriptionUnsubscribeConstMeta,
+        argValues: [subscription],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgePubSubSubscriptionUnsubscribeConstMeta =>
+      const TaskConstMeta(
+        debugName: "pub_sub_subscription_unsubscribe",
+        argNames: ["subscription"],
+      );
+
+  @override
+  Future<QuicClient> crateApiBridgeQuicClientClearPool({
+    required QuicClient client,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+            client,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 105,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeQuicClientClearPoolConstMeta,
+        argValues: [client],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeQuicClientClearPoolConstMeta =>
+      const TaskConstMeta(
+        debugName: "quic_client_clear_pool",
+        argNames: ["client"],
+      );
+
+  @override
+  Future<(QuicClient, QuicClientConfig)> crateApiBridgeQuicClientConfig({
+    required QuicClient client,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+            client,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 106,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_quic_client_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeQuicClientConfigConstMeta,
+        argValues: [client],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeQuicClientConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "quic_client_config",
+        argNames: ["client"],
+      );
+
+  @override
+  Future<QuicClientConfig> crateApiBridgeQuicClientConfigNew() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 107,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_quic_client_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeQuicClientConfigNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeQuicClientConfigNewConstMeta =>
+      const TaskConstMeta(debugName: "quic_client_config_new", argNames: []);
+
+  @override
+  Future<QuicClient> crateApiBridgeQuicClientCreate() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 108,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeQuicClientCreateConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeQuicClientCreateConstMeta =>
+      const TaskConstMeta(debugName: "quic_client_create", argNames: []);
+
+  @override
+  Future<QuicClient> crateApiBridgeQuicClientCreateWithConfig({
+    required QuicClientConfig config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_quic_client_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 109,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeQuicClientCreateWithConfigConstMeta,
+        argValues: [config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeQuicClientCreateWithConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "quic_client_create_with_config",
+        argNames: ["config"],
+      );
+
+  @override
+  Future<(QuicClient, String)> crateApiBridgeQuicClientGet({
+    required QuicClient client,
+    required String url,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+            client,
+            serializer,
+          );
+          sse_encode_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 110,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_string,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeQuicClientGetConstMeta,
+        argValues: [client, url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeQuicClientGetConstMeta =>
+      const TaskConstMeta(
+        debugName: "quic_client_get",
+        argNames: ["client", "url"],
+      );
+
+  @override
+  Future<(QuicClient, Uint8List)> crateApiBridgeQuicClientGetBytes({
+    required QuicClient client,
+    required String url,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+            client,
+            serializer,
+          );
+          sse_encode_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 111,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeQuicClientGetBytesConstMeta,
+        argValues: [client, url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeQuicClientGetBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "quic_client_get_bytes",
+        argNames: ["client", "url"],
+      );
+
+  @override
+  Future<(QuicClient, String)> crateApiBridgeQuicClientGetWithTimeout({
+    required QuicClient client,
+    required String url,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+            client,
+            serializer,
+          );
+          sse_encode_String(url, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 112,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_string,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeQuicClientGetWithTimeoutConstMeta,
+        argValues: [client, url],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeQuicClientGetWithTimeoutConstMeta =>
+      const TaskConstMeta(
+        debugName: "quic_client_get_with_timeout",
+        argNames: ["client", "url"],
+      );
+
+  @override
+  Future<(QuicClient, String)> crateApiBridgeQuicClientPost({
+    required QuicClient client,
+    required String url,
+    required String data,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+            client,
+            serializer,
+          );
+          sse_encode_String(url, serializer);
+          sse_encode_String(data, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 113,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_string,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeQuicClientPostConstMeta,
+        argValues: [client, url, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeQuicClientPostConstMeta =>
+      const TaskConstMeta(
+        debugName: "quic_client_post",
+        argNames: ["client", "url", "data"],
+      );
+
+  @override
+  Future<(QuicClient, Uint8List)> crateApiBridgeQuicClientPostBytes({
+    required QuicClient client,
+    required String url,
+    required List<int> data,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+            client,
+            serializer,
+          );
+          sse_encode_String(url, serializer);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 114,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeQuicClientPostBytesConstMeta,
+        argValues: [client, url, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeQuicClientPostBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "quic_client_post_bytes",
+        argNames: ["client", "url", "data"],
+      );
+
+  @override
+  Future<(QuicClient, String)> crateApiBridgeQuicClientPostWithTimeout({
+    required QuicClient client,
+    required String url,
+    required String data,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+            client,
+            serializer,
+          );
+          sse_encode_String(url, serializer);
+          sse_encode_String(data, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 115,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_string,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeQuicClientPostWithTimeoutConstMeta,
+        argValues: [client, url, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeQuicClientPostWithTimeoutConstMeta =>
+      const TaskConstMeta(
+        debugName: "quic_client_post_with_timeout",
+        argNames: ["client", "url", "data"],
+      );
+
+  @override
+  Future<(QuicClient, String)> crateApiBridgeQuicClientSend({
+    required QuicClient client,
+    required String url,
+    required String data,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+            client,
+            serializer,
+          );
+          sse_encode_String(url, serializer);
+          sse_encode_String(data, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 116,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_string,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeQuicClientSendConstMeta,
+        argValues: [client, url, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeQuicClientSendConstMeta =>
+      const TaskConstMeta(
+        debugName: "quic_client_send",
+        argNames: ["client", "url", "data"],
+      );
+
+  @override
+  Future<(QuicClient, Uint8List)> crateApiBridgeQuicClientSendBytes({
+    required QuicClient client,
+    required String url,
+    required List<int> data,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+            client,
+            serializer,
+          );
+          sse_encode_String(url, serializer);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 117,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeQuicClientSendBytesConstMeta,
+        argValues: [client, url, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeQuicClientSendBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "quic_client_send_bytes",
+        argNames: ["client", "url", "data"],
+      );
+
+  @override
+  Future<(QuicClient, Uint8List)> crateApiBridgeQuicClientSendBytesWithTimeout({
+    required QuicClient client,
+    required String url,
+    required List<int> data,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+            client,
+            serializer,
+          );
+          sse_encode_String(url, serializer);
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 118,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeQuicClientSendBytesWithTimeoutConstMeta,
+        argValues: [client, url, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeQuicClientSendBytesWithTimeoutConstMeta =>
+      const TaskConstMeta(
+        debugName: "quic_client_send_bytes_with_timeout",
+        argNames: ["client", "url", "data"],
+      );
+
+  @override
+  Future<(QuicClient, QuicStreamingTransfer)>
+  crateApiBridgeQuicClientSendStreaming({
+    required QuicClient client,
+    required String url,
+    BigInt? uploadLength,
+    BigInt? responseLength,
+    required FutureOr<Uint8List?> Function() nextBodyChunk,
+    required FutureOr<void> Function(Uint8List) onResponseChunk,
+    required FutureOr<void> Function(QuicTransferProgress) onProgress,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+            client,
+            serializer,
+          );
+          sse_encode_String(url, serializer);
+          sse_encode_opt_box_autoadd_u_64(uploadLength, serializer);
+          sse_encode_opt_box_autoadd_u_64(responseLength, serializer);
+          sse_encode_DartFn_Inputs__Output_opt_list_prim_u_8_strict_AnyhowException(
+            nextBodyChunk,
+            serializer,
+          );
+          sse_encode_DartFn_Inputs_list_prim_u_8_strict_Output_unit_AnyhowException(
+            onResponseChunk,
+            serializer,
+          );
+          sse_encode_DartFn_Inputs_quic_transfer_progress_Output_unit_AnyhowException(
+            onProgress,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 119,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_streaming_transfer,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeQuicClientSendStreamingConstMeta,
+        argValues: [
+          client,
+          url,
+          uploadLength,
+          responseLength,
+          nextBodyChunk,
+          onResponseChunk,
+          onProgress,
+        ],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeQuicClientSendStreamingConstMeta =>
+      const TaskConstMeta(
+        debugName: "quic_client_send_streaming",
+        argNames: [
+          "client",
+          "url",
+          "uploadLength",
+          "responseLength",
+          "nextBodyChunk",
+          "onResponseChunk",
+          "onProgress",
+        ],
+      );
+
+  @override
+  Future<(QuicClient, String)> crateApiBridgeQuicClientSendWithTimeout({
+    required QuicClient client,
+    required String url,
+    required String data,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+            client,
+            serializer,
+          );
+          sse_encode_String(url, serializer);
+          sse_encode_String(data, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 120,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_string,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeQuicClientSendWithTimeoutConstMeta,
+        argValues: [client, url, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeQuicClientSendWithTimeoutConstMeta =>
+      const TaskConstMeta(
+        debugName: "quic_client_send_with_timeout",
+        argNames: ["client", "url", "data"],
+      );
+
+  @override
+  Future<(QuicRecvStream, Uint8List?)> crateApiBridgeRecvStreamRead({
+    required QuicRecvStream stream,
+    required BigInt maxLength,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+            stream,
+            serializer,
+          );
+          sse_encode_usize(maxLength, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 121,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_opt_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_quic_read_exception,
+        ),
+        constMeta: kCrateApiBridgeRecvStreamReadConstMeta,
+        argValues: [stream, maxLength],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeRecvStreamReadConstMeta =>
+      const TaskConstMeta(
+        debugName: "recv_stream_read",
+        argNames: ["stream", "maxLength"],
+      );
+
+  @override
+  Future<(QuicRecvStream, QuicStreamChunk?)> crateApiBridgeRecvStreamReadChunk({
+    required QuicRecvStream stream,
+    required BigInt maxLength,
+    required bool ordered,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+            stream,
+            serializer,
+          );
+          sse_encode_usize(maxLength, serializer);
+          sse_encode_bool(ordered, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 122,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_opt_box_autoadd_quic_stream_chunk,
+          decodeErrorData: sse_decode_quic_read_exception,
+        ),
+        constMeta: kCrateApiBridgeRecvStreamReadChunkConstMeta,
+        argValues: [stream, maxLength, ordered],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeRecvStreamReadChunkConstMeta =>
+      const TaskConstMeta(
+        debugName: "recv_stream_read_chunk",
+        argNames: ["stream", "maxLength", "ordered"],
+      );
+
+  @override
+  Future<(QuicRecvStream, List<QuicStreamChunk>?)>
+  crateApiBridgeRecvStreamReadChunks({
+    required QuicRecvStream stream,
+    required BigInt maxChunks,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+            stream,
+            serializer,
+          );
+          sse_encode_usize(maxChunks, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 123,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_opt_list_quic_stream_chunk,
+          decodeErrorData: sse_decode_quic_read_exception,
+        ),
+        constMeta: kCrateApiBridgeRecvStreamReadChunksConstMeta,
+        argValues: [stream, maxChunks],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeRecvStreamReadChunksConstMeta =>
+      const TaskConstMeta(
+        debugName: "recv_stream_read_chunks",
+        argNames: ["stream", "maxChunks"],
+      );
+
+  @override
+  Future<(QuicRecvStream, Uint8List)> crateApiBridgeRecvStreamReadToEnd({
+    required QuicRecvStream stream,
+    required BigInt maxLength,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+            stream,
+            serializer,
+          );
+          sse_encode_usize(maxLength, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 124,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_quic_read_to_end_exception,
+        ),
+        constMeta: kCrateApiBridgeRecvStreamReadToEndConstMeta,
+        argValues: [stream, maxLength],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeRecvStreamReadToEndConstMeta =>
+      const TaskConstMeta(
+        debugName: "recv_stream_read_to_end",
+        argNames: ["stream", "maxLength"],
+      );
+
+  @override
+  Future<void> crateApiBridgeRpcCallCancel({required QuicRpcCall call}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall(
+            call,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 125,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeRpcCallCancelConstMeta,
+        argValues: [call],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeRpcCallCancelConstMeta =>
+      const TaskConstMeta(debugName: "rpc_call_cancel", argNames: ["call"]);
+
+  @override
+  Future<Uint8List?> crateApiBridgeRpcCallNextMessage({
+    required QuicRpcCall call,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall(
+            call,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 126,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_quic_rpc_status,
+        ),
+        constMeta: kCrateApiBridgeRpcCallNextMessageConstMeta,
+        argValues: [call],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeRpcCallNextMessageConstMeta =>
+      const TaskConstMeta(
+        debugName: "rpc_call_next_message",
+        argNames: ["call"],
+      );
+
+  @override
+  Future<QuicRpcCallOptions> crateApiBridgeRpcCallOptionsNew() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 127,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_quic_rpc_call_options,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeRpcCallOptionsNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeRpcCallOptionsNewConstMeta =>
+      const TaskConstMeta(debugName: "rpc_call_options_new", argNames: []);
+
+  @override
+  Future<Uint8List> crateApiBridgeRpcClientCall({
+    required QuicRpcClient client,
+    required String method,
+    required List<int> payload,
+    required QuicRpcCallOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient(
+            client,
+            serializer,
+          );
+          sse_encode_String(method, serializer);
+          sse_encode_list_prim_u_8_loose(payload, serializer);
+          sse_encode_box_autoadd_quic_rpc_call_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 128,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_quic_rpc_status,
+        ),
+        constMeta: kCrateApiBridgeRpcClientCallConstMeta,
+        argValues: [client, method, payload, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeRpcClientCallConstMeta =>
+      const TaskConstMeta(
+        debugName: "rpc_client_call",
+        argNames: ["client", "method", "payload", "options"],
+      );
+
+  @override
+  Future<QuicRpcCall> crateApiBridgeRpcClientCallStreaming({
+    required QuicRpcClient client,
+    required String method,
+    required List<int> payload,
+    required QuicRpcCallOptions options,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient(
+            client,
+            serializer,
+          );
+          sse_encode_String(method, serializer);
+          sse_encode_list_prim_u_8_loose(payload, serializer);
+          sse_encode_box_autoadd_quic_rpc_call_options(options, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 129,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall,
+          decodeErrorData: sse_decode_quic_rpc_status,
+        ),
+        constMeta: kCrateApiBridgeRpcClientCallStreamingConstMeta,
+        argValues: [client, method, payload, options],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeRpcClientCallStreamingConstMeta =>
+      const TaskConstMeta(
+        debugName: "rpc_client_call_streaming",
+        argNames: ["client", "method", "payload", "options"],
+      );
+
+  @override
+  Future<QuicRpcClientConfig> crateApiBridgeRpcClientConfigNew() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 130,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_quic_rpc_client_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeRpcClientConfigNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeRpcClientConfigNewConstMeta =>
+      const TaskConstMeta(debugName: "rpc_client_config_new", argNames: []);
+
+  @override
+  Future<QuicRpcClient> crateApiBridgeRpcClientNew({
+    required QuicConnection connection,
+    required QuicRpcClientConfig config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+            connection,
+            serializer,
+          );
+          sse_encode_box_autoadd_quic_rpc_client_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 131,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeRpcClientNewConstMeta,
+        argValues: [connection, config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeRpcClientNewConstMeta => const TaskConstMeta(
+    debugName: "rpc_client_new",
+    argNames: ["connection", "config"],
+  );
+
+  @override
+  Future<QuicRpcRegistry> crateApiBridgeRpcRegistryNew() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 132,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcRegistry,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeRpcRegistryNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeRpcRegistryNewConstMeta =>
+      const TaskConstMeta(debugName: "rpc_registry_new", argNames: []);
+
+  @override
+  Future<QuicRpcRegistry> crateApiBridgeRpcRegistryRegister({
+    required QuicRpcRegistry registry,
+    required String method,
+    required FutureOr<QuicRpcStatus> Function(QuicRpcRequest, QuicRpcResponder) handler,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcRegistry(
+            registry,
+            serializer,
+          );
+          sse_encode_String(method, serializer);
+          sse_encode_DartFn_Inputs_quic_rpc_request_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder_Output_quic_rpc_status_AnyhowException(
+            handler,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 133,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcRegistry,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeRpcRegistryRegisterConstMeta,
+        argValues: [registry, method, handler],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeRpcRegistryRegisterConstMeta =>
+      const TaskConstMeta(
+        debugName: "rpc_registry_register",
+        argNames: ["registry", "method", "handler"],
+      );
+
+  @override
+  Future<QuicRpcRegistry> crateApiBridgeRpcRegistryRegisterUnary({
+    required QuicRpcRegistry registry,
+    required String method,
+    required FutureOr<Uint8List> Function(QuicRpcRequest) handler,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcRegistry(
+            registry,
+            serializer,
+          );
+          sse_encode_String(method, serializer);
+          sse_encode_DartFn_Inputs_quic_rpc_request_Output_list_prim_u_8_strict_AnyhowException(
+            handler,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 134,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcRegistry,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeRpcRegistryRegisterUnaryConstMeta,
+        argValues: [registry, method, handler],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeRpcRegistryRegisterUnaryConstMeta =>
+      const TaskConstMeta(
+        debugName: "rpc_registry_register_unary",
+        argNames: ["registry", "method", "handler"],
+      );
+
+  @override
+  Future<void> crateApiBridgeRpcResponderSend({
+    required QuicRpcResponder responder,
+    required List<int> message,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder(
+            responder,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(message, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 135,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_quic_rpc_status,
+        ),
+        constMeta: kCrateApiBridgeRpcResponderSendConstMeta,
+        argValues: [responder, message],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeRpcResponderSendConstMeta =>
+      const TaskConstMeta(
+        debugName: "rpc_responder_send",
+        argNames: ["responder", "message"],
+      );
+
+  @override
+  Future<QuicSendStream> crateApiBridgeSendStreamFinish({
+    required QuicSendStream stream,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+            stream,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 136,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream,
+          decodeErrorData: sse_decode_quic_write_exception,
+        ),
+        constMeta: kCrateApiBridgeSendStreamFinishConstMeta,
+        argValues: [stream],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeSendStreamFinishConstMeta =>
+      const TaskConstMeta(
+        debugName: "send_stream_finish",
+        argNames: ["stream"],
+      );
+
+  @override
+  Future<QuicSendStream> crateApiBridgeSendStreamReset({
+    required QuicSendStream stream,
+    required int errorCode,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+            stream,
+            serializer,
+          );
+          sse_encode_u_32(errorCode, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 137,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream,
+          decodeErrorData: sse_decode_quic_write_exception,
+        ),
+        constMeta: kCrateApiBridgeSendStreamResetConstMeta,
+        argValues: [stream, errorCode],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeSendStreamResetConstMeta =>
+      const TaskConstMeta(
+        debugName: "send_stream_reset",
+        argNames: ["stream", "errorCode"],
+      );
+
+  @override
+  Future<(QuicSendStream, QuicSendStreamStats)> crateApiBridgeSendStreamStats({
+    required QuicSendStream stream,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+            stream,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 138,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_quic_send_stream_stats,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeSendStreamStatsConstMeta,
+        argValues: [stream],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeSendStreamStatsConstMeta =>
+      const TaskConstMeta(debugName: "send_stream_stats", argNames: ["stream"]);
+
+  @override
+  Future<(QuicSendStream, BigInt)> crateApiBridgeSendStreamWrite({
+    required QuicSendStream stream,
+    required List<int> data,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+            stream,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 139,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_usize,
+          decodeErrorData: sse_decode_quic_write_exception,
+        ),
+        constMeta: kCrateApiBridgeSendStreamWriteConstMeta,
+        argValues: [stream, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeSendStreamWriteConstMeta =>
+      const TaskConstMeta(
+        debugName: "send_stream_write",
+        argNames: ["stream", "data"],
+      );
+
+  @override
+  Future<QuicSendStream> crateApiBridgeSendStreamWriteAll({
+    required QuicSendStream stream,
+    required List<int> data,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+            stream,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 140,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream,
+          decodeErrorData: sse_decode_quic_write_exception,
+        ),
+        constMeta: kCrateApiBridgeSendStreamWriteAllConstMeta,
+        argValues: [stream, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeSendStreamWriteAllConstMeta =>
+      const TaskConstMeta(
+        debugName: "send_stream_write_all",
+        argNames: ["stream", "data"],
+      );
+
+  @override
+  Future<QuicSendStream> crateApiBridgeSendStreamWriteAllChunks({
+    required QuicSendStream stream,
+    required List<Uint8List> chunks,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+            stream,
+            serializer,
+          );
+          sse_encode_list_list_prim_u_8_strict(chunks, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 141,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream,
+          decodeErrorData: sse_decode_quic_write_exception,
+        ),
+        constMeta: kCrateApiBridgeSendStreamWriteAllChunksConstMeta,
+        argValues: [stream, chunks],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeSendStreamWriteAllChunksConstMeta =>
+      const TaskConstMeta(
+        debugName: "send_stream_write_all_chunks",
+        argNames: ["stream", "chunks"],
+      );
+
+  @override
+  Future<(QuicSendStream, QuicChunksWritten)>
+  crateApiBridgeSendStreamWriteChunks({
+    required QuicSendStream stream,
+    required List<Uint8List> chunks,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+            stream,
+            serializer,
+          );
+          sse_encode_list_list_prim_u_8_strict(chunks, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 142,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_quic_chunks_written,
+          decodeErrorData: sse_decode_quic_write_exception,
+        ),
+        constMeta: kCrateApiBridgeSendStreamWriteChunksConstMeta,
+        argValues: [stream, chunks],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeSendStreamWriteChunksConstMeta =>
+      const TaskConstMeta(
+        debugName: "send_stream_write_chunks",
+        argNames: ["stream", "chunks"],
+      );
+
+  @override
+  Future<QuicServerConfig> crateApiBridgeServerConfigIncomingBufferSize({
+    required QuicServerConfig config,
+    required BigInt size,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+            config,
+            serializer,
+          );
+          sse_encode_u_64(size, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 143,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeServerConfigIncomingBufferSizeConstMeta,
+        argValues: [config, size],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeServerConfigIncomingBufferSizeConstMeta =>
+      const TaskConstMeta(
+        debugName: "server_config_incoming_buffer_size",
+        argNames: ["config", "size"],
+      );
+
+  @override
+  Future<QuicServerConfig> crateApiBridgeServerConfigIncomingBufferSizeTotal({
+    required QuicServerConfig config,
+    required BigInt size,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+            config,
+            serializer,
+          );
+          sse_encode_u_64(size, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 144,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeServerConfigIncomingBufferSizeTotalConstMeta,
+        argValues: [config, size],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiBridgeServerConfigIncomingBufferSizeTotalConstMeta =>
+      const TaskConstMeta(
+        debugName: "server_config_incoming_buffer_size_total",
+        argNames: ["config", "size"],
+      );
+
+  @override
+  Future<QuicServerConfig> crateApiBridgeServerConfigMaxIncoming({
+    required QuicServerConfig config,
+    required BigInt maxIncoming,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+            config,
+            serializer,
+          );
+          sse_encode_usize(maxIncoming, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 145,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeServerConfigMaxIncomingConstMeta,
+        argValues: [config, maxIncoming],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeServerConfigMaxIncomingConstMeta =>
+      const TaskConstMeta(
+        debugName: "server_config_max_incoming",
+        argNames: ["config", "maxIncoming"],
+      );
+
+  @override
+  Future<QuicServerConfig> crateApiBridgeServerConfigMigration({
+    required QuicServerConfig config,
+    required bool allow,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+            config,
+            serializer,
+          );
+          sse_encode_bool(allow, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 146,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeServerConfigMigrationConstMeta,
+        argValues: [config, allow],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeServerConfigMigrationConstMeta =>
+      const TaskConstMeta(
+        debugName: "server_config_migration",
+        argNames: ["config", "allow"],
+      );
+
+  @override
+  Future<QuicServerConfig> crateApiBridgeServerConfigPreferredAddressV4({
+    required QuicServerConfig config,
+    String? addr,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+            config,
+            serializer,
+          );
+          sse_encode_opt_String(addr, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 147,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiBridgeServerConfigPreferredAddressV4ConstMeta,
+        argValues: [config, addr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeServerConfigPreferredAddressV4ConstMeta =>
+      const TaskConstMeta(
+        debugName: "server_config_preferred_address_v4",
+        argNames: ["config", "addr"],
+      );
+
+  @override
+  Future<QuicServerConfig> crateApiBridgeServerConfigPreferredAddressV6({
+    required QuicServerConfig config,
+    String? addr,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+            config,
+            serializer,
+          );
+          sse_encode_opt_String(addr, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 148,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiBridgeServerConfigPreferredAddressV6ConstMeta,
+        argValues: [config, addr],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeServerConfigPreferredAddressV6ConstMeta =>
+      const TaskConstMeta(
+        debugName: "server_config_preferred_address_v6",
+        argNames: ["config", "addr"],
+      );
+
+  @override
+  Future<(QuicServerConfig, bool)> crateApiBridgeServerConfigRetryEnabled({
+    required QuicServerConfig config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+            config,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 149,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_server_config_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeServerConfigRetryEnabledConstMeta,
+        argValues: [config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeServerConfigRetryEnabledConstMeta =>
+      const TaskConstMeta(
+        debugName: "server_config_retry_enabled",
+        argNames: ["config"],
+      );
+
+  @override
+  Future<QuicServerConfig> crateApiBridgeServerConfigRetryTokenLifetime({
+    required QuicServerConfig config,
+    required BigInt lifetimeMs,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+            config,
+            serializer,
+          );
+          sse_encode_u_64(lifetimeMs, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 150,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeServerConfigRetryTokenLifetimeConstMeta,
+        argValues: [config, lifetimeMs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeServerConfigRetryTokenLifetimeConstMeta =>
+      const TaskConstMeta(
+        debugName: "server_config_retry_token_lifetime",
+        argNames: ["config", "lifetimeMs"],
+      );
+
+  @override
+  Future<QuicServerConfig> crateApiBridgeServerConfigTransportConfig({
+    required QuicServerConfig config,
+    required QuicTransportConfig transportConfig,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+            config,
+            serializer,
+          );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
+            transportConfig,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 151,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeServerConfigTransportConfigConstMeta,
+        argValues: [config, transportConfig],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeServerConfigTransportConfigConstMeta =>
+      const TaskConstMeta(
+        debugName: "server_config_transport_config",
+        argNames: ["config", "transportConfig"],
+      );
+
+  @override
+  Future<QuicServerConfig> crateApiBridgeServerConfigUseRetry({
+    required QuicServerConfig config,
+    required bool enabled,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+            config,
+            serializer,
+          );
+          sse_encode_bool(enabled, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 152,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeServerConfigUseRetryConstMeta,
+        argValues: [config, enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeServerConfigUseRetryConstMeta =>
+      const TaskConstMeta(
+        debugName: "server_config_use_retry",
+        argNames: ["config", "enabled"],
+      );
+
+  @override
+  Future<QuicServerConfig> crateApiBridgeServerConfigWithCertResolver({
+    required QuicCertificateResolver resolver,
+    required List<Uint8List> alpnProtocols,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver(
+            resolver,
+            serializer,
+          );
+          sse_encode_list_list_prim_u_8_strict(alpnProtocols, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 153,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiBridgeServerConfigWithCertResolverConstMeta,
+        argValues: [resolver, alpnProtocols],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeServerConfigWithCertResolverConstMeta =>
+      const TaskConstMeta(
+        debugName: "server_config_with_cert_resolver",
+        argNames: ["resolver", "alpnProtocols"],
+      );
+
+  @override
+  Future<QuicServerConfig> crateApiBridgeServerConfigWithCertResolverAndKeyLog({
+    required QuicCertificateResolver resolver,
+    required List<Uint8List> alpnProtocols,
+    required QuicKeyLog keyLog,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver(
+            resolver,
+            serializer,
+          );
+          sse_encode_list_list_prim_u_8_strict(alpnProtocols, serializer);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicKeyLog(
+            keyLog,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 154,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta:
+            kCrateApiBridgeServerConfigWithCertResolverAndKeyLogConstMeta,
+        argValues: [resolver, alpnProtocols, keyLog],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiBridgeServerConfigWithCertResolverAndKeyLogConstMeta =>
+      const TaskConstMeta(
+        debugName: "server_config_with_cert_resolver_and_key_log",
+        argNames: ["resolver", "alpnProtocols", "keyLog"],
+      );
+
+  @override
+  Future<QuicServerConfig> crateApiBridgeServerConfigWithCertificate({
+    required CertificateChain certChain,
+    required PrivateKey key,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_box_autoadd_certificate_chain(certChain, serializer);
+          sse_encode_box_autoadd_private_key(key, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 155,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiBridgeServerConfigWithCertificateConstMeta,
+        argValues: [certChain, key],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeServerConfigWithCertificateConstMeta =>
+      const TaskConstMeta(
+        debugName: "server_config_with_certificate",
+        argNames: ["certChain", "key"],
+      );
+
+  @override
+  Future<QuicServerConfig> crateApiBridgeServerConfigWithCryptoAndKeyLog({
+    required List<Uint8List> certChain,
+    required List<int> key,
+    required List<Uint8List> alpnProtocols,
+    required QuicKeyLog keyLog,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_list_prim_u_8_strict(certChain, serializer);
+          sse_encode_list_prim_u_8_loose(key, serializer);
+          sse_encode_list_list_prim_u_8_strict(alpnProtocols, serializer);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicKeyLog(
+            keyLog,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 156,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiBridgeServerConfigWithCryptoAndKeyLogConstMeta,
+        argValues: [certChain, key, alpnProtocols, keyLog],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeServerConfigWithCryptoAndKeyLogConstMeta =>
+      const TaskConstMeta(
+        debugName: "server_config_with_crypto_and_key_log",
+        argNames: ["certChain", "key", "alpnProtocols", "keyLog"],
+      );
+
+  @override
+  Future<QuicServerConfig> crateApiBridgeServerConfigWithSingleCert({
+    required List<Uint8List> certChain,
+    required List<int> key,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_list_prim_u_8_strict(certChain, serializer);
+          sse_encode_list_prim_u_8_loose(key, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 157,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig,
+          decodeErrorData: sse_decode_String,
+        ),
+        constMeta: kCrateApiBridgeServerConfigWithSingleCertConstMeta,
+        argValues: [certChain, key],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeServerConfigWithSingleCertConstMeta =>
+      const TaskConstMeta(
+        debugName: "server_config_with_single_cert",
+        argNames: ["certChain", "key"],
+      );
+
+  @override
+  Future<void> crateApiBridgeSetLogFilter({required String filter}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(filter, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 158,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeSetLogFilterConstMeta,
+        argValues: [filter],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeSetLogFilterConstMeta =>
+      const TaskConstMeta(debugName: "set_log_filter", argNames: ["filter"]);
+
+  @override
+  Future<SimpleQuicServer> crateApiBridgeSimpleQuicServerBind({
+    required QuicServerConfig serverConfig,
+    required String addr,
+    required SimpleQuicServerConfig config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+            serverConfig,
+            serializer,
+          );
+          sse_encode_String(addr, serializer);
+          sse_encode_box_autoadd_simple_quic_server_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 159,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeSimpleQuicServerBindConstMeta,
+        argValues: [serverConfig, addr, config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeSimpleQuicServerBindConstMeta =>
+      const TaskConstMeta(
+        debugName: "simple_quic_server_bind",
+        argNames: ["serverConfig", "addr", "config"],
+      );
+
+  @override
+  Future<(SimpleQuicServer, SelfSignedCertificate)>
+  crateApiBridgeSimpleQuicServerBindHttp3SelfSigned({
+    required String addr,
+    required List<String> subjectAltNames,
+    required SimpleQuicServerConfig config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(addr, serializer);
+          sse_encode_list_String(subjectAltNames, serializer);
+          sse_encode_box_autoadd_simple_quic_server_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 160,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_self_signed_certificate,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeSimpleQuicServerBindHttp3SelfSignedConstMeta,
+        argValues: [addr, subjectAltNames, config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiBridgeSimpleQuicServerBindHttp3SelfSignedConstMeta =>
+      const TaskConstMeta(
+        debugName: "simple_quic_server_bind_http3_self_signed",
+        argNames: ["addr", "subjectAltNames", "config"],
+      );
+
+  @override
+  Future<(SimpleQuicServer, SelfSignedCertificate)>
+  crateApiBridgeSimpleQuicServerBindSelfSigned({
+    required String addr,
+    required List<String> subjectAltNames,
+    required SimpleQuicServerConfig config,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(addr, serializer);
+          sse_encode_list_String(subjectAltNames, serializer);
+          sse_encode_box_autoadd_simple_quic_server_config(config, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 161,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_self_signed_certificate,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeSimpleQuicServerBindSelfSignedConstMeta,
+        argValues: [addr, subjectAltNames, config],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeSimpleQuicServerBindSelfSignedConstMeta =>
+      const TaskConstMeta(
+        debugName: "simple_quic_server_bind_self_signed",
+        argNames: ["addr", "subjectAltNames", "config"],
+      );
+
+  @override
+  Future<SimpleQuicServerConfig> crateApiBridgeSimpleQuicServerConfigNew() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 162,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_simple_quic_server_config,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeSimpleQuicServerConfigNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeSimpleQuicServerConfigNewConstMeta =>
+      const TaskConstMeta(
+        debugName: "simple_quic_server_config_new",
+        argNames: [],
+      );
+
+  @override
+  Future<(SimpleQuicServer, String)> crateApiBridgeSimpleQuicServerLocalAddr({
+    required SimpleQuicServer server,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+            server,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 163,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_string,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeSimpleQuicServerLocalAddrConstMeta,
+        argValues: [server],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeSimpleQuicServerLocalAddrConstMeta =>
+      const TaskConstMeta(
+        debugName: "simple_quic_server_local_addr",
+        argNames: ["server"],
+      );
+
+  @override
+  Future<SimpleQuicServer> crateApiBridgeSimpleQuicServerServe({
+    required SimpleQuicServer server,
+    required FutureOr<Uint8List> Function(Uint8List) handler,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+            server,
+            serializer,
+          );
+          sse_encode_DartFn_Inputs_list_prim_u_8_strict_Output_list_prim_u_8_strict_AnyhowException(
+            handler,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 164,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeSimpleQuicServerServeConstMeta,
+        argValues: [server, handler],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeSimpleQuicServerServeConstMeta =>
+      const TaskConstMeta(
+        debugName: "simple_quic_server_serve",
+        argNames: ["server", "handler"],
+      );
+
+  @override
+  Future<SimpleQuicServer> crateApiBridgeSimpleQuicServerServeFiles({
+    required SimpleQuicServer server,
+    required QuicFileReceiver receiver,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+            server,
+            serializer,
+          );
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver(
+            receiver,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 165,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeSimpleQuicServerServeFilesConstMeta,
+        argValues: [server, receiver],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeSimpleQuicServerServeFilesConstMeta =>
+      const TaskConstMeta(
+        debugName: "simple_quic_server_serve_files",
+        argNames: ["server", "receiver"],
+      );
+
+  @override
+  Future<SimpleQuicServer> crateApiBridgeSimpleQuicServerServeHttp3({
+    required SimpleQuicServer server,
+    required Http3Router router,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+            server,
+            serializer,
+          );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Router(
+            router,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 166,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeSimpleQuicServerServeHttp3ConstMeta,
+        argValues: [server, router],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeSimpleQuicServerServeHttp3ConstMeta =>
+      const TaskConstMeta(
+        debugName: "simple_quic_server_serve_http3",
+        argNames: ["server", "router"],
+      );
+
+  @override
+  Future<SimpleQuicServer> crateApiBridgeSimpleQuicServerServePubsub({
+    required SimpleQuicServer server,
+    required QuicPubSubBroker broker,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+            server,
+            serializer,
+          );
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker(
+            broker,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 167,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeSimpleQuicServerServePubsubConstMeta,
+        argValues: [server, broker],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeSimpleQuicServerServePubsubConstMeta =>
+      const TaskConstMeta(
+        debugName: "simple_quic_server_serve_pubsub",
+        argNames: ["server", "broker"],
+      );
+
+  @override
+  Future<SimpleQuicServer> crateApiBridgeSimpleQuicServerServeRpc({
+    required SimpleQuicServer server,
+    required QuicRpcRegistry registry,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+            server,
+            serializer,
+          );
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcRegistry(
+            registry,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 168,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeSimpleQuicServerServeRpcConstMeta,
+        argValues: [server, registry],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeSimpleQuicServerServeRpcConstMeta =>
+      const TaskConstMeta(
+        debugName: "simple_quic_server_serve_rpc",
+        argNames: ["server", "registry"],
+      );
+
+  @override
+  Future<(SimpleQuicServer, BigInt)> crateApiBridgeSimpleQuicServerShutdown({
+    required SimpleQuicServer server,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+            server,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 169,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_u_64,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeSimpleQuicServerShutdownConstMeta,
+        argValues: [server],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeSimpleQuicServerShutdownConstMeta =>
+      const TaskConstMeta(
+        debugName: "simple_quic_server_shutdown",
+        argNames: ["server"],
+      );
+
+  @override
+  Future<(SimpleQuicServer, SimpleQuicServerStats)>
+  crateApiBridgeSimpleQuicServerStats({required SimpleQuicServer server}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+            server,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 170,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_simple_quic_server_stats,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeSimpleQuicServerStatsConstMeta,
+        argValues: [server],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeSimpleQuicServerStatsConstMeta =>
+      const TaskConstMeta(
+        debugName: "simple_quic_server_stats",
+        argNames: ["server"],
+      );
+
+  @override
+  Future<BigInt> crateApiBridgeStreamReassemblerBufferedBytes({
+    required QuicStreamReassembler reassembler,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler(
+            reassembler,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 171,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_usize,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeStreamReassemblerBufferedBytesConstMeta,
+        argValues: [reassembler],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeStreamReassemblerBufferedBytesConstMeta =>
+      const TaskConstMeta(
+        debugName: "stream_reassembler_buffered_bytes",
+        argNames: ["reassembler"],
+      );
+
+  @override
+  Future<BigInt> crateApiBridgeStreamReassemblerContiguousOffset({
+    required QuicStreamReassembler reassembler,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler(
+            reassembler,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 172,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeStreamReassemblerContiguousOffsetConstMeta,
+        argValues: [reassembler],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeStreamReassemblerContiguousOffsetConstMeta =>
+      const TaskConstMeta(
+        debugName: "stream_reassembler_contiguous_offset",
+        argNames: ["reassembler"],
+      );
+
+  @override
+  Future<QuicStreamReassembler> crateApiBridgeStreamReassemblerNew() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 173,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeStreamReassemblerNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeStreamReassemblerNewConstMeta =>
+      const TaskConstMeta(debugName: "stream_reassembler_new", argNames: []);
+
+  @override
+  Future<Uint8List> crateApiBridgeStreamReassemblerPush({
+    required QuicStreamReassembler reassembler,
+    required QuicStreamChunk chunk,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler(
+            reassembler,
+            serializer,
+          );
+          sse_encode_box_autoadd_quic_stream_chunk(chunk, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 174,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeStreamReassemblerPushConstMeta,
+        argValues: [reassembler, chunk],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeStreamReassemblerPushConstMeta =>
+      const TaskConstMeta(
+        debugName: "stream_reassembler_push",
+        argNames: ["reassembler", "chunk"],
+      );
+
+  @override
+  Future<void> crateApiBridgeStreamingTransferCancel({
+    required QuicStreamingTransfer transfer,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
+            transfer,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 175,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeStreamingTransferCancelConstMeta,
+        argValues: [transfer],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeStreamingTransferCancelConstMeta =>
+      const TaskConstMeta(
+        debugName: "streaming_transfer_cancel",
+        argNames: ["transfer"],
+      );
+
+  @override
+  Future<QuicTransferProgress> crateApiBridgeStreamingTransferProgress({
+    required QuicStreamingTransfer transfer,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
+            transfer,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 176,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_quic_transfer_progress,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeStreamingTransferProgressConstMeta,
+        argValues: [transfer],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeStreamingTransferProgressConstMeta =>
+      const TaskConstMeta(
+        debugName: "streaming_transfer_progress",
+        argNames: ["transfer"],
+      );
+
+  @override
+  Future<QuicTransferProgress> crateApiBridgeStreamingTransferWait({
+    required QuicStreamingTransfer transfer,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
+            transfer,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 177,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_quic_transfer_progress,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeStreamingTransferWaitConstMeta,
+        argValues: [transfer],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeStreamingTransferWaitConstMeta =>
+      const TaskConstMeta(
+        debugName: "streaming_transfer_wait",
+        argNames: ["transfer"],
+      );
+
+  @override
+  Future<QuicTransportConfig> crateApiBridgeTransportConfigNew() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 178,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeTransportConfigNewConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeTransportConfigNewConstMeta =>
+      const TaskConstMeta(debugName: "transport_config_new", argNames: []);
+
+  @override
+  Future<(QuicSendStream, QuicRecvStream)>
+  crateApiBridgeWebtransportSessionAcceptBi({
+    required WebTransportSession session,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+            session,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 179,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeWebtransportSessionAcceptBiConstMeta,
+        argValues: [session],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeWebtransportSessionAcceptBiConstMeta =>
+      const TaskConstMeta(
+        debugName: "webtransport_session_accept_bi",
+        argNames: ["session"],
+      );
+
+  @override
+  Future<QuicRecvStream> crateApiBridgeWebtransportSessionAcceptUni({
+    required WebTransportSession session,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+            session,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 180,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeWebtransportSessionAcceptUniConstMeta,
+        argValues: [session],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeWebtransportSessionAcceptUniConstMeta =>
+      const TaskConstMeta(
+        debugName: "webtransport_session_accept_uni",
+        argNames: ["session"],
+      );
+
+  @override
+  Future<void> crateApiBridgeWebtransportSessionClose({
+    required WebTransportSession session,
+    required int code,
+    required String reason,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+            session,
+            serializer,
+          );
+          sse_encode_u_32(code, serializer);
+          sse_encode_String(reason, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 181,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeWebtransportSessionCloseConstMeta,
+        argValues: [session, code, reason],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeWebtransportSessionCloseConstMeta =>
+      const TaskConstMeta(
+        debugName: "webtransport_session_close",
+        argNames: ["session", "code", "reason"],
+      );
+
+  @override
+  Future<WebTransportCloseInfo> crateApiBridgeWebtransportSessionClosed({
+    required WebTransportSession session,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+            session,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 182,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_web_transport_close_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeWebtransportSessionClosedConstMeta,
+        argValues: [session],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeWebtransportSessionClosedConstMeta =>
+      const TaskConstMeta(
+        debugName: "webtransport_session_closed",
+        argNames: ["session"],
+      );
+
+  @override
+  Future<(QuicEndpoint, WebTransportSession)>
+  crateApiBridgeWebtransportSessionConnect({
+    required QuicEndpoint endpoint,
+    required String url,
+    required List<Http3Header> headers,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+            endpoint,
+            serializer,
+          );
+          sse_encode_String(url, serializer);
+          sse_encode_list_http_3_header(headers, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 183,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_web_transport_session,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeWebtransportSessionConnectConstMeta,
+        argValues: [endpoint, url, headers],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeWebtransportSessionConnectConstMeta =>
+      const TaskConstMeta(
+        debugName: "webtransport_session_connect",
+        argNames: ["endpoint", "url", "headers"],
+      );
+
+  @override
+  Future<QuicConnection> crateApiBridgeWebtransportSessionConnection({
+    required WebTransportSession session,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+            session,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 184,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeWebtransportSessionConnectionConstMeta,
+        argValues: [session],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeWebtransportSessionConnectionConstMeta =>
+      const TaskConstMeta(
+        debugName: "webtransport_session_connection",
+        argNames: ["session"],
+      );
+
+  @override
+  Future<BigInt> crateApiBridgeWebtransportSessionId({
+    required WebTransportSession session,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+            session,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 185,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeWebtransportSessionIdConstMeta,
+        argValues: [session],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeWebtransportSessionIdConstMeta =>
+      const TaskConstMeta(
+        debugName: "webtransport_session_id",
+        argNames: ["session"],
+      );
+
+  @override
+  Future<BigInt?> crateApiBridgeWebtransportSessionMaxDatagramSize({
+    required WebTransportSession session,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+            session,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 186,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_usize,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiBridgeWebtransportSessionMaxDatagramSizeConstMeta,
+        argValues: [session],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta
+  get kCrateApiBridgeWebtransportSessionMaxDatagramSizeConstMeta =>
+      const TaskConstMeta(
+        debugName: "webtransport_session_max_datagram_size",
+        argNames: ["session"],
+      );
+
+  @override
+  Future<(QuicSendStream, QuicRecvStream)>
+  crateApiBridgeWebtransportSessionOpenBi({
+    required WebTransportSession session,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+            session,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 187,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeWebtransportSessionOpenBiConstMeta,
+        argValues: [session],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeWebtransportSessionOpenBiConstMeta =>
+      const TaskConstMeta(
+        debugName: "webtransport_session_open_bi",
+        argNames: ["session"],
+      );
+
+  @override
+  Future<QuicSendStream> crateApiBridgeWebtransportSessionOpenUni({
+    required WebTransportSession session,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+            session,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 188,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData:
+              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeWebtransportSessionOpenUniConstMeta,
+        argValues: [session],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeWebtransportSessionOpenUniConstMeta =>
+      const TaskConstMeta(
+        debugName: "webtransport_session_open_uni",
+        argNames: ["session"],
+      );
+
+  @override
+  Future<Uint8List> crateApiBridgeWebtransportSessionReadDatagram({
+    required WebTransportSession session,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+            session,
+            serializer,
+          );
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 189,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeWebtransportSessionReadDatagramConstMeta,
+        argValues: [session],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeWebtransportSessionReadDatagramConstMeta =>
+      const TaskConstMeta(
+        debugName: "webtransport_session_read_datagram",
+        argNames: ["session"],
+      );
+
+  @override
+  Future<void> crateApiBridgeWebtransportSessionSendDatagram({
+    required WebTransportSession session,
+    required List<int> data,
+  }) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+            session,
+            serializer,
+          );
+          sse_encode_list_prim_u_8_loose(data, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 190,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_quic_error,
+        ),
+        constMeta: kCrateApiBridgeWebtransportSessionSendDatagramConstMeta,
+        argValues: [session, data],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiBridgeWebtransportSessionSendDatagramConstMeta =>
+      const TaskConstMeta(
+        debugName: "webtransport_session_send_datagram",
+        argNames: ["session", "data"],
+      );
+
+  Future<void> Function(int, dynamic)
+  encode_DartFn_Inputs_String_Output_unit_AnyhowException(
+    FutureOr<void> Function(String) raw,
+  ) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_String(rawArg0);
+
+      Box<void>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_unit(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+        callId: callId,
+        ptr: output.ptr,
+        rustVecLen: output.rustVecLen,
+        dataLen: output.dataLen,
+      );
+    };
+  }
+
+  Future<void> Function(int,)
+  encode_DartFn_Inputs__Output_opt_list_prim_u_8_strict_AnyhowException(
+    FutureOr<Uint8List?> Function() raw,
+  ) {
+    return (callId) async {
+      Box<Uint8List?>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw());
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_opt_list_prim_u_8_strict(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+        callId: callId,
+        ptr: output.ptr,
+        rustVecLen: output.rustVecLen,
+        dataLen: output.dataLen,
+      );
+    };
+  }
+
+  Future<void> Function(int, dynamic, dynamic)
+  encode_DartFn_Inputs_http_3_server_request_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream_Output_unit_AnyhowException(
+    FutureOr<void> Function(Http3ServerRequest, Http3ServerStream) raw,
+  ) {
+    return (callId, rawArg0, rawArg1) async {
+      final arg0 = dco_decode_http_3_server_request(rawArg0);
+      final arg1 =
+          dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+            rawArg1,
+          );
+
+      Box<void>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0, arg1));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_unit(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+        callId: callId,
+        ptr: output.ptr,
+        rustVecLen: output.rustVecLen,
+        dataLen: output.dataLen,
+      );
+    };
+  }
+
+  Future<void> Function(int, dynamic)
+  encode_DartFn_Inputs_list_prim_u_8_strict_Output_list_prim_u_8_strict_AnyhowException(
+    FutureOr<Uint8List> Function(Uint8List) raw,
+  ) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_list_prim_u_8_strict(rawArg0);
+
+      Box<Uint8List>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_list_prim_u_8_strict(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+        callId: callId,
+        ptr: output.ptr,
+        rustVecLen: output.rustVecLen,
+        dataLen: output.dataLen,
+      );
+    };
+  }
+
+  Future<void> Function(int, dynamic)
+  encode_DartFn_Inputs_list_prim_u_8_strict_Output_unit_AnyhowException(
+    FutureOr<void> Function(Uint8List) raw,
+  ) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_list_prim_u_8_strict(rawArg0);
+
+      Box<void>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_unit(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+        callId: callId,
+        ptr: output.ptr,
+        rustVecLen: output.rustVecLen,
+        dataLen: output.dataLen,
+      );
+    };
+  }
+
+  Future<void> Function(int, dynamic)
+  encode_DartFn_Inputs_quic_file_transfer_progress_Output_unit_AnyhowException(
+    FutureOr<void> Function(QuicFileTransferProgress) raw,
+  ) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_quic_file_transfer_progress(rawArg0);
+
+      Box<void>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_unit(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+        callId: callId,
+        ptr: output.ptr,
+        rustVecLen: output.rustVecLen,
+        dataLen: output.dataLen,
+      );
+    };
+  }
+
+  Future<void> Function(int, dynamic, dynamic)
+  encode_DartFn_Inputs_quic_rpc_request_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder_Output_quic_rpc_status_AnyhowException(
+    FutureOr<QuicRpcStatus> Function(QuicRpcRequest, QuicRpcResponder) raw,
+  ) {
+    return (callId, rawArg0, rawArg1) async {
+      final arg0 = dco_decode_quic_rpc_request(rawArg0);
+      final arg1 =
+          dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder(
+            rawArg1,
+          );
+
+      Box<QuicRpcStatus>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0, arg1));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_quic_rpc_status(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+        callId: callId,
+        ptr: output.ptr,
+        rustVecLen: output.rustVecLen,
+        dataLen: output.dataLen,
+      );
+    };
+  }
+
+  Future<void> Function(int, dynamic)
+  encode_DartFn_Inputs_quic_rpc_request_Output_list_prim_u_8_strict_AnyhowException(
+    FutureOr<Uint8List> Function(QuicRpcRequest) raw,
+  ) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_quic_rpc_request(rawArg0);
+
+      Box<Uint8List>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_list_prim_u_8_strict(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+        callId: callId,
+        ptr: output.ptr,
+        rustVecLen: output.rustVecLen,
+        dataLen: output.dataLen,
+      );
+    };
+  }
+
+  Future<void> Function(int, dynamic)
+  encode_DartFn_Inputs_quic_transfer_progress_Output_unit_AnyhowException(
+    FutureOr<void> Function(QuicTransferProgress) raw,
+  ) {
+    return (callId, rawArg0) async {
+      final arg0 = dco_decode_quic_transfer_progress(rawArg0);
+
+      Box<void>? rawOutput;
+      Box<AnyhowException>? rawError;
+      try {
+        rawOutput = Box(await raw(arg0));
+      } catch (e, s) {
+        rawError = Box(AnyhowException("$e\n\n$s"));
+      }
+
+      final serializer = SseSerializer(generalizedFrbRustBinding);
+      assert((rawOutput != null) ^ (rawError != null));
+      if (rawOutput != null) {
+        serializer.buffer.putUint8(0);
+        sse_encode_unit(rawOutput.value, serializer);
+      } else {
+        serializer.buffer.putUint8(1);
+        sse_encode_AnyhowException(rawError!.value, serializer);
+      }
+      final output = serializer.intoRaw();
+
+      generalizedFrbRustBinding.dartFnDeliverOutput(
+        callId: callId,
+        ptr: output.ptr,
+        rustVecLen: output.rustVecLen,
+        dataLen: output.dataLen,
+      );
+    };
+  }
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_Http3Client => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_Http3Client => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_Http3ResponseStream => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_Http3ResponseStream => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_Http3Router => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Router;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_Http3Router => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Router;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_Http3ServerStream => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_Http3ServerStream => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicCertificateResolver => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicCertificateResolver => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicClient => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicClient => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicConnection => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicConnection => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicConnectionPool => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicConnectionPool => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicDatagramChannel => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicDatagramChannel => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicDatagramMux => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicDatagramMux => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicDatagramSubscription => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicDatagramSubscription => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicEndpoint => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicEndpoint => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicEndpointConfig => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicEndpointConfig => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicFileReceiver => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicFileReceiver => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicFileUpload => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicFileUpload => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicFramedStream => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicFramedStream => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicKeyLog => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicKeyLog;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicKeyLog => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicKeyLog;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicPubSubBroker => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicPubSubBroker => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicPubSubClient => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicPubSubClient => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicPubSubSubscription => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicPubSubSubscription => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicRecvStream => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicRecvStream => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicRpcCall => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicRpcCall => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicRpcClient => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicRpcClient => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicRpcRegistry => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcRegistry;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicRpcRegistry => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcRegistry;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicRpcResponder => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicRpcResponder => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicSendStream => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicSendStream => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicServerConfig => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicServerConfig => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicStreamReassembler => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicStreamReassembler => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicStreamingTransfer => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicStreamingTransfer => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_QuicTransportConfig => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_QuicTransportConfig => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_SimpleQuicServer => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_SimpleQuicServer => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer;
+
+  RustArcIncrementStrongCountFnType
+  get rust_arc_increment_strong_count_WebTransportSession => wire
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession;
+
+  RustArcDecrementStrongCountFnType
+  get rust_arc_decrement_strong_count_WebTransportSession => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession;
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  Http3Client
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Http3ClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Http3ResponseStream
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Http3ResponseStreamImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Http3Router
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Router(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Http3RouterImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Http3ServerStream
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Http3ServerStreamImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicCertificateResolver
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicCertificateResolverImpl.frbInternalDcoDecode(
+      raw as List<dynamic>,
+    );
+  }
+
+  @protected
+  QuicClient
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicConnection
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicConnectionImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicConnectionPool
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicConnectionPoolImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicDatagramChannel
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicDatagramChannelImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicDatagramMux
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicDatagramMuxImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicDatagramSubscription
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicDatagramSubscriptionImpl.frbInternalDcoDecode(
+      raw as List<dynamic>,
+    );
+  }
+
+  @protected
+  QuicEndpoint
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicEndpointImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicEndpointConfig
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicEndpointConfigImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicFileReceiver
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicFileReceiverImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicFileUpload
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicFileUploadImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicFramedStream
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicFramedStreamImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicKeyLog
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicKeyLog(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicKeyLogImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicPubSubBroker
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicPubSubBrokerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicPubSubClient
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicPubSubClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicPubSubSubscription
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicPubSubSubscriptionImpl.frbInternalDcoDecode(
+      raw as List<dynamic>,
+    );
+  }
+
+  @protected
+  QuicRecvStream
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicRecvStreamImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicRpcCall
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicRpcCallImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicRpcClient
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicRpcClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicRpcRegistry
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcRegistry(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicRpcRegistryImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicRpcResponder
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicRpcResponderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicSendStream
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicSendStreamImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicServerConfig
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicServerConfigImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicStreamReassembler
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicStreamReassemblerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicStreamingTransfer
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicStreamingTransferImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicTransportConfig
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicTransportConfigImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  SimpleQuicServer
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SimpleQuicServerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  WebTransportSession
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return WebTransportSessionImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Http3ResponseStream
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Http3ResponseStreamImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Http3ServerStream
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Http3ServerStreamImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicConnection
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicConnectionImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicDatagramChannel
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicDatagramChannelImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicDatagramMux
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicDatagramMuxImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicDatagramSubscription
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicDatagramSubscriptionImpl.frbInternalDcoDecode(
+      raw as List<dynamic>,
+    );
+  }
+
+  @protected
+  QuicFileReceiver
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicFileReceiverImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicFileUpload
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicFileUploadImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicFramedStream
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicFramedStreamImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicPubSubBroker
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicPubSubBrokerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicPubSubClient
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicPubSubClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicPubSubSubscription
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicPubSubSubscriptionImpl.frbInternalDcoDecode(
+      raw as List<dynamic>,
+    );
+  }
+
+  @protected
+  QuicRpcCall
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicRpcCallImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicRpcClient
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicRpcClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicRpcResponder
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicRpcResponderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicStreamReassembler
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicStreamReassemblerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicStreamingTransfer
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicStreamingTransferImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  WebTransportSession
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return WebTransportSessionImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  FutureOr<void> Function(String)
+  dco_decode_DartFn_Inputs_String_Output_unit_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  FutureOr<Uint8List?> Function()
+  dco_decode_DartFn_Inputs__Output_opt_list_prim_u_8_strict_AnyhowException(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  FutureOr<void> Function(Http3ServerRequest, Http3ServerStream)
+  dco_decode_DartFn_Inputs_http_3_server_request_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream_Output_unit_AnyhowException(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  FutureOr<Uint8List> Function(Uint8List)
+  dco_decode_DartFn_Inputs_list_prim_u_8_strict_Output_list_prim_u_8_strict_AnyhowException(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  FutureOr<void> Function(Uint8List)
+  dco_decode_DartFn_Inputs_list_prim_u_8_strict_Output_unit_AnyhowException(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  FutureOr<void> Function(QuicFileTransferProgress)
+  dco_decode_DartFn_Inputs_quic_file_transfer_progress_Output_unit_AnyhowException(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  FutureOr<QuicRpcStatus> Function(QuicRpcRequest, QuicRpcResponder)
+  dco_decode_DartFn_Inputs_quic_rpc_request_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder_Output_quic_rpc_status_AnyhowException(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  FutureOr<Uint8List> Function(QuicRpcRequest)
+  dco_decode_DartFn_Inputs_quic_rpc_request_Output_list_prim_u_8_strict_AnyhowException(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  FutureOr<void> Function(QuicTransferProgress)
+  dco_decode_DartFn_Inputs_quic_transfer_progress_Output_unit_AnyhowException(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError('');
+  }
+
+  @protected
+  Object dco_decode_DartOpaque(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return decodeDartOpaque(raw, generalizedFrbRustBinding);
+  }
+
+  @protected
+  Http3Client
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Http3ClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Http3ResponseStream
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Http3ResponseStreamImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Http3Router
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Router(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Http3RouterImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  Http3ServerStream
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Http3ServerStreamImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicCertificateResolver
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicCertificateResolverImpl.frbInternalDcoDecode(
+      raw as List<dynamic>,
+    );
+  }
+
+  @protected
+  QuicClient
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicConnection
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicConnectionImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicConnectionPool
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicConnectionPoolImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicDatagramChannel
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicDatagramChannelImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicDatagramMux
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicDatagramMuxImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicDatagramSubscription
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicDatagramSubscriptionImpl.frbInternalDcoDecode(
+      raw as List<dynamic>,
+    );
+  }
+
+  @protected
+  QuicEndpoint
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicEndpointImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicEndpointConfig
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicEndpointConfigImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicFileReceiver
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicFileReceiverImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicFileUpload
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicFileUploadImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicFramedStream
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicFramedStreamImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicKeyLog
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicKeyLog(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicKeyLogImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicPubSubBroker
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicPubSubBrokerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicPubSubClient
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicPubSubClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicPubSubSubscription
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicPubSubSubscriptionImpl.frbInternalDcoDecode(
+      raw as List<dynamic>,
+    );
+  }
+
+  @protected
+  QuicRecvStream
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicRecvStreamImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicRpcCall
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicRpcCallImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicRpcClient
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicRpcClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicRpcRegistry
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcRegistry(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicRpcRegistryImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicRpcResponder
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicRpcResponderImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicSendStream
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicSendStreamImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicServerConfig
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicServerConfigImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicStreamReassembler
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicStreamReassemblerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicStreamingTransfer
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicStreamingTransferImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  QuicTransportConfig
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicTransportConfigImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  SimpleQuicServer
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return SimpleQuicServerImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  WebTransportSession
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return WebTransportSessionImpl.frbInternalDcoDecode(raw as List<dynamic>);
+  }
+
+  @protected
+  RustStreamSink<QuicLogRecord> dco_decode_StreamSink_quic_log_record_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  QuicConnection
+  dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+      raw,
+    );
+  }
+
+  @protected
+  QuicRecvStream
+  dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+      raw,
+    );
+  }
+
+  @protected
+  QuicSendStream
+  dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+      raw,
+    );
+  }
+
+  @protected
+  CertificateChain dco_decode_box_autoadd_certificate_chain(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_certificate_chain(raw);
+  }
+
+  @protected
+  Http3ClientConfig dco_decode_box_autoadd_http_3_client_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_http_3_client_config(raw);
+  }
+
+  @protected
+  Http3Request dco_decode_box_autoadd_http_3_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_http_3_request(raw);
+  }
+
+  @protected
+  PrivateKey dco_decode_box_autoadd_private_key(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_private_key(raw);
+  }
+
+  @protected
+  QuicClientConfig dco_decode_box_autoadd_quic_client_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_quic_client_config(raw);
+  }
+
+  @protected
+  QuicConnectionEnd dco_decode_box_autoadd_quic_connection_end(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_quic_connection_end(raw);
+  }
+
+  @protected
+  QuicConnectionPoolConfig dco_decode_box_autoadd_quic_connection_pool_config(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_quic_connection_pool_config(raw);
+  }
+
+  @protected
+  QuicDatagramChannelConfig dco_decode_box_autoadd_quic_datagram_channel_config(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_quic_datagram_channel_config(raw);
+  }
+
+  @protected
+  QuicDatagramSubscriptionConfig
+  dco_decode_box_autoadd_quic_datagram_subscription_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_quic_datagram_subscription_config(raw);
+  }
+
+  @protected
+  QuicFileTransferConfig dco_decode_box_autoadd_quic_file_transfer_config(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_quic_file_transfer_config(raw);
+  }
+
+  @protected
+  QuicPubSubBrokerConfig dco_decode_box_autoadd_quic_pub_sub_broker_config(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_quic_pub_sub_broker_config(raw);
+  }
+
+  @protected
+  QuicPubSubMessage dco_decode_box_autoadd_quic_pub_sub_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_quic_pub_sub_message(raw);
+  }
+
+  @protected
+  QuicPubSubSubscribeOptions
+  dco_decode_box_autoadd_quic_pub_sub_subscribe_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_quic_pub_sub_subscribe_options(raw);
+  }
+
+  @protected
+  QuicReadException dco_decode_box_autoadd_quic_read_exception(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_quic_read_exception(raw);
+  }
+
+  @protected
+  QuicRpcCallOptions dco_decode_box_autoadd_quic_rpc_call_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_quic_rpc_call_options(raw);
+  }
+
+  @protected
+  QuicRpcClientConfig dco_decode_box_autoadd_quic_rpc_client_config(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_quic_rpc_client_config(raw);
+  }
+
+  @protected
+  QuicStreamChunk dco_decode_box_autoadd_quic_stream_chunk(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_quic_stream_chunk(raw);
+  }
+
+  @protected
+  SimpleQuicServerConfig dco_decode_box_autoadd_simple_quic_server_config(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_simple_quic_server_config(raw);
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_usize(raw);
+  }
+
+  @protected
+  CertificateChain dco_decode_certificate_chain(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 1)
+      throw Exception('unexpected arr length: expect 1 but see ${arr.length}');
+    return CertificateChain(
+      certificates: dco_decode_list_list_prim_u_8_strict(arr[0]),
+    );
+  }
+
+  @protected
+  CertificateKeyAlgorithm dco_decode_certificate_key_algorithm(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CertificateKeyAlgorithm.values[raw as int];
+  }
+
+  @protected
+  Http3ClientConfig dco_decode_http_3_client_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return Http3ClientConfig(
+      connectTimeoutMs: dco_decode_u_64(arr[0]),
+      requestTimeoutMs: dco_decode_u_64(arr[1]),
+      maxResponseSize: dco_decode_usize(arr[2]),
+      maxFieldSectionSize: dco_decode_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  Http3Header dco_decode_http_3_header(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return Http3Header(
+      name: dco_decode_String(arr[0]),
+      value: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  Http3Request dco_decode_http_3_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return Http3Request(
+      method: dco_decode_String(arr[0]),
+      url: dco_decode_String(arr[1]),
+      headers: dco_decode_list_http_3_header(arr[2]),
+      body: dco_decode_list_prim_u_8_strict(arr[3]),
+      trailers: dco_decode_list_http_3_header(arr[4]),
+    );
+  }
+
+  @protected
+  Http3Response dco_decode_http_3_response(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return Http3Response(
+      status: dco_decode_u_16(arr[0]),
+      headers: dco_decode_list_http_3_header(arr[1]),
+      body: dco_decode_list_prim_u_8_strict(arr[2]),
+      trailers: dco_decode_list_http_3_header(arr[3]),
+    );
+  }
+
+  @protected
+  Http3ServerRequest dco_decode_http_3_server_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return Http3ServerRequest(
+      method: dco_decode_String(arr[0]),
+      path: dco_decode_String(arr[1]),
+      query: dco_decode_opt_String(arr[2]),
+      headers: dco_decode_list_http_3_header(arr[3]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  PlatformInt64 dco_decode_isize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<Http3Header> dco_decode_list_http_3_header(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_http_3_header).toList();
+  }
+
+  @protected
+  List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_list_prim_u_8_strict).toList();
+  }
+
+  @protected
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as List<int>;
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<QuicAuthorityPoolStats> dco_decode_list_quic_authority_pool_stats(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>)
+        .map(dco_decode_quic_authority_pool_stats)
+        .toList();
+  }
+
+  @protected
+  List<QuicLogField> dco_decode_list_quic_log_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_quic_log_field).toList();
+  }
+
+  @protected
+  List<QuicRpcMetadata> dco_decode_list_quic_rpc_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_quic_rpc_metadata).toList();
+  }
+
+  @protected
+  List<QuicStreamChunk> dco_decode_list_quic_stream_chunk(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_quic_stream_chunk).toList();
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  QuicConnection?
+  dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+          raw,
+        );
+  }
+
+  @protected
+  QuicRecvStream?
+  dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+          raw,
+        );
+  }
+
+  @protected
+  QuicSendStream?
+  dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+          raw,
+        );
+  }
+
+  @protected
+  QuicConnectionEnd? dco_decode_opt_box_autoadd_quic_connection_end(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_quic_connection_end(raw);
+  }
+
+  @protected
+  QuicPubSubMessage? dco_decode_opt_box_autoadd_quic_pub_sub_message(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null
+        ? null
+        : dco_decode_box_autoadd_quic_pub_sub_message(raw);
+  }
+
+  @protected
+  QuicStreamChunk? dco_decode_opt_box_autoadd_quic_stream_chunk(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_quic_stream_chunk(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_usize(raw);
+  }
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_prim_u_8_strict(raw);
+  }
+
+  @protected
+  List<QuicStreamChunk>? dco_decode_opt_list_quic_stream_chunk(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_quic_stream_chunk(raw);
+  }
+
+  @protected
+  PrivateKey dco_decode_private_key(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return PrivateKey(
+      keyData: dco_decode_list_prim_u_8_strict(arr[0]),
+      format: dco_decode_private_key_format(arr[1]),
+    );
+  }
+
+  @protected
+  PrivateKeyFormat dco_decode_private_key_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PrivateKeyFormat.values[raw as int];
+  }
+
+  @protected
+  QuicAuthorityPoolStats dco_decode_quic_authority_pool_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return QuicAuthorityPoolStats(
+      authority: dco_decode_String(arr[0]),
+      connections: dco_decode_u_64(arr[1]),
+      activeStreams: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  QuicChunksWritten dco_decode_quic_chunks_written(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return QuicChunksWritten(
+      bytes: dco_decode_usize(arr[0]),
+      chunks: dco_decode_usize(arr[1]),
+    );
+  }
+
+  @protected
+  QuicClientConfig dco_decode_quic_client_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return QuicClientConfig(
+      maxConnectionsPerHost: dco_decode_usize(arr[0]),
+      connectTimeoutMs: dco_decode_u_64(arr[1]),
+      requestTimeoutMs: dco_decode_u_64(arr[2]),
+      retryAttempts: dco_decode_u_32(arr[3]),
+      retryDelayMs: dco_decode_u_64(arr[4]),
+      keepAliveTimeoutMs: dco_decode_u_64(arr[5]),
+      maxResponseSize: dco_decode_usize(arr[6]),
+    );
+  }
+
+  @protected
+  QuicConnectionEnd dco_decode_quic_connection_end(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return QuicConnectionEnd(
+      kind: dco_decode_quic_connection_end_kind(arr[0]),
+      errorCode: dco_decode_opt_box_autoadd_u_64(arr[1]),
+      reason: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  QuicConnectionEndKind dco_decode_quic_connection_end_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicConnectionEndKind.values[raw as int];
+  }
+
+  @protected
+  QuicConnectionPoolConfig dco_decode_quic_connection_pool_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return QuicConnectionPoolConfig(
+      maxConnectionsPerAuthority: dco_decode_usize(arr[0]),
+      maxStreamsPerConnection: dco_decode_u_32(arr[1]),
+      idleTimeoutMs: dco_decode_u_64(arr[2]),
+      evictionIntervalMs: dco_decode_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  QuicConnectionPoolStats dco_decode_quic_connection_pool_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return QuicConnectionPoolStats(
+      authorities: dco_decode_list_quic_authority_pool_stats(arr[0]),
+      hits: dco_decode_u_64(arr[1]),
+      misses: dco_decode_u_64(arr[2]),
+      evictedIdle: dco_decode_u_64(arr[3]),
+      evictedDead: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  QuicConnectionStats dco_decode_quic_connection_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return QuicConnectionStats(
+      path: dco_decode_quic_path_stats(arr[0]),
+      frameTx: dco_decode_quic_frame_stats(arr[1]),
+      frameRx: dco_decode_quic_frame_stats(arr[2]),
+      udpTx: dco_decode_quic_udp_stats(arr[3]),
+      udpRx: dco_decode_quic_udp_stats(arr[4]),
+    );
+  }
+
+  @protected
+  QuicDatagramBatch dco_decode_quic_datagram_batch(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return QuicDatagramBatch(
+      datagrams: dco_decode_list_list_prim_u_8_strict(arr[0]),
+      dropped: dco_decode_u_64(arr[1]),
+      end: dco_decode_opt_box_autoadd_quic_connection_end(arr[2]),
+    );
+  }
+
+  @protected
+  QuicDatagramChannelConfig dco_decode_quic_datagram_channel_config(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return QuicDatagramChannelConfig(
+      fragmentation: dco_decode_bool(arr[0]),
+      maxMessageSize: dco_decode_usize(arr[1]),
+      reassemblyTimeoutMs: dco_decode_u_64(arr[2]),
+      channelCapacity: dco_decode_usize(arr[3]),
+      dropPolicy: dco_decode_quic_datagram_drop_policy(arr[4]),
+    );
+  }
+
+  @protected
+  QuicDatagramDropPolicy dco_decode_quic_datagram_drop_policy(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicDatagramDropPolicy.values[raw as int];
+  }
+
+  @protected
+  QuicDatagramException dco_decode_quic_datagram_exception(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return QuicDatagramException_UnsupportedByPeer();
+      case 1:
+        return QuicDatagramException_TooLarge(
+          maxSize: dco_decode_usize(raw[1]),
+        );
+      case 2:
+        return QuicDatagramException_ConnectionLost(dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  QuicDatagramSubscriptionConfig dco_decode_quic_datagram_subscription_config(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return QuicDatagramSubscriptionConfig(
+      capacity: dco_decode_usize(arr[0]),
+      dropPolicy: dco_decode_quic_datagram_drop_policy(arr[1]),
+    );
+  }
+
+  @protected
+  QuicError dco_decode_quic_error(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return QuicError_Connection(dco_decode_String(raw[1]));
+      case 1:
+        return QuicError_Endpoint(dco_decode_String(raw[1]));
+      case 2:
+        return QuicError_Stream(dco_decode_String(raw[1]));
+      case 3:
+        return QuicError_Tls(dco_decode_String(raw[1]));
+      case 4:
+        return QuicError_Config(dco_decode_String(raw[1]));
+      case 5:
+        return QuicError_Network(dco_decode_String(raw[1]));
+      case 6:
+        return QuicError_Write(dco_decode_String(raw[1]));
+      case 7:
+        return QuicError_ResponseTooLong(limit: dco_decode_usize(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  QuicFileTransferConfig dco_decode_quic_file_transfer_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return QuicFileTransferConfig(
+      chunkSize: dco_decode_usize(arr[0]),
+      parallelStreams: dco_decode_usize(arr[1]),
+    );
+  }
+
+  @protected
+  QuicFileTransferErrorKind dco_decode_quic_file_transfer_error_kind(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicFileTransferErrorKind.values[raw as int];
+  }
+
+  @protected
+  QuicFileTransferException dco_decode_quic_file_transfer_exception(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return QuicFileTransferException(
+      kind: dco_decode_quic_file_transfer_error_kind(arr[0]),
+      message: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  QuicFileTransferProgress dco_decode_quic_file_transfer_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return QuicFileTransferProgress(
+      bytesTransferred: dco_decode_u_64(arr[0]),
+      totalBytes: dco_decode_u_64(arr[1]),
+      resumedFrom: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  QuicFrameErrorKind dco_decode_quic_frame_error_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicFrameErrorKind.values[raw as int];
+  }
+
+  @protected
+  QuicFrameException dco_decode_quic_frame_exception(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return QuicFrameException(
+      kind: dco_decode_quic_frame_error_kind(arr[0]),
+      message: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  QuicFrameStats dco_decode_quic_frame_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 22)
+      throw Exception('unexpected arr length: expect 22 but see ${arr.length}');
+    return QuicFrameStats(
+      acks: dco_decode_u_64(arr[0]),
+      crypto: dco_decode_u_64(arr[1]),
+      connectionClose: dco_decode_u_64(arr[2]),
+      dataBlocked: dco_decode_u_64(arr[3]),
+      datagram: dco_decode_u_64(arr[4]),
+      handshakeDone: dco_decode_u_64(arr[5]),
+      maxData: dco_decode_u_64(arr[6]),
+      maxStreamData: dco_decode_u_64(arr[7]),
+      maxStreamsBidi: dco_decode_u_64(arr[8]),
+      maxStreamsUni: dco_decode_u_64(arr[9]),
+      newConnectionId: dco_decode_u_64(arr[10]),
+      newToken: dco_decode_u_64(arr[11]),
+      pathChallenge: dco_decode_u_64(arr[12]),
+      pathResponse: dco_decode_u_64(arr[13]),
+      ping: dco_decode_u_64(arr[14]),
+      resetStream: dco_decode_u_64(arr[15]),
+      retireConnectionId: dco_decode_u_64(arr[16]),
+      stream: dco_decode_u_64(arr[17]),
+      streamDataBlocked: dco_decode_u_64(arr[18]),
+      streamsBlockedBidi: dco_decode_u_64(arr[19]),
+      streamsBlockedUni: dco_decode_u_64(arr[20]),
+      stopSending: dco_decode_u_64(arr[21]),
+    );
+  }
+
+  @protected
+  QuicLogField dco_decode_quic_log_field(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return QuicLogField(
+      name: dco_decode_String(arr[0]),
+      value: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  QuicLogLevel dco_decode_quic_log_level(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicLogLevel.values[raw as int];
+  }
+
+  @protected
+  QuicLogRecord dco_decode_quic_log_record(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return QuicLogRecord(
+      level: dco_decode_quic_log_level(arr[0]),
+      target: dco_decode_String(arr[1]),
+      message: dco_decode_String(arr[2]),
+      fields: dco_decode_list_quic_log_field(arr[3]),
+      connectionId: dco_decode_opt_String(arr[4]),
+      timestampMs: dco_decode_i_64(arr[5]),
+    );
+  }
+
+  @protected
+  QuicPathStats dco_decode_quic_path_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return QuicPathStats(
+      rttMillis: dco_decode_u_64(arr[0]),
+      cwnd: dco_decode_u_64(arr[1]),
+      lostPackets: dco_decode_u_64(arr[2]),
+      lostBytes: dco_decode_u_64(arr[3]),
+      sentPackets: dco_decode_u_64(arr[4]),
+      congestionEvents: dco_decode_u_64(arr[5]),
+    );
+  }
+
+  @protected
+  QuicPubSubBrokerConfig dco_decode_quic_pub_sub_broker_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return QuicPubSubBrokerConfig(
+      historySize: dco_decode_usize(arr[0]),
+      maxMessageSize: dco_decode_usize(arr[1]),
+    );
+  }
+
+  @protected
+  QuicPubSubDelivery dco_decode_quic_pub_sub_delivery(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicPubSubDelivery.values[raw as int];
+  }
+
+  @protected
+  QuicPubSubMessage dco_decode_quic_pub_sub_message(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return QuicPubSubMessage(
+      sequence: dco_decode_u_64(arr[0]),
+      payload: dco_decode_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  QuicPubSubSubscribeOptions dco_decode_quic_pub_sub_subscribe_options(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return QuicPubSubSubscribeOptions(
+      delivery: dco_decode_quic_pub_sub_delivery(arr[0]),
+      resumeAfter: dco_decode_opt_box_autoadd_u_64(arr[1]),
+      bufferCapacity: dco_decode_usize(arr[2]),
+      maxMessageSize: dco_decode_usize(arr[3]),
+    );
+  }
+
+  @protected
+  QuicReadException dco_decode_quic_read_exception(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return QuicReadException_Reset(dco_decode_u_64(raw[1]));
+      case 1:
+        return QuicReadException_ConnectionLost(dco_decode_String(raw[1]));
+      case 2:
+        return QuicReadException_ZeroRttRejected();
+      case 3:
+        return QuicReadException_ClosedStream();
+      case 4:
+        return QuicReadException_IllegalOrderedRead();
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  QuicReadToEndException dco_decode_quic_read_to_end_exception(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return QuicReadToEndException_Read(
+          dco_decode_box_autoadd_quic_read_exception(raw[1]),
+        );
+      case 1:
+        return QuicReadToEndException_TooLong();
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  QuicRpcCallOptions dco_decode_quic_rpc_call_options(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return QuicRpcCallOptions(
+      deadlineMs: dco_decode_opt_box_autoadd_u_64(arr[0]),
+      metadata: dco_decode_list_quic_rpc_metadata(arr[1]),
+    );
+  }
+
+  @protected
+  QuicRpcClientConfig dco_decode_quic_rpc_client_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return QuicRpcClientConfig(
+      maxMessageSize: dco_decode_usize(arr[0]),
+      defaultDeadlineMs: dco_decode_opt_box_autoadd_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  QuicRpcCode dco_decode_quic_rpc_code(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return QuicRpcCode.values[raw as int];
+  }
+
+  @protected
+  QuicRpcMetadata dco_decode_quic_rpc_metadata(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return QuicRpcMetadata(
+      key: dco_decode_String(arr[0]),
+      value: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  QuicRpcRequest dco_decode_quic_rpc_request(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return QuicRpcRequest(
+      method: dco_decode_String(arr[0]),
+      metadata: dco_decode_list_quic_rpc_metadata(arr[1]),
+      payload: dco_decode_list_prim_u_8_strict(arr[2]),
+      deadlineMs: dco_decode_opt_box_autoadd_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  QuicRpcStatus dco_decode_quic_rpc_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return QuicRpcStatus(
+      code: dco_decode_quic_rpc_code(arr[0]),
+      message: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  QuicSendStreamStats dco_decode_quic_send_stream_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return QuicSendStreamStats(
+      bytesQueued: dco_decode_u_64(arr[0]),
+      acknowledged: dco_decode_bool(arr[1]),
+      stopErrorCode: dco_decode_opt_box_autoadd_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  QuicStreamChunk dco_decode_quic_stream_chunk(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return QuicStreamChunk(
+      offset: dco_decode_u_64(arr[0]),
+      data: dco_decode_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  QuicTransferProgress dco_decode_quic_transfer_progress(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return QuicTransferProgress(
+      bytesSent: dco_decode_u_64(arr[0]),
+      bytesReceived: dco_decode_u_64(arr[1]),
+      totalToSend: dco_decode_opt_box_autoadd_u_64(arr[2]),
+      expectedTotalReceived: dco_decode_opt_box_autoadd_u_64(arr[3]),
+    );
+  }
+
+  @protected
+  QuicUdpStats dco_decode_quic_udp_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return QuicUdpStats(
+      datagrams: dco_decode_u_64(arr[0]),
+      bytes: dco_decode_u_64(arr[1]),
+      ios: dco_decode_u_64(arr[2]),
+    );
+  }
+
+  @protected
+  QuicWriteException dco_decode_quic_write_exception(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    switch (raw[0]) {
+      case 0:
+        return QuicWriteException_Stopped(dco_decode_u_64(raw[1]));
+      case 1:
+        return QuicWriteException_ConnectionLost(dco_decode_String(raw[1]));
+      default:
+        throw Exception("unreachable");
+    }
+  }
+
+  @protected
+  (Http3Client, Http3ResponseStream)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_http_3_client_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_http_3_response_stream(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client(
+        arr[0],
+      ),
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+        arr[1],
+      ),
+    );
+  }
+
+  @protected
+  (Http3Client, Http3Response)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_http_3_client_http_3_response(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client(
+        arr[0],
+      ),
+      dco_decode_http_3_response(arr[1]),
+    );
+  }
+
+  @protected
+  (QuicCertificateResolver, bool)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_certificate_resolver_bool(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver(
+        arr[0],
+      ),
+      dco_decode_bool(arr[1]),
+    );
+  }
+
+  @protected
+  (QuicClient, QuicStreamingTransfer)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_streaming_transfer(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+        arr[0],
+      ),
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
+        arr[1],
+      ),
+    );
+  }
+
+  @protected
+  (QuicClient, Uint8List)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_list_prim_u_8_strict(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+        arr[0],
+      ),
+      dco_decode_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  (QuicClient, QuicClientConfig)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_quic_client_config(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+        arr[0],
+      ),
+      dco_decode_quic_client_config(arr[1]),
+    );
+  }
+
+  @protected
+  (QuicClient, String)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_string(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+        arr[0],
+      ),
+      dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  (QuicConnection, QuicDatagramMux)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_datagram_mux(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+        arr[0],
+      ),
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
+        arr[1],
+      ),
+    );
+  }
+
+  @protected
+  (QuicConnection, QuicPubSubClient)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_pub_sub_client(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+        arr[0],
+      ),
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
+        arr[1],
+      ),
+    );
+  }
+
+  @protected
+  (QuicConnection, QuicRecvStream)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+        arr[0],
+      ),
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+        arr[1],
+      ),
+    );
+  }
+
+  @protected
+  (QuicConnection, QuicSendStream)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+        arr[0],
+      ),
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+        arr[1],
+      ),
+    );
+  }
+
+  @protected
+  (QuicConnection, QuicSendStream, QuicRecvStream)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) {
+      throw Exception('Expected 3 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+        arr[0],
+      ),
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+        arr[1],
+      ),
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+        arr[2],
+      ),
+    );
+  }
+
+  @protected
+  (QuicConnection, BigInt?)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_box_autoadd_usize(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+        arr[0],
+      ),
+      dco_decode_opt_box_autoadd_usize(arr[1]),
+    );
+  }
+
+  @protected
+  (QuicConnection, Uint8List?)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_list_prim_u_8_strict(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+        arr[0],
+      ),
+      dco_decode_opt_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  (QuicConnection, String?)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_string(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+        arr[0],
+      ),
+      dco_decode_opt_String(arr[1]),
+    );
+  }
+
+  @protected
+  (QuicConnectionPool, QuicConnection)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+        arr[0],
+      ),
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+        arr[1],
+      ),
+    );
+  }
+
+  @protected
+  (QuicConnectionPool, QuicConnection, bool)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_bool(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) {
+      throw Exception('Expected 3 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+        arr[0],
+      ),
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+        arr[1],
+      ),
+      dco_decode_bool(arr[2]),
+    );
+  }
+
+  @protected
+  (QuicConnectionPool, QuicEndpoint, QuicConnection)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3) {
+      throw Exception('Expected 3 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+        arr[0],
+      ),
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+        arr[1],
+      ),
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+        arr[2],
+      ),
+    );
+  }
+
+  @protected
+  (QuicConnectionPool, QuicConnectionPoolStats)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_quic_connection_pool_stats(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+        arr[0],
+      ),
+      dco_decode_quic_connection_pool_stats(arr[1]),
+    );
+  }
+
+  @protected
+  (QuicConnectionPool, BigInt)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_usize(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+        arr[0],
+      ),
+      dco_decode_usize(arr[1]),
+    );
+  }
+
+  @protected
+  (QuicConnection, QuicConnectionStats)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_quic_connection_stats(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+        arr[0],
+      ),
+      dco_decode_quic_connection_stats(arr[1]),
+    );
+  }
+
+  @protected
+  (QuicConnection, SocketAddress)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_socket_address(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+        arr[0],
+      ),
+      dco_decode_socket_address(arr[1]),
+    );
+  }
+
+  @protected
+  (QuicConnection, BigInt)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_u_64(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+        arr[0],
+      ),
+      dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  (QuicConnection, BigInt)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_usize(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+        arr[0],
+      ),
+      dco_decode_usize(arr[1]),
+    );
+  }
+
+  @protected
+  (QuicEndpoint, QuicConnection)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+        arr[0],
+      ),
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+        arr[1],
+      ),
+    );
+  }
+
+  @protected
+  (QuicEndpoint, WebTransportSession)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_web_transport_session(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+        arr[0],
+      ),
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+        arr[1],
+      ),
+    );
+  }
+
+  @protected
+  (QuicEndpoint, QuicConnection?)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_opt_box_autoadd_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+        arr[0],
+      ),
+      dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+        arr[1],
+      ),
+    );
+  }
+
+  @protected
+  (QuicRecvStream, Uint8List)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_list_prim_u_8_strict(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+        arr[0],
+      ),
+      dco_decode_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  (QuicRecvStream, QuicStreamChunk?)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_opt_box_autoadd_quic_stream_chunk(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+        arr[0],
+      ),
+      dco_decode_opt_box_autoadd_quic_stream_chunk(arr[1]),
+    );
+  }
+
+  @protected
+  (QuicRecvStream, Uint8List?)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_opt_list_prim_u_8_strict(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+        arr[0],
+      ),
+      dco_decode_opt_list_prim_u_8_strict(arr[1]),
+    );
+  }
+
+  @protected
+  (QuicRecvStream, List<QuicStreamChunk>?)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_opt_list_quic_stream_chunk(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+        arr[0],
+      ),
+      dco_decode_opt_list_quic_stream_chunk(arr[1]),
+    );
+  }
+
+  @protected
+  (QuicSendStream, QuicRecvStream)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+        arr[0],
+      ),
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+        arr[1],
+      ),
+    );
+  }
+
+  @protected
+  (QuicSendStream, QuicChunksWritten)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_quic_chunks_written(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+        arr[0],
+      ),
+      dco_decode_quic_chunks_written(arr[1]),
+    );
+  }
+
+  @protected
+  (QuicSendStream, QuicSendStreamStats)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_quic_send_stream_stats(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+        arr[0],
+      ),
+      dco_decode_quic_send_stream_stats(arr[1]),
+    );
+  }
+
+  @protected
+  (QuicSendStream, BigInt)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_usize(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+        arr[0],
+      ),
+      dco_decode_usize(arr[1]),
+    );
+  }
+
+  @protected
+  (QuicServerConfig, bool)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_server_config_bool(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+        arr[0],
+      ),
+      dco_decode_bool(arr[1]),
+    );
+  }
+
+  @protected
+  (SimpleQuicServer, SelfSignedCertificate)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_self_signed_certificate(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+        arr[0],
+      ),
+      dco_decode_self_signed_certificate(arr[1]),
+    );
+  }
+
+  @protected
+  (SimpleQuicServer, SimpleQuicServerStats)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_simple_quic_server_stats(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+        arr[0],
+      ),
+      dco_decode_simple_quic_server_stats(arr[1]),
+    );
+  }
+
+  @protected
+  (SimpleQuicServer, String)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_string(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+        arr[0],
+      ),
+      dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  (SimpleQuicServer, BigInt)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_u_64(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+        arr[0],
+      ),
+      dco_decode_u_64(arr[1]),
+    );
+  }
+
+  @protected
+  (CertificateChain, PrivateKey)
+  dco_decode_record_certificate_chain_private_key(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (
+      dco_decode_certificate_chain(arr[0]),
+      dco_decode_private_key(arr[1]),
+    );
+  }
+
+  @protected
+  SelfSignedCertificate dco_decode_self_signed_certificate(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return SelfSignedCertificate(
+      certificateChain: dco_decode_certificate_chain(arr[0]),
+      privateKey: dco_decode_private_key(arr[1]),
+      sha256Fingerprint: dco_decode_String(arr[2]),
+    );
+  }
+
+  @protected
+  SimpleQuicServerConfig dco_decode_simple_quic_server_config(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return SimpleQuicServerConfig(
+      maxConcurrentStreamsPerConnection: dco_decode_u_32(arr[0]),
+      maxConcurrentRequests: dco_decode_u_32(arr[1]),
+      maxRequestSize: dco_decode_usize(arr[2]),
+      requestTimeoutMs: dco_decode_u_64(arr[3]),
+      shutdownTimeoutMs: dco_decode_u_64(arr[4]),
+    );
+  }
+
+  @protected
+  SimpleQuicServerStats dco_decode_simple_quic_server_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return SimpleQuicServerStats(
+      connectionsAccepted: dco_decode_u_64(arr[0]),
+      activeConnections: dco_decode_u_64(arr[1]),
+      requestsHandled: dco_decode_u_64(arr[2]),
+      requestsFailed: dco_decode_u_64(arr[3]),
+      activeRequests: dco_decode_u_64(arr[4]),
+      bytesReceived: dco_decode_u_64(arr[5]),
+      bytesSent: dco_decode_u_64(arr[6]),
+    );
+  }
+
+  @protected
+  SocketAddress dco_decode_socket_address(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return SocketAddress(
+      ip: dco_decode_String(arr[0]),
+      port: dco_decode_u_16(arr[1]),
+    );
+  }
+
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  WebTransportCloseInfo dco_decode_web_transport_close_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return WebTransportCloseInfo(
+      code: dco_decode_u_32(arr[0]),
+      reason: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  Http3Client
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return Http3ClientImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  Http3ResponseStream
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return Http3ResponseStreamImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  Http3Router
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Router(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return Http3RouterImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  Http3ServerStream
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return Http3ServerStreamImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicCertificateResolver
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicCertificateResolverImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicClient
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicClientImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicConnection
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicConnectionImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicConnectionPool
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicConnectionPoolImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicDatagramChannel
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicDatagramChannelImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicDatagramMux
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicDatagramMuxImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicDatagramSubscription
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicDatagramSubscriptionImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicEndpoint
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicEndpointImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicEndpointConfig
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicEndpointConfigImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicFileReceiver
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicFileReceiverImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicFileUpload
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicFileUploadImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicFramedStream
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicFramedStreamImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicKeyLog
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicKeyLog(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicKeyLogImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicPubSubBroker
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicPubSubBrokerImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicPubSubClient
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicPubSubClientImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicPubSubSubscription
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicPubSubSubscriptionImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicRecvStream
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicRecvStreamImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicRpcCall
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicRpcCallImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicRpcClient
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicRpcClientImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicRpcRegistry
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcRegistry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicRpcRegistryImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicRpcResponder
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicRpcResponderImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicSendStream
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicSendStreamImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicServerConfig
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicServerConfigImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicStreamReassembler
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicStreamReassemblerImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicStreamingTransfer
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicStreamingTransferImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicTransportConfig
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicTransportConfigImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  SimpleQuicServer
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return SimpleQuicServerImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  WebTransportSession
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return WebTransportSessionImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  Http3ResponseStream
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return Http3ResponseStreamImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  Http3ServerStream
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return Http3ServerStreamImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicConnection
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicConnectionImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicDatagramChannel
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicDatagramChannelImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicDatagramMux
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicDatagramMuxImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicDatagramSubscription
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicDatagramSubscriptionImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicFileReceiver
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicFileReceiverImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicFileUpload
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicFileUploadImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicFramedStream
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicFramedStreamImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicPubSubBroker
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicPubSubBrokerImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicPubSubClient
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicPubSubClientImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicPubSubSubscription
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicPubSubSubscriptionImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicRpcCall
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicRpcCallImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicRpcClient
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicRpcClientImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicRpcResponder
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicRpcResponderImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicStreamReassembler
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicStreamReassemblerImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicStreamingTransfer
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicStreamingTransferImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  WebTransportSession
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return WebTransportSessionImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  Object sse_decode_DartOpaque(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_isize(deserializer);
+    return decodeDartOpaque(inner, generalizedFrbRustBinding);
+  }
+
+  @protected
+  Http3Client
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return Http3ClientImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  Http3ResponseStream
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return Http3ResponseStreamImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  Http3Router
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Router(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return Http3RouterImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  Http3ServerStream
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return Http3ServerStreamImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicCertificateResolver
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicCertificateResolverImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicClient
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicClientImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicConnection
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicConnectionImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicConnectionPool
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicConnectionPoolImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicDatagramChannel
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicDatagramChannelImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicDatagramMux
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicDatagramMuxImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicDatagramSubscription
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicDatagramSubscriptionImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicEndpoint
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicEndpointImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicEndpointConfig
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicEndpointConfigImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicFileReceiver
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicFileReceiverImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicFileUpload
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicFileUploadImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicFramedStream
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicFramedStreamImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicKeyLog
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicKeyLog(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicKeyLogImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicPubSubBroker
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicPubSubBrokerImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicPubSubClient
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicPubSubClientImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicPubSubSubscription
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicPubSubSubscriptionImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicRecvStream
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicRecvStreamImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicRpcCall
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicRpcCallImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicRpcClient
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicRpcClientImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicRpcRegistry
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcRegistry(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicRpcRegistryImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicRpcResponder
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicRpcResponderImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicSendStream
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicSendStreamImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicServerConfig
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicServerConfigImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicStreamReassembler
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicStreamReassemblerImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicStreamingTransfer
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicStreamingTransferImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  QuicTransportConfig
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return QuicTransportConfigImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  SimpleQuicServer
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return SimpleQuicServerImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  WebTransportSession
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return WebTransportSessionImpl.frbInternalSseDecode(
+      sse_decode_usize(deserializer),
+      sse_decode_i_32(deserializer),
+    );
+  }
+
+  @protected
+  RustStreamSink<QuicLogRecord> sse_decode_StreamSink_quic_log_record_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  QuicConnection
+  sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+      deserializer,
+    ));
+  }
+
+  @protected
+  QuicRecvStream
+  sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+      deserializer,
+    ));
+  }
+
+  @protected
+  QuicSendStream
+  sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+      deserializer,
+    ));
+  }
+
+  @protected
+  CertificateChain sse_decode_box_autoadd_certificate_chain(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_certificate_chain(deserializer));
+  }
+
+  @protected
+  Http3ClientConfig sse_decode_box_autoadd_http_3_client_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_http_3_client_config(deserializer));
+  }
+
+  @protected
+  Http3Request sse_decode_box_autoadd_http_3_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_http_3_request(deserializer));
+  }
+
+  @protected
+  PrivateKey sse_decode_box_autoadd_private_key(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_private_key(deserializer));
+  }
+
+  @protected
+  QuicClientConfig sse_decode_box_autoadd_quic_client_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_quic_client_config(deserializer));
+  }
+
+  @protected
+  QuicConnectionEnd sse_decode_box_autoadd_quic_connection_end(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_quic_connection_end(deserializer));
+  }
+
+  @protected
+  QuicConnectionPoolConfig sse_decode_box_autoadd_quic_connection_pool_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_quic_connection_pool_config(deserializer));
+  }
+
+  @protected
+  QuicDatagramChannelConfig sse_decode_box_autoadd_quic_datagram_channel_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_quic_datagram_channel_config(deserializer));
+  }
+
+  @protected
+  QuicDatagramSubscriptionConfig
+  sse_decode_box_autoadd_quic_datagram_subscription_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_quic_datagram_subscription_config(deserializer));
+  }
+
+  @protected
+  QuicFileTransferConfig sse_decode_box_autoadd_quic_file_transfer_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_quic_file_transfer_config(deserializer));
+  }
+
+  @protected
+  QuicPubSubBrokerConfig sse_decode_box_autoadd_quic_pub_sub_broker_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_quic_pub_sub_broker_config(deserializer));
+  }
+
+  @protected
+  QuicPubSubMessage sse_decode_box_autoadd_quic_pub_sub_message(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_quic_pub_sub_message(deserializer));
+  }
+
+  @protected
+  QuicPubSubSubscribeOptions
+  sse_decode_box_autoadd_quic_pub_sub_subscribe_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_quic_pub_sub_subscribe_options(deserializer));
+  }
+
+  @protected
+  QuicReadException sse_decode_box_autoadd_quic_read_exception(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_quic_read_exception(deserializer));
+  }
+
+  @protected
+  QuicRpcCallOptions sse_decode_box_autoadd_quic_rpc_call_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_quic_rpc_call_options(deserializer));
+  }
+
+  @protected
+  QuicRpcClientConfig sse_decode_box_autoadd_quic_rpc_client_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_quic_rpc_client_config(deserializer));
+  }
+
+  @protected
+  QuicStreamChunk sse_decode_box_autoadd_quic_stream_chunk(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_quic_stream_chunk(deserializer));
+  }
+
+  @protected
+  SimpleQuicServerConfig sse_decode_box_autoadd_simple_quic_server_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_simple_quic_server_config(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_usize(deserializer));
+  }
+
+  @protected
+  CertificateChain sse_decode_certificate_chain(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_certificates = sse_decode_list_list_prim_u_8_strict(deserializer);
+    return CertificateChain(certificates: var_certificates);
+  }
+
+  @protected
+  CertificateKeyAlgorithm sse_decode_certificate_key_algorithm(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return CertificateKeyAlgorithm.values[inner];
+  }
+
+  @protected
+  Http3ClientConfig sse_decode_http_3_client_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_connectTimeoutMs = sse_decode_u_64(deserializer);
+    var var_requestTimeoutMs = sse_decode_u_64(deserializer);
+    var var_maxResponseSize = sse_decode_usize(deserializer);
+    var var_maxFieldSectionSize = sse_decode_u_64(deserializer);
+    return Http3ClientConfig(
+      connectTimeoutMs: var_connectTimeoutMs,
+      requestTimeoutMs: var_requestTimeoutMs,
+      maxResponseSize: var_maxResponseSize,
+      maxFieldSectionSize: var_maxFieldSectionSize,
+    );
+  }
+
+  @protected
+  Http3Header sse_decode_http_3_header(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    return Http3Header(name: var_name, value: var_value);
+  }
+
+  @protected
+  Http3Request sse_decode_http_3_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_method = sse_decode_String(deserializer);
+    var var_url = sse_decode_String(deserializer);
+    var var_headers = sse_decode_list_http_3_header(deserializer);
+    var var_body = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_trailers = sse_decode_list_http_3_header(deserializer);
+    return Http3Request(
+      method: var_method,
+      url: var_url,
+      headers: var_headers,
+      body: var_body,
+      trailers: var_trailers,
+    );
+  }
+
+  @protected
+  Http3Response sse_decode_http_3_response(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_status = sse_decode_u_16(deserializer);
+    var var_headers = sse_decode_list_http_3_header(deserializer);
+    var var_body = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_trailers = sse_decode_list_http_3_header(deserializer);
+    return Http3Response(
+      status: var_status,
+      headers: var_headers,
+      body: var_body,
+      trailers: var_trailers,
+    );
+  }
+
+  @protected
+  Http3ServerRequest sse_decode_http_3_server_request(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_method = sse_decode_String(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_query = sse_decode_opt_String(deserializer);
+    var var_headers = sse_decode_list_http_3_header(deserializer);
+    return Http3ServerRequest(
+      method: var_method,
+      path: var_path,
+      query: var_query,
+      headers: var_headers,
+    );
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_isize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Http3Header> sse_decode_list_http_3_header(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Http3Header>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_http_3_header(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Uint8List> sse_decode_list_list_prim_u_8_strict(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Uint8List>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_list_prim_u_8_strict(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<QuicAuthorityPoolStats> sse_decode_list_quic_authority_pool_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <QuicAuthorityPoolStats>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_quic_authority_pool_stats(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<QuicLogField> sse_decode_list_quic_log_field(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <QuicLogField>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_quic_log_field(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<QuicRpcMetadata> sse_decode_list_quic_rpc_metadata(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <QuicRpcMetadata>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_quic_rpc_metadata(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<QuicStreamChunk> sse_decode_list_quic_stream_chunk(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <QuicStreamChunk>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_quic_stream_chunk(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  QuicConnection?
+  sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+        deserializer,
+      ));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  QuicRecvStream?
+  sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+        deserializer,
+      ));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  QuicSendStream?
+  sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+        deserializer,
+      ));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  QuicConnectionEnd? sse_decode_opt_box_autoadd_quic_connection_end(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_quic_connection_end(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  QuicPubSubMessage? sse_decode_opt_box_autoadd_quic_pub_sub_message(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_quic_pub_sub_message(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  QuicStreamChunk? sse_decode_opt_box_autoadd_quic_stream_chunk(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_quic_stream_chunk(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_usize(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_prim_u_8_strict(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  List<QuicStreamChunk>? sse_decode_opt_list_quic_stream_chunk(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_quic_stream_chunk(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PrivateKey sse_decode_private_key(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_keyData = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_format = sse_decode_private_key_format(deserializer);
+    return PrivateKey(keyData: var_keyData, format: var_format);
+  }
+
+  @protected
+  PrivateKeyFormat sse_decode_private_key_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PrivateKeyFormat.values[inner];
+  }
+
+  @protected
+  QuicAuthorityPoolStats sse_decode_quic_authority_pool_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_authority = sse_decode_String(deserializer);
+    var var_connections = sse_decode_u_64(deserializer);
+    var var_activeStreams = sse_decode_u_64(deserializer);
+    return QuicAuthorityPoolStats(
+      authority: var_authority,
+      connections: var_connections,
+      activeStreams: var_activeStreams,
+    );
+  }
+
+  @protected
+  QuicChunksWritten sse_decode_quic_chunks_written(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_bytes = sse_decode_usize(deserializer);
+    var var_chunks = sse_decode_usize(deserializer);
+    return QuicChunksWritten(bytes: var_bytes, chunks: var_chunks);
+  }
+
+  @protected
+  QuicClientConfig sse_decode_quic_client_config(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxConnectionsPerHost = sse_decode_usize(deserializer);
+    var var_connectTimeoutMs = sse_decode_u_64(deserializer);
+    var var_requestTimeoutMs = sse_decode_u_64(deserializer);
+    var var_retryAttempts = sse_decode_u_32(deserializer);
+    var var_retryDelayMs = sse_decode_u_64(deserializer);
+    var var_keepAliveTimeoutMs = sse_decode_u_64(deserializer);
+    var var_maxResponseSize = sse_decode_usize(deserializer);
+    return QuicClientConfig(
+      maxConnectionsPerHost: var_maxConnectionsPerHost,
+      connectTimeoutMs: var_connectTimeoutMs,
+      requestTimeoutMs: var_requestTimeoutMs,
+      retryAttempts: var_retryAttempts,
+      retryDelayMs: var_retryDelayMs,
+      keepAliveTimeoutMs: var_keepAliveTimeoutMs,
+      maxResponseSize: var_maxResponseSize,
+    );
+  }
+
+  @protected
+  QuicConnectionEnd sse_decode_quic_connection_end(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_quic_connection_end_kind(deserializer);
+    var var_errorCode = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_reason = sse_decode_String(deserializer);
+    return QuicConnectionEnd(
+      kind: var_kind,
+      errorCode: var_errorCode,
+      reason: var_reason,
+    );
+  }
+
+  @protected
+  QuicConnectionEndKind sse_decode_quic_connection_end_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return QuicConnectionEndKind.values[inner];
+  }
+
+  @protected
+  QuicConnectionPoolConfig sse_decode_quic_connection_pool_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxConnectionsPerAuthority = sse_decode_usize(deserializer);
+    var var_maxStreamsPerConnection = sse_decode_u_32(deserializer);
+    var var_idleTimeoutMs = sse_decode_u_64(deserializer);
+    var var_evictionIntervalMs = sse_decode_u_64(deserializer);
+    return QuicConnectionPoolConfig(
+      maxConnectionsPerAuthority: var_maxConnectionsPerAuthority,
+      maxStreamsPerConnection: var_maxStreamsPerConnection,
+      idleTimeoutMs: var_idleTimeoutMs,
+      evictionIntervalMs: var_evictionIntervalMs,
+    );
+  }
+
+  @protected
+  QuicConnectionPoolStats sse_decode_quic_connection_pool_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_authorities = sse_decode_list_quic_authority_pool_stats(
+      deserializer,
+    );
+    var var_hits = sse_decode_u_64(deserializer);
+    var var_misses = sse_decode_u_64(deserializer);
+    var var_evictedIdle = sse_decode_u_64(deserializer);
+    var var_evictedDead = sse_decode_u_64(deserializer);
+    return QuicConnectionPoolStats(
+      authorities: var_authorities,
+      hits: var_hits,
+      misses: var_misses,
+      evictedIdle: var_evictedIdle,
+      evictedDead: var_evictedDead,
+    );
+  }
+
+  @protected
+  QuicConnectionStats sse_decode_quic_connection_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_path = sse_decode_quic_path_stats(deserializer);
+    var var_frameTx = sse_decode_quic_frame_stats(deserializer);
+    var var_frameRx = sse_decode_quic_frame_stats(deserializer);
+    var var_udpTx = sse_decode_quic_udp_stats(deserializer);
+    var var_udpRx = sse_decode_quic_udp_stats(deserializer);
+    return QuicConnectionStats(
+      path: var_path,
+      frameTx: var_frameTx,
+      frameRx: var_frameRx,
+      udpTx: var_udpTx,
+      udpRx: var_udpRx,
+    );
+  }
+
+  @protected
+  QuicDatagramBatch sse_decode_quic_datagram_batch(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_datagrams = sse_decode_list_list_prim_u_8_strict(deserializer);
+    var var_dropped = sse_decode_u_64(deserializer);
+    var var_end = sse_decode_opt_box_autoadd_quic_connection_end(deserializer);
+    return QuicDatagramBatch(
+      datagrams: var_datagrams,
+      dropped: var_dropped,
+      end: var_end,
+    );
+  }
+
+  @protected
+  QuicDatagramChannelConfig sse_decode_quic_datagram_channel_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fragmentation = sse_decode_bool(deserializer);
+    var var_maxMessageSize = sse_decode_usize(deserializer);
+    var var_reassemblyTimeoutMs = sse_decode_u_64(deserializer);
+    var var_channelCapacity = sse_decode_usize(deserializer);
+    var var_dropPolicy = sse_decode_quic_datagram_drop_policy(deserializer);
+    return QuicDatagramChannelConfig(
+      fragmentation: var_fragmentation,
+      maxMessageSize: var_maxMessageSize,
+      reassemblyTimeoutMs: var_reassemblyTimeoutMs,
+      channelCapacity: var_channelCapacity,
+      dropPolicy: var_dropPolicy,
+    );
+  }
+
+  @protected
+  QuicDatagramDropPolicy sse_decode_quic_datagram_drop_policy(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return QuicDatagramDropPolicy.values[inner];
+  }
+
+  @protected
+  QuicDatagramException sse_decode_quic_datagram_exception(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        return QuicDatagramException_UnsupportedByPeer();
+      case 1:
+        var var_maxSize = sse_decode_usize(deserializer);
+        return QuicDatagramException_TooLarge(maxSize: var_maxSize);
+      case 2:
+        var var_field0 = sse_decode_String(deserializer);
+        return QuicDatagramException_ConnectionLost(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  QuicDatagramSubscriptionConfig sse_decode_quic_datagram_subscription_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_capacity = sse_decode_usize(deserializer);
+    var var_dropPolicy = sse_decode_quic_datagram_drop_policy(deserializer);
+    return QuicDatagramSubscriptionConfig(
+      capacity: var_capacity,
+      dropPolicy: var_dropPolicy,
+    );
+  }
+
+  @protected
+  QuicError sse_decode_quic_error(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_String(deserializer);
+        return QuicError_Connection(var_field0);
+      case 1:
+        var var_field0 = sse_decode_String(deserializer);
+        return QuicError_Endpoint(var_field0);
+      case 2:
+        var var_field0 = sse_decode_String(deserializer);
+        return QuicError_Stream(var_field0);
+      case 3:
+        var var_field0 = sse_decode_String(deserializer);
+        return QuicError_Tls(var_field0);
+      case 4:
+        var var_field0 = sse_decode_String(deserializer);
+        return QuicError_Config(var_field0);
+      case 5:
+        var var_field0 = sse_decode_String(deserializer);
+        return QuicError_Network(var_field0);
+      case 6:
+        var var_field0 = sse_decode_String(deserializer);
+        return QuicError_Write(var_field0);
+      case 7:
+        var var_limit = sse_decode_usize(deserializer);
+        return QuicError_ResponseTooLong(limit: var_limit);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  QuicFileTransferConfig sse_decode_quic_file_transfer_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_chunkSize = sse_decode_usize(deserializer);
+    var var_parallelStreams = sse_decode_usize(deserializer);
+    return QuicFileTransferConfig(
+      chunkSize: var_chunkSize,
+      parallelStreams: var_parallelStreams,
+    );
+  }
+
+  @protected
+  QuicFileTransferErrorKind sse_decode_quic_file_transfer_error_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return QuicFileTransferErrorKind.values[inner];
+  }
+
+  @protected
+  QuicFileTransferException sse_decode_quic_file_transfer_exception(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_quic_file_transfer_error_kind(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return QuicFileTransferException(kind: var_kind, message: var_message);
+  }
+
+  @protected
+  QuicFileTransferProgress sse_decode_quic_file_transfer_progress(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_bytesTransferred = sse_decode_u_64(deserializer);
+    var var_totalBytes = sse_decode_u_64(deserializer);
+    var var_resumedFrom = sse_decode_u_64(deserializer);
+    return QuicFileTransferProgress(
+      bytesTransferred: var_bytesTransferred,
+      totalBytes: var_totalBytes,
+      resumedFrom: var_resumedFrom,
+    );
+  }
+
+  @protected
+  QuicFrameErrorKind sse_decode_quic_frame_error_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return QuicFrameErrorKind.values[inner];
+  }
+
+  @protected
+  QuicFrameException sse_decode_quic_frame_exception(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_quic_frame_error_kind(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return QuicFrameException(kind: var_kind, message: var_message);
+  }
+
+  @protected
+  QuicFrameStats sse_decode_quic_frame_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_acks = sse_decode_u_64(deserializer);
+    var var_crypto = sse_decode_u_64(deserializer);
+    var var_connectionClose = sse_decode_u_64(deserializer);
+    var var_dataBlocked = sse_decode_u_64(deserializer);
+    var var_datagram = sse_decode_u_64(deserializer);
+    var var_handshakeDone = sse_decode_u_64(deserializer);
+    var var_maxData = sse_decode_u_64(deserializer);
+    var var_maxStreamData = sse_decode_u_64(deserializer);
+    var var_maxStreamsBidi = sse_decode_u_64(deserializer);
+    var var_maxStreamsUni = sse_decode_u_64(deserializer);
+    var var_newConnectionId = sse_decode_u_64(deserializer);
+    var var_newToken = sse_decode_u_64(deserializer);
+    var var_pathChallenge = sse_decode_u_64(deserializer);
+    var var_pathResponse = sse_decode_u_64(deserializer);
+    var var_ping = sse_decode_u_64(deserializer);
+    var var_resetStream = sse_decode_u_64(deserializer);
+    var var_retireConnectionId = sse_decode_u_64(deserializer);
+    var var_stream = sse_decode_u_64(deserializer);
+    var var_streamDataBlocked = sse_decode_u_64(deserializer);
+    var var_streamsBlockedBidi = sse_decode_u_64(deserializer);
+    var var_streamsBlockedUni = sse_decode_u_64(deserializer);
+    var var_stopSending = sse_decode_u_64(deserializer);
+    return QuicFrameStats(
+      acks: var_acks,
+      crypto: var_crypto,
+      connectionClose: var_connectionClose,
+      dataBlocked: var_dataBlocked,
+      datagram: var_datagram,
+      handshakeDone: var_handshakeDone,
+      maxData: var_maxData,
+      maxStreamData: var_maxStreamData,
+      maxStreamsBidi: var_maxStreamsBidi,
+      maxStreamsUni: var_maxStreamsUni,
+      newConnectionId: var_newConnectionId,
+      newToken: var_newToken,
+      pathChallenge: var_pathChallenge,
+      pathResponse: var_pathResponse,
+      ping: var_ping,
+      resetStream: var_resetStream,
+      retireConnectionId: var_retireConnectionId,
+      stream: var_stream,
+      streamDataBlocked: var_streamDataBlocked,
+      streamsBlockedBidi: var_streamsBlockedBidi,
+      streamsBlockedUni: var_streamsBlockedUni,
+      stopSending: var_stopSending,
+    );
+  }
+
+  @protected
+  QuicLogField sse_decode_quic_log_field(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    return QuicLogField(name: var_name, value: var_value);
+  }
+
+  @protected
+  QuicLogLevel sse_decode_quic_log_level(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return QuicLogLevel.values[inner];
+  }
+
+  @protected
+  QuicLogRecord sse_decode_quic_log_record(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_level = sse_decode_quic_log_level(deserializer);
+    var var_target = sse_decode_String(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    var var_fields = sse_decode_list_quic_log_field(deserializer);
+    var var_connectionId = sse_decode_opt_String(deserializer);
+    var var_timestampMs = sse_decode_i_64(deserializer);
+    return QuicLogRecord(
+      level: var_level,
+      target: var_target,
+      message: var_message,
+      fields: var_fields,
+      connectionId: var_connectionId,
+      timestampMs: var_timestampMs,
+    );
+  }
+
+  @protected
+  QuicPathStats sse_decode_quic_path_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_rttMillis = sse_decode_u_64(deserializer);
+    var var_cwnd = sse_decode_u_64(deserializer);
+    var var_lostPackets = sse_decode_u_64(deserializer);
+    var var_lostBytes = sse_decode_u_64(deserializer);
+    var var_sentPackets = sse_decode_u_64(deserializer);
+    var var_congestionEvents = sse_decode_u_64(deserializer);
+    return QuicPathStats(
+      rttMillis: var_rttMillis,
+      cwnd: var_cwnd,
+      lostPackets: var_lostPackets,
+      lostBytes: var_lostBytes,
+      sentPackets: var_sentPackets,
+      congestionEvents: var_congestionEvents,
+    );
+  }
+
+  @protected
+  QuicPubSubBrokerConfig sse_decode_quic_pub_sub_broker_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_historySize = sse_decode_usize(deserializer);
+    var var_maxMessageSize = sse_decode_usize(deserializer);
+    return QuicPubSubBrokerConfig(
+      historySize: var_historySize,
+      maxMessageSize: var_maxMessageSize,
+    );
+  }
+
+  @protected
+  QuicPubSubDelivery sse_decode_quic_pub_sub_delivery(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return QuicPubSubDelivery.values[inner];
+  }
+
+  @protected
+  QuicPubSubMessage sse_decode_quic_pub_sub_message(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_sequence = sse_decode_u_64(deserializer);
+    var var_payload = sse_decode_list_prim_u_8_strict(deserializer);
+    return QuicPubSubMessage(sequence: var_sequence, payload: var_payload);
+  }
+
+  @protected
+  QuicPubSubSubscribeOptions sse_decode_quic_pub_sub_subscribe_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_delivery = sse_decode_quic_pub_sub_delivery(deserializer);
+    var var_resumeAfter = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_bufferCapacity = sse_decode_usize(deserializer);
+    var var_maxMessageSize = sse_decode_usize(deserializer);
+    return QuicPubSubSubscribeOptions(
+      delivery: var_delivery,
+      resumeAfter: var_resumeAfter,
+      bufferCapacity: var_bufferCapacity,
+      maxMessageSize: var_maxMessageSize,
+    );
+  }
+
+  @protected
+  QuicReadException sse_decode_quic_read_exception(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_u_64(deserializer);
+        return QuicReadException_Reset(var_field0);
+      case 1:
+        var var_field0 = sse_decode_String(deserializer);
+        return QuicReadException_ConnectionLost(var_field0);
+      case 2:
+        return QuicReadException_ZeroRttRejected();
+      case 3:
+        return QuicReadException_ClosedStream();
+      case 4:
+        return QuicReadException_IllegalOrderedRead();
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  QuicReadToEndException sse_decode_quic_read_to_end_exception(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_box_autoadd_quic_read_exception(
+          deserializer,
+        );
+        return QuicReadToEndException_Read(var_field0);
+      case 1:
+        return QuicReadToEndException_TooLong();
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  QuicRpcCallOptions sse_decode_quic_rpc_call_options(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_deadlineMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_metadata = sse_decode_list_quic_rpc_metadata(deserializer);
+    return QuicRpcCallOptions(
+      deadlineMs: var_deadlineMs,
+      metadata: var_metadata,
+    );
+  }
+
+  @protected
+  QuicRpcClientConfig sse_decode_quic_rpc_client_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxMessageSize = sse_decode_usize(deserializer);
+    var var_defaultDeadlineMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return QuicRpcClientConfig(
+      maxMessageSize: var_maxMessageSize,
+      defaultDeadlineMs: var_defaultDeadlineMs,
+    );
+  }
+
+  @protected
+  QuicRpcCode sse_decode_quic_rpc_code(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return QuicRpcCode.values[inner];
+  }
+
+  @protected
+  QuicRpcMetadata sse_decode_quic_rpc_metadata(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    var var_value = sse_decode_String(deserializer);
+    return QuicRpcMetadata(key: var_key, value: var_value);
+  }
+
+  @protected
+  QuicRpcRequest sse_decode_quic_rpc_request(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_method = sse_decode_String(deserializer);
+    var var_metadata = sse_decode_list_quic_rpc_metadata(deserializer);
+    var var_payload = sse_decode_list_prim_u_8_strict(deserializer);
+    var var_deadlineMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return QuicRpcRequest(
+      method: var_method,
+      metadata: var_metadata,
+      payload: var_payload,
+      deadlineMs: var_deadlineMs,
+    );
+  }
+
+  @protected
+  QuicRpcStatus sse_decode_quic_rpc_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_code = sse_decode_quic_rpc_code(deserializer);
+    var var_message = sse_decode_String(deserializer);
+    return QuicRpcStatus(code: var_code, message: var_message);
+  }
+
+  @protected
+  QuicSendStreamStats sse_decode_quic_send_stream_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_bytesQueued = sse_decode_u_64(deserializer);
+    var var_acknowledged = sse_decode_bool(deserializer);
+    var var_stopErrorCode = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return QuicSendStreamStats(
+      bytesQueued: var_bytesQueued,
+      acknowledged: var_acknowledged,
+      stopErrorCode: var_stopErrorCode,
+    );
+  }
+
+  @protected
+  QuicStreamChunk sse_decode_quic_stream_chunk(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_offset = sse_decode_u_64(deserializer);
+    var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+    return QuicStreamChunk(offset: var_offset, data: var_data);
+  }
+
+  @protected
+  QuicTransferProgress sse_decode_quic_transfer_progress(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_bytesSent = sse_decode_u_64(deserializer);
+    var var_bytesReceived = sse_decode_u_64(deserializer);
+    var var_totalToSend = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_expectedTotalReceived = sse_decode_opt_box_autoadd_u_64(
+      deserializer,
+    );
+    return QuicTransferProgress(
+      bytesSent: var_bytesSent,
+      bytesReceived: var_bytesReceived,
+      totalToSend: var_totalToSend,
+      expectedTotalReceived: var_expectedTotalReceived,
+    );
+  }
+
+  @protected
+  QuicUdpStats sse_decode_quic_udp_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_datagrams = sse_decode_u_64(deserializer);
+    var var_bytes = sse_decode_u_64(deserializer);
+    var var_ios = sse_decode_u_64(deserializer);
+    return QuicUdpStats(
+      datagrams: var_datagrams,
+      bytes: var_bytes,
+      ios: var_ios,
+    );
+  }
+
+  @protected
+  QuicWriteException sse_decode_quic_write_exception(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var tag_ = sse_decode_i_32(deserializer);
+    switch (tag_) {
+      case 0:
+        var var_field0 = sse_decode_u_64(deserializer);
+        return QuicWriteException_Stopped(var_field0);
+      case 1:
+        var var_field0 = sse_decode_String(deserializer);
+        return QuicWriteException_ConnectionLost(var_field0);
+      default:
+        throw UnimplementedError('');
+    }
+  }
+
+  @protected
+  (Http3Client, Http3ResponseStream)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_http_3_client_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_http_3_response_stream(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client(
+          deserializer,
+        );
+    var var_field1 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+          deserializer,
+        );
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (Http3Client, Http3Response)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_http_3_client_http_3_response(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client(
+          deserializer,
+        );
+    var var_field1 = sse_decode_http_3_response(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicCertificateResolver, bool)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_certificate_resolver_bool(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver(
+          deserializer,
+        );
+    var var_field1 = sse_decode_bool(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicClient, QuicStreamingTransfer)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_streaming_transfer(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+          deserializer,
+        );
+    var var_field1 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
+          deserializer,
+        );
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicClient, Uint8List)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_list_prim_u_8_strict(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+          deserializer,
+        );
+    var var_field1 = sse_decode_list_prim_u_8_strict(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicClient, QuicClientConfig)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_quic_client_config(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+          deserializer,
+        );
+    var var_field1 = sse_decode_quic_client_config(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicClient, String)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+          deserializer,
+        );
+    var var_field1 = sse_decode_String(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicConnection, QuicDatagramMux)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_datagram_mux(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+          deserializer,
+        );
+    var var_field1 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
+          deserializer,
+        );
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicConnection, QuicPubSubClient)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_pub_sub_client(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+          deserializer,
+        );
+    var var_field1 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
+          deserializer,
+        );
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicConnection, QuicRecvStream)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+          deserializer,
+        );
+    var var_field1 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+          deserializer,
+        );
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicConnection, QuicSendStream)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+          deserializer,
+        );
+    var var_field1 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+          deserializer,
+        );
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicConnection, QuicSendStream, QuicRecvStream)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+          deserializer,
+        );
+    var var_field1 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+          deserializer,
+        );
+    var var_field2 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+          deserializer,
+        );
+    return (var_field0, var_field1, var_field2);
+  }
+
+  @protected
+  (QuicConnection, BigInt?)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_box_autoadd_usize(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+          deserializer,
+        );
+    var var_field1 = sse_decode_opt_box_autoadd_usize(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicConnection, Uint8List?)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_list_prim_u_8_strict(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+          deserializer,
+        );
+    var var_field1 = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicConnection, String?)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+          deserializer,
+        );
+    var var_field1 = sse_decode_opt_String(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicConnectionPool, QuicConnection)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+          deserializer,
+        );
+    var var_field1 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+          deserializer,
+        );
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicConnectionPool, QuicConnection, bool)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_bool(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+          deserializer,
+        );
+    var var_field1 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+          deserializer,
+        );
+    var var_field2 = sse_decode_bool(deserializer);
+    return (var_field0, var_field1, var_field2);
+  }
+
+  @protected
+  (QuicConnectionPool, QuicEndpoint, QuicConnection)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+          deserializer,
+        );
+    var var_field1 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+          deserializer,
+        );
+    var var_field2 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+          deserializer,
+        );
+    return (var_field0, var_field1, var_field2);
+  }
+
+  @protected
+  (QuicConnectionPool, QuicConnectionPoolStats)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_quic_connection_pool_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+          deserializer,
+        );
+    var var_field1 = sse_decode_quic_connection_pool_stats(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicConnectionPool, BigInt)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_usize(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+          deserializer,
+        );
+    var var_field1 = sse_decode_usize(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicConnection, QuicConnectionStats)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_quic_connection_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+          deserializer,
+        );
+    var var_field1 = sse_decode_quic_connection_stats(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicConnection, SocketAddress)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_socket_address(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+          deserializer,
+        );
+    var var_field1 = sse_decode_socket_address(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicConnection, BigInt)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_u_64(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+          deserializer,
+        );
+    var var_field1 = sse_decode_u_64(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicConnection, BigInt)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_usize(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+          deserializer,
+        );
+    var var_field1 = sse_decode_usize(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicEndpoint, QuicConnection)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+          deserializer,
+        );
+    var var_field1 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+          deserializer,
+        );
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicEndpoint, WebTransportSession)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_web_transport_session(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+          deserializer,
+        );
+    var var_field1 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+          deserializer,
+        );
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicEndpoint, QuicConnection?)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_opt_box_autoadd_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+          deserializer,
+        );
+    var var_field1 =
+        sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+          deserializer,
+        );
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicRecvStream, Uint8List)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_list_prim_u_8_strict(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+          deserializer,
+        );
+    var var_field1 = sse_decode_list_prim_u_8_strict(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicRecvStream, QuicStreamChunk?)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_opt_box_autoadd_quic_stream_chunk(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+          deserializer,
+        );
+    var var_field1 = sse_decode_opt_box_autoadd_quic_stream_chunk(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicRecvStream, Uint8List?)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_opt_list_prim_u_8_strict(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+          deserializer,
+        );
+    var var_field1 = sse_decode_opt_list_prim_u_8_strict(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicRecvStream, List<QuicStreamChunk>?)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_opt_list_quic_stream_chunk(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+          deserializer,
+        );
+    var var_field1 = sse_decode_opt_list_quic_stream_chunk(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicSendStream, QuicRecvStream)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+          deserializer,
+        );
+    var var_field1 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+          deserializer,
+        );
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicSendStream, QuicChunksWritten)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_quic_chunks_written(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+          deserializer,
+        );
+    var var_field1 = sse_decode_quic_chunks_written(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicSendStream, QuicSendStreamStats)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_quic_send_stream_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+          deserializer,
+        );
+    var var_field1 = sse_decode_quic_send_stream_stats(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicSendStream, BigInt)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_usize(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+          deserializer,
+        );
+    var var_field1 = sse_decode_usize(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  (QuicServerConfig, bool)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_server_config_bool(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+          deserializer,
+        );
+    var var_field1 = sse_decode_bool(deserializer);
+    return (var_field0, var_field1);
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_QuicSendStream => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream;
+  @protected
+  (SimpleQuicServer, SelfSignedCertificate)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_self_signed_certificate(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+          deserializer,
+        );
+    var var_field1 = sse_decode_self_signed_certificate(deserializer);
+    return (var_field0, var_field1);
+  }
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_QuicServerConfig => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig;
+  @protected
+  (SimpleQuicServer, SimpleQuicServerStats)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_simple_quic_server_stats(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+          deserializer,
+        );
+    var var_field1 = sse_decode_simple_quic_server_stats(deserializer);
+    return (var_field0, var_field1);
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_QuicServerConfig => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig;
+  @protected
+  (SimpleQuicServer, String)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_string(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+          deserializer,
+        );
+    var var_field1 = sse_decode_String(deserializer);
+    return (var_field0, var_field1);
+  }
 
-  RustArcIncrementStrongCountFnType
-  get rust_arc_increment_strong_count_QuicTransportConfig => wire
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig;
+  @protected
+  (SimpleQuicServer, BigInt)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_u_64(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 =
+        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+          deserializer,
+        );
+    var var_field1 = sse_decode_u_64(deserializer);
+    return (var_field0, var_field1);
+  }
 
-  RustArcDecrementStrongCountFnType
-  get rust_arc_decrement_strong_count_QuicTransportConfig => wire
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig;
+  @protected
+  (CertificateChain, PrivateKey)
+  sse_decode_record_certificate_chain_private_key(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_certificate_chain(deserializer);
+    var var_field1 = sse_decode_private_key(deserializer);
+    return (var_field0, var_field1);
+  }
 
   @protected
-  QuicClient
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
-    dynamic raw,
+  SelfSignedCertificate sse_decode_self_signed_certificate(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return QuicClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_certificateChain = sse_decode_certificate_chain(deserializer);
+    var var_privateKey = sse_decode_private_key(deserializer);
+    var var_sha256Fingerprint = sse_decode_String(deserializer);
+    return SelfSignedCertificate(
+      certificateChain: var_certificateChain,
+      privateKey: var_privateKey,
+      sha256Fingerprint: var_sha256Fingerprint,
+    );
   }
 
   @protected
-  QuicConnection
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-    dynamic raw,
+  SimpleQuicServerConfig sse_decode_simple_quic_server_config(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return QuicConnectionImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_maxConcurrentStreamsPerConnection = sse_decode_u_32(deserializer);
+    var var_maxConcurrentRequests = sse_decode_u_32(deserializer);
+    var var_maxRequestSize = sse_decode_usize(deserializer);
+    var var_requestTimeoutMs = sse_decode_u_64(deserializer);
+    var var_shutdownTimeoutMs = sse_decode_u_64(deserializer);
+    return SimpleQuicServerConfig(
+      maxConcurrentStreamsPerConnection: var_maxConcurrentStreamsPerConnection,
+      maxConcurrentRequests: var_maxConcurrentRequests,
+      maxRequestSize: var_maxRequestSize,
+      requestTimeoutMs: var_requestTimeoutMs,
+      shutdownTimeoutMs: var_shutdownTimeoutMs,
+    );
   }
 
   @protected
-  QuicEndpoint
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
-    dynamic raw,
+  SimpleQuicServerStats sse_decode_simple_quic_server_stats(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return QuicEndpointImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_connectionsAccepted = sse_decode_u_64(deserializer);
+    var var_activeConnections = sse_decode_u_64(deserializer);
+    var var_requestsHandled = sse_decode_u_64(deserializer);
+    var var_requestsFailed = sse_decode_u_64(deserializer);
+    var var_activeRequests = sse_decode_u_64(deserializer);
+    var var_bytesReceived = sse_decode_u_64(deserializer);
+    var var_bytesSent = sse_decode_u_64(deserializer);
+    return SimpleQuicServerStats(
+      connectionsAccepted: var_connectionsAccepted,
+      activeConnections: var_activeConnections,
+      requestsHandled: var_requestsHandled,
+      requestsFailed: var_requestsFailed,
+      activeRequests: var_activeRequests,
+      bytesReceived: var_bytesReceived,
+      bytesSent: var_bytesSent,
+    );
   }
 
   @protected
-  QuicEndpointConfig
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
-    dynamic raw,
+  SocketAddress sse_decode_socket_address(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_ip = sse_decode_String(deserializer);
+    var var_port = sse_decode_u_16(deserializer);
+    return SocketAddress(ip: var_ip, port: var_port);
+  }
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  WebTransportCloseInfo sse_decode_web_transport_close_info(
+    SseDeserializer deserializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return QuicEndpointConfigImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_code = sse_decode_u_32(deserializer);
+    var var_reason = sse_decode_String(deserializer);
+    return WebTransportCloseInfo(code: var_code, reason: var_reason);
   }
 
   @protected
-  QuicRecvStream
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
-    dynamic raw,
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return QuicRecvStreamImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client(
+    Http3Client self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as Http3ClientImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+    Http3ResponseStream self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as Http3ResponseStreamImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Router(
+    Http3Router self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as Http3RouterImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+    Http3ServerStream self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as Http3ServerStreamImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver(
+    QuicCertificateResolver self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicCertificateResolverImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+    QuicClient self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicClientImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    QuicConnection self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicConnectionImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+    QuicConnectionPool self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicConnectionPoolImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
+    QuicDatagramChannel self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicDatagramChannelImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
+    QuicDatagramMux self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicDatagramMuxImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription(
+    QuicDatagramSubscription self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicDatagramSubscriptionImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  QuicSendStream
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
-    dynamic raw,
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+    QuicEndpoint self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return QuicSendStreamImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicEndpointImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  QuicServerConfig
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
-    dynamic raw,
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
+    QuicEndpointConfig self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return QuicServerConfigImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicEndpointConfigImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  QuicTransportConfig
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
-    dynamic raw,
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver(
+    QuicFileReceiver self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return QuicTransportConfigImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicFileReceiverImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  QuicClient
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
-    dynamic raw,
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload(
+    QuicFileUpload self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return QuicClientImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicFileUploadImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  QuicConnection
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-    dynamic raw,
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream(
+    QuicFramedStream self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return QuicConnectionImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicFramedStreamImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  QuicEndpoint
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
-    dynamic raw,
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicKeyLog(
+    QuicKeyLog self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return QuicEndpointImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicKeyLogImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  QuicEndpointConfig
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
-    dynamic raw,
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker(
+    QuicPubSubBroker self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return QuicEndpointConfigImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicPubSubBrokerImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  QuicRecvStream
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
-    dynamic raw,
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
+    QuicPubSubClient self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return QuicRecvStreamImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicPubSubClientImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  QuicSendStream
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
-    dynamic raw,
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+    QuicPubSubSubscription self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return QuicSendStreamImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicPubSubSubscriptionImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  QuicServerConfig
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
-    dynamic raw,
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+    QuicRecvStream self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return QuicServerConfigImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicRecvStreamImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  QuicTransportConfig
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
-    dynamic raw,
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall(
+    QuicRpcCall self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return QuicTransportConfigImpl.frbInternalDcoDecode(raw as List<dynamic>);
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicRpcCallImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient(
+    QuicRpcClient self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicRpcClientImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  QuicClientConfig dco_decode_box_autoadd_quic_client_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_quic_client_config(raw);
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcRegistry(
+    QuicRpcRegistry self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicRpcRegistryImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  QuicReadException dco_decode_box_autoadd_quic_read_exception(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_quic_read_exception(raw);
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder(
+    QuicRpcResponder self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicRpcResponderImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  BigInt dco_decode_box_autoadd_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_usize(raw);
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+    QuicSendStream self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicSendStreamImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_list_prim_u_8_strict).toList();
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+    QuicServerConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicServerConfigImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler(
+    QuicStreamReassembler self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicStreamReassemblerImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
+    QuicStreamingTransfer self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicStreamingTransferImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
+    QuicTransportConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicTransportConfigImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_usize(raw);
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+    SimpleQuicServer self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as SimpleQuicServerImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
 
   @protected
-  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_list_prim_u_8_strict(raw);
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+    WebTransportSession self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as WebTransportSessionImpl).frbInternalSseEncode(move: true),
+      serializer,
+    );
   }
-
-  @protected
-  QuicClientConfig dco_decode_quic_client_config(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return QuicClientConfig(
-      maxConnectionsPerHost: dco_decode_usize(arr[0]),
-      connectTimeoutMs: dco_decode_u_64(arr[1]),
-      requestTimeoutMs: dco_decode_u_64(arr[2]),
-      retryAttempts: dco_decode_u_32(arr[3]),
-      retryDelayMs: dco_decode_u_64(arr[4]),
-      keepAliveTimeoutMs: dco_decode_u_64(arr[5]),
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+    Http3ResponseStream self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as Http3ResponseStreamImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  QuicConnectionStats dco_decode_quic_connection_stats(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return QuicConnectionStats(
-      path: dco_decode_quic_path_stats(arr[0]),
-      frameTx: dco_decode_quic_frame_stats(arr[1]),
-      frameRx: dco_decode_quic_frame_stats(arr[2]),
-      udpTx: dco_decode_quic_udp_stats(arr[3]),
-      udpRx: dco_decode_quic_udp_stats(arr[4]),
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+    Http3ServerStream self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as Http3ServerStreamImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  QuicDatagramException dco_decode_quic_datagram_exception(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return QuicDatagramException_UnsupportedByPeer();
-      case 1:
-        return QuicDatagramException_TooLarge(
-          maxSize: dco_decode_usize(raw[1]),
-        );
-      case 2:
-        return QuicDatagramException_ConnectionLost(dco_decode_String(raw[1]));
-      default:
-        throw Exception("unreachable");
-    }
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    QuicConnection self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicConnectionImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
   }
 
   @protected
-  QuicError dco_decode_quic_error(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return QuicError_Connection(dco_decode_String(raw[1]));
-      case 1:
-        return QuicError_Endpoint(dco_decode_String(raw[1]));
-      case 2:
-        return QuicError_Stream(dco_decode_String(raw[1]));
-      case 3:
-        return QuicError_Tls(dco_decode_String(raw[1]));
-      case 4:
-        return QuicError_Config(dco_decode_String(raw[1]));
-      case 5:
-        return QuicError_Network(dco_decode_String(raw[1]));
-      case 6:
-        return QuicError_Write(dco_decode_String(raw[1]));
-      default:
-        throw Exception("unreachable");
-    }
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
+    QuicDatagramChannel self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicDatagramChannelImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
   }
 
   @protected
-  QuicFrameStats dco_decode_quic_frame_stats(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 22)
-      throw Exception('unexpected arr length: expect 22 but see ${arr.length}');
-    return QuicFrameStats(
-      acks: dco_decode_u_64(arr[0]),
-      crypto: dco_decode_u_64(arr[1]),
-      connectionClose: dco_decode_u_64(arr[2]),
-      dataBlocked: dco_decode_u_64(arr[3]),
-      datagram: dco_decode_u_64(arr[4]),
-      handshakeDone: dco_decode_u_64(arr[5]),
-      maxData: dco_decode_u_64(arr[6]),
-      maxStreamData: dco_decode_u_64(arr[7]),
-      maxStreamsBidi: dco_decode_u_64(arr[8]),
-      maxStreamsUni: dco_decode_u_64(arr[9]),
-      newConnectionId: dco_decode_u_64(arr[10]),
-      newToken: dco_decode_u_64(arr[11]),
-      pathChallenge: dco_decode_u_64(arr[12]),
-      pathResponse: dco_decode_u_64(arr[13]),
-      ping: dco_decode_u_64(arr[14]),
-      resetStream: dco_decode_u_64(arr[15]),
-      retireConnectionId: dco_decode_u_64(arr[16]),
-      stream: dco_decode_u_64(arr[17]),
-      streamDataBlocked: dco_decode_u_64(arr[18]),
-      streamsBlockedBidi: dco_decode_u_64(arr[19]),
-      streamsBlockedUni: dco_decode_u_64(arr[20]),
-      stopSending: dco_decode_u_64(arr[21]),
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
+    QuicDatagramMux self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicDatagramMuxImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  QuicPathStats dco_decode_quic_path_stats(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return QuicPathStats(
-      rttMillis: dco_decode_u_64(arr[0]),
-      cwnd: dco_decode_u_64(arr[1]),
-      lostPackets: dco_decode_u_64(arr[2]),
-      lostBytes: dco_decode_u_64(arr[3]),
-      sentPackets: dco_decode_u_64(arr[4]),
-      congestionEvents: dco_decode_u_64(arr[5]),
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription(
+    QuicDatagramSubscription self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicDatagramSubscriptionImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  QuicReadException dco_decode_quic_read_exception(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return QuicReadException_Reset(dco_decode_u_64(raw[1]));
-      case 1:
-        return QuicReadException_ConnectionLost(dco_decode_String(raw[1]));
-      case 2:
-        return QuicReadException_ZeroRttRejected();
-      case 3:
-        return QuicReadException_ClosedStream();
-      case 4:
-        return QuicReadException_IllegalOrderedRead();
-      default:
-        throw Exception("unreachable");
-    }
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver(
+    QuicFileReceiver self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicFileReceiverImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
   }
 
   @protected
-  QuicReadToEndException dco_decode_quic_read_to_end_exception(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return QuicReadToEndException_Read(
-          dco_decode_box_autoadd_quic_read_exception(raw[1]),
-        );
-      case 1:
-        return QuicReadToEndException_TooLong();
-      default:
-        throw Exception("unreachable");
-    }
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload(
+    QuicFileUpload self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicFileUploadImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
   }
 
   @protected
-  QuicUdpStats dco_decode_quic_udp_stats(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return QuicUdpStats(
-      datagrams: dco_decode_u_64(arr[0]),
-      bytes: dco_decode_u_64(arr[1]),
-      ios: dco_decode_u_64(arr[2]),
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream(
+    QuicFramedStream self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicFramedStreamImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  QuicWriteException dco_decode_quic_write_exception(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return QuicWriteException_Stopped(dco_decode_u_64(raw[1]));
-      case 1:
-        return QuicWriteException_ConnectionLost(dco_decode_String(raw[1]));
-      default:
-        throw Exception("unreachable");
-    }
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker(
+    QuicPubSubBroker self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicPubSubBrokerImpl).frbInternalSseEncode(move: false),
+      serializer,
+    );
   }
 
   @protected
-  (QuicClient, QuicClientConfig)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_quic_client_config(
-    dynamic raw,
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
+    QuicPubSubClient self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
-        arr[0],
-      ),
-      dco_decode_quic_client_config(arr[1]),
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicPubSubClientImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  (QuicClient, String)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_string(
-    dynamic raw,
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+    QuicPubSubSubscription self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
-        arr[0],
-      ),
-      dco_decode_String(arr[1]),
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicPubSubSubscriptionImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  (QuicConnection, QuicSendStream)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream(
-    dynamic raw,
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall(
+    QuicRpcCall self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-        arr[0],
-      ),
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
-        arr[1],
-      ),
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicRpcCallImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  (QuicConnection, QuicSendStream, QuicRecvStream)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream(
-    dynamic raw,
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient(
+    QuicRpcClient self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3) {
-      throw Exception('Expected 3 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-        arr[0],
-      ),
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
-        arr[1],
-      ),
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
-        arr[2],
-      ),
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicRpcClientImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  (QuicConnection, BigInt?)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_box_autoadd_usize(
-    dynamic raw,
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder(
+    QuicRpcResponder self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-        arr[0],
-      ),
-      dco_decode_opt_box_autoadd_usize(arr[1]),
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicRpcResponderImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  (QuicConnection, Uint8List?)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_list_prim_u_8_strict(
-    dynamic raw,
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler(
+    QuicStreamReassembler self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-        arr[0],
-      ),
-      dco_decode_opt_list_prim_u_8_strict(arr[1]),
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicStreamReassemblerImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  (QuicConnection, String?)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_string(
-    dynamic raw,
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
+    QuicStreamingTransfer self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-        arr[0],
-      ),
-      dco_decode_opt_String(arr[1]),
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicStreamingTransferImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  (QuicConnection, QuicConnectionStats)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_quic_connection_stats(
-    dynamic raw,
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+    WebTransportSession self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-        arr[0],
-      ),
-      dco_decode_quic_connection_stats(arr[1]),
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as WebTransportSessionImpl).frbInternalSseEncode(move: false),
+      serializer,
     );
   }
 
   @protected
-  (QuicConnection, SocketAddress)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_socket_address(
-    dynamic raw,
+  void sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
+    FutureOr<void> Function(String) self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-        arr[0],
-      ),
-      dco_decode_socket_address(arr[1]),
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+      encode_DartFn_Inputs_String_Output_unit_AnyhowException(self),
+      serializer,
     );
   }
 
   @protected
-  (QuicConnection, BigInt)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_u_64(
-    dynamic raw,
+  void
+  sse_encode_DartFn_Inputs__Output_opt_list_prim_u_8_strict_AnyhowException(
+    FutureOr<Uint8List?> Function() self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-        arr[0],
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+      encode_DartFn_Inputs__Output_opt_list_prim_u_8_strict_AnyhowException(
+        self,
       ),
-      dco_decode_u_64(arr[1]),
+      serializer,
     );
   }
 
   @protected
-  (QuicConnection, BigInt)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_usize(
-    dynamic raw,
+  void
+  sse_encode_DartFn_Inputs_http_3_server_request_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream_Output_unit_AnyhowException(
+    FutureOr<void> Function(Http3ServerRequest, Http3ServerStream) self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-        arr[0],
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+      encode_DartFn_Inputs_http_3_server_request_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream_Output_unit_AnyhowException(
+        self,
       ),
-      dco_decode_usize(arr[1]),
+      serializer,
     );
   }
 
   @protected
-  (QuicEndpoint, QuicConnection)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
-    dynamic raw,
+  void
+  sse_encode_DartFn_Inputs_list_prim_u_8_strict_Output_list_prim_u_8_strict_AnyhowException(
+    FutureOr<Uint8List> Function(Uint8List) self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
-        arr[0],
-      ),
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-        arr[1],
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+      encode_DartFn_Inputs_list_prim_u_8_strict_Output_list_prim_u_8_strict_AnyhowException(
+        self,
       ),
+      serializer,
     );
   }
 
   @protected
-  (QuicRecvStream, Uint8List)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_list_prim_u_8_strict(
-    dynamic raw,
+  void
+  sse_encode_DartFn_Inputs_list_prim_u_8_strict_Output_unit_AnyhowException(
+    FutureOr<void> Function(Uint8List) self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
-        arr[0],
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+      encode_DartFn_Inputs_list_prim_u_8_strict_Output_unit_AnyhowException(
+        self,
       ),
-      dco_decode_list_prim_u_8_strict(arr[1]),
+      serializer,
     );
   }
 
   @protected
-  (QuicRecvStream, Uint8List?)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_opt_list_prim_u_8_strict(
-    dynamic raw,
+  void
+  sse_encode_DartFn_Inputs_quic_file_transfer_progress_Output_unit_AnyhowException(
+    FutureOr<void> Function(QuicFileTransferProgress) self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
-        arr[0],
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+      encode_DartFn_Inputs_quic_file_transfer_progress_Output_unit_AnyhowException(
+        self,
       ),
-      dco_decode_opt_list_prim_u_8_strict(arr[1]),
+      serializer,
     );
   }
 
   @protected
-  (QuicSendStream, BigInt)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_usize(
-    dynamic raw,
+  void
+  sse_encode_DartFn_Inputs_quic_rpc_request_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder_Output_quic_rpc_status_AnyhowException(
+    FutureOr<QuicRpcStatus> Function(QuicRpcRequest, QuicRpcResponder) self,
+    SseSerializer serializer,
   ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
-        arr[0],
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+      encode_DartFn_Inputs_quic_rpc_request_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder_Output_quic_rpc_status_AnyhowException(
+        self,
       ),
-      dco_decode_usize(arr[1]),
+      serializer,
     );
   }
 
   @protected
-  SocketAddress dco_decode_socket_address(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return SocketAddress(
-      ip: dco_decode_String(arr[0]),
-      port: dco_decode_u_16(arr[1]),
+  void
+  sse_encode_DartFn_Inputs_quic_rpc_request_Output_list_prim_u_8_strict_AnyhowException(
+    FutureOr<Uint8List> Function(QuicRpcRequest) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+      encode_DartFn_Inputs_quic_rpc_request_Output_list_prim_u_8_strict_AnyhowException(
+        self,
+      ),
+      serializer,
     );
   }
 
   @protected
-  int dco_decode_u_16(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void
+  sse_encode_DartFn_Inputs_quic_transfer_progress_Output_unit_AnyhowException(
+    FutureOr<void> Function(QuicTransferProgress) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_DartOpaque(
+      encode_DartFn_Inputs_quic_transfer_progress_Output_unit_AnyhowException(
+        self,
+      ),
+      serializer,
+    );
   }
 
   @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_DartOpaque(Object self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_isize(
+      PlatformPointerUtil.ptrToPlatformInt64(
+        encodeDartOpaque(
+          self,
+          portManager.dartHandlerPort,
+          generalizedFrbRustBinding,
+        ),
+      ),
+      serializer,
+    );
   }
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client(
+    Http3Client self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as Http3ClientImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
   }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+    Http3ResponseStream self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as Http3ResponseStreamImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Router(
+    Http3Router self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as Http3RouterImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
   }
 
   @protected
-  BigInt dco_decode_usize(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+    Http3ServerStream self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as Http3ServerStreamImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
   }
 
   @protected
-  QuicClient
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver(
+    QuicCertificateResolver self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return QuicClientImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as QuicCertificateResolverImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  QuicConnection
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+    QuicClient self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return QuicConnectionImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as QuicClientImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  QuicEndpoint
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    QuicConnection self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return QuicEndpointImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as QuicConnectionImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  QuicEndpointConfig
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+    QuicConnectionPool self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return QuicEndpointConfigImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as QuicConnectionPoolImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  QuicRecvStream
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
+    QuicDatagramChannel self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return QuicRecvStreamImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as QuicDatagramChannelImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  QuicSendStream
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
+    QuicDatagramMux self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return QuicSendStreamImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as QuicDatagramMuxImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  QuicServerConfig
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription(
+    QuicDatagramSubscription self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return QuicServerConfigImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as QuicDatagramSubscriptionImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  QuicTransportConfig
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+    QuicEndpoint self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return QuicTransportConfigImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as QuicEndpointImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  QuicClient
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
+    QuicEndpointConfig self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return QuicClientImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as QuicEndpointConfigImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  QuicConnection
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver(
+    QuicFileReceiver self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return QuicConnectionImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as QuicFileReceiverImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  QuicEndpoint
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload(
+    QuicFileUpload self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return QuicEndpointImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as QuicFileUploadImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  QuicEndpointConfig
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream(
+    QuicFramedStream self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return QuicEndpointConfigImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as QuicFramedStreamImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  QuicRecvStream
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicKeyLog(
+    QuicKeyLog self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return QuicRecvStreamImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as QuicKeyLogImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  QuicSendStream
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker(
+    QuicPubSubBroker self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return QuicSendStreamImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as QuicPubSubBrokerImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  QuicServerConfig
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
+    QuicPubSubClient self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return QuicServerConfigImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as QuicPubSubClientImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  QuicTransportConfig
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+    QuicPubSubSubscription self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return QuicTransportConfigImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
+    sse_encode_usize(
+      (self as QuicPubSubSubscriptionImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+    QuicRecvStream self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+    sse_encode_usize(
+      (self as QuicRecvStreamImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
   }
 
   @protected
-  QuicClientConfig sse_decode_box_autoadd_quic_client_config(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall(
+    QuicRpcCall self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_quic_client_config(deserializer));
+    sse_encode_usize(
+      (self as QuicRpcCallImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
   }
 
   @protected
-  QuicReadException sse_decode_box_autoadd_quic_read_exception(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient(
+    QuicRpcClient self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_quic_read_exception(deserializer));
+    sse_encode_usize(
+      (self as QuicRpcClientImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
   }
 
   @protected
-  BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer) {
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcRegistry(
+    QuicRpcRegistry self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_usize(deserializer));
+    sse_encode_usize(
+      (self as QuicRpcRegistryImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
   }
 
   @protected
-  List<Uint8List> sse_decode_list_list_prim_u_8_strict(
-    SseDeserializer deserializer,
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder(
+    QuicRpcResponder self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicRpcResponderImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
+  }
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <Uint8List>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_list_prim_u_8_strict(deserializer));
-    }
-    return ans_;
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+    QuicSendStream self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(
+      (self as QuicSendStreamImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
   }
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+    QuicServerConfig self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_usize(
+      (self as QuicServerConfigImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler(
+    QuicStreamReassembler self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+    sse_encode_usize(
+      (self as QuicStreamReassemblerImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
+    QuicStreamingTransfer self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_usize(
+      (self as QuicStreamingTransferImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
   }
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer) {
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
+    QuicTransportConfig self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_usize(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_usize(
+      (self as QuicTransportConfigImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
   }
 
   @protected
-  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+    SimpleQuicServer self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_list_prim_u_8_strict(deserializer));
-    } else {
-      return null;
-    }
+    sse_encode_usize(
+      (self as SimpleQuicServerImpl).frbInternalSseEncode(move: null),
+      serializer,
+    );
   }
 
   @protected
-  QuicClientConfig sse_decode_quic_client_config(SseDeserializer deserializer) {
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+    WebTransportSession self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_maxConnectionsPerHost = sse_decode_usize(deserializer);
-    var var_connectTimeoutMs = sse_decode_u_64(deserializer);
-    var var_requestTimeoutMs = sse_decode_u_64(deserializer);
-    var var_retryAttempts = sse_decode_u_32(deserializer);
-    var var_retryDelayMs = sse_decode_u_64(deserializer);
-    var var_keepAliveTimeoutMs = sse_decode_u_64(deserializer);
-    return QuicClientConfig(
-      maxConnectionsPerHost: var_maxConnectionsPerHost,
-      connectTimeoutMs: var_connectTimeoutMs,
-      requestTimeoutMs: var_requestTimeoutMs,
-      retryAttempts: var_retryAttempts,
-      retryDelayMs: var_retryDelayMs,
-      keepAliveTimeoutMs: var_keepAliveTimeoutMs,
+    sse_encode_usize(
+      (self as WebTransportSessionImpl).frbInternalSseEncode(move: null),
+      serializer,
     );
   }
 
   @protected
-  QuicConnectionStats sse_decode_quic_connection_stats(
-    SseDeserializer deserializer,
+  void sse_encode_StreamSink_quic_log_record_Sse(
+    RustStreamSink<QuicLogRecord> self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_path = sse_decode_quic_path_stats(deserializer);
-    var var_frameTx = sse_decode_quic_frame_stats(deserializer);
-    var var_frameRx = sse_decode_quic_frame_stats(deserializer);
-    var var_udpTx = sse_decode_quic_udp_stats(deserializer);
-    var var_udpRx = sse_decode_quic_udp_stats(deserializer);
-    return QuicConnectionStats(
-      path: var_path,
-      frameTx: var_frameTx,
-      frameRx: var_frameRx,
-      udpTx: var_udpTx,
-      udpRx: var_udpRx,
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_quic_log_record,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
     );
   }
 
   @protected
-  QuicDatagramException sse_decode_quic_datagram_exception(
-    SseDeserializer deserializer,
-  ) {
+  void sse_encode_String(String self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        return QuicDatagramException_UnsupportedByPeer();
-      case 1:
-        var var_maxSize = sse_decode_usize(deserializer);
-        return QuicDatagramException_TooLarge(maxSize: var_maxSize);
-      case 2:
-        var var_field0 = sse_decode_String(deserializer);
-        return QuicDatagramException_ConnectionLost(var_field0);
-      default:
-        throw UnimplementedError('');
-    }
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
   }
 
   @protected
-  QuicError sse_decode_quic_error(SseDeserializer deserializer) {
+  void sse_encode_bool(bool self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_field0 = sse_decode_String(deserializer);
-        return QuicError_Connection(var_field0);
-      case 1:
-        var var_field0 = sse_decode_String(deserializer);
-        return QuicError_Endpoint(var_field0);
-      case 2:
-        var var_field0 = sse_decode_String(deserializer);
-        return QuicError_Stream(var_field0);
-      case 3:
-        var var_field0 = sse_decode_String(deserializer);
-        return QuicError_Tls(var_field0);
-      case 4:
-        var var_field0 = sse_decode_String(deserializer);
-        return QuicError_Config(var_field0);
-      case 5:
-        var var_field0 = sse_decode_String(deserializer);
-        return QuicError_Network(var_field0);
-      case 6:
-        var var_field0 = sse_decode_String(deserializer);
-        return QuicError_Write(var_field0);
-      default:
-        throw UnimplementedError('');
-    }
+    serializer.buffer.putUint8(self ? 1 : 0);
   }
 
   @protected
-  QuicFrameStats sse_decode_quic_frame_stats(SseDeserializer deserializer) {
+  void
+  sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    QuicConnection self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_acks = sse_decode_u_64(deserializer);
-    var var_crypto = sse_decode_u_64(deserializer);
-    var var_connectionClose = sse_decode_u_64(deserializer);
-    var var_dataBlocked = sse_decode_u_64(deserializer);
-    var var_datagram = sse_decode_u_64(deserializer);
-    var var_handshakeDone = sse_decode_u_64(deserializer);
-    var var_maxData = sse_decode_u_64(deserializer);
-    var var_maxStreamData = sse_decode_u_64(deserializer);
-    var var_maxStreamsBidi = sse_decode_u_64(deserializer);
-    var var_maxStreamsUni = sse_decode_u_64(deserializer);
-    var var_newConnectionId = sse_decode_u_64(deserializer);
-    var var_newToken = sse_decode_u_64(deserializer);
-    var var_pathChallenge = sse_decode_u_64(deserializer);
-    var var_pathResponse = sse_decode_u_64(deserializer);
-    var var_ping = sse_decode_u_64(deserializer);
-    var var_resetStream = sse_decode_u_64(deserializer);
-    var var_retireConnectionId = sse_decode_u_64(deserializer);
-    var var_stream = sse_decode_u_64(deserializer);
-    var var_streamDataBlocked = sse_decode_u_64(deserializer);
-    var var_streamsBlockedBidi = sse_decode_u_64(deserializer);
-    var var_streamsBlockedUni = sse_decode_u_64(deserializer);
-    var var_stopSending = sse_decode_u_64(deserializer);
-    return QuicFrameStats(
-      acks: var_acks,
-      crypto: var_crypto,
-      connectionClose: var_connectionClose,
-      dataBlocked: var_dataBlocked,
-      datagram: var_datagram,
-      handshakeDone: var_handshakeDone,
-      maxData: var_maxData,
-      maxStreamData: var_maxStreamData,
-      maxStreamsBidi: var_maxStreamsBidi,
-      maxStreamsUni: var_maxStreamsUni,
-      newConnectionId: var_newConnectionId,
-      newToken: var_newToken,
-      pathChallenge: var_pathChallenge,
-      pathResponse: var_pathResponse,
-      ping: var_ping,
-      resetStream: var_resetStream,
-      retireConnectionId: var_retireConnectionId,
-      stream: var_stream,
-      streamDataBlocked: var_streamDataBlocked,
-      streamsBlockedBidi: var_streamsBlockedBidi,
-      streamsBlockedUni: var_streamsBlockedUni,
-      stopSending: var_stopSending,
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+      self,
+      serializer,
     );
   }
 
   @protected
-  QuicPathStats sse_decode_quic_path_stats(SseDeserializer deserializer) {
+  void
+  sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+    QuicRecvStream self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_rttMillis = sse_decode_u_64(deserializer);
-    var var_cwnd = sse_decode_u_64(deserializer);
-    var var_lostPackets = sse_decode_u_64(deserializer);
-    var var_lostBytes = sse_decode_u_64(deserializer);
-    var var_sentPackets = sse_decode_u_64(deserializer);
-    var var_congestionEvents = sse_decode_u_64(deserializer);
-    return QuicPathStats(
-      rttMillis: var_rttMillis,
-      cwnd: var_cwnd,
-      lostPackets: var_lostPackets,
-      lostBytes: var_lostBytes,
-      sentPackets: var_sentPackets,
-      congestionEvents: var_congestionEvents,
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+      self,
+      serializer,
     );
   }
 
   @protected
-  QuicReadException sse_decode_quic_read_exception(
-    SseDeserializer deserializer,
+  void
+  sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+    QuicSendStream self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+      self,
+      serializer,
+    );
+  }
 
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_field0 = sse_decode_u_64(deserializer);
-        return QuicReadException_Reset(var_field0);
-      case 1:
-        var var_field0 = sse_decode_String(deserializer);
-        return QuicReadException_ConnectionLost(var_field0);
-      case 2:
-        return QuicReadException_ZeroRttRejected();
-      case 3:
-        return QuicReadException_ClosedStream();
-      case 4:
-        return QuicReadException_IllegalOrderedRead();
-      default:
-        throw UnimplementedError('');
-    }
+  @protected
+  void sse_encode_box_autoadd_certificate_chain(
+    CertificateChain self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_certificate_chain(self, serializer);
   }
 
   @protected
-  QuicReadToEndException sse_decode_quic_read_to_end_exception(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_http_3_client_config(
+    Http3ClientConfig self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_field0 = sse_decode_box_autoadd_quic_read_exception(
-          deserializer,
-        );
-        return QuicReadToEndException_Read(var_field0);
-      case 1:
-        return QuicReadToEndException_TooLong();
-      default:
-        throw UnimplementedError('');
-    }
+    sse_encode_http_3_client_config(self, serializer);
   }
 
   @protected
-  QuicUdpStats sse_decode_quic_udp_stats(SseDeserializer deserializer) {
+  void sse_encode_box_autoadd_http_3_request(
+    Http3Request self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_datagrams = sse_decode_u_64(deserializer);
-    var var_bytes = sse_decode_u_64(deserializer);
-    var var_ios = sse_decode_u_64(deserializer);
-    return QuicUdpStats(
-      datagrams: var_datagrams,
-      bytes: var_bytes,
-      ios: var_ios,
-    );
+    sse_encode_http_3_request(self, serializer);
   }
 
   @protected
-  QuicWriteException sse_decode_quic_write_exception(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_private_key(
+    PrivateKey self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_private_key(self, serializer);
+  }
 
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_field0 = sse_decode_u_64(deserializer);
-        return QuicWriteException_Stopped(var_field0);
-      case 1:
-        var var_field0 = sse_decode_String(deserializer);
-        return QuicWriteException_ConnectionLost(var_field0);
-      default:
-        throw UnimplementedError('');
-    }
+  @protected
+  void sse_encode_box_autoadd_quic_client_config(
+    QuicClientConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_quic_client_config(self, serializer);
   }
 
   @protected
-  (QuicClient, QuicClientConfig)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_quic_client_config(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_quic_connection_end(
+    QuicConnectionEnd self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
-          deserializer,
-        );
-    var var_field1 = sse_decode_quic_client_config(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_quic_connection_end(self, serializer);
   }
 
   @protected
-  (QuicClient, String)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_string(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_quic_connection_pool_config(
+    QuicConnectionPoolConfig self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
-          deserializer,
-        );
-    var var_field1 = sse_decode_String(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_quic_connection_pool_config(self, serializer);
   }
 
   @protected
-  (QuicConnection, QuicSendStream)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_quic_datagram_channel_config(
+    QuicDatagramChannelConfig self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-          deserializer,
-        );
-    var var_field1 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
-          deserializer,
-        );
-    return (var_field0, var_field1);
+    sse_encode_quic_datagram_channel_config(self, serializer);
   }
 
   @protected
-  (QuicConnection, QuicSendStream, QuicRecvStream)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_quic_datagram_subscription_config(
+    QuicDatagramSubscriptionConfig self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-          deserializer,
-        );
-    var var_field1 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
-          deserializer,
-        );
-    var var_field2 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
-          deserializer,
-        );
-    return (var_field0, var_field1, var_field2);
+    sse_encode_quic_datagram_subscription_config(self, serializer);
   }
 
   @protected
-  (QuicConnection, BigInt?)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_box_autoadd_usize(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_quic_file_transfer_config(
+    QuicFileTransferConfig self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-          deserializer,
-        );
-    var var_field1 = sse_decode_opt_box_autoadd_usize(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_quic_file_transfer_config(self, serializer);
   }
 
   @protected
-  (QuicConnection, Uint8List?)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_list_prim_u_8_strict(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_quic_pub_sub_broker_config(
+    QuicPubSubBrokerConfig self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-          deserializer,
-        );
-    var var_field1 = sse_decode_opt_list_prim_u_8_strict(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_quic_pub_sub_broker_config(self, serializer);
   }
 
   @protected
-  (QuicConnection, String?)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_string(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_quic_pub_sub_message(
+    QuicPubSubMessage self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-          deserializer,
-        );
-    var var_field1 = sse_decode_opt_String(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_quic_pub_sub_message(self, serializer);
   }
 
   @protected
-  (QuicConnection, QuicConnectionStats)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_quic_connection_stats(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_quic_pub_sub_subscribe_options(
+    QuicPubSubSubscribeOptions self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-          deserializer,
-        );
-    var var_field1 = sse_decode_quic_connection_stats(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_quic_pub_sub_subscribe_options(self, serializer);
   }
 
   @protected
-  (QuicConnection, SocketAddress)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_socket_address(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_quic_read_exception(
+    QuicReadException self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-          deserializer,
-        );
-    var var_field1 = sse_decode_socket_address(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_quic_read_exception(self, serializer);
   }
 
   @protected
-  (QuicConnection, BigInt)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_u_64(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_quic_rpc_call_options(
+    QuicRpcCallOptions self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-          deserializer,
-        );
-    var var_field1 = sse_decode_u_64(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_quic_rpc_call_options(self, serializer);
   }
 
   @protected
-  (QuicConnection, BigInt)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_usize(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_quic_rpc_client_config(
+    QuicRpcClientConfig self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-          deserializer,
-        );
-    var var_field1 = sse_decode_usize(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_quic_rpc_client_config(self, serializer);
   }
 
   @protected
-  (QuicEndpoint, QuicConnection)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_quic_stream_chunk(
+    QuicStreamChunk self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
-          deserializer,
-        );
-    var var_field1 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-          deserializer,
-        );
-    return (var_field0, var_field1);
+    sse_encode_quic_stream_chunk(self, serializer);
   }
 
   @protected
-  (QuicRecvStream, Uint8List)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_list_prim_u_8_strict(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_simple_quic_server_config(
+    SimpleQuicServerConfig self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
-          deserializer,
-        );
-    var var_field1 = sse_decode_list_prim_u_8_strict(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_simple_quic_server_config(self, serializer);
   }
 
   @protected
-  (QuicRecvStream, Uint8List?)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_opt_list_prim_u_8_strict(
-    SseDeserializer deserializer,
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self, serializer);
+  }
+
+  @protected
+  void sse_encode_certificate_chain(
+    CertificateChain self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
-          deserializer,
-        );
-    var var_field1 = sse_decode_opt_list_prim_u_8_strict(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_list_list_prim_u_8_strict(self.certificates, serializer);
   }
 
   @protected
-  (QuicSendStream, BigInt)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_usize(
-    SseDeserializer deserializer,
+  void sse_encode_certificate_key_algorithm(
+    CertificateKeyAlgorithm self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
-          deserializer,
-        );
-    var var_field1 = sse_decode_usize(deserializer);
-    return (var_field0, var_field1);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  SocketAddress sse_decode_socket_address(SseDeserializer deserializer) {
+  void sse_encode_http_3_client_config(
+    Http3ClientConfig self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_ip = sse_decode_String(deserializer);
-    var var_port = sse_decode_u_16(deserializer);
-    return SocketAddress(ip: var_ip, port: var_port);
+    sse_encode_u_64(self.connectTimeoutMs, serializer);
+    sse_encode_u_64(self.requestTimeoutMs, serializer);
+    sse_encode_usize(self.maxResponseSize, serializer);
+    sse_encode_u_64(self.maxFieldSectionSize, serializer);
   }
 
   @protected
-  int sse_decode_u_16(SseDeserializer deserializer) {
+  void sse_encode_http_3_header(Http3Header self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint16();
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.value, serializer);
   }
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
+  void sse_encode_http_3_request(Http3Request self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
+    sse_encode_String(self.method, serializer);
+    sse_encode_String(self.url, serializer);
+    sse_encode_list_http_3_header(self.headers, serializer);
+    sse_encode_list_prim_u_8_strict(self.body, serializer);
+    sse_encode_list_http_3_header(self.trailers, serializer);
   }
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+  void sse_encode_http_3_response(
+    Http3Response self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    sse_encode_u_16(self.status, serializer);
+    sse_encode_list_http_3_header(self.headers, serializer);
+    sse_encode_list_prim_u_8_strict(self.body, serializer);
+    sse_encode_list_http_3_header(self.trailers, serializer);
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_http_3_server_request(
+    Http3ServerRequest self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+    sse_encode_String(self.method, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_opt_String(self.query, serializer);
+    sse_encode_list_http_3_header(self.headers, serializer);
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_i_32(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
   }
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer) {
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
+    serializer.buffer.putPlatformInt64(self);
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_isize(PlatformInt64 self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+    serializer.buffer.putPlatformInt64(self);
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
-    QuicClient self,
+  void sse_encode_list_http_3_header(
+    List<Http3Header> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as QuicClientImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_http_3_header(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-    QuicConnection self,
+  void sse_encode_list_list_prim_u_8_strict(
+    List<Uint8List> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as QuicConnectionImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_list_prim_u_8_strict(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
-    QuicEndpoint self,
+  void sse_encode_list_prim_u_8_loose(
+    List<int> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as QuicEndpointImpl).frbInternalSseEncode(move: true),
-      serializer,
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(
+      self is Uint8List ? self : Uint8List.fromList(self),
     );
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
-    QuicEndpointConfig self,
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as QuicEndpointConfigImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
-    QuicRecvStream self,
+  void sse_encode_list_quic_authority_pool_stats(
+    List<QuicAuthorityPoolStats> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as QuicRecvStreamImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_quic_authority_pool_stats(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
-    QuicSendStream self,
+  void sse_encode_list_quic_log_field(
+    List<QuicLogField> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as QuicSendStreamImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_quic_log_field(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
-    QuicServerConfig self,
+  void sse_encode_list_quic_rpc_metadata(
+    List<QuicRpcMetadata> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as QuicServerConfigImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_quic_rpc_metadata(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
-    QuicTransportConfig self,
+  void sse_encode_list_quic_stream_chunk(
+    List<QuicStreamChunk> self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as QuicTransportConfigImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_quic_stream_chunk(item, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
-    QuicClient self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as QuicClientImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
   }
 
   @protected
   void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-    QuicConnection self,
+  sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    QuicConnection? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as QuicConnectionImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+        self,
+        serializer,
+      );
+    }
   }
 
   @protected
   void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
-    QuicEndpoint self,
+  sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+    QuicRecvStream? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as QuicEndpointImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+        self,
+        serializer,
+      );
+    }
   }
 
   @protected
   void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
-    QuicEndpointConfig self,
+  sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+    QuicSendStream? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as QuicEndpointConfigImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+        self,
+        serializer,
+      );
+    }
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
-    QuicRecvStream self,
+  void sse_encode_opt_box_autoadd_quic_connection_end(
+    QuicConnectionEnd? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as QuicRecvStreamImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_quic_connection_end(self, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
-    QuicSendStream self,
+  void sse_encode_opt_box_autoadd_quic_pub_sub_message(
+    QuicPubSubMessage? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as QuicSendStreamImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_quic_pub_sub_message(self, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
-    QuicServerConfig self,
+  void sse_encode_opt_box_autoadd_quic_stream_chunk(
+    QuicStreamChunk? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as QuicServerConfigImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_quic_stream_chunk(self, serializer);
+    }
   }
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
-    QuicTransportConfig self,
-    SseSerializer serializer,
-  ) {
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as QuicTransportConfigImpl).frbInternalSseEncode(move: null),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_opt_box_autoadd_usize(
+    BigInt? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_usize(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_quic_client_config(
-    QuicClientConfig self,
+  void sse_encode_opt_list_prim_u_8_strict(
+    Uint8List? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_quic_client_config(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_prim_u_8_strict(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_quic_read_exception(
-    QuicReadException self,
+  void sse_encode_opt_list_quic_stream_chunk(
+    List<QuicStreamChunk>? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_quic_read_exception(self, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_quic_stream_chunk(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer) {
+  void sse_encode_private_key(PrivateKey self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(self, serializer);
+    sse_encode_list_prim_u_8_strict(self.keyData, serializer);
+    sse_encode_private_key_format(self.format, serializer);
   }
 
   @protected
-  void sse_encode_list_list_prim_u_8_strict(
-    List<Uint8List> self,
+  void sse_encode_private_key_format(
+    PrivateKeyFormat self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_list_prim_u_8_strict(item, serializer);
-    }
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_loose(
-    List<int> self,
+  void sse_encode_quic_authority_pool_stats(
+    QuicAuthorityPoolStats self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(
-      self is Uint8List ? self : Uint8List.fromList(self),
-    );
+    sse_encode_String(self.authority, serializer);
+    sse_encode_u_64(self.connections, serializer);
+    sse_encode_u_64(self.activeStreams, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_quic_chunks_written(
+    QuicChunksWritten self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    sse_encode_usize(self.bytes, serializer);
+    sse_encode_usize(self.chunks, serializer);
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_quic_client_config(
+    QuicClientConfig self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
+    sse_encode_usize(self.maxConnectionsPerHost, serializer);
+    sse_encode_u_64(self.connectTimeoutMs, serializer);
+    sse_encode_u_64(self.requestTimeoutMs, serializer);
+    sse_encode_u_32(self.retryAttempts, serializer);
+    sse_encode_u_64(self.retryDelayMs, serializer);
+    sse_encode_u_64(self.keepAliveTimeoutMs, serializer);
+    sse_encode_usize(self.maxResponseSize, serializer);
   }
 
   @protected
-  void sse_encode_opt_box_autoadd_usize(
-    BigInt? self,
+  void sse_encode_quic_connection_end(
+    QuicConnectionEnd self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_quic_connection_end_kind(self.kind, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.errorCode, serializer);
+    sse_encode_String(self.reason, serializer);
+  }
 
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_usize(self, serializer);
-    }
+  @protected
+  void sse_encode_quic_connection_end_kind(
+    QuicConnectionEndKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_opt_list_prim_u_8_strict(
-    Uint8List? self,
+  void sse_encode_quic_connection_pool_config(
+    QuicConnectionPoolConfig self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_list_prim_u_8_strict(self, serializer);
-    }
+    sse_encode_usize(self.maxConnectionsPerAuthority, serializer);
+    sse_encode_u_32(self.maxStreamsPerConnection, serializer);
+    sse_encode_u_64(self.idleTimeoutMs, serializer);
+    sse_encode_u_64(self.evictionIntervalMs, serializer);
   }
 
   @protected
-  void sse_encode_quic_client_config(
-    QuicClientConfig self,
+  void sse_encode_quic_connection_pool_stats(
+    QuicConnectionPoolStats self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(self.maxConnectionsPerHost, serializer);
-    sse_encode_u_64(self.connectTimeoutMs, serializer);
-    sse_encode_u_64(self.requestTimeoutMs, serializer);
-    sse_encode_u_32(self.retryAttempts, serializer);
-    sse_encode_u_64(self.retryDelayMs, serializer);
-    sse_encode_u_64(self.keepAliveTimeoutMs, serializer);
+    sse_encode_list_quic_authority_pool_stats(self.authorities, serializer);
+    sse_encode_u_64(self.hits, serializer);
+    sse_encode_u_64(self.misses, serializer);
+    sse_encode_u_64(self.evictedIdle, serializer);
+    sse_encode_u_64(self.evictedDead, serializer);
   }
 
   @protected
@@ -3763,6 +16490,39 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_quic_udp_stats(self.udpRx, serializer);
   }
 
+  @protected
+  void sse_encode_quic_datagram_batch(
+    QuicDatagramBatch self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_list_prim_u_8_strict(self.datagrams, serializer);
+    sse_encode_u_64(self.dropped, serializer);
+    sse_encode_opt_box_autoadd_quic_connection_end(self.end, serializer);
+  }
+
+  @protected
+  void sse_encode_quic_datagram_channel_config(
+    QuicDatagramChannelConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.fragmentation, serializer);
+    sse_encode_usize(self.maxMessageSize, serializer);
+    sse_encode_u_64(self.reassemblyTimeoutMs, serializer);
+    sse_encode_usize(self.channelCapacity, serializer);
+    sse_encode_quic_datagram_drop_policy(self.dropPolicy, serializer);
+  }
+
+  @protected
+  void sse_encode_quic_datagram_drop_policy(
+    QuicDatagramDropPolicy self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
   @protected
   void sse_encode_quic_datagram_exception(
     QuicDatagramException self,
@@ -3781,6 +16541,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_quic_datagram_subscription_config(
+    QuicDatagramSubscriptionConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.capacity, serializer);
+    sse_encode_quic_datagram_drop_policy(self.dropPolicy, serializer);
+  }
+
   @protected
   void sse_encode_quic_error(QuicError self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3806,9 +16576,71 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
       case QuicError_Write(field0: final field0):
         sse_encode_i_32(6, serializer);
         sse_encode_String(field0, serializer);
+      case QuicError_ResponseTooLong(limit: final limit):
+        sse_encode_i_32(7, serializer);
+        sse_encode_usize(limit, serializer);
     }
   }
 
+  @protected
+  void sse_encode_quic_file_transfer_config(
+    QuicFileTransferConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.chunkSize, serializer);
+    sse_encode_usize(self.parallelStreams, serializer);
+  }
+
+  @protected
+  void sse_encode_quic_file_transfer_error_kind(
+    QuicFileTransferErrorKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_quic_file_transfer_exception(
+    QuicFileTransferException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_quic_file_transfer_error_kind(self.kind, serializer);
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_quic_file_transfer_progress(
+    QuicFileTransferProgress self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.bytesTransferred, serializer);
+    sse_encode_u_64(self.totalBytes, serializer);
+    sse_encode_u_64(self.resumedFrom, serializer);
+  }
+
+  @protected
+  void sse_encode_quic_frame_error_kind(
+    QuicFrameErrorKind self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_quic_frame_exception(
+    QuicFrameException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_quic_frame_error_kind(self.kind, serializer);
+    sse_encode_String(self.message, serializer);
+  }
+
   @protected
   void sse_encode_quic_frame_stats(
     QuicFrameStats self,
@@ -3839,6 +16671,33 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_64(self.stopSending, serializer);
   }
 
+  @protected
+  void sse_encode_quic_log_field(QuicLogField self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.value, serializer);
+  }
+
+  @protected
+  void sse_encode_quic_log_level(QuicLogLevel self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_quic_log_record(
+    QuicLogRecord self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_quic_log_level(self.level, serializer);
+    sse_encode_String(self.target, serializer);
+    sse_encode_String(self.message, serializer);
+    sse_encode_list_quic_log_field(self.fields, serializer);
+    sse_encode_opt_String(self.connectionId, serializer);
+    sse_encode_i_64(self.timestampMs, serializer);
+  }
+
   @protected
   void sse_encode_quic_path_stats(
     QuicPathStats self,
@@ -3853,6 +16712,47 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_u_64(self.congestionEvents, serializer);
   }
 
+  @protected
+  void sse_encode_quic_pub_sub_broker_config(
+    QuicPubSubBrokerConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.historySize, serializer);
+    sse_encode_usize(self.maxMessageSize, serializer);
+  }
+
+  @protected
+  void sse_encode_quic_pub_sub_delivery(
+    QuicPubSubDelivery self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_quic_pub_sub_message(
+    QuicPubSubMessage self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.sequence, serializer);
+    sse_encode_list_prim_u_8_strict(self.payload, serializer);
+  }
+
+  @protected
+  void sse_encode_quic_pub_sub_subscribe_options(
+    QuicPubSubSubscribeOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_quic_pub_sub_delivery(self.delivery, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.resumeAfter, serializer);
+    sse_encode_usize(self.bufferCapacity, serializer);
+    sse_encode_usize(self.maxMessageSize, serializer);
+  }
+
   @protected
   void sse_encode_quic_read_exception(
     QuicReadException self,
@@ -3890,6 +16790,97 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     }
   }
 
+  @protected
+  void sse_encode_quic_rpc_call_options(
+    QuicRpcCallOptions self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_box_autoadd_u_64(self.deadlineMs, serializer);
+    sse_encode_list_quic_rpc_metadata(self.metadata, serializer);
+  }
+
+  @protected
+  void sse_encode_quic_rpc_client_config(
+    QuicRpcClientConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_usize(self.maxMessageSize, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.defaultDeadlineMs, serializer);
+  }
+
+  @protected
+  void sse_encode_quic_rpc_code(QuicRpcCode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_quic_rpc_metadata(
+    QuicRpcMetadata self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.key, serializer);
+    sse_encode_String(self.value, serializer);
+  }
+
+  @protected
+  void sse_encode_quic_rpc_request(
+    QuicRpcRequest self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.method, serializer);
+    sse_encode_list_quic_rpc_metadata(self.metadata, serializer);
+    sse_encode_list_prim_u_8_strict(self.payload, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.deadlineMs, serializer);
+  }
+
+  @protected
+  void sse_encode_quic_rpc_status(
+    QuicRpcStatus self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_quic_rpc_code(self.code, serializer);
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_quic_send_stream_stats(
+    QuicSendStreamStats self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.bytesQueued, serializer);
+    sse_encode_bool(self.acknowledged, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.stopErrorCode, serializer);
+  }
+
+  @protected
+  void sse_encode_quic_stream_chunk(
+    QuicStreamChunk self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.offset, serializer);
+    sse_encode_list_prim_u_8_strict(self.data, serializer);
+  }
+
+  @protected
+  void sse_encode_quic_transfer_progress(
+    QuicTransferProgress self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.bytesSent, serializer);
+    sse_encode_u_64(self.bytesReceived, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.totalToSend, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.expectedTotalReceived, serializer);
+  }
+
   @protected
   void sse_encode_quic_udp_stats(QuicUdpStats self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -3916,30 +16907,157 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
 
   @protected
   void
-  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_quic_client_config(
-    (QuicClient, QuicClientConfig) self,
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_http_3_client_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_http_3_response_stream(
+    (Http3Client, Http3ResponseStream) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client(
+      self.$1,
+      serializer,
+    );
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+      self.$2,
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_http_3_client_http_3_response(
+    (Http3Client, Http3Response) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client(
+      self.$1,
+      serializer,
+    );
+    sse_encode_http_3_response(self.$2, serializer);
+  }
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_certificate_resolver_bool(
+    (QuicCertificateResolver, bool) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver(
+      self.$1,
+      serializer,
+    );
+    sse_encode_bool(self.$2, serializer);
+  }
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_streaming_transfer(
+    (QuicClient, QuicStreamingTransfer) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+      self.$1,
+      serializer,
+    );
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
+      self.$2,
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_list_prim_u_8_strict(
+    (QuicClient, Uint8List) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+      self.$1,
+      serializer,
+    );
+    sse_encode_list_prim_u_8_strict(self.$2, serializer);
+  }
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_quic_client_config(
+    (QuicClient, QuicClientConfig) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+      self.$1,
+      serializer,
+    );
+    sse_encode_quic_client_config(self.$2, serializer);
+  }
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_string(
+    (QuicClient, String) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+      self.$1,
+      serializer,
+    );
+    sse_encode_String(self.$2, serializer);
+  }
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_datagram_mux(
+    (QuicConnection, QuicDatagramMux) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+      self.$1,
+      serializer,
+    );
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
+      self.$2,
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_pub_sub_client(
+    (QuicConnection, QuicPubSubClient) self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
       self.$1,
       serializer,
     );
-    sse_encode_quic_client_config(self.$2, serializer);
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
+      self.$2,
+      serializer,
+    );
   }
 
   @protected
   void
-  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_string(
-    (QuicClient, String) self,
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream(
+    (QuicConnection, QuicRecvStream) self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
       self.$1,
       serializer,
     );
-    sse_encode_String(self.$2, serializer);
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+      self.$2,
+      serializer,
+    );
   }
 
   @protected
@@ -4022,6 +17140,90 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_String(self.$2, serializer);
   }
 
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
+    (QuicConnectionPool, QuicConnection) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+      self.$1,
+      serializer,
+    );
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+      self.$2,
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_bool(
+    (QuicConnectionPool, QuicConnection, bool) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+      self.$1,
+      serializer,
+    );
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+      self.$2,
+      serializer,
+    );
+    sse_encode_bool(self.$3, serializer);
+  }
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
+    (QuicConnectionPool, QuicEndpoint, QuicConnection) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+      self.$1,
+      serializer,
+    );
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+      self.$2,
+      serializer,
+    );
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+      self.$3,
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_quic_connection_pool_stats(
+    (QuicConnectionPool, QuicConnectionPoolStats) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+      self.$1,
+      serializer,
+    );
+    sse_encode_quic_connection_pool_stats(self.$2, serializer);
+  }
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_usize(
+    (QuicConnectionPool, BigInt) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+      self.$1,
+      serializer,
+    );
+    sse_encode_usize(self.$2, serializer);
+  }
+
   @protected
   void
   sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_quic_connection_stats(
@@ -4095,6 +17297,40 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     );
   }
 
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_web_transport_session(
+    (QuicEndpoint, WebTransportSession) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+      self.$1,
+      serializer,
+    );
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+      self.$2,
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_opt_box_autoadd_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
+    (QuicEndpoint, QuicConnection?) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+      self.$1,
+      serializer,
+    );
+    sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+      self.$2,
+      serializer,
+    );
+  }
+
   @protected
   void
   sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_list_prim_u_8_strict(
@@ -4109,6 +17345,20 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_list_prim_u_8_strict(self.$2, serializer);
   }
 
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_opt_box_autoadd_quic_stream_chunk(
+    (QuicRecvStream, QuicStreamChunk?) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+      self.$1,
+      serializer,
+    );
+    sse_encode_opt_box_autoadd_quic_stream_chunk(self.$2, serializer);
+  }
+
   @protected
   void
   sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_opt_list_prim_u_8_strict(
@@ -4123,6 +17373,65 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_opt_list_prim_u_8_strict(self.$2, serializer);
   }
 
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_opt_list_quic_stream_chunk(
+    (QuicRecvStream, List<QuicStreamChunk>?) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+      self.$1,
+      serializer,
+    );
+    sse_encode_opt_list_quic_stream_chunk(self.$2, serializer);
+  }
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream(
+    (QuicSendStream, QuicRecvStream) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+      self.$1,
+      serializer,
+    );
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+      self.$2,
+      serializer,
+    );
+  }
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_quic_chunks_written(
+    (QuicSendStream, QuicChunksWritten) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+      self.$1,
+      serializer,
+    );
+    sse_encode_quic_chunks_written(self.$2, serializer);
+  }
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_quic_send_stream_stats(
+    (QuicSendStream, QuicSendStreamStats) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+      self.$1,
+      serializer,
+    );
+    sse_encode_quic_send_stream_stats(self.$2, serializer);
+  }
+
   @protected
   void
   sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_usize(
@@ -4137,6 +17446,125 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     sse_encode_usize(self.$2, serializer);
   }
 
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_server_config_bool(
+    (QuicServerConfig, bool) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+      self.$1,
+      serializer,
+    );
+    sse_encode_bool(self.$2, serializer);
+  }
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_self_signed_certificate(
+    (SimpleQuicServer, SelfSignedCertificate) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+      self.$1,
+      serializer,
+    );
+    sse_encode_self_signed_certificate(self.$2, serializer);
+  }
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_simple_quic_server_stats(
+    (SimpleQuicServer, SimpleQuicServerStats) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+      self.$1,
+      serializer,
+    );
+    sse_encode_simple_quic_server_stats(self.$2, serializer);
+  }
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_string(
+    (SimpleQuicServer, String) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+      self.$1,
+      serializer,
+    );
+    sse_encode_String(self.$2, serializer);
+  }
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_u_64(
+    (SimpleQuicServer, BigInt) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+      self.$1,
+      serializer,
+    );
+    sse_encode_u_64(self.$2, serializer);
+  }
+
+  @protected
+  void sse_encode_record_certificate_chain_private_key(
+    (CertificateChain, PrivateKey) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_certificate_chain(self.$1, serializer);
+    sse_encode_private_key(self.$2, serializer);
+  }
+
+  @protected
+  void sse_encode_self_signed_certificate(
+    SelfSignedCertificate self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_certificate_chain(self.certificateChain, serializer);
+    sse_encode_private_key(self.privateKey, serializer);
+    sse_encode_String(self.sha256Fingerprint, serializer);
+  }
+
+  @protected
+  void sse_encode_simple_quic_server_config(
+    SimpleQuicServerConfig self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.maxConcurrentStreamsPerConnection, serializer);
+    sse_encode_u_32(self.maxConcurrentRequests, serializer);
+    sse_encode_usize(self.maxRequestSize, serializer);
+    sse_encode_u_64(self.requestTimeoutMs, serializer);
+    sse_encode_u_64(self.shutdownTimeoutMs, serializer);
+  }
+
+  @protected
+  void sse_encode_simple_quic_server_stats(
+    SimpleQuicServerStats self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.connectionsAccepted, serializer);
+    sse_encode_u_64(self.activeConnections, serializer);
+    sse_encode_u_64(self.requestsHandled, serializer);
+    sse_encode_u_64(self.requestsFailed, serializer);
+    sse_encode_u_64(self.activeRequests, serializer);
+    sse_encode_u_64(self.bytesReceived, serializer);
+    sse_encode_u_64(self.bytesSent, serializer);
+  }
+
   @protected
   void sse_encode_socket_address(SocketAddress self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
@@ -4173,106 +17601,495 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
     // Codec=Sse (Serialization based), see doc to use other codecs
   }
 
-  @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
+  @protected
+  void sse_encode_usize(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
+  }
+
+  @protected
+  void sse_encode_web_transport_close_info(
+    WebTransportCloseInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.code, serializer);
+    sse_encode_String(self.reason, serializer);
+  }
+}
+
+@sealed
+class Http3ClientImpl extends RustOpaque implements Http3Client {
+  // Not to be used by end users
+  Http3ClientImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  Http3ClientImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_Http3Client,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_Http3Client,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_Http3ClientPtr,
+  );
+}
+
+@sealed
+class Http3ResponseStreamImpl extends RustOpaque
+    implements Http3ResponseStream {
+  // Not to be used by end users
+  Http3ResponseStreamImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  Http3ResponseStreamImpl.frbInternalSseDecode(
+    BigInt ptr,
+    int externalSizeOnNative,
+  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount: RustLib
+        .instance
+        .api
+        .rust_arc_increment_strong_count_Http3ResponseStream,
+    rustArcDecrementStrongCount: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_Http3ResponseStream,
+    rustArcDecrementStrongCountPtr: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_Http3ResponseStreamPtr,
+  );
+}
+
+@sealed
+class Http3RouterImpl extends RustOpaque implements Http3Router {
+  // Not to be used by end users
+  Http3RouterImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  Http3RouterImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_Http3Router,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_Http3Router,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_Http3RouterPtr,
+  );
+}
+
+@sealed
+class Http3ServerStreamImpl extends RustOpaque implements Http3ServerStream {
+  // Not to be used by end users
+  Http3ServerStreamImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  Http3ServerStreamImpl.frbInternalSseDecode(
+    BigInt ptr,
+    int externalSizeOnNative,
+  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_Http3ServerStream,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_Http3ServerStream,
+    rustArcDecrementStrongCountPtr: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_Http3ServerStreamPtr,
+  );
+}
+
+@sealed
+class QuicCertificateResolverImpl extends RustOpaque
+    implements QuicCertificateResolver {
+  // Not to be used by end users
+  QuicCertificateResolverImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  QuicCertificateResolverImpl.frbInternalSseDecode(
+    BigInt ptr,
+    int externalSizeOnNative,
+  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount: RustLib
+        .instance
+        .api
+        .rust_arc_increment_strong_count_QuicCertificateResolver,
+    rustArcDecrementStrongCount: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_QuicCertificateResolver,
+    rustArcDecrementStrongCountPtr: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_QuicCertificateResolverPtr,
+  );
+}
+
+@sealed
+class QuicClientImpl extends RustOpaque implements QuicClient {
+  // Not to be used by end users
+  QuicClientImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  QuicClientImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_QuicClient,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_QuicClient,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_QuicClientPtr,
+  );
+}
+
+@sealed
+class QuicConnectionImpl extends RustOpaque implements QuicConnection {
+  // Not to be used by end users
+  QuicConnectionImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  QuicConnectionImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_QuicConnection,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_QuicConnection,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_QuicConnectionPtr,
+  );
+}
+
+@sealed
+class QuicConnectionPoolImpl extends RustOpaque implements QuicConnectionPool {
+  // Not to be used by end users
+  QuicConnectionPoolImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  QuicConnectionPoolImpl.frbInternalSseDecode(
+    BigInt ptr,
+    int externalSizeOnNative,
+  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_QuicConnectionPool,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_QuicConnectionPool,
+    rustArcDecrementStrongCountPtr: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_QuicConnectionPoolPtr,
+  );
+}
+
+@sealed
+class QuicDatagramChannelImpl extends RustOpaque
+    implements QuicDatagramChannel {
+  // Not to be used by end users
+  QuicDatagramChannelImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  QuicDatagramChannelImpl.frbInternalSseDecode(
+    BigInt ptr,
+    int externalSizeOnNative,
+  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount: RustLib
+        .instance
+        .api
+        .rust_arc_increment_strong_count_QuicDatagramChannel,
+    rustArcDecrementStrongCount: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_QuicDatagramChannel,
+    rustArcDecrementStrongCountPtr: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_QuicDatagramChannelPtr,
+  );
+}
+
+@sealed
+class QuicDatagramMuxImpl extends RustOpaque implements QuicDatagramMux {
+  // Not to be used by end users
+  QuicDatagramMuxImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  QuicDatagramMuxImpl.frbInternalSseDecode(
+    BigInt ptr,
+    int externalSizeOnNative,
+  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_QuicDatagramMux,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_QuicDatagramMux,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_QuicDatagramMuxPtr,
+  );
+}
+
+@sealed
+class QuicDatagramSubscriptionImpl extends RustOpaque
+    implements QuicDatagramSubscription {
+  // Not to be used by end users
+  QuicDatagramSubscriptionImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  QuicDatagramSubscriptionImpl.frbInternalSseDecode(
+    BigInt ptr,
+    int externalSizeOnNative,
+  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount: RustLib
+        .instance
+        .api
+        .rust_arc_increment_strong_count_QuicDatagramSubscription,
+    rustArcDecrementStrongCount: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_QuicDatagramSubscription,
+    rustArcDecrementStrongCountPtr: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_QuicDatagramSubscriptionPtr,
+  );
+}
+
+@sealed
+class QuicEndpointConfigImpl extends RustOpaque implements QuicEndpointConfig {
+  // Not to be used by end users
+  QuicEndpointConfigImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  QuicEndpointConfigImpl.frbInternalSseDecode(
+    BigInt ptr,
+    int externalSizeOnNative,
+  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_QuicEndpointConfig,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_QuicEndpointConfig,
+    rustArcDecrementStrongCountPtr: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_QuicEndpointConfigPtr,
+  );
+}
+
+@sealed
+class QuicEndpointImpl extends RustOpaque implements QuicEndpoint {
+  // Not to be used by end users
+  QuicEndpointImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  QuicEndpointImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_QuicEndpoint,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_QuicEndpoint,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_QuicEndpointPtr,
+  );
+}
+
+@sealed
+class QuicFileReceiverImpl extends RustOpaque implements QuicFileReceiver {
+  // Not to be used by end users
+  QuicFileReceiverImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
 
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
+  // Not to be used by end users
+  QuicFileReceiverImpl.frbInternalSseDecode(
+    BigInt ptr,
+    int externalSizeOnNative,
+  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
 
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_QuicFileReceiver,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_QuicFileReceiver,
+    rustArcDecrementStrongCountPtr: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_QuicFileReceiverPtr,
+  );
 }
 
 @sealed
-class QuicClientImpl extends RustOpaque implements QuicClient {
+class QuicFileUploadImpl extends RustOpaque implements QuicFileUpload {
   // Not to be used by end users
-  QuicClientImpl.frbInternalDcoDecode(List<dynamic> wire)
+  QuicFileUploadImpl.frbInternalDcoDecode(List<dynamic> wire)
     : super.frbInternalDcoDecode(wire, _kStaticData);
 
   // Not to be used by end users
-  QuicClientImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+  QuicFileUploadImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
     : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
 
   static final _kStaticData = RustArcStaticData(
     rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_QuicClient,
+        RustLib.instance.api.rust_arc_increment_strong_count_QuicFileUpload,
     rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_QuicClient,
+        RustLib.instance.api.rust_arc_decrement_strong_count_QuicFileUpload,
     rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_QuicClientPtr,
+        RustLib.instance.api.rust_arc_decrement_strong_count_QuicFileUploadPtr,
   );
 }
 
 @sealed
-class QuicConnectionImpl extends RustOpaque implements QuicConnection {
+class QuicFramedStreamImpl extends RustOpaque implements QuicFramedStream {
   // Not to be used by end users
-  QuicConnectionImpl.frbInternalDcoDecode(List<dynamic> wire)
+  QuicFramedStreamImpl.frbInternalDcoDecode(List<dynamic> wire)
     : super.frbInternalDcoDecode(wire, _kStaticData);
 
   // Not to be used by end users
-  QuicConnectionImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+  QuicFramedStreamImpl.frbInternalSseDecode(
+    BigInt ptr,
+    int externalSizeOnNative,
+  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_QuicFramedStream,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_QuicFramedStream,
+    rustArcDecrementStrongCountPtr: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_QuicFramedStreamPtr,
+  );
+}
+
+@sealed
+class QuicKeyLogImpl extends RustOpaque implements QuicKeyLog {
+  // Not to be used by end users
+  QuicKeyLogImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  QuicKeyLogImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
     : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
 
   static final _kStaticData = RustArcStaticData(
     rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_QuicConnection,
+        RustLib.instance.api.rust_arc_increment_strong_count_QuicKeyLog,
     rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_QuicConnection,
+        RustLib.instance.api.rust_arc_decrement_strong_count_QuicKeyLog,
     rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_QuicConnectionPtr,
+        RustLib.instance.api.rust_arc_decrement_strong_count_QuicKeyLogPtr,
   );
 }
 
 @sealed
-class QuicEndpointConfigImpl extends RustOpaque implements QuicEndpointConfig {
+class QuicPubSubBrokerImpl extends RustOpaque implements QuicPubSubBroker {
   // Not to be used by end users
-  QuicEndpointConfigImpl.frbInternalDcoDecode(List<dynamic> wire)
+  QuicPubSubBrokerImpl.frbInternalDcoDecode(List<dynamic> wire)
     : super.frbInternalDcoDecode(wire, _kStaticData);
 
   // Not to be used by end users
-  QuicEndpointConfigImpl.frbInternalSseDecode(
+  QuicPubSubBrokerImpl.frbInternalSseDecode(
     BigInt ptr,
     int externalSizeOnNative,
   ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
 
   static final _kStaticData = RustArcStaticData(
     rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_QuicEndpointConfig,
+        RustLib.instance.api.rust_arc_increment_strong_count_QuicPubSubBroker,
     rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_QuicEndpointConfig,
+        RustLib.instance.api.rust_arc_decrement_strong_count_QuicPubSubBroker,
     rustArcDecrementStrongCountPtr: RustLib
         .instance
         .api
-        .rust_arc_decrement_strong_count_QuicEndpointConfigPtr,
+        .rust_arc_decrement_strong_count_QuicPubSubBrokerPtr,
   );
 }
 
 @sealed
-class QuicEndpointImpl extends RustOpaque implements QuicEndpoint {
+class QuicPubSubClientImpl extends RustOpaque implements QuicPubSubClient {
   // Not to be used by end users
-  QuicEndpointImpl.frbInternalDcoDecode(List<dynamic> wire)
+  QuicPubSubClientImpl.frbInternalDcoDecode(List<dynamic> wire)
     : super.frbInternalDcoDecode(wire, _kStaticData);
 
   // Not to be used by end users
-  QuicEndpointImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
-    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+  QuicPubSubClientImpl.frbInternalSseDecode(
+    BigInt ptr,
+    int externalSizeOnNative,
+  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
 
   static final _kStaticData = RustArcStaticData(
     rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_QuicEndpoint,
+        RustLib.instance.api.rust_arc_increment_strong_count_QuicPubSubClient,
     rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_QuicEndpoint,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_QuicEndpointPtr,
+        RustLib.instance.api.rust_arc_decrement_strong_count_QuicPubSubClient,
+    rustArcDecrementStrongCountPtr: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_QuicPubSubClientPtr,
+  );
+}
+
+@sealed
+class QuicPubSubSubscriptionImpl extends RustOpaque
+    implements QuicPubSubSubscription {
+  // Not to be used by end users
+  QuicPubSubSubscriptionImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  QuicPubSubSubscriptionImpl.frbInternalSseDecode(
+    BigInt ptr,
+    int externalSizeOnNative,
+  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount: RustLib
+        .instance
+        .api
+        .rust_arc_increment_strong_count_QuicPubSubSubscription,
+    rustArcDecrementStrongCount: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_QuicPubSubSubscription,
+    rustArcDecrementStrongCountPtr: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_QuicPubSubSubscriptionPtr,
   );
 }
 
@@ -4296,6 +18113,92 @@ class QuicRecvStreamImpl extends RustOpaque implements QuicRecvStream {
   );
 }
 
+@sealed
+class QuicRpcCallImpl extends RustOpaque implements QuicRpcCall {
+  // Not to be used by end users
+  QuicRpcCallImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  QuicRpcCallImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_QuicRpcCall,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_QuicRpcCall,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_QuicRpcCallPtr,
+  );
+}
+
+@sealed
+class QuicRpcClientImpl extends RustOpaque implements QuicRpcClient {
+  // Not to be used by end users
+  QuicRpcClientImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  QuicRpcClientImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative)
+    : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_QuicRpcClient,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_QuicRpcClient,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_QuicRpcClientPtr,
+  );
+}
+
+@sealed
+class QuicRpcRegistryImpl extends RustOpaque implements QuicRpcRegistry {
+  // Not to be used by end users
+  QuicRpcRegistryImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  QuicRpcRegistryImpl.frbInternalSseDecode(
+    BigInt ptr,
+    int externalSizeOnNative,
+  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_QuicRpcRegistry,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_QuicRpcRegistry,
+    rustArcDecrementStrongCountPtr:
+        RustLib.instance.api.rust_arc_decrement_strong_count_QuicRpcRegistryPtr,
+  );
+}
+
+@sealed
+class QuicRpcResponderImpl extends RustOpaque implements QuicRpcResponder {
+  // Not to be used by end users
+  QuicRpcResponderImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  QuicRpcResponderImpl.frbInternalSseDecode(
+    BigInt ptr,
+    int externalSizeOnNative,
+  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_QuicRpcResponder,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_QuicRpcResponder,
+    rustArcDecrementStrongCountPtr: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_QuicRpcResponderPtr,
+  );
+}
+
 @sealed
 class QuicSendStreamImpl extends RustOpaque implements QuicSendStream {
   // Not to be used by end users
@@ -4340,6 +18243,64 @@ class QuicServerConfigImpl extends RustOpaque implements QuicServerConfig {
   );
 }
 
+@sealed
+class QuicStreamReassemblerImpl extends RustOpaque
+    implements QuicStreamReassembler {
+  // Not to be used by end users
+  QuicStreamReassemblerImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  QuicStreamReassemblerImpl.frbInternalSseDecode(
+    BigInt ptr,
+    int externalSizeOnNative,
+  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount: RustLib
+        .instance
+        .api
+        .rust_arc_increment_strong_count_QuicStreamReassembler,
+    rustArcDecrementStrongCount: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_QuicStreamReassembler,
+    rustArcDecrementStrongCountPtr: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_QuicStreamReassemblerPtr,
+  );
+}
+
+@sealed
+class QuicStreamingTransferImpl extends RustOpaque
+    implements QuicStreamingTransfer {
+  // Not to be used by end users
+  QuicStreamingTransferImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  QuicStreamingTransferImpl.frbInternalSseDecode(
+    BigInt ptr,
+    int externalSizeOnNative,
+  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount: RustLib
+        .instance
+        .api
+        .rust_arc_increment_strong_count_QuicStreamingTransfer,
+    rustArcDecrementStrongCount: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_QuicStreamingTransfer,
+    rustArcDecrementStrongCountPtr: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_QuicStreamingTransferPtr,
+  );
+}
+
 @sealed
 class QuicTransportConfigImpl extends RustOpaque
     implements QuicTransportConfig {
@@ -4368,3 +18329,56 @@ class QuicTransportConfigImpl extends RustOpaque
         .rust_arc_decrement_strong_count_QuicTransportConfigPtr,
   );
 }
+
+@sealed
+class SimpleQuicServerImpl extends RustOpaque implements SimpleQuicServer {
+  // Not to be used by end users
+  SimpleQuicServerImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  SimpleQuicServerImpl.frbInternalSseDecode(
+    BigInt ptr,
+    int externalSizeOnNative,
+  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount:
+        RustLib.instance.api.rust_arc_increment_strong_count_SimpleQuicServer,
+    rustArcDecrementStrongCount:
+        RustLib.instance.api.rust_arc_decrement_strong_count_SimpleQuicServer,
+    rustArcDecrementStrongCountPtr: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_SimpleQuicServerPtr,
+  );
+}
+
+@sealed
+class WebTransportSessionImpl extends RustOpaque
+    implements WebTransportSession {
+  // Not to be used by end users
+  WebTransportSessionImpl.frbInternalDcoDecode(List<dynamic> wire)
+    : super.frbInternalDcoDecode(wire, _kStaticData);
+
+  // Not to be used by end users
+  WebTransportSessionImpl.frbInternalSseDecode(
+    BigInt ptr,
+    int externalSizeOnNative,
+  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+  static final _kStaticData = RustArcStaticData(
+    rustArcIncrementStrongCount: RustLib
+        .instance
+        .api
+        .rust_arc_increment_strong_count_WebTransportSession,
+    rustArcDecrementStrongCount: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_WebTransportSession,
+    rustArcDecrementStrongCountPtr: RustLib
+        .instance
+        .api
+        .rust_arc_decrement_strong_count_WebTransportSessionPtr,
+  );
+}
@@ -8,14 +8,31 @@
 
 import 'api/bridge.dart';
 import 'convenience/client.dart';
+import 'convenience/datagram_channel.dart';
+import 'convenience/file_transfer.dart';
+import 'convenience/framing.dart';
+import 'convenience/http3.dart';
+import 'convenience/http3_server.dart';
+import 'convenience/pool.dart';
+import 'convenience/pubsub.dart';
+import 'convenience/reassembly.dart';
+import 'convenience/rpc.dart';
+import 'convenience/server.dart';
+import 'convenience/streaming.dart';
+import 'convenience/webtransport.dart';
+import 'core/cert_resolver.dart';
 import 'core/config.dart';
 import 'core/connection.dart';
+import 'core/datagram.dart';
 import 'core/endpoint.dart';
+import 'core/keylog.dart';
 import 'core/stream.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'errors.dart';
 import 'frb_generated.dart';
+import 'logging.dart';
+import 'models/certificate.dart';
 import 'models/types.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
@@ -27,6 +44,26 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
     required super.portManager,
   });
 
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_Http3ClientPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_Http3ResponseStreamPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_Http3RouterPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Router;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_Http3ServerStreamPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicCertificateResolverPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver;
+
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_QuicClientPtr => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient;
@@ -35,6 +72,22 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   get rust_arc_decrement_strong_count_QuicConnectionPtr => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection;
 
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicConnectionPoolPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicDatagramChannelPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicDatagramMuxPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicDatagramSubscriptionPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription;
+
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_QuicEndpointPtr => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint;
@@ -43,10 +96,54 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   get rust_arc_decrement_strong_count_QuicEndpointConfigPtr => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig;
 
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicFileReceiverPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicFileUploadPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicFramedStreamPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicKeyLogPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicKeyLog;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicPubSubBrokerPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicPubSubClientPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicPubSubSubscriptionPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription;
+
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_QuicRecvStreamPtr => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream;
 
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicRpcCallPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicRpcClientPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicRpcRegistryPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcRegistry;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicRpcResponderPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder;
+
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_QuicSendStreamPtr => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream;
@@ -55,1021 +152,4401 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   get rust_arc_decrement_strong_count_QuicServerConfigPtr => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig;
 
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicStreamReassemblerPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_QuicStreamingTransferPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer;
+
   CrossPlatformFinalizerArg
   get rust_arc_decrement_strong_count_QuicTransportConfigPtr => wire
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig;
 
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_SimpleQuicServerPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer;
+
+  CrossPlatformFinalizerArg
+  get rust_arc_decrement_strong_count_WebTransportSessionPtr => wire
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession;
+
   @protected
-  QuicClient
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
-    dynamic raw,
-  );
+  AnyhowException dco_decode_AnyhowException(dynamic raw);
 
   @protected
-  QuicConnection
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+  Http3Client
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client(
     dynamic raw,
   );
 
   @protected
-  QuicEndpoint
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+  Http3ResponseStream
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
     dynamic raw,
   );
 
   @protected
-  QuicEndpointConfig
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
+  Http3Router
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Router(
     dynamic raw,
   );
 
   @protected
-  QuicRecvStream
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+  Http3ServerStream
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
     dynamic raw,
   );
 
   @protected
-  QuicSendStream
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+  QuicCertificateResolver
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver(
     dynamic raw,
   );
 
   @protected
-  QuicServerConfig
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+  QuicClient
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
     dynamic raw,
   );
 
   @protected
-  QuicTransportConfig
-  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
+  QuicConnection
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
     dynamic raw,
   );
 
   @protected
-  QuicClient
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+  QuicConnectionPool
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
     dynamic raw,
   );
 
   @protected
-  QuicConnection
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+  QuicDatagramChannel
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
     dynamic raw,
   );
 
   @protected
-  QuicEndpoint
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+  QuicDatagramMux
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
     dynamic raw,
   );
 
   @protected
-  QuicEndpointConfig
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
+  QuicDatagramSubscription
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription(
     dynamic raw,
   );
 
   @protected
-  QuicRecvStream
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+  QuicEndpoint
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
     dynamic raw,
   );
 
   @protected
-  QuicSendStream
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+  QuicEndpointConfig
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
     dynamic raw,
   );
 
   @protected
-  QuicServerConfig
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+  QuicFileReceiver
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver(
     dynamic raw,
   );
 
   @protected
-  QuicTransportConfig
-  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
+  QuicFileUpload
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload(
     dynamic raw,
   );
 
   @protected
-  String dco_decode_String(dynamic raw);
+  QuicFramedStream
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream(
+    dynamic raw,
+  );
 
   @protected
-  QuicClientConfig dco_decode_box_autoadd_quic_client_config(dynamic raw);
+  QuicKeyLog
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicKeyLog(
+    dynamic raw,
+  );
 
   @protected
-  QuicReadException dco_decode_box_autoadd_quic_read_exception(dynamic raw);
+  QuicPubSubBroker
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker(
+    dynamic raw,
+  );
 
   @protected
-  BigInt dco_decode_box_autoadd_usize(dynamic raw);
+  QuicPubSubClient
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
+    dynamic raw,
+  );
 
   @protected
-  List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw);
+  QuicPubSubSubscription
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+    dynamic raw,
+  );
 
   @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+  QuicRecvStream
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+    dynamic raw,
+  );
 
   @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+  QuicRpcCall
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall(
+    dynamic raw,
+  );
 
   @protected
-  String? dco_decode_opt_String(dynamic raw);
+  QuicRpcClient
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient(
+    dynamic raw,
+  );
 
   @protected
-  BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw);
+  QuicRpcRegistry
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcRegistry(
+    dynamic raw,
+  );
 
   @protected
-  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+  QuicRpcResponder
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder(
+    dynamic raw,
+  );
 
   @protected
-  QuicClientConfig dco_decode_quic_client_config(dynamic raw);
+  QuicSendStream
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+    dynamic raw,
+  );
 
   @protected
-  QuicConnectionStats dco_decode_quic_connection_stats(dynamic raw);
+  QuicServerConfig
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+    dynamic raw,
+  );
 
   @protected
-  QuicDatagramException dco_decode_quic_datagram_exception(dynamic raw);
+  QuicStreamReassembler
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler(
+    dynamic raw,
+  );
 
   @protected
-  QuicError dco_decode_quic_error(dynamic raw);
+  QuicStreamingTransfer
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
+    dynamic raw,
+  );
 
   @protected
-  QuicFrameStats dco_decode_quic_frame_stats(dynamic raw);
+  QuicTransportConfig
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
+    dynamic raw,
+  );
 
   @protected
-  QuicPathStats dco_decode_quic_path_stats(dynamic raw);
+  SimpleQuicServer
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+    dynamic raw,
+  );
 
   @protected
-  QuicReadException dco_decode_quic_read_exception(dynamic raw);
+  WebTransportSession
+  dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+    dynamic raw,
+  );
 
   @protected
-  QuicReadToEndException dco_decode_quic_read_to_end_exception(dynamic raw);
+  Http3ResponseStream
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+    dynamic raw,
+  );
 
   @protected
-  QuicUdpStats dco_decode_quic_udp_stats(dynamic raw);
+  Http3ServerStream
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+    dynamic raw,
+  );
 
   @protected
-  QuicWriteException dco_decode_quic_write_exception(dynamic raw);
+  QuicConnection
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    dynamic raw,
+  );
 
   @protected
-  (QuicClient, QuicClientConfig)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_quic_client_config(
+  QuicDatagramChannel
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
     dynamic raw,
   );
 
   @protected
-  (QuicClient, String)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_string(
+  QuicDatagramMux
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
     dynamic raw,
   );
 
   @protected
-  (QuicConnection, QuicSendStream)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream(
+  QuicDatagramSubscription
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription(
     dynamic raw,
   );
 
   @protected
-  (QuicConnection, QuicSendStream, QuicRecvStream)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream(
+  QuicFileReceiver
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver(
     dynamic raw,
   );
 
   @protected
-  (QuicConnection, BigInt?)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_box_autoadd_usize(
+  QuicFileUpload
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload(
     dynamic raw,
   );
 
   @protected
-  (QuicConnection, Uint8List?)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_list_prim_u_8_strict(
+  QuicFramedStream
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream(
     dynamic raw,
   );
 
   @protected
-  (QuicConnection, String?)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_string(
+  QuicPubSubBroker
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker(
     dynamic raw,
   );
 
   @protected
-  (QuicConnection, QuicConnectionStats)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_quic_connection_stats(
+  QuicPubSubClient
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
     dynamic raw,
   );
 
   @protected
-  (QuicConnection, SocketAddress)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_socket_address(
+  QuicPubSubSubscription
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
     dynamic raw,
   );
 
   @protected
-  (QuicConnection, BigInt)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_u_64(
+  QuicRpcCall
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall(
     dynamic raw,
   );
 
   @protected
-  (QuicConnection, BigInt)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_usize(
+  QuicRpcClient
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient(
     dynamic raw,
   );
 
   @protected
-  (QuicEndpoint, QuicConnection)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
+  QuicRpcResponder
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder(
     dynamic raw,
   );
 
   @protected
-  (QuicRecvStream, Uint8List)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_list_prim_u_8_strict(
+  QuicStreamReassembler
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler(
     dynamic raw,
   );
 
   @protected
-  (QuicRecvStream, Uint8List?)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_opt_list_prim_u_8_strict(
+  QuicStreamingTransfer
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
     dynamic raw,
   );
 
   @protected
-  (QuicSendStream, BigInt)
-  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_usize(
+  WebTransportSession
+  dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
     dynamic raw,
   );
 
   @protected
-  SocketAddress dco_decode_socket_address(dynamic raw);
+  FutureOr<void> Function(String)
+  dco_decode_DartFn_Inputs_String_Output_unit_AnyhowException(dynamic raw);
 
   @protected
-  int dco_decode_u_16(dynamic raw);
+  FutureOr<Uint8List?> Function()
+  dco_decode_DartFn_Inputs__Output_opt_list_prim_u_8_strict_AnyhowException(
+    dynamic raw,
+  );
 
   @protected
-  int dco_decode_u_32(dynamic raw);
+  FutureOr<void> Function(Http3ServerRequest, Http3ServerStream)
+  dco_decode_DartFn_Inputs_http_3_server_request_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream_Output_unit_AnyhowException(
+    dynamic raw,
+  );
 
   @protected
-  BigInt dco_decode_u_64(dynamic raw);
+  FutureOr<Uint8List> Function(Uint8List)
+  dco_decode_DartFn_Inputs_list_prim_u_8_strict_Output_list_prim_u_8_strict_AnyhowException(
+    dynamic raw,
+  );
 
   @protected
-  int dco_decode_u_8(dynamic raw);
+  FutureOr<void> Function(Uint8List)
+  dco_decode_DartFn_Inputs_list_prim_u_8_strict_Output_unit_AnyhowException(
+    dynamic raw,
+  );
 
   @protected
-  void dco_decode_unit(dynamic raw);
+  FutureOr<void> Function(QuicFileTransferProgress)
+  dco_decode_DartFn_Inputs_quic_file_transfer_progress_Output_unit_AnyhowException(
+    dynamic raw,
+  );
 
   @protected
-  BigInt dco_decode_usize(dynamic raw);
+  FutureOr<QuicRpcStatus> Function(QuicRpcRequest, QuicRpcResponder)
+  dco_decode_DartFn_Inputs_quic_rpc_request_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder_Output_quic_rpc_status_AnyhowException(
+    dynamic raw,
+  );
 
   @protected
-  QuicClient
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
-    SseDeserializer deserializer,
+  FutureOr<Uint8List> Function(QuicRpcRequest)
+  dco_decode_DartFn_Inputs_quic_rpc_request_Output_list_prim_u_8_strict_AnyhowException(
+    dynamic raw,
   );
 
   @protected
-  QuicConnection
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-    SseDeserializer deserializer,
+  FutureOr<void> Function(QuicTransferProgress)
+  dco_decode_DartFn_Inputs_quic_transfer_progress_Output_unit_AnyhowException(
+    dynamic raw,
   );
 
   @protected
-  QuicEndpoint
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
-    SseDeserializer deserializer,
-  );
+  Object dco_decode_DartOpaque(dynamic raw);
 
   @protected
-  QuicEndpointConfig
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
-    SseDeserializer deserializer,
+  Http3Client
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client(
+    dynamic raw,
   );
 
   @protected
-  QuicRecvStream
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
-    SseDeserializer deserializer,
+  Http3ResponseStream
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+    dynamic raw,
   );
 
   @protected
-  QuicSendStream
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
-    SseDeserializer deserializer,
+  Http3Router
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Router(
+    dynamic raw,
   );
 
   @protected
-  QuicServerConfig
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
-    SseDeserializer deserializer,
+  Http3ServerStream
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+    dynamic raw,
   );
 
   @protected
-  QuicTransportConfig
-  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
-    SseDeserializer deserializer,
+  QuicCertificateResolver
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver(
+    dynamic raw,
   );
 
   @protected
   QuicClient
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
-    SseDeserializer deserializer,
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+    dynamic raw,
   );
 
   @protected
   QuicConnection
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-    SseDeserializer deserializer,
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    dynamic raw,
   );
 
   @protected
-  QuicEndpoint
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
-    SseDeserializer deserializer,
+  QuicConnectionPool
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+    dynamic raw,
   );
 
   @protected
-  QuicEndpointConfig
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
-    SseDeserializer deserializer,
+  QuicDatagramChannel
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
+    dynamic raw,
   );
 
   @protected
-  QuicRecvStream
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
-    SseDeserializer deserializer,
+  QuicDatagramMux
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
+    dynamic raw,
   );
 
   @protected
-  QuicSendStream
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
-    SseDeserializer deserializer,
+  QuicDatagramSubscription
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription(
+    dynamic raw,
   );
 
   @protected
-  QuicServerConfig
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
-    SseDeserializer deserializer,
+  QuicEndpoint
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+    dynamic raw,
   );
 
   @protected
-  QuicTransportConfig
-  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
-    SseDeserializer deserializer,
+  QuicEndpointConfig
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
+    dynamic raw,
   );
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer);
+  QuicFileReceiver
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver(
+    dynamic raw,
+  );
 
   @protected
-  QuicClientConfig sse_decode_box_autoadd_quic_client_config(
-    SseDeserializer deserializer,
+  QuicFileUpload
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload(
+    dynamic raw,
   );
 
   @protected
-  QuicReadException sse_decode_box_autoadd_quic_read_exception(
-    SseDeserializer deserializer,
+  QuicFramedStream
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream(
+    dynamic raw,
   );
 
   @protected
-  BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer);
+  QuicKeyLog
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicKeyLog(
+    dynamic raw,
+  );
 
   @protected
-  List<Uint8List> sse_decode_list_list_prim_u_8_strict(
-    SseDeserializer deserializer,
+  QuicPubSubBroker
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker(
+    dynamic raw,
   );
 
   @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+  QuicPubSubClient
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
+    dynamic raw,
+  );
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  QuicPubSubSubscription
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+    dynamic raw,
+  );
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  QuicRecvStream
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+    dynamic raw,
+  );
 
   @protected
-  BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer);
+  QuicRpcCall
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall(
+    dynamic raw,
+  );
 
   @protected
-  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+  QuicRpcClient
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient(
+    dynamic raw,
+  );
 
   @protected
-  QuicClientConfig sse_decode_quic_client_config(SseDeserializer deserializer);
+  QuicRpcRegistry
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcRegistry(
+    dynamic raw,
+  );
 
   @protected
-  QuicConnectionStats sse_decode_quic_connection_stats(
-    SseDeserializer deserializer,
+  QuicRpcResponder
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder(
+    dynamic raw,
   );
 
   @protected
-  QuicDatagramException sse_decode_quic_datagram_exception(
-    SseDeserializer deserializer,
+  QuicSendStream
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+    dynamic raw,
   );
 
   @protected
-  QuicError sse_decode_quic_error(SseDeserializer deserializer);
+  QuicServerConfig
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+    dynamic raw,
+  );
 
   @protected
-  QuicFrameStats sse_decode_quic_frame_stats(SseDeserializer deserializer);
+  QuicStreamReassembler
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler(
+    dynamic raw,
+  );
 
   @protected
-  QuicPathStats sse_decode_quic_path_stats(SseDeserializer deserializer);
+  QuicStreamingTransfer
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
+    dynamic raw,
+  );
 
   @protected
-  QuicReadException sse_decode_quic_read_exception(
-    SseDeserializer deserializer,
+  QuicTransportConfig
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
+    dynamic raw,
   );
 
   @protected
-  QuicReadToEndException sse_decode_quic_read_to_end_exception(
-    SseDeserializer deserializer,
+  SimpleQuicServer
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+    dynamic raw,
   );
 
   @protected
-  QuicUdpStats sse_decode_quic_udp_stats(SseDeserializer deserializer);
+  WebTransportSession
+  dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+    dynamic raw,
+  );
 
   @protected
-  QuicWriteException sse_decode_quic_write_exception(
-    SseDeserializer deserializer,
+  RustStreamSink<QuicLogRecord> dco_decode_StreamSink_quic_log_record_Sse(
+    dynamic raw,
   );
 
   @protected
-  (QuicClient, QuicClientConfig)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_quic_client_config(
-    SseDeserializer deserializer,
-  );
+  String dco_decode_String(dynamic raw);
 
   @protected
-  (QuicClient, String)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_string(
-    SseDeserializer deserializer,
-  );
+  bool dco_decode_bool(dynamic raw);
 
   @protected
-  (QuicConnection, QuicSendStream)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream(
-    SseDeserializer deserializer,
+  QuicConnection
+  dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    dynamic raw,
   );
 
   @protected
-  (QuicConnection, QuicSendStream, QuicRecvStream)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream(
-    SseDeserializer deserializer,
+  QuicRecvStream
+  dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+    dynamic raw,
   );
 
   @protected
-  (QuicConnection, BigInt?)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_box_autoadd_usize(
-    SseDeserializer deserializer,
+  QuicSendStream
+  dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+    dynamic raw,
   );
 
   @protected
-  (QuicConnection, Uint8List?)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_list_prim_u_8_strict(
-    SseDeserializer deserializer,
-  );
+  CertificateChain dco_decode_box_autoadd_certificate_chain(dynamic raw);
 
   @protected
-  (QuicConnection, String?)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_string(
-    SseDeserializer deserializer,
-  );
+  Http3ClientConfig dco_decode_box_autoadd_http_3_client_config(dynamic raw);
 
   @protected
-  (QuicConnection, QuicConnectionStats)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_quic_connection_stats(
-    SseDeserializer deserializer,
-  );
+  Http3Request dco_decode_box_autoadd_http_3_request(dynamic raw);
 
   @protected
-  (QuicConnection, SocketAddress)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_socket_address(
-    SseDeserializer deserializer,
-  );
+  PrivateKey dco_decode_box_autoadd_private_key(dynamic raw);
 
   @protected
-  (QuicConnection, BigInt)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_u_64(
-    SseDeserializer deserializer,
-  );
+  QuicClientConfig dco_decode_box_autoadd_quic_client_config(dynamic raw);
 
   @protected
-  (QuicConnection, BigInt)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_usize(
-    SseDeserializer deserializer,
-  );
+  QuicConnectionEnd dco_decode_box_autoadd_quic_connection_end(dynamic raw);
 
   @protected
-  (QuicEndpoint, QuicConnection)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
-    SseDeserializer deserializer,
+  QuicConnectionPoolConfig dco_decode_box_autoadd_quic_connection_pool_config(
+    dynamic raw,
   );
 
   @protected
-  (QuicRecvStream, Uint8List)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_list_prim_u_8_strict(
-    SseDeserializer deserializer,
+  QuicDatagramChannelConfig dco_decode_box_autoadd_quic_datagram_channel_config(
+    dynamic raw,
   );
 
   @protected
-  (QuicRecvStream, Uint8List?)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_opt_list_prim_u_8_strict(
-    SseDeserializer deserializer,
+  QuicDatagramSubscriptionConfig
+  dco_decode_box_autoadd_quic_datagram_subscription_config(dynamic raw);
+
+  @protected
+  QuicFileTransferConfig dco_decode_box_autoadd_quic_file_transfer_config(
+    dynamic raw,
   );
 
   @protected
-  (QuicSendStream, BigInt)
-  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_usize(
-    SseDeserializer deserializer,
+  QuicPubSubBrokerConfig dco_decode_box_autoadd_quic_pub_sub_broker_config(
+    dynamic raw,
   );
 
   @protected
-  SocketAddress sse_decode_socket_address(SseDeserializer deserializer);
+  QuicPubSubMessage dco_decode_box_autoadd_quic_pub_sub_message(dynamic raw);
 
   @protected
-  int sse_decode_u_16(SseDeserializer deserializer);
+  QuicPubSubSubscribeOptions
+  dco_decode_box_autoadd_quic_pub_sub_subscribe_options(dynamic raw);
 
   @protected
-  int sse_decode_u_32(SseDeserializer deserializer);
+  QuicReadException dco_decode_box_autoadd_quic_read_exception(dynamic raw);
 
   @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer);
+  QuicRpcCallOptions dco_decode_box_autoadd_quic_rpc_call_options(dynamic raw);
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  QuicRpcClientConfig dco_decode_box_autoadd_quic_rpc_client_config(
+    dynamic raw,
+  );
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  QuicStreamChunk dco_decode_box_autoadd_quic_stream_chunk(dynamic raw);
 
   @protected
-  BigInt sse_decode_usize(SseDeserializer deserializer);
+  SimpleQuicServerConfig dco_decode_box_autoadd_simple_quic_server_config(
+    dynamic raw,
+  );
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer);
+  BigInt dco_decode_box_autoadd_usize(dynamic raw);
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
-    QuicClient self,
-    SseSerializer serializer,
-  );
+  CertificateChain dco_decode_certificate_chain(dynamic raw);
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-    QuicConnection self,
-    SseSerializer serializer,
-  );
+  CertificateKeyAlgorithm dco_decode_certificate_key_algorithm(dynamic raw);
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
-    QuicEndpoint self,
-    SseSerializer serializer,
-  );
+  Http3ClientConfig dco_decode_http_3_client_config(dynamic raw);
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
-    QuicEndpointConfig self,
-    SseSerializer serializer,
-  );
+  Http3Header dco_decode_http_3_header(dynamic raw);
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
-    QuicRecvStream self,
-    SseSerializer serializer,
-  );
+  Http3Request dco_decode_http_3_request(dynamic raw);
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
-    QuicSendStream self,
-    SseSerializer serializer,
-  );
+  Http3Response dco_decode_http_3_response(dynamic raw);
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
-    QuicServerConfig self,
-    SseSerializer serializer,
-  );
+  Http3ServerRequest dco_decode_http_3_server_request(dynamic raw);
 
   @protected
-  void
-  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
-    QuicTransportConfig self,
-    SseSerializer serializer,
-  );
+  int dco_decode_i_32(dynamic raw);
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
-    QuicClient self,
-    SseSerializer serializer,
-  );
+  PlatformInt64 dco_decode_i_64(dynamic raw);
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
-    QuicConnection self,
-    SseSerializer serializer,
-  );
+  PlatformInt64 dco_decode_isize(dynamic raw);
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
-    QuicEndpoint self,
-    SseSerializer serializer,
-  );
+  List<String> dco_decode_list_String(dynamic raw);
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
-    QuicEndpointConfig self,
-    SseSerializer serializer,
-  );
+  List<Http3Header> dco_decode_list_http_3_header(dynamic raw);
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
-    QuicRecvStream self,
-    SseSerializer serializer,
-  );
+  List<Uint8List> dco_decode_list_list_prim_u_8_strict(dynamic raw);
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
-    QuicSendStream self,
-    SseSerializer serializer,
-  );
+  List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
-    QuicServerConfig self,
-    SseSerializer serializer,
-  );
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
   @protected
-  void
-  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
-    QuicTransportConfig self,
-    SseSerializer serializer,
+  List<QuicAuthorityPoolStats> dco_decode_list_quic_authority_pool_stats(
+    dynamic raw,
   );
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  List<QuicLogField> dco_decode_list_quic_log_field(dynamic raw);
 
   @protected
-  void sse_encode_box_autoadd_quic_client_config(
-    QuicClientConfig self,
-    SseSerializer serializer,
-  );
+  List<QuicRpcMetadata> dco_decode_list_quic_rpc_metadata(dynamic raw);
 
   @protected
-  void sse_encode_box_autoadd_quic_read_exception(
-    QuicReadException self,
-    SseSerializer serializer,
+  List<QuicStreamChunk> dco_decode_list_quic_stream_chunk(dynamic raw);
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw);
+
+  @protected
+  QuicConnection?
+  dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    dynamic raw,
   );
 
   @protected
-  void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer);
+  QuicRecvStream?
+  dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+    dynamic raw,
+  );
 
   @protected
-  void sse_encode_list_list_prim_u_8_strict(
-    List<Uint8List> self,
-    SseSerializer serializer,
+  QuicSendStream?
+  dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+    dynamic raw,
   );
 
   @protected
-  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+  QuicConnectionEnd? dco_decode_opt_box_autoadd_quic_connection_end(
+    dynamic raw,
+  );
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
+  QuicPubSubMessage? dco_decode_opt_box_autoadd_quic_pub_sub_message(
+    dynamic raw,
   );
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+  QuicStreamChunk? dco_decode_opt_box_autoadd_quic_stream_chunk(dynamic raw);
 
   @protected
-  void sse_encode_opt_box_autoadd_usize(BigInt? self, SseSerializer serializer);
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
 
   @protected
-  void sse_encode_opt_list_prim_u_8_strict(
-    Uint8List? self,
-    SseSerializer serializer,
-  );
+  BigInt? dco_decode_opt_box_autoadd_usize(dynamic raw);
 
   @protected
-  void sse_encode_quic_client_config(
-    QuicClientConfig self,
-    SseSerializer serializer,
-  );
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
 
   @protected
-  void sse_encode_quic_connection_stats(
-    QuicConnectionStats self,
-    SseSerializer serializer,
-  );
+  List<QuicStreamChunk>? dco_decode_opt_list_quic_stream_chunk(dynamic raw);
 
   @protected
-  void sse_encode_quic_datagram_exception(
-    QuicDatagramException self,
-    SseSerializer serializer,
-  );
+  PrivateKey dco_decode_private_key(dynamic raw);
 
   @protected
-  void sse_encode_quic_error(QuicError self, SseSerializer serializer);
+  PrivateKeyFormat dco_decode_private_key_format(dynamic raw);
 
   @protected
-  void sse_encode_quic_frame_stats(
-    QuicFrameStats self,
-    SseSerializer serializer,
-  );
+  QuicAuthorityPoolStats dco_decode_quic_authority_pool_stats(dynamic raw);
 
   @protected
-  void sse_encode_quic_path_stats(QuicPathStats self, SseSerializer serializer);
+  QuicChunksWritten dco_decode_quic_chunks_written(dynamic raw);
 
   @protected
-  void sse_encode_quic_read_exception(
-    QuicReadException self,
-    SseSerializer serializer,
-  );
+  QuicClientConfig dco_decode_quic_client_config(dynamic raw);
 
   @protected
-  void sse_encode_quic_read_to_end_exception(
-    QuicReadToEndException self,
-    SseSerializer serializer,
-  );
+  QuicConnectionEnd dco_decode_quic_connection_end(dynamic raw);
 
   @protected
-  void sse_encode_quic_udp_stats(QuicUdpStats self, SseSerializer serializer);
+  QuicConnectionEndKind dco_decode_quic_connection_end_kind(dynamic raw);
 
   @protected
-  void sse_encode_quic_write_exception(
-    QuicWriteException self,
-    SseSerializer serializer,
+  QuicConnectionPoolConfig dco_decode_quic_connection_pool_config(dynamic raw);
+
+  @protected
+  QuicConnectionPoolStats dco_decode_quic_connection_pool_stats(dynamic raw);
+
+  @protected
+  QuicConnectionStats dco_decode_quic_connection_stats(dynamic raw);
+
+  @protected
+  QuicDatagramBatch dco_decode_quic_datagram_batch(dynamic raw);
+
+  @protected
+  QuicDatagramChannelConfig dco_decode_quic_datagram_channel_config(
+    dynamic raw,
   );
 
   @protected
-  void
-  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_quic_client_config(
-    (QuicClient, QuicClientConfig) self,
-    SseSerializer serializer,
+  QuicDatagramDropPolicy dco_decode_quic_datagram_drop_policy(dynamic raw);
+
+  @protected
+  QuicDatagramException dco_decode_quic_datagram_exception(dynamic raw);
+
+  @protected
+  QuicDatagramSubscriptionConfig dco_decode_quic_datagram_subscription_config(
+    dynamic raw,
   );
 
   @protected
-  void
-  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_string(
-    (QuicClient, String) self,
-    SseSerializer serializer,
+  QuicError dco_decode_quic_error(dynamic raw);
+
+  @protected
+  QuicFileTransferConfig dco_decode_quic_file_transfer_config(dynamic raw);
+
+  @protected
+  QuicFileTransferErrorKind dco_decode_quic_file_transfer_error_kind(
+    dynamic raw,
   );
 
   @protected
-  void
-  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream(
-    (QuicConnection, QuicSendStream) self,
-    SseSerializer serializer,
+  QuicFileTransferException dco_decode_quic_file_transfer_exception(
+    dynamic raw,
   );
 
   @protected
-  void
-  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream(
-    (QuicConnection, QuicSendStream, QuicRecvStream) self,
-    SseSerializer serializer,
+  QuicFileTransferProgress dco_decode_quic_file_transfer_progress(dynamic raw);
+
+  @protected
+  QuicFrameErrorKind dco_decode_quic_frame_error_kind(dynamic raw);
+
+  @protected
+  QuicFrameException dco_decode_quic_frame_exception(dynamic raw);
+
+  @protected
+  QuicFrameStats dco_decode_quic_frame_stats(dynamic raw);
+
+  @protected
+  QuicLogField dco_decode_quic_log_field(dynamic raw);
+
+  @protected
+  QuicLogLevel dco_decode_quic_log_level(dynamic raw);
+
+  @protected
+  QuicLogRecord dco_decode_quic_log_record(dynamic raw);
+
+  @protected
+  QuicPathStats dco_decode_quic_path_stats(dynamic raw);
+
+  @protected
+  QuicPubSubBrokerConfig dco_decode_quic_pub_sub_broker_config(dynamic raw);
+
+  @protected
+  QuicPubSubDelivery dco_decode_quic_pub_sub_delivery(dynamic raw);
+
+  @protected
+  QuicPubSubMessage dco_decode_quic_pub_sub_message(dynamic raw);
+
+  @protected
+  QuicPubSubSubscribeOptions dco_decode_quic_pub_sub_subscribe_options(
+    dynamic raw,
   );
 
   @protected
-  void
-  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_box_autoadd_usize(
-    (QuicConnection, BigInt?) self,
-    SseSerializer serializer,
+  QuicReadException dco_decode_quic_read_exception(dynamic raw);
+
+  @protected
+  QuicReadToEndException dco_decode_quic_read_to_end_exception(dynamic raw);
+
+  @protected
+  QuicRpcCallOptions dco_decode_quic_rpc_call_options(dynamic raw);
+
+  @protected
+  QuicRpcClientConfig dco_decode_quic_rpc_client_config(dynamic raw);
+
+  @protected
+  QuicRpcCode dco_decode_quic_rpc_code(dynamic raw);
+
+  @protected
+  QuicRpcMetadata dco_decode_quic_rpc_metadata(dynamic raw);
+
+  @protected
+  QuicRpcRequest dco_decode_quic_rpc_request(dynamic raw);
+
+  @protected
+  QuicRpcStatus dco_decode_quic_rpc_status(dynamic raw);
+
+  @protected
+  QuicSendStreamStats dco_decode_quic_send_stream_stats(dynamic raw);
+
+  @protected
+  QuicStreamChunk dco_decode_quic_stream_chunk(dynamic raw);
+
+  @protected
+  QuicTransferProgress dco_decode_quic_transfer_progress(dynamic raw);
+
+  @protected
+  QuicUdpStats dco_decode_quic_udp_stats(dynamic raw);
+
+  @protected
+  QuicWriteException dco_decode_quic_write_exception(dynamic raw);
+
+  @protected
+  (Http3Client, Http3ResponseStream)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_http_3_client_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_http_3_response_stream(
+    dynamic raw,
   );
 
   @protected
-  void
-  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_list_prim_u_8_strict(
-    (QuicConnection, Uint8List?) self,
-    SseSerializer serializer,
+  (Http3Client, Http3Response)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_http_3_client_http_3_response(
+    dynamic raw,
   );
 
   @protected
-  void
-  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_string(
-    (QuicConnection, String?) self,
-    SseSerializer serializer,
+  (QuicCertificateResolver, bool)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_certificate_resolver_bool(
+    dynamic raw,
   );
 
   @protected
-  void
-  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_quic_connection_stats(
-    (QuicConnection, QuicConnectionStats) self,
-    SseSerializer serializer,
+  (QuicClient, QuicStreamingTransfer)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_streaming_transfer(
+    dynamic raw,
   );
 
   @protected
-  void
-  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_socket_address(
-    (QuicConnection, SocketAddress) self,
-    SseSerializer serializer,
+  (QuicClient, Uint8List)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_list_prim_u_8_strict(
+    dynamic raw,
   );
 
   @protected
-  void
-  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_u_64(
-    (QuicConnection, BigInt) self,
-    SseSerializer serializer,
+  (QuicClient, QuicClientConfig)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_quic_client_config(
+    dynamic raw,
   );
 
   @protected
-  void
-  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_usize(
-    (QuicConnection, BigInt) self,
-    SseSerializer serializer,
+  (QuicClient, String)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_string(
+    dynamic raw,
   );
 
   @protected
-  void
-  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
-    (QuicEndpoint, QuicConnection) self,
-    SseSerializer serializer,
+  (QuicConnection, QuicDatagramMux)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_datagram_mux(
+    dynamic raw,
   );
 
   @protected
-  void
-  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_list_prim_u_8_strict(
-    (QuicRecvStream, Uint8List) self,
-    SseSerializer serializer,
+  (QuicConnection, QuicPubSubClient)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_pub_sub_client(
+    dynamic raw,
   );
 
   @protected
-  void
-  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_opt_list_prim_u_8_strict(
-    (QuicRecvStream, Uint8List?) self,
-    SseSerializer serializer,
+  (QuicConnection, QuicRecvStream)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream(
+    dynamic raw,
   );
 
   @protected
-  void
-  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_usize(
-    (QuicSendStream, BigInt) self,
-    SseSerializer serializer,
+  (QuicConnection, QuicSendStream)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream(
+    dynamic raw,
   );
 
   @protected
-  void sse_encode_socket_address(SocketAddress self, SseSerializer serializer);
+  (QuicConnection, QuicSendStream, QuicRecvStream)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream(
+    dynamic raw,
+  );
 
   @protected
-  void sse_encode_u_16(int self, SseSerializer serializer);
+  (QuicConnection, BigInt?)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_box_autoadd_usize(
+    dynamic raw,
+  );
 
   @protected
-  void sse_encode_u_32(int self, SseSerializer serializer);
+  (QuicConnection, Uint8List?)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_list_prim_u_8_strict(
+    dynamic raw,
+  );
 
   @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+  (QuicConnection, String?)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_string(
+    dynamic raw,
+  );
 
   @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+  (QuicConnectionPool, QuicConnection)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
+    dynamic raw,
+  );
 
   @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+  (QuicConnectionPool, QuicConnection, bool)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_bool(
+    dynamic raw,
+  );
 
   @protected
-  void sse_encode_usize(BigInt self, SseSerializer serializer);
+  (QuicConnectionPool, QuicEndpoint, QuicConnection)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
+    dynamic raw,
+  );
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  (QuicConnectionPool, QuicConnectionPoolStats)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_quic_connection_pool_stats(
+    dynamic raw,
+  );
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
-}
+  (QuicConnectionPool, BigInt)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_usize(
+    dynamic raw,
+  );
 
-// Section: wire_class
+  @protected
+  (QuicConnection, QuicConnectionStats)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_quic_connection_stats(
+    dynamic raw,
+  );
+
+  @protected
+  (QuicConnection, SocketAddress)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_socket_address(
+    dynamic raw,
+  );
+
+  @protected
+  (QuicConnection, BigInt)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_u_64(
+    dynamic raw,
+  );
+
+  @protected
+  (QuicConnection, BigInt)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_usize(
+    dynamic raw,
+  );
+
+  @protected
+  (QuicEndpoint, QuicConnection)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
+    dynamic raw,
+  );
+
+  @protected
+  (QuicEndpoint, WebTransportSession)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_web_transport_session(
+    dynamic raw,
+  );
+
+  @protected
+  (QuicEndpoint, QuicConnection?)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_opt_box_autoadd_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
+    dynamic raw,
+  );
+
+  @protected
+  (QuicRecvStream, Uint8List)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_list_prim_u_8_strict(
+    dynamic raw,
+  );
+
+  @protected
+  (QuicRecvStream, QuicStreamChunk?)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_opt_box_autoadd_quic_stream_chunk(
+    dynamic raw,
+  );
+
+  @protected
+  (QuicRecvStream, Uint8List?)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_opt_list_prim_u_8_strict(
+    dynamic raw,
+  );
+
+  @protected
+  (QuicRecvStream, List<QuicStreamChunk>?)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_opt_list_quic_stream_chunk(
+    dynamic raw,
+  );
+
+  @protected
+  (QuicSendStream, QuicRecvStream)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream(
+    dynamic raw,
+  );
+
+  @protected
+  (QuicSendStream, QuicChunksWritten)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_quic_chunks_written(
+    dynamic raw,
+  );
+
+  @protected
+  (QuicSendStream, QuicSendStreamStats)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_quic_send_stream_stats(
+    dynamic raw,
+  );
+
+  @protected
+  (QuicSendStream, BigInt)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_usize(
+    dynamic raw,
+  );
+
+  @protected
+  (QuicServerConfig, bool)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_server_config_bool(
+    dynamic raw,
+  );
+
+  @protected
+  (SimpleQuicServer, SelfSignedCertificate)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_self_signed_certificate(
+    dynamic raw,
+  );
+
+  @protected
+  (SimpleQuicServer, SimpleQuicServerStats)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_simple_quic_server_stats(
+    dynamic raw,
+  );
+
+  @protected
+  (SimpleQuicServer, String)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_string(
+    dynamic raw,
+  );
+
+  @protected
+  (SimpleQuicServer, BigInt)
+  dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_u_64(
+    dynamic raw,
+  );
+
+  @protected
+  (CertificateChain, PrivateKey)
+  dco_decode_record_certificate_chain_private_key(dynamic raw);
+
+  @protected
+  SelfSignedCertificate dco_decode_self_signed_certificate(dynamic raw);
+
+  @protected
+  SimpleQuicServerConfig dco_decode_simple_quic_server_config(dynamic raw);
+
+  @protected
+  SimpleQuicServerStats dco_decode_simple_quic_server_stats(dynamic raw);
+
+  @protected
+  SocketAddress dco_decode_socket_address(dynamic raw);
+
+  @protected
+  int dco_decode_u_16(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
+  @protected
+  int dco_decode_u_8(dynamic raw);
+
+  @protected
+  void dco_decode_unit(dynamic raw);
+
+  @protected
+  BigInt dco_decode_usize(dynamic raw);
+
+  @protected
+  WebTransportCloseInfo dco_decode_web_transport_close_info(dynamic raw);
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+  @protected
+  Http3Client
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Http3ResponseStream
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Http3Router
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Router(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Http3ServerStream
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicCertificateResolver
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicClient
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicConnection
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicConnectionPool
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicDatagramChannel
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicDatagramMux
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicDatagramSubscription
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicEndpoint
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicEndpointConfig
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicFileReceiver
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicFileUpload
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicFramedStream
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicKeyLog
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicKeyLog(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicPubSubBroker
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicPubSubClient
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicPubSubSubscription
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicRecvStream
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicRpcCall
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicRpcClient
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicRpcRegistry
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcRegistry(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicRpcResponder
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicSendStream
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicServerConfig
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicStreamReassembler
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicStreamingTransfer
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicTransportConfig
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SimpleQuicServer
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WebTransportSession
+  sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Http3ResponseStream
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Http3ServerStream
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicConnection
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicDatagramChannel
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicDatagramMux
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicDatagramSubscription
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicFileReceiver
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicFileUpload
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicFramedStream
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicPubSubBroker
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicPubSubClient
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicPubSubSubscription
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicRpcCall
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicRpcClient
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicRpcResponder
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicStreamReassembler
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicStreamingTransfer
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WebTransportSession
+  sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Object sse_decode_DartOpaque(SseDeserializer deserializer);
+
+  @protected
+  Http3Client
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Http3ResponseStream
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Http3Router
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Router(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Http3ServerStream
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicCertificateResolver
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicClient
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicConnection
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicConnectionPool
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicDatagramChannel
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicDatagramMux
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicDatagramSubscription
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicEndpoint
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicEndpointConfig
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicFileReceiver
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicFileUpload
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicFramedStream
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicKeyLog
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicKeyLog(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicPubSubBroker
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicPubSubClient
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicPubSubSubscription
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicRecvStream
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicRpcCall
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicRpcClient
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicRpcRegistry
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcRegistry(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicRpcResponder
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicSendStream
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicServerConfig
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicStreamReassembler
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicStreamingTransfer
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicTransportConfig
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SimpleQuicServer
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  WebTransportSession
+  sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  RustStreamSink<QuicLogRecord> sse_decode_StreamSink_quic_log_record_Sse(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer);
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer);
+
+  @protected
+  QuicConnection
+  sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicRecvStream
+  sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicSendStream
+  sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  CertificateChain sse_decode_box_autoadd_certificate_chain(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Http3ClientConfig sse_decode_box_autoadd_http_3_client_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Http3Request sse_decode_box_autoadd_http_3_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PrivateKey sse_decode_box_autoadd_private_key(SseDeserializer deserializer);
+
+  @protected
+  QuicClientConfig sse_decode_box_autoadd_quic_client_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicConnectionEnd sse_decode_box_autoadd_quic_connection_end(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicConnectionPoolConfig sse_decode_box_autoadd_quic_connection_pool_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicDatagramChannelConfig sse_decode_box_autoadd_quic_datagram_channel_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicDatagramSubscriptionConfig
+  sse_decode_box_autoadd_quic_datagram_subscription_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicFileTransferConfig sse_decode_box_autoadd_quic_file_transfer_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicPubSubBrokerConfig sse_decode_box_autoadd_quic_pub_sub_broker_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicPubSubMessage sse_decode_box_autoadd_quic_pub_sub_message(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicPubSubSubscribeOptions
+  sse_decode_box_autoadd_quic_pub_sub_subscribe_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicReadException sse_decode_box_autoadd_quic_read_exception(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicRpcCallOptions sse_decode_box_autoadd_quic_rpc_call_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicRpcClientConfig sse_decode_box_autoadd_quic_rpc_client_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicStreamChunk sse_decode_box_autoadd_quic_stream_chunk(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SimpleQuicServerConfig sse_decode_box_autoadd_simple_quic_server_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_box_autoadd_usize(SseDeserializer deserializer);
+
+  @protected
+  CertificateChain sse_decode_certificate_chain(SseDeserializer deserializer);
+
+  @protected
+  CertificateKeyAlgorithm sse_decode_certificate_key_algorithm(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Http3ClientConfig sse_decode_http_3_client_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Http3Header sse_decode_http_3_header(SseDeserializer deserializer);
+
+  @protected
+  Http3Request sse_decode_http_3_request(SseDeserializer deserializer);
+
+  @protected
+  Http3Response sse_decode_http_3_response(SseDeserializer deserializer);
+
+  @protected
+  Http3ServerRequest sse_decode_http_3_server_request(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_isize(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<Http3Header> sse_decode_list_http_3_header(SseDeserializer deserializer);
+
+  @protected
+  List<Uint8List> sse_decode_list_list_prim_u_8_strict(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<QuicAuthorityPoolStats> sse_decode_list_quic_authority_pool_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<QuicLogField> sse_decode_list_quic_log_field(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<QuicRpcMetadata> sse_decode_list_quic_rpc_metadata(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<QuicStreamChunk> sse_decode_list_quic_stream_chunk(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  QuicConnection?
+  sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicRecvStream?
+  sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicSendStream?
+  sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicConnectionEnd? sse_decode_opt_box_autoadd_quic_connection_end(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicPubSubMessage? sse_decode_opt_box_autoadd_quic_pub_sub_message(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicStreamChunk? sse_decode_opt_box_autoadd_quic_stream_chunk(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_usize(SseDeserializer deserializer);
+
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<QuicStreamChunk>? sse_decode_opt_list_quic_stream_chunk(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PrivateKey sse_decode_private_key(SseDeserializer deserializer);
+
+  @protected
+  PrivateKeyFormat sse_decode_private_key_format(SseDeserializer deserializer);
+
+  @protected
+  QuicAuthorityPoolStats sse_decode_quic_authority_pool_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicChunksWritten sse_decode_quic_chunks_written(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicClientConfig sse_decode_quic_client_config(SseDeserializer deserializer);
+
+  @protected
+  QuicConnectionEnd sse_decode_quic_connection_end(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicConnectionEndKind sse_decode_quic_connection_end_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicConnectionPoolConfig sse_decode_quic_connection_pool_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicConnectionPoolStats sse_decode_quic_connection_pool_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicConnectionStats sse_decode_quic_connection_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicDatagramBatch sse_decode_quic_datagram_batch(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicDatagramChannelConfig sse_decode_quic_datagram_channel_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicDatagramDropPolicy sse_decode_quic_datagram_drop_policy(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicDatagramException sse_decode_quic_datagram_exception(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicDatagramSubscriptionConfig sse_decode_quic_datagram_subscription_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicError sse_decode_quic_error(SseDeserializer deserializer);
+
+  @protected
+  QuicFileTransferConfig sse_decode_quic_file_transfer_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicFileTransferErrorKind sse_decode_quic_file_transfer_error_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicFileTransferException sse_decode_quic_file_transfer_exception(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicFileTransferProgress sse_decode_quic_file_transfer_progress(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicFrameErrorKind sse_decode_quic_frame_error_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicFrameException sse_decode_quic_frame_exception(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicFrameStats sse_decode_quic_frame_stats(SseDeserializer deserializer);
+
+  @protected
+  QuicLogField sse_decode_quic_log_field(SseDeserializer deserializer);
+
+  @protected
+  QuicLogLevel sse_decode_quic_log_level(SseDeserializer deserializer);
+
+  @protected
+  QuicLogRecord sse_decode_quic_log_record(SseDeserializer deserializer);
+
+  @protected
+  QuicPathStats sse_decode_quic_path_stats(SseDeserializer deserializer);
+
+  @protected
+  QuicPubSubBrokerConfig sse_decode_quic_pub_sub_broker_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicPubSubDelivery sse_decode_quic_pub_sub_delivery(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicPubSubMessage sse_decode_quic_pub_sub_message(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicPubSubSubscribeOptions sse_decode_quic_pub_sub_subscribe_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicReadException sse_decode_quic_read_exception(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicReadToEndException sse_decode_quic_read_to_end_exception(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicRpcCallOptions sse_decode_quic_rpc_call_options(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicRpcClientConfig sse_decode_quic_rpc_client_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicRpcCode sse_decode_quic_rpc_code(SseDeserializer deserializer);
+
+  @protected
+  QuicRpcMetadata sse_decode_quic_rpc_metadata(SseDeserializer deserializer);
+
+  @protected
+  QuicRpcRequest sse_decode_quic_rpc_request(SseDeserializer deserializer);
+
+  @protected
+  QuicRpcStatus sse_decode_quic_rpc_status(SseDeserializer deserializer);
+
+  @protected
+  QuicSendStreamStats sse_decode_quic_send_stream_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicStreamChunk sse_decode_quic_stream_chunk(SseDeserializer deserializer);
+
+  @protected
+  QuicTransferProgress sse_decode_quic_transfer_progress(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  QuicUdpStats sse_decode_quic_udp_stats(SseDeserializer deserializer);
+
+  @protected
+  QuicWriteException sse_decode_quic_write_exception(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (Http3Client, Http3ResponseStream)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_http_3_client_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_http_3_response_stream(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (Http3Client, Http3Response)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_http_3_client_http_3_response(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicCertificateResolver, bool)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_certificate_resolver_bool(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicClient, QuicStreamingTransfer)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_streaming_transfer(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicClient, Uint8List)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_list_prim_u_8_strict(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicClient, QuicClientConfig)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_quic_client_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicClient, String)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_string(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicConnection, QuicDatagramMux)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_datagram_mux(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicConnection, QuicPubSubClient)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_pub_sub_client(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicConnection, QuicRecvStream)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicConnection, QuicSendStream)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicConnection, QuicSendStream, QuicRecvStream)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicConnection, BigInt?)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_box_autoadd_usize(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicConnection, Uint8List?)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_list_prim_u_8_strict(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicConnection, String?)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_string(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicConnectionPool, QuicConnection)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicConnectionPool, QuicConnection, bool)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_bool(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicConnectionPool, QuicEndpoint, QuicConnection)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicConnectionPool, QuicConnectionPoolStats)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_quic_connection_pool_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicConnectionPool, BigInt)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_usize(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicConnection, QuicConnectionStats)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_quic_connection_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicConnection, SocketAddress)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_socket_address(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicConnection, BigInt)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_u_64(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicConnection, BigInt)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_usize(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicEndpoint, QuicConnection)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicEndpoint, WebTransportSession)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_web_transport_session(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicEndpoint, QuicConnection?)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_opt_box_autoadd_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicRecvStream, Uint8List)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_list_prim_u_8_strict(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicRecvStream, QuicStreamChunk?)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_opt_box_autoadd_quic_stream_chunk(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicRecvStream, Uint8List?)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_opt_list_prim_u_8_strict(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicRecvStream, List<QuicStreamChunk>?)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_opt_list_quic_stream_chunk(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicSendStream, QuicRecvStream)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicSendStream, QuicChunksWritten)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_quic_chunks_written(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicSendStream, QuicSendStreamStats)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_quic_send_stream_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicSendStream, BigInt)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_usize(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (QuicServerConfig, bool)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_server_config_bool(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (SimpleQuicServer, SelfSignedCertificate)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_self_signed_certificate(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (SimpleQuicServer, SimpleQuicServerStats)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_simple_quic_server_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (SimpleQuicServer, String)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_string(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (SimpleQuicServer, BigInt)
+  sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_u_64(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  (CertificateChain, PrivateKey)
+  sse_decode_record_certificate_chain_private_key(SseDeserializer deserializer);
+
+  @protected
+  SelfSignedCertificate sse_decode_self_signed_certificate(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SimpleQuicServerConfig sse_decode_simple_quic_server_config(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SimpleQuicServerStats sse_decode_simple_quic_server_stats(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  SocketAddress sse_decode_socket_address(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_usize(SseDeserializer deserializer);
+
+  @protected
+  WebTransportCloseInfo sse_decode_web_transport_close_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client(
+    Http3Client self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+    Http3ResponseStream self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Router(
+    Http3Router self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+    Http3ServerStream self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver(
+    QuicCertificateResolver self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+    QuicClient self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    QuicConnection self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+    QuicConnectionPool self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
+    QuicDatagramChannel self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
+    QuicDatagramMux self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription(
+    QuicDatagramSubscription self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+    QuicEndpoint self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
+    QuicEndpointConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver(
+    QuicFileReceiver self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload(
+    QuicFileUpload self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream(
+    QuicFramedStream self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicKeyLog(
+    QuicKeyLog self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker(
+    QuicPubSubBroker self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
+    QuicPubSubClient self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+    QuicPubSubSubscription self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+    QuicRecvStream self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall(
+    QuicRpcCall self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient(
+    QuicRpcClient self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcRegistry(
+    QuicRpcRegistry self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder(
+    QuicRpcResponder self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+    QuicSendStream self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+    QuicServerConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler(
+    QuicStreamReassembler self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
+    QuicStreamingTransfer self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
+    QuicTransportConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+    SimpleQuicServer self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+    WebTransportSession self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+    Http3ResponseStream self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+    Http3ServerStream self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    QuicConnection self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
+    QuicDatagramChannel self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
+    QuicDatagramMux self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription(
+    QuicDatagramSubscription self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver(
+    QuicFileReceiver self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload(
+    QuicFileUpload self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream(
+    QuicFramedStream self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker(
+    QuicPubSubBroker self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
+    QuicPubSubClient self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+    QuicPubSubSubscription self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall(
+    QuicRpcCall self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient(
+    QuicRpcClient self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder(
+    QuicRpcResponder self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler(
+    QuicStreamReassembler self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
+    QuicStreamingTransfer self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+    WebTransportSession self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_DartFn_Inputs_String_Output_unit_AnyhowException(
+    FutureOr<void> Function(String) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_DartFn_Inputs__Output_opt_list_prim_u_8_strict_AnyhowException(
+    FutureOr<Uint8List?> Function() self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_DartFn_Inputs_http_3_server_request_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream_Output_unit_AnyhowException(
+    FutureOr<void> Function(Http3ServerRequest, Http3ServerStream) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_DartFn_Inputs_list_prim_u_8_strict_Output_list_prim_u_8_strict_AnyhowException(
+    FutureOr<Uint8List> Function(Uint8List) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_DartFn_Inputs_list_prim_u_8_strict_Output_unit_AnyhowException(
+    FutureOr<void> Function(Uint8List) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_DartFn_Inputs_quic_file_transfer_progress_Output_unit_AnyhowException(
+    FutureOr<void> Function(QuicFileTransferProgress) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_DartFn_Inputs_quic_rpc_request_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder_Output_quic_rpc_status_AnyhowException(
+    FutureOr<QuicRpcStatus> Function(QuicRpcRequest, QuicRpcResponder) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_DartFn_Inputs_quic_rpc_request_Output_list_prim_u_8_strict_AnyhowException(
+    FutureOr<Uint8List> Function(QuicRpcRequest) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_DartFn_Inputs_quic_transfer_progress_Output_unit_AnyhowException(
+    FutureOr<void> Function(QuicTransferProgress) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_DartOpaque(Object self, SseSerializer serializer);
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client(
+    Http3Client self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+    Http3ResponseStream self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Router(
+    Http3Router self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+    Http3ServerStream self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver(
+    QuicCertificateResolver self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+    QuicClient self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    QuicConnection self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+    QuicConnectionPool self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
+    QuicDatagramChannel self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
+    QuicDatagramMux self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription(
+    QuicDatagramSubscription self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+    QuicEndpoint self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
+    QuicEndpointConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver(
+    QuicFileReceiver self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload(
+    QuicFileUpload self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream(
+    QuicFramedStream self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicKeyLog(
+    QuicKeyLog self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker(
+    QuicPubSubBroker self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
+    QuicPubSubClient self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+    QuicPubSubSubscription self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+    QuicRecvStream self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall(
+    QuicRpcCall self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient(
+    QuicRpcClient self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcRegistry(
+    QuicRpcRegistry self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder(
+    QuicRpcResponder self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+    QuicSendStream self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+    QuicServerConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler(
+    QuicStreamReassembler self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
+    QuicStreamingTransfer self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
+    QuicTransportConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+    SimpleQuicServer self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+    WebTransportSession self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_quic_log_record_Sse(
+    RustStreamSink<QuicLogRecord> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void
+  sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    QuicConnection self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+    QuicRecvStream self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+    QuicSendStream self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_certificate_chain(
+    CertificateChain self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_http_3_client_config(
+    Http3ClientConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_http_3_request(
+    Http3Request self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_private_key(
+    PrivateKey self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_quic_client_config(
+    QuicClientConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_quic_connection_end(
+    QuicConnectionEnd self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_quic_connection_pool_config(
+    QuicConnectionPoolConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_quic_datagram_channel_config(
+    QuicDatagramChannelConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_quic_datagram_subscription_config(
+    QuicDatagramSubscriptionConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_quic_file_transfer_config(
+    QuicFileTransferConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_quic_pub_sub_broker_config(
+    QuicPubSubBrokerConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_quic_pub_sub_message(
+    QuicPubSubMessage self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_quic_pub_sub_subscribe_options(
+    QuicPubSubSubscribeOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_quic_read_exception(
+    QuicReadException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_quic_rpc_call_options(
+    QuicRpcCallOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_quic_rpc_client_config(
+    QuicRpcClientConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_quic_stream_chunk(
+    QuicStreamChunk self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_simple_quic_server_config(
+    SimpleQuicServerConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_usize(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_certificate_chain(
+    CertificateChain self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_certificate_key_algorithm(
+    CertificateKeyAlgorithm self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_http_3_client_config(
+    Http3ClientConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_http_3_header(Http3Header self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_http_3_request(Http3Request self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_http_3_response(Http3Response self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_http_3_server_request(
+    Http3ServerRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_isize(PlatformInt64 self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_http_3_header(
+    List<Http3Header> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_list_prim_u_8_strict(
+    List<Uint8List> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_quic_authority_pool_stats(
+    List<QuicAuthorityPoolStats> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_quic_log_field(
+    List<QuicLogField> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_quic_rpc_metadata(
+    List<QuicRpcMetadata> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_quic_stream_chunk(
+    List<QuicStreamChunk> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void
+  sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    QuicConnection? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+    QuicRecvStream? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+    QuicSendStream? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_quic_connection_end(
+    QuicConnectionEnd? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_quic_pub_sub_message(
+    QuicPubSubMessage? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_quic_stream_chunk(
+    QuicStreamChunk? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_usize(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_list_prim_u_8_strict(
+    Uint8List? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_list_quic_stream_chunk(
+    List<QuicStreamChunk>? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_private_key(PrivateKey self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_private_key_format(
+    PrivateKeyFormat self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_authority_pool_stats(
+    QuicAuthorityPoolStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_chunks_written(
+    QuicChunksWritten self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_client_config(
+    QuicClientConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_connection_end(
+    QuicConnectionEnd self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_connection_end_kind(
+    QuicConnectionEndKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_connection_pool_config(
+    QuicConnectionPoolConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_connection_pool_stats(
+    QuicConnectionPoolStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_connection_stats(
+    QuicConnectionStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_datagram_batch(
+    QuicDatagramBatch self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_datagram_channel_config(
+    QuicDatagramChannelConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_datagram_drop_policy(
+    QuicDatagramDropPolicy self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_datagram_exception(
+    QuicDatagramException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_datagram_subscription_config(
+    QuicDatagramSubscriptionConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_error(QuicError self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_quic_file_transfer_config(
+    QuicFileTransferConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_file_transfer_error_kind(
+    QuicFileTransferErrorKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_file_transfer_exception(
+    QuicFileTransferException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_file_transfer_progress(
+    QuicFileTransferProgress self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_frame_error_kind(
+    QuicFrameErrorKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_frame_exception(
+    QuicFrameException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_frame_stats(
+    QuicFrameStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_log_field(QuicLogField self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_quic_log_level(QuicLogLevel self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_quic_log_record(QuicLogRecord self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_quic_path_stats(QuicPathStats self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_quic_pub_sub_broker_config(
+    QuicPubSubBrokerConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_pub_sub_delivery(
+    QuicPubSubDelivery self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_pub_sub_message(
+    QuicPubSubMessage self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_pub_sub_subscribe_options(
+    QuicPubSubSubscribeOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_read_exception(
+    QuicReadException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_read_to_end_exception(
+    QuicReadToEndException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_rpc_call_options(
+    QuicRpcCallOptions self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_rpc_client_config(
+    QuicRpcClientConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_rpc_code(QuicRpcCode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_quic_rpc_metadata(
+    QuicRpcMetadata self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_rpc_request(
+    QuicRpcRequest self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_rpc_status(QuicRpcStatus self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_quic_send_stream_stats(
+    QuicSendStreamStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_stream_chunk(
+    QuicStreamChunk self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_transfer_progress(
+    QuicTransferProgress self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_quic_udp_stats(QuicUdpStats self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_quic_write_exception(
+    QuicWriteException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_http_3_client_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_http_3_response_stream(
+    (Http3Client, Http3ResponseStream) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_http_3_client_http_3_response(
+    (Http3Client, Http3Response) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_certificate_resolver_bool(
+    (QuicCertificateResolver, bool) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_streaming_transfer(
+    (QuicClient, QuicStreamingTransfer) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_list_prim_u_8_strict(
+    (QuicClient, Uint8List) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_quic_client_config(
+    (QuicClient, QuicClientConfig) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_client_string(
+    (QuicClient, String) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_datagram_mux(
+    (QuicConnection, QuicDatagramMux) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_pub_sub_client(
+    (QuicConnection, QuicPubSubClient) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream(
+    (QuicConnection, QuicRecvStream) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream(
+    (QuicConnection, QuicSendStream) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream(
+    (QuicConnection, QuicSendStream, QuicRecvStream) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_box_autoadd_usize(
+    (QuicConnection, BigInt?) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_list_prim_u_8_strict(
+    (QuicConnection, Uint8List?) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_opt_string(
+    (QuicConnection, String?) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
+    (QuicConnectionPool, QuicConnection) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_bool(
+    (QuicConnectionPool, QuicConnection, bool) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
+    (QuicConnectionPool, QuicEndpoint, QuicConnection) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_quic_connection_pool_stats(
+    (QuicConnectionPool, QuicConnectionPoolStats) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_pool_usize(
+    (QuicConnectionPool, BigInt) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_quic_connection_stats(
+    (QuicConnection, QuicConnectionStats) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_socket_address(
+    (QuicConnection, SocketAddress) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_u_64(
+    (QuicConnection, BigInt) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection_usize(
+    (QuicConnection, BigInt) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
+    (QuicEndpoint, QuicConnection) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_web_transport_session(
+    (QuicEndpoint, WebTransportSession) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_endpoint_opt_box_autoadd_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_connection(
+    (QuicEndpoint, QuicConnection?) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_list_prim_u_8_strict(
+    (QuicRecvStream, Uint8List) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_opt_box_autoadd_quic_stream_chunk(
+    (QuicRecvStream, QuicStreamChunk?) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_opt_list_prim_u_8_strict(
+    (QuicRecvStream, Uint8List?) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream_opt_list_quic_stream_chunk(
+    (QuicRecvStream, List<QuicStreamChunk>?) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_recv_stream(
+    (QuicSendStream, QuicRecvStream) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_quic_chunks_written(
+    (QuicSendStream, QuicChunksWritten) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_quic_send_stream_stats(
+    (QuicSendStream, QuicSendStreamStats) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_send_stream_usize(
+    (QuicSendStream, BigInt) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_quic_server_config_bool(
+    (QuicServerConfig, bool) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_self_signed_certificate(
+    (SimpleQuicServer, SelfSignedCertificate) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_simple_quic_server_stats(
+    (SimpleQuicServer, SimpleQuicServerStats) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_string(
+    (SimpleQuicServer, String) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void
+  sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_simple_quic_server_u_64(
+    (SimpleQuicServer, BigInt) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_record_certificate_chain_private_key(
+    (CertificateChain, PrivateKey) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_self_signed_certificate(
+    SelfSignedCertificate self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_simple_quic_server_config(
+    SimpleQuicServerConfig self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_simple_quic_server_stats(
+    SimpleQuicServerStats self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_socket_address(SocketAddress self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_8(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_unit(void self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_web_transport_close_info(
+    WebTransportCloseInfo self,
+    SseSerializer serializer,
+  );
+}
+
+// Section: wire_class
+
+class RustLibWire implements BaseWire {
+  RustLibWire.fromExternalLibrary(ExternalLibrary lib);
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Router(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Router(
+        ptr,
+      );
 
-class RustLibWire implements BaseWire {
-  RustLibWire.fromExternalLibrary(ExternalLibrary lib);
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Router(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Router(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicKeyLog(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicKeyLog(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicKeyLog(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicKeyLog(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall(
+        ptr,
+      );
 
   void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient(
     int ptr,
   ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient(
         ptr,
       );
 
   void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient(
     int ptr,
   ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient(
         ptr,
       );
 
   void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcRegistry(
     int ptr,
   ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcRegistry(
         ptr,
       );
 
   void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcRegistry(
     int ptr,
   ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnection(
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcRegistry(
         ptr,
       );
 
   void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder(
     int ptr,
   ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder(
         ptr,
       );
 
   void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder(
     int ptr,
   ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder(
         ptr,
       );
 
   void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
     int ptr,
   ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
         ptr,
       );
 
   void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
     int ptr,
   ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpointConfig(
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
         ptr,
       );
 
   void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
     int ptr,
   ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
         ptr,
       );
 
   void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
     int ptr,
   ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
         ptr,
       );
 
   void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler(
     int ptr,
   ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler(
         ptr,
       );
 
   void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler(
     int ptr,
   ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler(
         ptr,
       );
 
   void
-  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
     int ptr,
   ) => wasmModule
-      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
         ptr,
       );
 
   void
-  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
     int ptr,
   ) => wasmModule
-      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicServerConfig(
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
         ptr,
       );
 
@@ -1088,6 +4565,38 @@ class RustLibWire implements BaseWire {
       .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
         ptr,
       );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+        ptr,
+      );
+
+  void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+        ptr,
+      );
+
+  void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+    int ptr,
+  ) => wasmModule
+      .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+        ptr,
+      );
 }
 
 @JS('wasm_bindgen')
@@ -1096,6 +4605,56 @@ external RustLibWasmModule get wasmModule;
 @JS()
 @anonymous
 extension type RustLibWasmModule._(JSObject _) implements JSObject {
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Client(
+    int ptr,
+  );
+
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ResponseStream(
+    int ptr,
+  );
+
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Router(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3Router(
+    int ptr,
+  );
+
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerHttp3ServerStream(
+    int ptr,
+  );
+
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicCertificateResolver(
+    int ptr,
+  );
+
   external void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicClient(
     int ptr,
@@ -1116,6 +4675,46 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     int ptr,
   );
 
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicConnectionPool(
+    int ptr,
+  );
+
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramChannel(
+    int ptr,
+  );
+
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramMux(
+    int ptr,
+  );
+
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicDatagramSubscription(
+    int ptr,
+  );
+
   external void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicEndpoint(
     int ptr,
@@ -1136,6 +4735,76 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     int ptr,
   );
 
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileReceiver(
+    int ptr,
+  );
+
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFileUpload(
+    int ptr,
+  );
+
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicFramedStream(
+    int ptr,
+  );
+
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicKeyLog(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicKeyLog(
+    int ptr,
+  );
+
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubBroker(
+    int ptr,
+  );
+
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubClient(
+    int ptr,
+  );
+
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicPubSubSubscription(
+    int ptr,
+  );
+
   external void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRecvStream(
     int ptr,
@@ -1146,6 +4815,46 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     int ptr,
   );
 
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcCall(
+    int ptr,
+  );
+
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcClient(
+    int ptr,
+  );
+
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcRegistry(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcRegistry(
+    int ptr,
+  );
+
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicRpcResponder(
+    int ptr,
+  );
+
   external void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicSendStream(
     int ptr,
@@ -1166,6 +4875,26 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
     int ptr,
   );
 
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamReassembler(
+    int ptr,
+  );
+
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicStreamingTransfer(
+    int ptr,
+  );
+
   external void
   rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
     int ptr,
@@ -1175,4 +4904,24 @@ extension type RustLibWasmModule._(JSObject _) implements JSObject {
   rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerQuicTransportConfig(
     int ptr,
   );
+
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSimpleQuicServer(
+    int ptr,
+  );
+
+  external void
+  rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+    int ptr,
+  );
+
+  external void
+  rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerWebTransportSession(
+    int ptr,
+  );
 }
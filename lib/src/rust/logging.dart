@@ -0,0 +1,75 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Structured field attached to a log record
+class QuicLogField {
+  final String name;
+  final String value;
+
+  const QuicLogField({required this.name, required this.value});
+
+  @override
+  int get hashCode => name.hashCode ^ value.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QuicLogField &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          value == other.value;
+}
+
+/// Severity of a log record
+enum QuicLogLevel { trace, debug, info, warn, error }
+
+/// A single tracing event
+class QuicLogRecord {
+  final QuicLogLevel level;
+
+  /// Module path or explicit target of the event (e.g. `quinn_proto::connection`)
+  final String target;
+  final String message;
+  final List<QuicLogField> fields;
+
+  /// Connection the event belongs to, taken from the enclosing connection span
+  final String? connectionId;
+
+  /// Unix timestamp in milliseconds
+  final PlatformInt64 timestampMs;
+
+  const QuicLogRecord({
+    required this.level,
+    required this.target,
+    required this.message,
+    required this.fields,
+    this.connectionId,
+    required this.timestampMs,
+  });
+
+  @override
+  int get hashCode =>
+      level.hashCode ^
+      target.hashCode ^
+      message.hashCode ^
+      fields.hashCode ^
+      connectionId.hashCode ^
+      timestampMs.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is QuicLogRecord &&
+          runtimeType == other.runtimeType &&
+          level == other.level &&
+          target == other.target &&
+          message == other.message &&
+          fields == other.fields &&
+          connectionId == other.connectionId &&
+          timestampMs == other.timestampMs;
+}
@@ -0,0 +1,86 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// Certificate chain for TLS
+class CertificateChain {
+  final List<Uint8List> certificates;
+
+  const CertificateChain({required this.certificates});
+
+  @override
+  int get hashCode => certificates.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CertificateChain &&
+          runtimeType == other.runtimeType &&
+          certificates == other.certificates;
+}
+
+/// Key algorithm for generated certificates
+enum CertificateKeyAlgorithm { ecdsaP256, ecdsaP384, ed25519 }
+
+/// Private key for TLS
+class PrivateKey {
+  final Uint8List keyData;
+  final PrivateKeyFormat format;
+
+  const PrivateKey({required this.keyData, required this.format});
+
+  @override
+  int get hashCode => keyData.hashCode ^ format.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is PrivateKey &&
+          runtimeType == other.runtimeType &&
+          keyData == other.keyData &&
+          format == other.format;
+}
+
+/// Encoding of a private key's DER bytes
+enum PrivateKeyFormat {
+  /// PKCS#8 `PrivateKeyInfo` (`BEGIN PRIVATE KEY`)
+  pkcs8,
+  /// SEC1 EC key (`BEGIN EC PRIVATE KEY`)
+  sec1,
+  /// PKCS#1 RSA key (`BEGIN RSA PRIVATE KEY`)
+  pkcs1,
+}
+
+/// Self-signed certificate with its key and SHA-256 fingerprint
+class SelfSignedCertificate {
+  final CertificateChain certificateChain;
+  final PrivateKey privateKey;
+
+  /// Lowercase hex SHA-256 digest of the DER certificate, for client-side pinning
+  final String sha256Fingerprint;
+
+  const SelfSignedCertificate({
+    required this.certificateChain,
+    required this.privateKey,
+    required this.sha256Fingerprint,
+  });
+
+  @override
+  int get hashCode =>
+      certificateChain.hashCode ^
+      privateKey.hashCode ^
+      sha256Fingerprint.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SelfSignedCertificate &&
+          runtimeType == other.runtimeType &&
+          certificateChain == other.certificateChain &&
+          privateKey == other.privateKey &&
+          sha256Fingerprint == other.sha256Fingerprint;
+}
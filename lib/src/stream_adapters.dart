@@ -0,0 +1,185 @@
+import 'dart:async';
+import 'dart:typed_data';
+
+import 'rust/api/bridge.dart';
+import 'rust/core/stream.dart';
+
+/// Receive the data of [stream] as a Dart stream
+///
+/// Each event holds the chunks that were available at once, so the stream
+/// takes as few calls across the bridge as the connection allows. Data is only
+/// read while the subscription is active; pausing it lets QUIC flow control
+/// slow down the peer. The stream closes once the peer finishes its send half,
+/// and a reset is delivered as a `QuicReadException` error. Cancelling the
+/// subscription stops the QUIC stream. [stream] is taken over and must not be
+/// used afterwards.
+///
+/// ```dart
+/// final (_, send, recv) = await connectionOpenBi(connection: connection);
+/// await quicRecvStreamData(recv).pipe(file.openWrite());
+/// ```
+Stream<Uint8List> quicRecvStreamData(
+  QuicRecvStream stream, {
+  int maxChunksPerRead = 16,
+}) async* {
+  var current = stream;
+  try {
+    while (true) {
+      final (next, chunks) = await recvStreamReadChunks(
+        stream: current,
+        maxChunks: BigInt.from(maxChunksPerRead),
+      );
+      current = next;
+      if (chunks == null) return;
+      yield _concat(chunks);
+    }
+  } finally {
+    current.dispose();
+  }
+}
+
+Uint8List _concat(List<QuicStreamChunk> chunks) {
+  if (chunks.length == 1) return chunks.single.data;
+  final builder = BytesBuilder(copy: false);
+  for (final chunk in chunks) {
+    builder.add(chunk.data);
+  }
+  return builder.takeBytes();
+}
+
+/// Writes to a [QuicSendStream] through the [StreamSink] interface
+///
+/// Data added with [add] is written in order; [addStream] waits for each
+/// write before taking the next event from its source, so a fast producer is
+/// held back by QUIC flow control. [close] finishes the stream once all data
+/// has been written. [addError] resets the stream instead, so the peer can
+/// tell an aborted transfer from a complete one. A failed write completes
+/// [done] with a `QuicWriteException` and drops later data.
+///
+/// ```dart
+/// final (_, send, recv) = await connectionOpenBi(connection: connection);
+/// final sink = QuicSendStreamSink(send);
+/// await file.openRead().pipe(sink);
+/// ```
+class QuicSendStreamSink implements StreamSink<List<int>> {
+  /// Take over [stream]; it must not be used afterwards
+  QuicSendStreamSink(QuicSendStream stream) : _stream = stream;
+
+  QuicSendStream _stream;
+  Future<void> _writes = Future.value();
+  final _done = Completer<void>();
+  var _closed = false;
+  var _addingStream = false;
+
+  /// Error code the stream is reset with when [addError] is called
+  static const int abortErrorCode = 0;
+
+  @override
+  Future<void> get done => _done.future;
+
+  @override
+  void add(List<int> data) {
+    _checkAdd();
+    _enqueue((stream) => sendStreamWriteAll(stream: stream, data: data));
+  }
+
+  @override
+  void addError(Object error, [StackTrace? stackTrace]) {
+    _checkAdd();
+    _abort(error, stackTrace);
+  }
+
+  @override
+  Future<void> addStream(Stream<List<int>> stream) async {
+    _checkAdd();
+    _addingStream = true;
+    try {
+      await for (final data in stream) {
+        if (_done.isCompleted) break;
+        _enqueue((stream) => sendStreamWriteAll(stream: stream, data: data));
+        await _writes;
+      }
+    } catch (error, stackTrace) {
+      _abort(error, stackTrace);
+    } finally {
+      _addingStream = false;
+    }
+  }
+
+  /// Wait until all data added so far has been written to the stream
+  Future<void> flush() => _writes;
+
+  /// Get write-side statistics once all data added so far has been written
+  Future<QuicSendStreamStats> stats() {
+    final stats = Completer<QuicSendStreamStats>();
+    _enqueue((stream) async {
+      final (next, result) = await sendStreamStats(stream: stream);
+      stats.complete(result);
+      return next;
+    });
+    _writes.whenComplete(() {
+      if (!stats.isCompleted) {
+        stats.completeError(StateError('QuicSendStreamSink has ended'));
+      }
+    });
+    return stats.future;
+  }
+
+  @override
+  Future<void> close() {
+    if (_addingStream) {
+      throw StateError('Cannot close QuicSendStreamSink while adding a stream');
+    }
+    if (!_closed) {
+      _closed = true;
+      _enqueue((stream) => sendStreamFinish(stream: stream));
+      _writes.whenComplete(() {
+        if (_done.isCompleted) return;
+        _stream.dispose();
+        _done.complete();
+      });
+    }
+    return done;
+  }
+
+  void _checkAdd() {
+    if (_closed) throw StateError('QuicSendStreamSink is closed');
+    if (_addingStream) {
+      throw StateError('QuicSendStreamSink is adding a stream');
+    }
+  }
+
+  void _enqueue(Future<QuicSendStream> Function(QuicSendStream) operation) {
+    _writes = _writes.then((_) async {
+      if (_done.isCompleted) return;
+      try {
+        _stream = await operation(_stream);
+      } catch (error, stackTrace) {
+        _fail(error, stackTrace);
+      }
+    });
+  }
+
+  void _abort(Object error, StackTrace? stackTrace) {
+    _closed = true;
+    _writes = _writes.then((_) async {
+      if (_done.isCompleted) return;
+      try {
+        _stream = await sendStreamReset(
+          stream: _stream,
+          errorCode: abortErrorCode,
+        );
+      } catch (_) {
+        // The stream already ended; report the original error
+      }
+      _fail(error, stackTrace);
+    });
+  }
+
+  void _fail(Object error, StackTrace? stackTrace) {
+    _closed = true;
+    if (_done.isCompleted) return;
+    _stream.dispose();
+    _done.completeError(error, stackTrace);
+  }
+}
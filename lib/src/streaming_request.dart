@@ -0,0 +1,141 @@
+import 'dart:async';
+import 'dart:typed_data';
+
+import 'rust/api/bridge.dart';
+import 'rust/convenience/client.dart';
+import 'rust/convenience/streaming.dart';
+
+/// A request started with [sendStreamingRequest]
+class QuicStreamingResponse {
+  QuicStreamingResponse._(this.client, this.transfer, this.body, this.done);
+
+  /// The client that was passed in; use this one for further requests
+  final QuicClient client;
+
+  /// Handle to the running transfer
+  final QuicStreamingTransfer transfer;
+
+  /// Response body chunks
+  ///
+  /// Pausing the subscription stops reading from the QUIC stream, and cancelling
+  /// it cancels the transfer.
+  final Stream<Uint8List> body;
+
+  /// Completes with the final progress once the whole response has been received
+  final Future<QuicTransferProgress> done;
+
+  /// Get the current progress
+  Future<QuicTransferProgress> progress() =>
+      streamingTransferProgress(transfer: transfer);
+
+  /// Cancel the transfer and reset the QUIC stream
+  Future<void> cancel() => streamingTransferCancel(transfer: transfer);
+}
+
+/// Send [body] to [url] and stream the response back
+///
+/// Chunks are pulled from [body] only as fast as the connection accepts them.
+/// If [contentLength] is given it is reported in progress events, and a body
+/// of any other length fails the transfer. [responseLength] does the same for
+/// the response; without it, progress reports the response length once the
+/// response has ended.
+///
+/// ```dart
+/// final response = await sendStreamingRequest(
+///   client: client,
+///   url: 'https://example.com/upload',
+///   body: file.openRead().map(Uint8List.fromList),
+///   contentLength: await file.length(),
+///   onProgress: (p) => print('${p.bytesSent} / ${p.totalToSend}'),
+/// );
+/// await response.body.pipe(output);
+/// ```
+Future<QuicStreamingResponse> sendStreamingRequest({
+  required QuicClient client,
+  required String url,
+  required Stream<Uint8List> body,
+  int? contentLength,
+  int? responseLength,
+  void Function(QuicTransferProgress progress)? onProgress,
+}) async {
+  final request = StreamIterator<Uint8List>(body);
+  final response = _ResponseController();
+
+  final (QuicClient nextClient, QuicStreamingTransfer transfer) =
+      await quicClientSendStreaming(
+        client: client,
+        url: url,
+        uploadLength: contentLength == null ? null : BigInt.from(contentLength),
+        responseLength:
+            responseLength == null ? null : BigInt.from(responseLength),
+        nextBodyChunk: () async =>
+            await request.moveNext() ? request.current : null,
+        onResponseChunk: response.add,
+        onProgress: (progress) => onProgress?.call(progress),
+      );
+  response.onCancel = () => streamingTransferCancel(transfer: transfer);
+
+  final done = streamingTransferWait(transfer: transfer);
+  unawaited(
+    done
+        .then<void>(
+          (_) => response.close(),
+          onError: (Object error, StackTrace stackTrace) =>
+              response.closeWithError(error, stackTrace),
+        )
+        .whenComplete(request.cancel),
+  );
+
+  return QuicStreamingResponse._(nextClient, transfer, response.stream, done);
+}
+
+/// Response stream whose `add` only completes while the listener wants more data
+class _ResponseController {
+  _ResponseController() {
+    _controller = StreamController<Uint8List>(
+      onListen: _resume,
+      onPause: _pause,
+      onResume: _resume,
+      onCancel: () {
+        _resume();
+        return onCancel?.call();
+      },
+    );
+  }
+
+  late final StreamController<Uint8List> _controller;
+
+  // Not flowing until someone listens
+  Completer<void>? _flowing = Completer<void>();
+
+  FutureOr<void> Function()? onCancel;
+
+  Stream<Uint8List> get stream => _controller.stream;
+
+  Future<void> add(Uint8List chunk) async {
+    if (_controller.isClosed) return;
+    _controller.add(chunk);
+
+    final flowing = _flowing;
+    if (flowing != null) await flowing.future;
+  }
+
+  void close() {
+    _resume();
+    if (!_controller.isClosed) _controller.close();
+  }
+
+  void closeWithError(Object error, StackTrace stackTrace) {
+    if (!_controller.isClosed) _controller.addError(error, stackTrace);
+    close();
+  }
+
+  void _pause() {
+    _flowing ??= Completer<void>();
+  }
+
+  void _resume() {
+    _flowing?.complete();
+    _flowing = null;
+  }
+}
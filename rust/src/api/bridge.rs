@@ -5,11 +5,32 @@ pub fn init_app() {
 }
 
 // Core API exposure functions to ensure flutter_rust_bridge discovers our types
-use crate::core::{QuicEndpoint, QuicConnection, QuicSendStream, QuicRecvStream};
+use crate::core::{QuicEndpoint, QuicConnection, QuicSendStream, QuicRecvStream, QuicStreamChunk};
+use crate::core::{QuicChunksWritten, QuicSendStreamStats};
 use crate::core::{QuicConnectionStats, QuicPathStats, QuicFrameStats, QuicUdpStats};
-use crate::core::{QuicServerConfig, QuicTransportConfig, QuicEndpointConfig};
-use crate::convenience::{QuicClient, QuicClientConfig};
+use crate::core::{QuicServerConfig, QuicTransportConfig, QuicEndpointConfig, QuicKeyLog, QuicCertificateResolver};
+use crate::core::{QuicDatagramSubscription, QuicDatagramSubscriptionConfig, QuicDatagramDropPolicy, QuicDatagramBatch, QuicConnectionEnd, QuicConnectionEndKind};
+use crate::convenience::{QuicClient, QuicClientConfig, SimpleQuicServer, SimpleQuicServerConfig, SimpleQuicServerStats};
+use crate::convenience::{QuicStreamingTransfer, QuicTransferProgress};
+use crate::convenience::{Http3Client, Http3ClientConfig, Http3Header, Http3Request, Http3Response, Http3ResponseStream};
+use crate::convenience::{Http3Router, Http3ServerRequest, Http3ServerStream};
+use crate::convenience::{WebTransportCloseInfo, WebTransportSession};
+use crate::convenience::{QuicDatagramChannel, QuicDatagramChannelConfig, QuicDatagramMux};
+use crate::convenience::QuicFramedStream;
+use crate::convenience::QuicStreamReassembler;
+use crate::convenience::{QuicRpcCall, QuicRpcCallOptions, QuicRpcClient, QuicRpcClientConfig, QuicRpcCode, QuicRpcMetadata};
+use crate::convenience::{QuicRpcRegistry, QuicRpcRequest, QuicRpcResponder, QuicRpcStatus};
+use crate::convenience::{QuicPubSubBroker, QuicPubSubBrokerConfig, QuicPubSubClient, QuicPubSubDelivery};
+use crate::convenience::{QuicPubSubMessage, QuicPubSubSubscribeOptions, QuicPubSubSubscription};
+use crate::convenience::{QuicFileReceiver, QuicFileTransferConfig, QuicFileTransferProgress, QuicFileUpload};
+use crate::convenience::{QuicConnectionPool, QuicConnectionPoolConfig, QuicConnectionPoolStats, QuicAuthorityPoolStats};
 use crate::errors::{QuicError, QuicWriteException, QuicReadException, QuicReadToEndException, QuicDatagramException};
+use crate::errors::{QuicFrameException, QuicFrameErrorKind};
+use crate::errors::{QuicFileTransferException, QuicFileTransferErrorKind};
+use crate::models::certificate::{CertificateChain, PrivateKey, PrivateKeyFormat, SelfSignedCertificate, CertificateKeyAlgorithm};
+use crate::logging::{QuicLogRecord, QuicLogField, QuicLogLevel};
+use crate::frb_generated::StreamSink;
+use flutter_rust_bridge::DartFnFuture;
 
 
 
@@ -23,9 +44,27 @@ pub fn create_client_endpoint() -> Result<QuicEndpoint, QuicError> {
     })
 }
 
+/// Create a new QUIC client endpoint that logs TLS secrets to `key_log`
+///
+/// The endpoint is driven by the bridge's runtime, so it stays usable after
+/// this call returns.
+pub async fn create_client_endpoint_with_key_log(key_log: QuicKeyLog) -> Result<QuicEndpoint, QuicError> {
+    QuicEndpoint::client_with_key_log(key_log)
+}
+
+/// Create a new QUIC client endpoint that offers `alpn_protocols` during the handshake
+///
+/// Use `[utf8.encode('h3')]` for WebTransport sessions.
+pub async fn create_client_endpoint_with_alpn(alpn_protocols: Vec<Vec<u8>>) -> Result<QuicEndpoint, QuicError> {
+    QuicEndpoint::client_with_alpn(alpn_protocols)
+}
+
 /// Create a new QUIC server endpoint
-pub fn create_server_endpoint(config: QuicServerConfig, addr: String) -> Result<QuicEndpoint, QuicError> {
-    QuicEndpoint::server(config, addr)
+///
+/// The endpoint is driven by the bridge's runtime, so it keeps accepting
+/// connections after this call returns.
+pub async fn create_server_endpoint(config: QuicServerConfig, addr: String) -> Result<QuicEndpoint, QuicError> {
+    QuicEndpoint::bind_server(config, addr)
 }
 
 /// Write data to a QUIC send stream
@@ -48,6 +87,33 @@ pub async fn send_stream_write_all(
     Ok(stream)
 }
 
+/// Write several buffers to a QUIC send stream without concatenating them
+/// This exposes the QuicSendStream.write_chunks() method to flutter_rust_bridge
+pub async fn send_stream_write_chunks(
+    mut stream: QuicSendStream,
+    chunks: Vec<Vec<u8>>,
+) -> Result<(QuicSendStream, QuicChunksWritten), QuicWriteException> {
+    let written = stream.write_chunks(chunks).await?;
+    Ok((stream, written))
+}
+
+/// Write several buffers to a QUIC send stream in their entirety
+/// This exposes the QuicSendStream.write_all_chunks() method to flutter_rust_bridge
+pub async fn send_stream_write_all_chunks(
+    mut stream: QuicSendStream,
+    chunks: Vec<Vec<u8>>,
+) -> Result<QuicSendStream, QuicWriteException> {
+    stream.write_all_chunks(chunks).await?;
+    Ok(stream)
+}
+
+/// Get write-side statistics of a QUIC send stream
+/// This exposes the QuicSendStream.stats() method to flutter_rust_bridge
+pub fn send_stream_stats(stream: QuicSendStream) -> (QuicSendStream, QuicSendStreamStats) {
+    let stats = stream.stats();
+    (stream, stats)
+}
+
 /// Finish a QUIC send stream
 /// This exposes the QuicSendStream.finish() method to flutter_rust_bridge
 pub fn send_stream_finish(
@@ -57,6 +123,16 @@ pub fn send_stream_finish(
     Ok(stream)
 }
 
+/// Reset a QUIC send stream, abandoning any unsent data
+/// This exposes the QuicSendStream.reset() method to flutter_rust_bridge
+pub fn send_stream_reset(
+    mut stream: QuicSendStream,
+    error_code: u32,
+) -> Result<QuicSendStream, QuicWriteException> {
+    stream.reset(error_code)?;
+    Ok(stream)
+}
+
 /// Read data from a QUIC recv stream
 /// This exposes the QuicRecvStream.read() method to flutter_rust_bridge
 pub async fn recv_stream_read(
@@ -67,6 +143,27 @@ pub async fn recv_stream_read(
     Ok((stream, data))
 }
 
+/// Read the next chunk of a QUIC recv stream together with its stream offset
+/// This exposes the QuicRecvStream.read_chunk() method to flutter_rust_bridge
+pub async fn recv_stream_read_chunk(
+    mut stream: QuicRecvStream,
+    max_length: usize,
+    ordered: bool,
+) -> Result<(QuicRecvStream, Option<QuicStreamChunk>), QuicReadException> {
+    let chunk = stream.read_chunk(max_length, ordered).await?;
+    Ok((stream, chunk))
+}
+
+/// Read every chunk already available on a QUIC recv stream, in stream order
+/// This exposes the QuicRecvStream.read_chunks() method to flutter_rust_bridge
+pub async fn recv_stream_read_chunks(
+    mut stream: QuicRecvStream,
+    max_chunks: usize,
+) -> Result<(QuicRecvStream, Option<Vec<QuicStreamChunk>>), QuicReadException> {
+    let chunks = stream.read_chunks(max_chunks).await?;
+    Ok((stream, chunks))
+}
+
 /// Read all remaining data from a QUIC recv stream
 /// This exposes the QuicRecvStream.read_to_end() method to flutter_rust_bridge
 pub async fn recv_stream_read_to_end(
@@ -95,6 +192,24 @@ pub async fn connection_open_uni(
     Ok((connection, send_stream))
 }
 
+/// Accept a bidirectional stream opened by the peer
+/// This exposes the QuicConnection.accept_bi() method to flutter_rust_bridge
+pub async fn connection_accept_bi(
+    connection: QuicConnection,
+) -> Result<(QuicConnection, QuicSendStream, QuicRecvStream), QuicError> {
+    let (send_stream, recv_stream) = connection.accept_bi().await?;
+    Ok((connection, send_stream, recv_stream))
+}
+
+/// Accept a unidirectional stream opened by the peer
+/// This exposes the QuicConnection.accept_uni() method to flutter_rust_bridge
+pub async fn connection_accept_uni(
+    connection: QuicConnection,
+) -> Result<(QuicConnection, QuicRecvStream), QuicError> {
+    let recv_stream = connection.accept_uni().await?;
+    Ok((connection, recv_stream))
+}
+
 /// Connect to a server using a QUIC endpoint
 /// This exposes the QuicEndpoint.connect() method to flutter_rust_bridge
 pub async fn endpoint_connect(
@@ -106,6 +221,25 @@ pub async fn endpoint_connect(
     Ok((endpoint, connection))
 }
 
+/// Accept the next incoming connection on a server endpoint
+/// This exposes the QuicEndpoint.accept() method to flutter_rust_bridge
+pub async fn endpoint_accept(
+    endpoint: QuicEndpoint,
+) -> Result<(QuicEndpoint, Option<QuicConnection>), QuicError> {
+    let connection = endpoint.accept().await?;
+    Ok((endpoint, connection))
+}
+
+/// Replace the server config used for new incoming connections
+/// This exposes the QuicEndpoint.set_server_config() method to flutter_rust_bridge
+pub fn endpoint_set_server_config(
+    mut endpoint: QuicEndpoint,
+    config: QuicServerConfig,
+) -> QuicEndpoint {
+    endpoint.set_server_config(config);
+    endpoint
+}
+
 /// Send a datagram on a QUIC connection
 /// This exposes the QuicConnection.send_datagram() method to flutter_rust_bridge
 pub fn connection_send_datagram(
@@ -135,6 +269,122 @@ pub async fn connection_read_datagram(
     (connection, data)
 }
 
+/// Create a new QuicDatagramSubscriptionConfig with default values
+pub fn datagram_subscription_config_new() -> QuicDatagramSubscriptionConfig {
+    QuicDatagramSubscriptionConfig::default()
+}
+
+/// Receive datagrams in the background into a bounded queue
+/// This exposes the QuicConnection.subscribe_datagrams() method to flutter_rust_bridge
+///
+/// The connection is borrowed, so the caller keeps using it while subscribed.
+pub async fn connection_subscribe_datagrams(
+    connection: &QuicConnection,
+    config: QuicDatagramSubscriptionConfig,
+) -> Result<QuicDatagramSubscription, QuicError> {
+    connection.subscribe_datagrams(config)
+}
+
+/// Wait for datagrams and take everything a subscription has queued
+pub async fn datagram_subscription_next_batch(
+    subscription: &QuicDatagramSubscription,
+) -> Result<QuicDatagramBatch, QuicError> {
+    subscription.next_batch().await
+}
+
+/// Stop a datagram subscription
+pub fn datagram_subscription_close(subscription: &QuicDatagramSubscription) {
+    subscription.close();
+}
+
+/// Create a new QuicDatagramChannelConfig with default values
+pub fn datagram_channel_config_new() -> QuicDatagramChannelConfig {
+    QuicDatagramChannelConfig::default()
+}
+
+/// Route a connection's datagrams to channels by flow ID
+pub async fn connection_datagram_mux(
+    connection: QuicConnection,
+    config: QuicDatagramChannelConfig,
+) -> Result<(QuicConnection, QuicDatagramMux), QuicError> {
+    let mux = QuicDatagramMux::new(&connection, config)?;
+    Ok((connection, mux))
+}
+
+/// Open the datagram channel for a flow ID
+pub fn datagram_mux_open_channel(mux: &QuicDatagramMux, flow_id: u64) -> Result<QuicDatagramChannel, QuicError> {
+    mux.open_channel(flow_id)
+}
+
+/// Get the flow ID of a datagram channel
+pub fn datagram_channel_flow_id(channel: &QuicDatagramChannel) -> u64 {
+    channel.flow_id()
+}
+
+/// Get the largest payload that fits a single datagram on a channel
+pub fn datagram_channel_max_payload_size(channel: &QuicDatagramChannel) -> Option<usize> {
+    channel.max_payload_size()
+}
+
+/// Send a payload on a datagram channel, fragmenting it if enabled
+pub async fn datagram_channel_send(channel: &QuicDatagramChannel, data: Vec<u8>) -> Result<(), QuicDatagramException> {
+    channel.send(data).await
+}
+
+/// Wait for payloads and take everything queued on a datagram channel
+pub async fn datagram_channel_next_batch(channel: &QuicDatagramChannel) -> Result<QuicDatagramBatch, QuicError> {
+    channel.next_batch().await
+}
+
+/// Close a datagram channel and free its flow ID
+pub fn datagram_channel_close(channel: &QuicDatagramChannel) {
+    channel.close();
+}
+
+/// Wrap a stream pair to send and receive length-prefixed messages
+pub fn framed_stream_new(
+    send: Option<QuicSendStream>,
+    recv: Option<QuicRecvStream>,
+    max_message_size: usize,
+) -> QuicFramedStream {
+    QuicFramedStream::new(send, recv, max_message_size)
+}
+
+/// Send one message on a framed stream
+pub async fn framed_stream_send(stream: &QuicFramedStream, message: Vec<u8>) -> Result<(), QuicFrameException> {
+    stream.send(message).await
+}
+
+/// Receive the next message from a framed stream, or `None` at the end of the stream
+pub async fn framed_stream_recv(stream: &QuicFramedStream) -> Result<Option<Vec<u8>>, QuicFrameException> {
+    stream.recv().await
+}
+
+/// Finish the send half of a framed stream
+pub async fn framed_stream_finish(stream: &QuicFramedStream) -> Result<(), QuicFrameException> {
+    stream.finish().await
+}
+
+/// Create a reassembler that puts unordered stream chunks back in order
+pub fn stream_reassembler_new() -> QuicStreamReassembler {
+    QuicStreamReassembler::new()
+}
+
+/// Add an unordered chunk and get the data that became contiguous
+pub fn stream_reassembler_push(reassembler: &QuicStreamReassembler, chunk: QuicStreamChunk) -> Vec<u8> {
+    reassembler.push(chunk)
+}
+
+/// Get the stream offset up to which a reassembler has released data
+pub fn stream_reassembler_contiguous_offset(reassembler: &QuicStreamReassembler) -> u64 {
+    reassembler.contiguous_offset()
+}
+
+/// Get the number of bytes a reassembler holds until a gap is filled
+pub fn stream_reassembler_buffered_bytes(reassembler: &QuicStreamReassembler) -> usize {
+    reassembler.buffered_bytes()
+}
+
 /// Get datagram send buffer space
 /// This exposes the QuicConnection.datagram_send_buffer_space() method to flutter_rust_bridge
 pub fn connection_datagram_send_buffer_space(
@@ -223,6 +473,200 @@ pub fn server_config_with_single_cert(
     QuicServerConfig::with_single_cert(cert_chain, key)
 }
 
+/// Create a new server config from a loaded certificate chain and private key
+pub fn server_config_with_certificate(
+    cert_chain: CertificateChain,
+    key: PrivateKey,
+) -> Result<QuicServerConfig, String> {
+    QuicServerConfig::with_certificate(cert_chain, key)
+}
+
+/// Load a certificate chain from PEM data
+pub fn certificate_chain_from_pem(pem: Vec<u8>) -> Result<CertificateChain, QuicError> {
+    CertificateChain::from_pem(&pem)
+}
+
+/// Load a PKCS#8, SEC1 or RSA private key from PEM data
+pub fn private_key_from_pem(pem: Vec<u8>) -> Result<PrivateKey, QuicError> {
+    PrivateKey::from_pem(&pem)
+}
+
+/// Load the certificate chain and private key from a password protected PKCS#12 bundle
+pub fn load_pkcs12(data: Vec<u8>, password: String) -> Result<(CertificateChain, PrivateKey), QuicError> {
+    crate::models::certificate::load_pkcs12(&data, &password)
+}
+
+/// Create a new server config that picks certificates by SNI from `resolver`
+pub fn server_config_with_cert_resolver(
+    resolver: QuicCertificateResolver,
+    alpn_protocols: Vec<Vec<u8>>,
+) -> Result<QuicServerConfig, String> {
+    QuicServerConfig::with_cert_resolver(resolver, alpn_protocols)
+}
+
+/// Set the transport config used for incoming connections
+pub fn server_config_transport_config(
+    mut config: QuicServerConfig,
+    transport_config: QuicTransportConfig,
+) -> QuicServerConfig {
+    config.transport_config(transport_config);
+    config
+}
+
+/// Set the maximum number of incoming connections buffered before they are accepted
+pub fn server_config_max_incoming(mut config: QuicServerConfig, max_incoming: usize) -> QuicServerConfig {
+    config.max_incoming(max_incoming);
+    config
+}
+
+/// Set how long retry tokens remain valid
+pub fn server_config_retry_token_lifetime(mut config: QuicServerConfig, lifetime_ms: u64) -> QuicServerConfig {
+    config.retry_token_lifetime(lifetime_ms);
+    config
+}
+
+/// Allow or forbid clients to migrate to a new address
+pub fn server_config_migration(mut config: QuicServerConfig, allow: bool) -> QuicServerConfig {
+    config.migration(allow);
+    config
+}
+
+/// Set the IPv4 preferred address advertised to clients, e.g. "203.0.113.1:443"
+pub fn server_config_preferred_address_v4(
+    mut config: QuicServerConfig,
+    addr: Option<String>,
+) -> Result<QuicServerConfig, String> {
+    config.preferred_address_v4(addr)?;
+    Ok(config)
+}
+
+/// Set the IPv6 preferred address advertised to clients, e.g. "[2001:db8::1]:443"
+pub fn server_config_preferred_address_v6(
+    mut config: QuicServerConfig,
+    addr: Option<String>,
+) -> Result<QuicServerConfig, String> {
+    config.preferred_address_v6(addr)?;
+    Ok(config)
+}
+
+/// Set the maximum number of bytes buffered for a single incoming connection
+pub fn server_config_incoming_buffer_size(mut config: QuicServerConfig, size: u64) -> QuicServerConfig {
+    config.incoming_buffer_size(size);
+    config
+}
+
+/// Set the maximum number of bytes buffered across all incoming connections
+pub fn server_config_incoming_buffer_size_total(mut config: QuicServerConfig, size: u64) -> QuicServerConfig {
+    config.incoming_buffer_size_total(size);
+    config
+}
+
+/// Require clients to prove their address with a Retry before they are accepted
+pub fn server_config_use_retry(mut config: QuicServerConfig, enabled: bool) -> QuicServerConfig {
+    config.use_retry(enabled);
+    config
+}
+
+/// Whether endpoints created from the config validate client addresses with a Retry
+pub fn server_config_retry_enabled(config: QuicServerConfig) -> (QuicServerConfig, bool) {
+    let enabled = config.retry_enabled();
+    (config, enabled)
+}
+
+/// Create an empty SNI certificate resolver
+pub fn cert_resolver_new() -> QuicCertificateResolver {
+    QuicCertificateResolver::new()
+}
+
+/// Add or replace the certificate served for `server_name`
+pub fn cert_resolver_add(
+    resolver: QuicCertificateResolver,
+    server_name: String,
+    cert_chain: CertificateChain,
+    key: PrivateKey,
+) -> Result<QuicCertificateResolver, QuicError> {
+    resolver.add(server_name, cert_chain, key)?;
+    Ok(resolver)
+}
+
+/// Stop serving a certificate for `server_name`
+pub fn cert_resolver_remove(
+    resolver: QuicCertificateResolver,
+    server_name: String,
+) -> (QuicCertificateResolver, bool) {
+    let removed = resolver.remove(server_name);
+    (resolver, removed)
+}
+
+/// Set the certificate used when no registered server name matches
+pub fn cert_resolver_set_default(
+    resolver: QuicCertificateResolver,
+    cert_chain: CertificateChain,
+    key: PrivateKey,
+) -> Result<QuicCertificateResolver, QuicError> {
+    resolver.set_default(cert_chain, key)?;
+    Ok(resolver)
+}
+
+/// Generate a self-signed certificate for the given DNS names and IP addresses
+pub fn generate_self_signed_certificate(
+    subject_alt_names: Vec<String>,
+    validity_days: u32,
+    algorithm: CertificateKeyAlgorithm,
+) -> Result<SelfSignedCertificate, QuicError> {
+    crate::models::certificate::generate_self_signed(subject_alt_names, validity_days, algorithm)
+}
+
+/// Create a new server config with ALPN protocols that logs TLS secrets to `key_log`
+pub fn server_config_with_crypto_and_key_log(
+    cert_chain: Vec<Vec<u8>>,
+    key: Vec<u8>,
+    alpn_protocols: Vec<Vec<u8>>,
+    key_log: QuicKeyLog,
+) -> Result<QuicServerConfig, String> {
+    QuicServerConfig::with_crypto_and_key_log(cert_chain, key, alpn_protocols, key_log)
+}
+
+/// Create a new server config that picks certificates by SNI from `resolver` and logs TLS secrets to `key_log`
+pub fn server_config_with_cert_resolver_and_key_log(
+    resolver: QuicCertificateResolver,
+    alpn_protocols: Vec<Vec<u8>>,
+    key_log: QuicKeyLog,
+) -> Result<QuicServerConfig, String> {
+    QuicServerConfig::with_cert_resolver_and_key_log(resolver, alpn_protocols, key_log)
+}
+
+/// Create a key log that appends NSS key log lines to a file (Wireshark compatible)
+pub fn key_log_file(path: String) -> Result<QuicKeyLog, QuicError> {
+    QuicKeyLog::file(path)
+}
+
+/// Create a key log that delivers each NSS key log line to a Dart callback
+pub async fn key_log_callback(
+    on_line: impl Fn(String) -> DartFnFuture<()> + Send + Sync + 'static,
+) -> QuicKeyLog {
+    QuicKeyLog::callback(forward_to_dart(on_line))
+}
+
+/// Turn a Dart callback into a synchronous one that delivers values in order
+///
+/// A Dart callback only runs once its future is awaited, so values are queued
+/// to a task that awaits each call before making the next.
+fn forward_to_dart<T: Send + 'static>(
+    callback: impl Fn(T) -> DartFnFuture<()> + Send + Sync + 'static,
+) -> impl Fn(T) + Send + Sync + 'static {
+    let (sender, mut receiver) = tokio::sync::mpsc::unbounded_channel();
+    tokio::spawn(async move {
+        while let Some(value) = receiver.recv().await {
+            // A throwing callback panics its own task instead of ending delivery
+            let _ = tokio::spawn(callback(value)).await;
+        }
+    });
+    move |value| {
+        let _ = sender.send(value);
+    }
+}
+
 /// Create a new transport config
 pub fn transport_config_new() -> QuicTransportConfig {
     QuicTransportConfig::new()
@@ -233,24 +677,110 @@ pub fn endpoint_config_new() -> QuicEndpointConfig {
     QuicEndpointConfig::new()
 }
 
+// Logging bridge functions
+
+/// Forward tracing output from quinn and flutter_quic to a Dart stream
+///
+/// `filter` uses `EnvFilter` directive syntax, e.g. "info,quinn=debug".
+/// Calling this again replaces the stream and the filter.
+pub fn init_logging(filter: String, sink: StreamSink<QuicLogRecord>) -> Result<(), QuicError> {
+    crate::logging::init_logging(&filter, move |record| {
+        let _ = sink.add(record);
+    })
+}
+
+/// Change the log filter directives at runtime
+pub fn set_log_filter(filter: String) -> Result<(), QuicError> {
+    crate::logging::set_log_filter(&filter)
+}
+
 // Type exposure functions to ensure flutter_rust_bridge discovers our types
 pub fn _expose_types_for_frb_generation() {
     let _endpoint: Option<QuicEndpoint> = None;
     let _connection: Option<QuicConnection> = None;
     let _send_stream: Option<QuicSendStream> = None;
     let _recv_stream: Option<QuicRecvStream> = None;
+    let _stream_chunk: Option<QuicStreamChunk> = None;
+    let _chunks_written: Option<QuicChunksWritten> = None;
+    let _send_stream_stats: Option<QuicSendStreamStats> = None;
     let _error: Option<QuicError> = None;
     let _write_exception: Option<QuicWriteException> = None;
     let _read_exception: Option<QuicReadException> = None;
     let _read_to_end_exception: Option<QuicReadToEndException> = None;
     let _datagram_exception: Option<QuicDatagramException> = None;
+    let _frame_exception: Option<QuicFrameException> = None;
+    let _frame_error_kind: Option<QuicFrameErrorKind> = None;
+    let _file_transfer_exception: Option<QuicFileTransferException> = None;
+    let _file_transfer_error_kind: Option<QuicFileTransferErrorKind> = None;
     let _connection_stats: Option<QuicConnectionStats> = None;
     let _path_stats: Option<QuicPathStats> = None;
     let _frame_stats: Option<QuicFrameStats> = None;
     let _udp_stats: Option<QuicUdpStats> = None;
+    let _datagram_subscription: Option<QuicDatagramSubscription> = None;
+    let _datagram_subscription_config: Option<QuicDatagramSubscriptionConfig> = None;
+    let _datagram_drop_policy: Option<QuicDatagramDropPolicy> = None;
+    let _datagram_batch: Option<QuicDatagramBatch> = None;
+    let _connection_end: Option<QuicConnectionEnd> = None;
+    let _connection_end_kind: Option<QuicConnectionEndKind> = None;
+    let _datagram_mux: Option<QuicDatagramMux> = None;
+    let _datagram_channel: Option<QuicDatagramChannel> = None;
+    let _datagram_channel_config: Option<QuicDatagramChannelConfig> = None;
+    let _framed_stream: Option<QuicFramedStream> = None;
+    let _stream_reassembler: Option<QuicStreamReassembler> = None;
+    let _rpc_client: Option<QuicRpcClient> = None;
+    let _rpc_client_config: Option<QuicRpcClientConfig> = None;
+    let _rpc_call: Option<QuicRpcCall> = None;
+    let _rpc_call_options: Option<QuicRpcCallOptions> = None;
+    let _rpc_code: Option<QuicRpcCode> = None;
+    let _rpc_status: Option<QuicRpcStatus> = None;
+    let _rpc_metadata: Option<QuicRpcMetadata> = None;
+    let _rpc_registry: Option<QuicRpcRegistry> = None;
+    let _rpc_request: Option<QuicRpcRequest> = None;
+    let _rpc_responder: Option<QuicRpcResponder> = None;
+    let _pubsub_broker: Option<QuicPubSubBroker> = None;
+    let _pubsub_broker_config: Option<QuicPubSubBrokerConfig> = None;
+    let _pubsub_client: Option<QuicPubSubClient> = None;
+    let _pubsub_delivery: Option<QuicPubSubDelivery> = None;
+    let _pubsub_message: Option<QuicPubSubMessage> = None;
+    let _pubsub_subscribe_options: Option<QuicPubSubSubscribeOptions> = None;
+    let _pubsub_subscription: Option<QuicPubSubSubscription> = None;
+    let _file_upload: Option<QuicFileUpload> = None;
+    let _file_receiver: Option<QuicFileReceiver> = None;
+    let _file_transfer_config: Option<QuicFileTransferConfig> = None;
+    let _file_transfer_progress: Option<QuicFileTransferProgress> = None;
     let _server_config: Option<QuicServerConfig> = None;
     let _transport_config: Option<QuicTransportConfig> = None;
     let _endpoint_config: Option<QuicEndpointConfig> = None;
+    let _key_log: Option<QuicKeyLog> = None;
+    let _cert_resolver: Option<QuicCertificateResolver> = None;
+    let _certificate_chain: Option<CertificateChain> = None;
+    let _private_key: Option<PrivateKey> = None;
+    let _private_key_format: Option<PrivateKeyFormat> = None;
+    let _self_signed_certificate: Option<SelfSignedCertificate> = None;
+    let _certificate_key_algorithm: Option<CertificateKeyAlgorithm> = None;
+    let _log_record: Option<QuicLogRecord> = None;
+    let _log_field: Option<QuicLogField> = None;
+    let _log_level: Option<QuicLogLevel> = None;
+    let _simple_server: Option<SimpleQuicServer> = None;
+    let _simple_server_config: Option<SimpleQuicServerConfig> = None;
+    let _simple_server_stats: Option<SimpleQuicServerStats> = None;
+    let _streaming_transfer: Option<QuicStreamingTransfer> = None;
+    let _transfer_progress: Option<QuicTransferProgress> = None;
+    let _connection_pool: Option<QuicConnectionPool> = None;
+    let _connection_pool_config: Option<QuicConnectionPoolConfig> = None;
+    let _connection_pool_stats: Option<QuicConnectionPoolStats> = None;
+    let _authority_pool_stats: Option<QuicAuthorityPoolStats> = None;
+    let _http3_client: Option<Http3Client> = None;
+    let _http3_client_config: Option<Http3ClientConfig> = None;
+    let _http3_header: Option<Http3Header> = None;
+    let _http3_request: Option<Http3Request> = None;
+    let _http3_response: Option<Http3Response> = None;
+    let _http3_response_stream: Option<Http3ResponseStream> = None;
+    let _http3_router: Option<Http3Router> = None;
+    let _http3_server_request: Option<Http3ServerRequest> = None;
+    let _http3_server_stream: Option<Http3ServerStream> = None;
+    let _webtransport_session: Option<WebTransportSession> = None;
+    let _webtransport_close_info: Option<WebTransportCloseInfo> = None;
 }
 
 // Legacy expose functions for backwards compatibility with generated code
@@ -308,6 +838,92 @@ pub async fn quic_client_send_with_timeout(
     Ok((client, response))
 }
 
+/// Send binary data using QuicClient and return the binary response
+pub async fn quic_client_send_bytes(
+    client: QuicClient,
+    url: String,
+    data: Vec<u8>,
+) -> Result<(QuicClient, Vec<u8>), QuicError> {
+    let response = client.send_bytes(url, data).await?;
+    Ok((client, response))
+}
+
+/// Send binary data with timeout using QuicClient
+pub async fn quic_client_send_bytes_with_timeout(
+    client: QuicClient,
+    url: String,
+    data: Vec<u8>,
+) -> Result<(QuicClient, Vec<u8>), QuicError> {
+    let response = client.send_bytes_with_timeout(url, data).await?;
+    Ok((client, response))
+}
+
+/// Send a GET request using QuicClient and return the binary response
+pub async fn quic_client_get_bytes(
+    client: QuicClient,
+    url: String,
+) -> Result<(QuicClient, Vec<u8>), QuicError> {
+    let response = client.get_bytes(url).await?;
+    Ok((client, response))
+}
+
+/// Send a POST request with a binary body using QuicClient
+pub async fn quic_client_post_bytes(
+    client: QuicClient,
+    url: String,
+    data: Vec<u8>,
+) -> Result<(QuicClient, Vec<u8>), QuicError> {
+    let response = client.post_bytes(url, data).await?;
+    Ok((client, response))
+}
+
+/// Start a streaming request using QuicClient
+///
+/// `next_body_chunk` is called whenever the stream can take more data and returns
+/// `null` at the end of the body. `on_response_chunk` is awaited before the next
+/// response chunk is read, so a slow consumer slows the server down.
+/// `on_progress` events are delivered in order without holding up the transfer.
+pub async fn quic_client_send_streaming(
+    client: QuicClient,
+    url: String,
+    upload_length: Option<u64>,
+    response_length: Option<u64>,
+    next_body_chunk: impl Fn() -> DartFnFuture<Option<Vec<u8>>> + Send + Sync + 'static,
+    on_response_chunk: impl Fn(Vec<u8>) -> DartFnFuture<()> + Send + Sync + 'static,
+    on_progress: impl Fn(QuicTransferProgress) -> DartFnFuture<()> + Send + Sync + 'static,
+) -> Result<(QuicClient, QuicStreamingTransfer), QuicError> {
+    let body = futures::stream::unfold((), move |()| {
+        let chunk = next_body_chunk();
+        async move { chunk.await.map(|chunk| (chunk, ())) }
+    });
+    let transfer = client.send_streaming(
+        url,
+        upload_length,
+        response_length,
+        body,
+        on_response_chunk,
+        forward_to_dart(on_progress),
+    ).await?;
+    Ok((client, transfer))
+}
+
+// Streaming transfers are borrowed so `cancel` can run while `wait` is pending
+
+/// Wait for a streaming transfer to finish and return its final progress
+pub async fn streaming_transfer_wait(transfer: &QuicStreamingTransfer) -> Result<QuicTransferProgress, QuicError> {
+    transfer.wait().await
+}
+
+/// Cancel a streaming transfer, resetting its QUIC stream
+pub fn streaming_transfer_cancel(transfer: &QuicStreamingTransfer) {
+    transfer.cancel();
+}
+
+/// Get the current progress of a streaming transfer
+pub fn streaming_transfer_progress(transfer: &QuicStreamingTransfer) -> QuicTransferProgress {
+    transfer.progress()
+}
+
 /// Send a GET request using QuicClient
 pub async fn quic_client_get(
     client: QuicClient,
@@ -371,3 +987,585 @@ pub fn _expose_quic_client_type(client: QuicClient) -> QuicClient {
 pub fn _expose_quic_client_config_type(config: QuicClientConfig) -> QuicClientConfig {
     config
 }
+
+/// Create a new SimpleQuicServerConfig with default values
+pub fn simple_quic_server_config_new() -> SimpleQuicServerConfig {
+    SimpleQuicServerConfig::default()
+}
+
+/// Bind a SimpleQuicServer with the given TLS configuration
+pub async fn simple_quic_server_bind(
+    server_config: QuicServerConfig,
+    addr: String,
+    config: SimpleQuicServerConfig,
+) -> Result<SimpleQuicServer, QuicError> {
+    SimpleQuicServer::bind(server_config, addr, config)
+}
+
+/// Bind a SimpleQuicServer with a generated self-signed certificate for development
+pub async fn simple_quic_server_bind_self_signed(
+    addr: String,
+    subject_alt_names: Vec<String>,
+    config: SimpleQuicServerConfig,
+) -> Result<(SimpleQuicServer, SelfSignedCertificate), QuicError> {
+    SimpleQuicServer::bind_self_signed(addr, subject_alt_names, config)
+}
+
+/// Start answering requests with a Dart handler
+///
+/// A handler that throws resets the request stream instead of answering it.
+pub async fn simple_quic_server_serve(
+    server: SimpleQuicServer,
+    handler: impl Fn(Vec<u8>) -> DartFnFuture<Vec<u8>> + Send + Sync + 'static,
+) -> Result<SimpleQuicServer, QuicError> {
+    server.serve(move |request| {
+        let response = handler(request);
+        async move { Ok(response.await) }
+    })?;
+    Ok(server)
+}
+
+/// Bind an HTTP/3 SimpleQuicServer with a generated self-signed certificate for development
+pub async fn simple_quic_server_bind_http3_self_signed(
+    addr: String,
+    subject_alt_names: Vec<String>,
+    config: SimpleQuicServerConfig,
+) -> Result<(SimpleQuicServer, SelfSignedCertificate), QuicError> {
+    SimpleQuicServer::bind_http3_self_signed(addr, subject_alt_names, config)
+}
+
+/// Start answering HTTP/3 requests with the routes of `router`
+pub async fn simple_quic_server_serve_http3(
+    server: SimpleQuicServer,
+    router: Http3Router,
+) -> Result<SimpleQuicServer, QuicError> {
+    server.serve_http3(router)?;
+    Ok(server)
+}
+
+/// Start answering RPC calls with the handlers of `registry`
+pub async fn simple_quic_server_serve_rpc(
+    server: SimpleQuicServer,
+    registry: QuicRpcRegistry,
+) -> Result<SimpleQuicServer, QuicError> {
+    server.serve_rpc(registry)?;
+    Ok(server)
+}
+
+/// Start delivering the topics of `broker` to subscribing clients
+///
+/// The broker stays usable for publishing.
+pub async fn simple_quic_server_serve_pubsub(
+    server: SimpleQuicServer,
+    broker: &QuicPubSubBroker,
+) -> Result<SimpleQuicServer, QuicError> {
+    server.serve_pubsub(broker)?;
+    Ok(server)
+}
+
+/// Start receiving file uploads into the directory of `receiver`
+pub async fn simple_quic_server_serve_files(
+    server: SimpleQuicServer,
+    receiver: &QuicFileReceiver,
+) -> Result<SimpleQuicServer, QuicError> {
+    server.serve_files(receiver)?;
+    Ok(server)
+}
+
+/// Get the address a SimpleQuicServer is listening on
+pub fn simple_quic_server_local_addr(server: SimpleQuicServer) -> Result<(SimpleQuicServer, String), QuicError> {
+    let addr = server.local_addr()?.to_string();
+    Ok((server, addr))
+}
+
+/// Get a snapshot of the SimpleQuicServer counters
+pub fn simple_quic_server_stats(server: SimpleQuicServer) -> (SimpleQuicServer, SimpleQuicServerStats) {
+    let stats = server.stats();
+    (server, stats)
+}
+
+/// Gracefully shut down a SimpleQuicServer
+///
+/// Returns the number of requests abandoned when the shutdown timeout expired.
+pub async fn simple_quic_server_shutdown(server: SimpleQuicServer) -> (SimpleQuicServer, u64) {
+    let abandoned = server.shutdown().await;
+    (server, abandoned)
+}
+
+/// Create a new QuicConnectionPoolConfig with default values
+pub fn connection_pool_config_new() -> QuicConnectionPoolConfig {
+    QuicConnectionPoolConfig::default()
+}
+
+/// Create a connection pool with background idle eviction
+pub async fn connection_pool_new(config: QuicConnectionPoolConfig) -> QuicConnectionPool {
+    QuicConnectionPool::new(config)
+}
+
+/// Acquire a pooled connection to `addr`, connecting through `endpoint` if needed
+pub async fn connection_pool_acquire(
+    pool: QuicConnectionPool,
+    endpoint: QuicEndpoint,
+    addr: String,
+    server_name: String,
+) -> Result<(QuicConnectionPool, QuicEndpoint, QuicConnection), QuicError> {
+    let connection = pool.acquire(&endpoint, addr, server_name).await?;
+    Ok((pool, endpoint, connection))
+}
+
+/// Return a connection obtained from `connection_pool_acquire`
+pub fn connection_pool_release(
+    pool: QuicConnectionPool,
+    connection: QuicConnection,
+) -> (QuicConnectionPool, QuicConnection) {
+    pool.release(&connection);
+    (pool, connection)
+}
+
+/// Remove a connection from the pool without closing it
+pub fn connection_pool_remove(
+    pool: QuicConnectionPool,
+    connection: QuicConnection,
+) -> (QuicConnectionPool, QuicConnection, bool) {
+    let removed = pool.remove(&connection);
+    (pool, connection, removed)
+}
+
+/// Close and remove idle connections now, returning how many were evicted
+pub fn connection_pool_evict_idle(pool: QuicConnectionPool) -> (QuicConnectionPool, usize) {
+    let evicted = pool.evict_idle();
+    (pool, evicted)
+}
+
+/// Get connection pool statistics
+pub fn connection_pool_stats(pool: QuicConnectionPool) -> (QuicConnectionPool, QuicConnectionPoolStats) {
+    let stats = pool.stats();
+    (pool, stats)
+}
+
+/// Remove every connection from the pool
+pub fn connection_pool_clear(pool: QuicConnectionPool) -> QuicConnectionPool {
+    pool.clear();
+    pool
+}
+
+/// Create a new Http3ClientConfig with default values
+pub fn http3_client_config_new() -> Http3ClientConfig {
+    Http3ClientConfig::default()
+}
+
+/// Create an HTTP/3 client
+pub async fn http3_client_create(config: Http3ClientConfig) -> Result<Http3Client, QuicError> {
+    Http3Client::create(config)
+}
+
+/// Create a request without headers, body or trailers
+pub fn http3_request_new(method: String, url: String) -> Http3Request {
+    Http3Request::new(method, url)
+}
+
+/// Send an HTTP/3 request and buffer the whole response
+pub async fn http3_client_send(
+    client: Http3Client,
+    request: Http3Request,
+) -> Result<(Http3Client, Http3Response), QuicError> {
+    let response = client.send(request).await?;
+    Ok((client, response))
+}
+
+/// Send an HTTP/3 request with a streamed body, returning once the response headers arrive
+///
+/// `next_body_chunk` is called whenever the stream can take more data and returns
+/// `null` at the end of the body.
+pub async fn http3_client_send_streaming(
+    client: Http3Client,
+    request: Http3Request,
+    next_body_chunk: impl Fn() -> DartFnFuture<Option<Vec<u8>>> + Send + Sync + 'static,
+) -> Result<(Http3Client, Http3ResponseStream), QuicError> {
+    let body = futures::stream::unfold((), move |()| {
+        let chunk = next_body_chunk();
+        async move { chunk.await.map(|chunk| (chunk, ())) }
+    });
+    let response = client.send_streaming(request, body).await?;
+    Ok((client, response))
+}
+
+/// Close every connection of an HTTP/3 client
+pub fn http3_client_close(client: Http3Client) -> Http3Client {
+    client.close();
+    client
+}
+
+// Response streams are borrowed so `cancel` can run while `next_chunk` is pending
+
+/// Get the status code of a streaming HTTP/3 response
+pub fn http3_response_stream_status(response: &Http3ResponseStream) -> u16 {
+    response.status()
+}
+
+/// Get the header fields of a streaming HTTP/3 response
+pub fn http3_response_stream_headers(response: &Http3ResponseStream) -> Vec<Http3Header> {
+    response.headers()
+}
+
+/// Read the next body chunk of a streaming HTTP/3 response, or `null` at the end
+pub async fn http3_response_stream_next_chunk(response: &Http3ResponseStream) -> Result<Option<Vec<u8>>, QuicError> {
+    response.next_chunk().await
+}
+
+/// Read the trailer fields of a streaming HTTP/3 response after its body
+pub async fn http3_response_stream_trailers(response: &Http3ResponseStream) -> Result<Vec<Http3Header>, QuicError> {
+    response.trailers().await
+}
+
+/// Cancel a streaming HTTP/3 request
+pub fn http3_response_stream_cancel(response: &Http3ResponseStream) {
+    response.cancel();
+}
+
+/// Create an HTTP/3 router without routes
+pub fn http3_router_new() -> Http3Router {
+    Http3Router::new()
+}
+
+/// Add a route handled by a Dart callback
+///
+/// `path` matches exactly, or as a prefix when it ends in `*`. A handler that
+/// throws before responding is answered with 500.
+pub fn http3_router_route(
+    mut router: Http3Router,
+    method: String,
+    path: String,
+    handler: impl Fn(Http3ServerRequest, Http3ServerStream) -> DartFnFuture<()> + Send + Sync + 'static,
+) -> Http3Router {
+    router.route(method, path, move |request, stream| {
+        let handled = handler(request, stream);
+        async move {
+            handled.await;
+            Ok(())
+        }
+    });
+    router
+}
+
+// Server streams are borrowed so the body can be read while a response is written
+
+/// Read the next request body chunk, or `null` at the end of the body
+pub async fn http3_server_stream_next_chunk(stream: &Http3ServerStream) -> Result<Option<Vec<u8>>, QuicError> {
+    stream.next_chunk().await
+}
+
+/// Read the whole request body, answering 413 if it exceeds `max_request_size`
+pub async fn http3_server_stream_read_body(stream: &Http3ServerStream) -> Result<Vec<u8>, QuicError> {
+    stream.read_body().await
+}
+
+/// Read the request trailer fields after the body
+pub async fn http3_server_stream_trailers(stream: &Http3ServerStream) -> Result<Vec<Http3Header>, QuicError> {
+    stream.trailers().await
+}
+
+/// Send the response status and header fields
+pub async fn http3_server_stream_send_response(
+    stream: &Http3ServerStream,
+    status: u16,
+    headers: Vec<Http3Header>,
+) -> Result<(), QuicError> {
+    stream.send_response(status, headers).await
+}
+
+/// Send a response body chunk
+pub async fn http3_server_stream_send_data(stream: &Http3ServerStream, chunk: Vec<u8>) -> Result<(), QuicError> {
+    stream.send_data(chunk).await
+}
+
+/// Send the trailer fields, if any, and finish the response
+pub async fn http3_server_stream_finish(stream: &Http3ServerStream, trailers: Vec<Http3Header>) -> Result<(), QuicError> {
+    stream.finish(trailers).await
+}
+
+/// Send a complete response with a buffered body
+pub async fn http3_server_stream_respond(
+    stream: &Http3ServerStream,
+    status: u16,
+    headers: Vec<Http3Header>,
+    body: Vec<u8>,
+) -> Result<(), QuicError> {
+    stream.respond(status, headers, body).await
+}
+
+/// Open a WebTransport session to an https URL
+pub async fn webtransport_session_connect(
+    endpoint: QuicEndpoint,
+    url: String,
+    headers: Vec<Http3Header>,
+) -> Result<(QuicEndpoint, WebTransportSession), QuicError> {
+    let session = WebTransportSession::connect(&endpoint, url, headers).await?;
+    Ok((endpoint, session))
+}
+
+/// Get the session ID of a WebTransport session
+pub fn webtransport_session_id(session: &WebTransportSession) -> u64 {
+    session.session_id()
+}
+
+/// Get the QUIC connection carrying a WebTransport session
+pub fn webtransport_session_connection(session: &WebTransportSession) -> QuicConnection {
+    session.connection()
+}
+
+/// Open a bidirectional stream within a WebTransport session
+pub async fn webtransport_session_open_bi(
+    session: &WebTransportSession,
+) -> Result<(QuicSendStream, QuicRecvStream), QuicError> {
+    session.open_bi().await
+}
+
+/// Open a unidirectional stream within a WebTransport session
+pub async fn webtransport_session_open_uni(session: &WebTransportSession) -> Result<QuicSendStream, QuicError> {
+    session.open_uni().await
+}
+
+/// Accept the next bidirectional stream opened by the server within a WebTransport session
+pub async fn webtransport_session_accept_bi(
+    session: &WebTransportSession,
+) -> Result<(QuicSendStream, QuicRecvStream), QuicError> {
+    session.accept_bi().await
+}
+
+/// Accept the next unidirectional stream opened by the server within a WebTransport session
+pub async fn webtransport_session_accept_uni(session: &WebTransportSession) -> Result<QuicRecvStream, QuicError> {
+    session.accept_uni().await
+}
+
+/// Send a datagram within a WebTransport session
+pub async fn webtransport_session_send_datagram(session: &WebTransportSession, data: Vec<u8>) -> Result<(), QuicError> {
+    session.send_datagram(data).await
+}
+
+/// Receive the next datagram sent within a WebTransport session
+pub async fn webtransport_session_read_datagram(session: &WebTransportSession) -> Result<Vec<u8>, QuicError> {
+    session.read_datagram().await
+}
+
+/// Get the largest datagram payload a WebTransport session can currently send
+pub fn webtransport_session_max_datagram_size(session: &WebTransportSession) -> Option<usize> {
+    session.max_datagram_size()
+}
+
+/// Close a WebTransport session with an application error code and reason
+pub async fn webtransport_session_close(session: &WebTransportSession, code: u32, reason: String) {
+    session.close(code, reason).await
+}
+
+/// Wait until a WebTransport session is closed by either side
+pub async fn webtransport_session_closed(session: &WebTransportSession) -> WebTransportCloseInfo {
+    session.closed().await
+}
+
+/// Create the default RPC client configuration (1 MiB messages, 30 second deadline)
+pub fn rpc_client_config_new() -> QuicRpcClientConfig {
+    QuicRpcClientConfig::default()
+}
+
+/// Create call options without a deadline override or metadata
+pub fn rpc_call_options_new() -> QuicRpcCallOptions {
+    QuicRpcCallOptions::default()
+}
+
+/// Create an RPC client calling the server on the other end of `connection`
+pub fn rpc_client_new(connection: QuicConnection, config: QuicRpcClientConfig) -> QuicRpcClient {
+    QuicRpcClient::new(connection, config)
+}
+
+// Clients and calls are borrowed so calls can run concurrently and be cancelled
+
+/// Make a call expecting exactly one response message
+pub async fn rpc_client_call(
+    client: &QuicRpcClient,
+    method: String,
+    payload: Vec<u8>,
+    options: QuicRpcCallOptions,
+) -> Result<Vec<u8>, QuicRpcStatus> {
+    client.call(method, payload, options).await
+}
+
+/// Make a call whose response messages are read with `rpc_call_next_message`
+pub async fn rpc_client_call_streaming(
+    client: &QuicRpcClient,
+    method: String,
+    payload: Vec<u8>,
+    options: QuicRpcCallOptions,
+) -> Result<QuicRpcCall, QuicRpcStatus> {
+    client.call_streaming(method, payload, options).await
+}
+
+/// Read the next response message, or `null` once the call completed successfully
+pub async fn rpc_call_next_message(call: &QuicRpcCall) -> Result<Option<Vec<u8>>, QuicRpcStatus> {
+    call.next_message().await
+}
+
+/// Cancel a call, resetting its stream
+pub fn rpc_call_cancel(call: &QuicRpcCall) {
+    call.cancel();
+}
+
+/// Create an RPC registry without handlers
+pub fn rpc_registry_new() -> QuicRpcRegistry {
+    QuicRpcRegistry::new()
+}
+
+/// Register a Dart handler that sends responses with `rpc_responder_send`
+///
+/// The returned status ends the call. A handler that throws fails the call
+/// with `Internal`.
+pub fn rpc_registry_register(
+    mut registry: QuicRpcRegistry,
+    method: String,
+    handler: impl Fn(QuicRpcRequest, QuicRpcResponder) -> DartFnFuture<QuicRpcStatus> + Send + Sync + 'static,
+) -> QuicRpcRegistry {
+    registry.register(method, move |request, responder| {
+        let handled = handler(request, responder);
+        async move {
+            let status = handled.await;
+            match status.code {
+                QuicRpcCode::Ok => Ok(()),
+                _ => Err(status),
+            }
+        }
+    });
+    registry
+}
+
+/// Register a Dart handler answering with a single response message
+///
+/// A handler that throws fails the call with `Internal`.
+pub fn rpc_registry_register_unary(
+    mut registry: QuicRpcRegistry,
+    method: String,
+    handler: impl Fn(QuicRpcRequest) -> DartFnFuture<Vec<u8>> + Send + Sync + 'static,
+) -> QuicRpcRegistry {
+    registry.register_unary(method, move |request| {
+        let handled = handler(request);
+        async move { Ok(handled.await) }
+    });
+    registry
+}
+
+/// Send one response message of a call
+pub async fn rpc_responder_send(responder: &QuicRpcResponder, message: Vec<u8>) -> Result<(), QuicRpcStatus> {
+    responder.send(message).await
+}
+
+/// Create the default broker configuration (1024 retained messages per topic, 1 MiB messages)
+pub fn pub_sub_broker_config_new() -> QuicPubSubBrokerConfig {
+    QuicPubSubBrokerConfig::default()
+}
+
+/// Create a pub/sub broker without topics
+pub fn pub_sub_broker_new(config: QuicPubSubBrokerConfig) -> Result<QuicPubSubBroker, QuicError> {
+    QuicPubSubBroker::new(config)
+}
+
+// Brokers, clients and subscriptions are borrowed so they can be used concurrently
+
+/// Publish a message to the subscribers of `topic`, returning its sequence number
+pub fn pub_sub_broker_publish(broker: &QuicPubSubBroker, topic: String, payload: Vec<u8>) -> Result<u64, QuicError> {
+    broker.publish(topic, payload)
+}
+
+/// Get the sequence number of the newest message of `topic`, or 0 if none was published
+pub fn pub_sub_broker_latest_sequence(broker: &QuicPubSubBroker, topic: String) -> u64 {
+    broker.latest_sequence(topic)
+}
+
+/// Create default subscribe options: stream delivery of new messages only
+pub fn pub_sub_subscribe_options_new() -> QuicPubSubSubscribeOptions {
+    QuicPubSubSubscribeOptions::default()
+}
+
+/// Open a pub/sub client on a connection to a broker
+pub async fn pub_sub_client_connect(connection: QuicConnection) -> Result<(QuicConnection, QuicPubSubClient), QuicError> {
+    let client = QuicPubSubClient::connect(&connection).await?;
+    Ok((connection, client))
+}
+
+/// Subscribe to `topic`
+pub async fn pub_sub_client_subscribe(
+    client: &QuicPubSubClient,
+    topic: String,
+    options: QuicPubSubSubscribeOptions,
+) -> Result<QuicPubSubSubscription, QuicError> {
+    client.subscribe(topic, options).await
+}
+
+/// Wait for the next message, or `null` once unsubscribed or the connection closed
+pub async fn pub_sub_subscription_next_message(subscription: &QuicPubSubSubscription) -> Option<QuicPubSubMessage> {
+    subscription.next_message().await
+}
+
+/// Get the sequence number to resume the subscription after on a new connection
+pub fn pub_sub_subscription_last_sequence(subscription: &QuicPubSubSubscription) -> u64 {
+    subscription.last_sequence()
+}
+
+/// Get the number of requested messages the broker no longer retained when subscribing
+pub fn pub_sub_subscription_missed(subscription: &QuicPubSubSubscription) -> u64 {
+    subscription.missed()
+}
+
+/// Get the subscribed topic
+pub fn pub_sub_subscription_topic(subscription: &QuicPubSubSubscription) -> String {
+    subscription.topic()
+}
+
+/// Stop receiving messages of a subscription's topic
+pub async fn pub_sub_subscription_unsubscribe(subscription: &QuicPubSubSubscription) -> Result<(), QuicError> {
+    subscription.unsubscribe().await
+}
+
+/// Create the default file transfer configuration (1 MiB chunks on 4 parallel streams)
+pub fn file_transfer_config_new() -> QuicFileTransferConfig {
+    QuicFileTransferConfig::default()
+}
+
+/// Start uploading the file at `path` to the receiver on the other end of `connection`
+///
+/// `on_progress` is called whenever the receiver acknowledges a chunk. The
+/// connection is borrowed, so the caller keeps using it during the upload.
+pub async fn file_upload_start(
+    connection: &QuicConnection,
+    path: String,
+    config: QuicFileTransferConfig,
+    on_progress: impl Fn(QuicFileTransferProgress) -> DartFnFuture<()> + Send + Sync + 'static,
+) -> Result<QuicFileUpload, QuicFileTransferException> {
+    QuicFileUpload::start(connection, path, config, forward_to_dart(on_progress))
+}
+
+// Uploads are borrowed so `cancel` can run while `wait` is pending
+
+/// Wait for the receiver to verify an uploaded file and return the final progress
+pub async fn file_upload_wait(upload: &QuicFileUpload) -> Result<QuicFileTransferProgress, QuicFileTransferException> {
+    upload.wait().await
+}
+
+/// Cancel an upload; the receiver keeps what it acknowledged for a later resume
+pub fn file_upload_cancel(upload: &QuicFileUpload) {
+    upload.cancel();
+}
+
+/// Get the current progress of an upload
+pub fn file_upload_progress(upload: &QuicFileUpload) -> QuicFileTransferProgress {
+    upload.progress()
+}
+
+/// Create a receiver storing uploaded files in `directory`
+pub fn file_receiver_new(directory: String, max_file_size: u64) -> Result<QuicFileReceiver, QuicFileTransferException> {
+    QuicFileReceiver::new(directory, max_file_size)
+}
+
+/// Receive files uploaded on `connection` until it closes
+pub async fn file_receiver_serve_connection(receiver: &QuicFileReceiver, connection: QuicConnection) -> QuicConnection {
+    receiver.serve_connection(&connection).await;
+    connection
+}
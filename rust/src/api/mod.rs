@@ -5,3 +5,4 @@ pub use crate::core;
 pub use crate::convenience;
 pub use crate::models;
 pub use crate::errors;
+pub use crate::logging;
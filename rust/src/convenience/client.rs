@@ -1,11 +1,16 @@
 //! Convenience Client API - Simple QUIC client interface
 
 use flutter_rust_bridge::frb;
+use crate::convenience::pool::{QuicConnectionPool, QuicConnectionPoolConfig};
+use crate::convenience::streaming::{QuicStreamingTransfer, QuicTransferProgress};
 use crate::core::{QuicEndpoint, QuicConnection};
-use crate::errors::QuicError;
+use crate::errors::{QuicError, QuicReadToEndException};
+use futures::future::{BoxFuture, FutureExt, Shared};
+use futures::Stream;
 use std::collections::HashMap;
+use std::future::Future;
 use std::sync::{Arc, Mutex};
-use std::time::{Duration, Instant};
+use std::time::Duration;
 use url::Url;
 
 /// Configuration for QuicClient
@@ -23,6 +28,8 @@ pub struct QuicClientConfig {
     pub retry_delay_ms: u64,
     /// Keep-alive timeout for connections in milliseconds
     pub keep_alive_timeout_ms: u64,
+    /// Maximum response size in bytes; larger responses fail the request
+    pub max_response_size: usize,
 }
 
 impl Default for QuicClientConfig {
@@ -34,17 +41,13 @@ impl Default for QuicClientConfig {
             retry_attempts: 3,
             retry_delay_ms: 1000,
             keep_alive_timeout_ms: 60000,
+            max_response_size: 1024 * 1024,
         }
     }
 }
 
-/// Entry in the connection pool
-#[derive(Debug)]
-struct PooledConnection {
-    connection: QuicConnection,
-    last_used: Instant,
-    host: String,
-}
+/// Handshake shared by every request waiting for the same host
+type PendingConnect = Shared<BoxFuture<'static, Result<QuicConnection, QuicError>>>;
 
 /// High-level QUIC client with Dio-style interface
 /// 
@@ -58,10 +61,21 @@ struct PooledConnection {
 /// ```
 #[frb(opaque)]
 pub struct QuicClient {
-    endpoint: QuicEndpoint,
+    endpoint: Arc<QuicEndpoint>,
     config: QuicClientConfig,
     // Connection pool keyed by host:port
-    pool: Arc<Mutex<HashMap<String, Vec<PooledConnection>>>>,
+    pool: QuicConnectionPool,
+    // In-progress handshakes keyed by host:port
+    connecting: Arc<Mutex<HashMap<String, PendingConnect>>>,
+}
+
+/// Pool settings derived from the client configuration
+fn pool_config(config: &QuicClientConfig) -> QuicConnectionPoolConfig {
+    QuicConnectionPoolConfig {
+        max_connections_per_authority: config.max_connections_per_host,
+        idle_timeout_ms: config.keep_alive_timeout_ms,
+        ..Default::default()
+    }
 }
 
 impl QuicClient {
@@ -70,15 +84,7 @@ impl QuicClient {
     /// Creates a client endpoint and initializes the connection pool.
     /// Uses insecure configuration suitable for testing.
     pub fn create() -> Result<Self, QuicError> {
-        let endpoint = QuicEndpoint::client()?;
-        let config = QuicClientConfig::default();
-        let pool = Arc::new(Mutex::new(HashMap::new()));
-        
-        Ok(Self {
-            endpoint,
-            config,
-            pool,
-        })
+        Self::create_with_config(QuicClientConfig::default())
     }
     
     /// Create a new QuicClient with custom configuration
@@ -87,23 +93,26 @@ impl QuicClient {
     /// * `config` - Custom configuration for the client
     pub fn create_with_config(config: QuicClientConfig) -> Result<Self, QuicError> {
         let endpoint = QuicEndpoint::client()?;
-        let pool = Arc::new(Mutex::new(HashMap::new()));
+        let pool = QuicConnectionPool::new(pool_config(&config));
         
         Ok(Self {
-            endpoint,
+            endpoint: Arc::new(endpoint),
             config,
             pool,
+            connecting: Arc::new(Mutex::new(HashMap::new())),
         })
     }
     
     /// Get or create a connection to the specified host
     /// 
     /// This method implements connection pooling - it will reuse existing
-    /// connections when possible or create new ones when needed.
+    /// connections when possible or create new ones when needed. Returns the
+    /// connection and whether it came from the pool. The connection must be
+    /// passed to `release_connection` once the request is done.
     /// 
     /// # Arguments
     /// * `url` - Target URL to connect to
-    async fn get_connection(&self, url: &str) -> Result<QuicConnection, QuicError> {
+    async fn get_connection(&self, url: &str) -> Result<(QuicConnection, bool), QuicError> {
         let parsed_url = Url::parse(url)
             .map_err(|e| QuicError::Connection(format!("Invalid URL: {:?}", e)))?;
         
@@ -114,45 +123,54 @@ impl QuicClient {
         let host_key = format!("{}:{}", host, port);
         let addr = format!("{}:{}", host, port);
         
-        // Try to get an existing connection from the pool
-        {
-            let mut pool = self.pool.lock().unwrap();
-            if let Some(connections) = pool.get_mut(&host_key) {
-                // Remove expired connections
-                let now = Instant::now();
-                let keep_alive_duration = Duration::from_millis(self.config.keep_alive_timeout_ms);
-                connections.retain(|conn| now.duration_since(conn.last_used) < keep_alive_duration);
-                
-                // Try to reuse an existing connection
-                if let Some(mut pooled_conn) = connections.pop() {
-                    pooled_conn.last_used = now;
-                    connections.push(pooled_conn);
-                    return Ok(connections.last().unwrap().connection.clone());
-                }
+        // Try to get an existing connection from the pool, or join a handshake in progress
+        let pending = {
+            // Checked under the handshake lock so a finishing handshake cannot be missed
+            let mut connecting = self.connecting.lock().unwrap();
+            if let Some(connection) = self.pool.try_acquire(&host_key) {
+                return Ok((connection, true));
             }
-        }
+            
+            connecting.entry(host_key.clone())
+                .or_insert_with(|| self.connect(host_key, addr, host.to_string()))
+                .clone()
+        };
         
-        // Create a new connection
-        let connection = self.endpoint.connect(addr, host.to_string()).await?;
+        let connection = pending.await?;
+        // Every waiter uses the new connection; if the pool had no room it stays unpooled
+        self.pool.share(&connection);
+        Ok((connection, false))
+    }
+    
+    /// Start a handshake that every concurrent caller for `host_key` can await
+    /// 
+    /// The handshake runs to completion as long as any caller is waiting on it.
+    /// On success the connection is pooled, unused until each waiter takes its
+    /// share; either way the pending entry is removed, so a failed handshake is
+    /// reported to all waiters and the next request retries.
+    fn connect(&self, host_key: String, addr: String, server_name: String) -> PendingConnect {
+        let endpoint = self.endpoint.clone();
+        let pool = self.pool.clone();
+        let connecting = self.connecting.clone();
         
-        // Add to pool
-        {
-            let mut pool = self.pool.lock().unwrap();
-            let connections = pool.entry(host_key.clone()).or_insert_with(Vec::new);
-            
-            // Limit connections per host
-            if connections.len() >= self.config.max_connections_per_host {
-                connections.remove(0); // Remove oldest connection
+        async move {
+            let result = pool.acquire(&endpoint, addr, server_name).await;
+            if let Ok(connection) = &result {
+                pool.release(connection);
             }
+            connecting.lock().unwrap().remove(&host_key);
             
-            connections.push(PooledConnection {
-                connection: connection.clone(),
-                last_used: Instant::now(),
-                host: host_key,
-            });
+            result
         }
-        
-        Ok(connection)
+        .boxed()
+        .shared()
+    }
+    
+    /// Hand a connection from `get_connection` back to the pool
+    /// 
+    /// Lost connections are pruned by the pool the next time their host is used.
+    fn release_connection(&self, connection: &QuicConnection) {
+        self.pool.release(connection);
     }
     
     /// Get the current configuration
@@ -161,7 +179,11 @@ impl QuicClient {
     }
     
     /// Update the client configuration
+    /// 
+    /// Pool limits only apply to a new pool, so the current one is replaced and
+    /// its connections are no longer reused.
     pub fn set_config(&mut self, config: QuicClientConfig) {
+        self.pool = QuicConnectionPool::new(pool_config(&config));
         self.config = config;
     }
     
@@ -169,9 +191,8 @@ impl QuicClient {
     /// 
     /// Returns information about the current state of the connection pool.
     pub fn pool_stats(&self) -> HashMap<String, usize> {
-        let pool = self.pool.lock().unwrap();
-        pool.iter()
-            .map(|(host, connections)| (host.clone(), connections.len()))
+        self.pool.stats().authorities.into_iter()
+            .map(|authority| (authority.authority, authority.connections as usize))
             .collect()
     }
     
@@ -179,8 +200,7 @@ impl QuicClient {
     /// 
     /// Forces all future requests to create new connections.
     pub fn clear_pool(&self) {
-        let mut pool = self.pool.lock().unwrap();
-        pool.clear();
+        self.pool.clear();
     }
     
     /// Send data to a URL and return the response
@@ -203,6 +223,31 @@ impl QuicClient {
     /// print('Response: $response');
     /// ```
     pub async fn send(&self, url: String, data: String) -> Result<String, QuicError> {
+        let response_bytes = self.send_bytes(url, data.into_bytes()).await?;
+        
+        // Convert response to UTF-8 string
+        String::from_utf8(response_bytes)
+            .map_err(|e| QuicError::Stream(format!("Response is not valid UTF-8: {:?}", e)))
+    }
+    
+    /// Send binary data to a URL and return the binary response
+    /// 
+    /// Same as `send()`, without assuming either body is UTF-8.
+    /// 
+    /// # Arguments
+    /// * `url` - Target URL (e.g., "https://example.com/api/upload")
+    /// * `data` - Request body bytes
+    /// 
+    /// # Returns
+    /// * `Ok(Vec<u8>)` - Response body bytes, at most `max_response_size` long
+    /// * `Err(QuicError)` - Connection, stream, or network errors
+    /// 
+    /// # Example
+    /// ```dart
+    /// final client = QuicClient.create();
+    /// final thumbnail = await client.sendBytes('https://api.example.com/thumbnail', imageBytes);
+    /// ```
+    pub async fn send_bytes(&self, url: String, data: Vec<u8>) -> Result<Vec<u8>, QuicError> {
         let mut last_error = QuicError::Connection("No attempts made".to_string());
         
         // Retry logic with configurable attempts
@@ -235,31 +280,49 @@ impl QuicClient {
     }
     
     /// Perform a single send attempt without retries
-    async fn send_once(&self, url: &str, data: &str) -> Result<String, QuicError> {
+    /// 
+    /// If a pooled connection turns out to be lost, the request is sent once more
+    /// on a fresh connection before the failure counts as an attempt, since it most
+    /// likely died while idle. The pool drops the lost connection on that retry.
+    async fn send_once(&self, url: &str, data: &[u8]) -> Result<Vec<u8>, QuicError> {
         // Get or create connection using our pooling logic
-        let connection = self.get_connection(url).await?;
+        let (connection, reused) = self.get_connection(url).await?;
+        let result = self.send_on(&connection, data).await;
+        self.release_connection(&connection);
         
+        match result {
+            Err(_) if reused && connection.close_reason().is_some() => {
+                let (connection, _) = self.get_connection(url).await?;
+                let result = self.send_on(&connection, data).await;
+                self.release_connection(&connection);
+                result
+            }
+            result => result,
+        }
+    }
+    
+    /// Send one request on a bidirectional stream of `connection`
+    async fn send_on(&self, connection: &QuicConnection, data: &[u8]) -> Result<Vec<u8>, QuicError> {
         // Open a bidirectional stream for request/response
         let (mut send_stream, mut recv_stream) = connection.open_bi().await?;
         
         // Send the request data
-        let request_bytes = data.as_bytes().to_vec();
-        send_stream.write_all(request_bytes).await
+        send_stream.write_all(data.to_vec()).await
             .map_err(|e| QuicError::Stream(format!("Failed to send data: {:?}", e)))?;
         
         // Finish sending (signals end of request)
         send_stream.finish()
             .map_err(|e| QuicError::Stream(format!("Failed to finish send stream: {:?}", e)))?;
         
-        // Read the response with a reasonable size limit (1MB)
-        let response_bytes = recv_stream.read_to_end(1024 * 1024).await
-            .map_err(|e| QuicError::Stream(format!("Failed to read response: {:?}", e)))?;
-        
-        // Convert response to UTF-8 string
-        let response = String::from_utf8(response_bytes)
-            .map_err(|e| QuicError::Stream(format!("Response is not valid UTF-8: {:?}", e)))?;
-        
-        Ok(response)
+        // Read the response up to the configured size limit
+        let max_response_size = self.config.max_response_size;
+        match recv_stream.read_to_end(max_response_size).await {
+            Ok(response_bytes) => Ok(response_bytes),
+            Err(QuicReadToEndException::TooLong) => Err(QuicError::ResponseTooLong {
+                limit: max_response_size,
+            }),
+            Err(e) => Err(QuicError::Stream(format!("Failed to read response: {:?}", e))),
+        }
     }
     
     /// Check if an error is retryable
@@ -293,6 +356,7 @@ impl QuicClient {
             QuicError::Tls(_) => false,
             QuicError::Endpoint(_) => false,
             QuicError::Write(_) => false,
+            QuicError::ResponseTooLong { .. } => false,
         }
     }
     
@@ -308,6 +372,71 @@ impl QuicClient {
         }
     }
     
+    /// Send binary data with timeout
+    /// 
+    /// Wraps `send_bytes()` with the configured request timeout.
+    pub async fn send_bytes_with_timeout(&self, url: String, data: Vec<u8>) -> Result<Vec<u8>, QuicError> {
+        let timeout_duration = Duration::from_millis(self.config.request_timeout_ms);
+        
+        match tokio::time::timeout(timeout_duration, self.send_bytes(url, data)).await {
+            Ok(result) => result,
+            Err(_) => Err(QuicError::Network("Request timed out".to_string())),
+        }
+    }
+    
+    /// Send a request whose body and response are streamed in chunks
+    /// 
+    /// For bodies too large to buffer, such as file uploads and downloads. Chunks
+    /// of `body` are written as flow control allows, and each response chunk is
+    /// passed to `on_response_chunk`, which is awaited before more is read. The
+    /// request is not retried, since a partly consumed body cannot be replayed.
+    /// 
+    /// # Arguments
+    /// * `url` - Target URL (e.g., "https://example.com/upload")
+    /// * `upload_length` - Request body length if known, reported in progress and enforced
+    /// * `response_length` - Response body length if known, reported in progress and enforced
+    /// * `body` - Request body chunks
+    /// * `on_response_chunk` - Receives each response chunk
+    /// * `on_progress` - Called after every chunk sent or received
+    /// 
+    /// # Returns
+    /// A handle to wait for, observe or cancel the running transfer
+    pub async fn send_streaming<S, C, CFut, P>(
+        &self,
+        url: String,
+        upload_length: Option<u64>,
+        response_length: Option<u64>,
+        body: S,
+        on_response_chunk: C,
+        on_progress: P,
+    ) -> Result<QuicStreamingTransfer, QuicError>
+    where
+        S: Stream<Item = Vec<u8>> + Send + 'static,
+        C: Fn(Vec<u8>) -> CFut + Send + Sync + 'static,
+        CFut: Future<Output = ()> + Send + 'static,
+        P: Fn(QuicTransferProgress) + Send + Sync + 'static,
+    {
+        let (connection, _) = self.get_connection(&url).await?;
+        let streams = match connection.open_bi().await {
+            Ok(streams) => streams,
+            Err(error) => {
+                self.release_connection(&connection);
+                return Err(error);
+            }
+        };
+        
+        let pool = self.pool.clone();
+        Ok(QuicStreamingTransfer::start(
+            streams,
+            upload_length,
+            response_length,
+            body,
+            on_response_chunk,
+            on_progress,
+            move || pool.release(&connection),
+        ))
+    }
+    
     /// Send a GET request to the specified URL
     /// 
     /// This provides an HTTP-like interface for simple GET requests without data.
@@ -358,6 +487,18 @@ impl QuicClient {
         self.send(url, data).await
     }
     
+    /// Send a GET request and return the binary response
+    /// 
+    /// Like `get()`, for responses that are not UTF-8 text (images, protobuf, ...).
+    pub async fn get_bytes(&self, url: String) -> Result<Vec<u8>, QuicError> {
+        self.send_bytes(url, Vec::new()).await
+    }
+    
+    /// Send a POST request with a binary body and return the binary response
+    pub async fn post_bytes(&self, url: String, data: Vec<u8>) -> Result<Vec<u8>, QuicError> {
+        self.send_bytes(url, data).await
+    }
+    
     /// Send a GET request with timeout
     /// 
     /// Combines the convenience of get() with timeout protection.
@@ -0,0 +1,352 @@
+//! Datagram Channel API - Multiplexed datagram flows on one connection
+//!
+//! Every datagram starts with its flow ID as a QUIC variable-length integer,
+//! the same layout as the quarter stream ID of HTTP Datagrams (RFC 9297).
+//! With fragmentation enabled the flow ID is followed by a message ID, the
+//! fragment index and the fragment count, each a varint, so both peers must
+//! use the same setting.
+
+use flutter_rust_bridge::frb;
+use crate::convenience::varint::{decode_varint, encode_varint};
+use crate::core::datagram::DatagramQueue;
+use crate::core::{QuicConnection, QuicConnectionEnd, QuicDatagramBatch, QuicDatagramDropPolicy};
+use crate::errors::{QuicDatagramException, QuicError};
+use bytes::Bytes;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio_util::task::AbortOnDropHandle;
+
+/// Largest value a QUIC variable-length integer can hold
+const MAX_VARINT: u64 = (1 << 62) - 1;
+/// Most fragmented messages reassembled at once; fragments of further messages are discarded
+const MAX_PARTIAL_MESSAGES: usize = 64;
+/// Smallest payload of a fragment other than the last
+///
+/// Every QUIC path carries 1200-byte packets, so a sender's fragments are at
+/// least this large; counts that would need smaller ones are rejected.
+const MIN_FRAGMENT_SIZE: usize = 1024;
+
+/// Configuration for datagram channels
+#[derive(Debug, Clone)]
+pub struct QuicDatagramChannelConfig {
+    /// Split payloads above the datagram size limit instead of rejecting them
+    pub fragmentation: bool,
+    /// Largest payload accepted for sending or reassembly when fragmenting
+    pub max_message_size: usize,
+    /// Time after which an incomplete fragmented message is discarded
+    pub reassembly_timeout_ms: u64,
+    /// Maximum number of datagrams held per channel until the application takes them
+    pub channel_capacity: usize,
+    pub drop_policy: QuicDatagramDropPolicy,
+}
+
+impl Default for QuicDatagramChannelConfig {
+    fn default() -> Self {
+        Self {
+            fragmentation: false,
+            max_message_size: 64 * 1024,
+            reassembly_timeout_ms: 1000,
+            channel_capacity: 256,
+            drop_policy: QuicDatagramDropPolicy::DropOldest,
+        }
+    }
+}
+
+struct PartialMessage {
+    fragments: Vec<Option<Vec<u8>>>,
+    received: usize,
+    size: usize,
+    started: Instant,
+}
+
+struct MuxState {
+    channels: HashMap<u64, Arc<DatagramQueue>>,
+    end: Option<QuicConnectionEnd>,
+}
+
+struct MuxShared {
+    connection: quinn::Connection,
+    config: QuicDatagramChannelConfig,
+    state: Mutex<MuxState>,
+}
+
+/// Routes a connection's datagrams to channels by flow ID
+///
+/// The mux consumes every datagram the connection receives, so neither
+/// `read_datagram` nor a datagram subscription should be used alongside it.
+/// Datagrams for flow IDs without an open channel are discarded.
+///
+/// # Example
+/// ```dart
+/// final (_, mux) = await connectionDatagramMux(
+///   connection: connection, config: await datagramChannelConfigNew());
+/// final positions = await datagramMuxOpenChannel(mux: mux, flowId: BigInt.from(1));
+/// await datagramChannelSend(channel: positions, data: encodePosition(player));
+/// ```
+#[frb(opaque)]
+pub struct QuicDatagramMux {
+    shared: Arc<MuxShared>,
+    _task: AbortOnDropHandle<()>,
+}
+
+impl QuicDatagramMux {
+    /// Start routing the datagrams of `connection`
+    pub fn new(connection: &QuicConnection, config: QuicDatagramChannelConfig) -> Result<Self, QuicError> {
+        if config.channel_capacity == 0 {
+            return Err(QuicError::Config("channel_capacity must be at least 1".to_string()));
+        }
+
+        let shared = Arc::new(MuxShared {
+            connection: connection.inner().clone(),
+            config,
+            state: Mutex::new(MuxState {
+                channels: HashMap::new(),
+                end: None,
+            }),
+        });
+        let task = tokio::spawn(route_datagrams(shared.clone()));
+
+        Ok(Self {
+            shared,
+            _task: AbortOnDropHandle::new(task),
+        })
+    }
+
+    /// Open the channel for `flow_id`
+    ///
+    /// Fails if the channel is already open or the ID does not fit a varint.
+    pub fn open_channel(&self, flow_id: u64) -> Result<QuicDatagramChannel, QuicError> {
+        if flow_id > MAX_VARINT {
+            return Err(QuicError::Config(format!("Flow ID {} exceeds the varint range", flow_id)));
+        }
+
+        let config = &self.shared.config;
+        let queue = Arc::new(DatagramQueue::new(config.channel_capacity, config.drop_policy)?);
+
+        let mut state = self.shared.state.lock().unwrap();
+        if state.channels.contains_key(&flow_id) {
+            return Err(QuicError::Config(format!("Channel {} is already open", flow_id)));
+        }
+        if let Some(end) = &state.end {
+            queue.finish(end.clone());
+        }
+        state.channels.insert(flow_id, queue.clone());
+
+        Ok(QuicDatagramChannel {
+            flow_id,
+            shared: self.shared.clone(),
+            queue,
+            next_message_id: AtomicU64::new(0),
+        })
+    }
+}
+
+/// One logical datagram flow of a `QuicDatagramMux`
+///
+/// Received datagrams are queued per channel with the mux's capacity and drop
+/// policy. Dropping or closing the channel frees its flow ID.
+#[frb(opaque)]
+pub struct QuicDatagramChannel {
+    flow_id: u64,
+    shared: Arc<MuxShared>,
+    queue: Arc<DatagramQueue>,
+    next_message_id: AtomicU64,
+}
+
+impl QuicDatagramChannel {
+    /// Get the flow ID that tags this channel's datagrams
+    pub fn flow_id(&self) -> u64 {
+        self.flow_id
+    }
+
+    /// Get the largest payload that fits a single datagram, if datagrams are supported
+    pub fn max_payload_size(&self) -> Option<usize> {
+        let max_size = self.shared.connection.max_datagram_size()?;
+        Some(max_size.saturating_sub(self.header_len(0, 1)))
+    }
+
+    /// Send a payload on this channel
+    ///
+    /// Without fragmentation, payloads above `max_payload_size` fail with
+    /// `TooLarge`. With fragmentation they are split across datagrams, and only
+    /// payloads above `max_message_size` fail. Fragments wait for send buffer
+    /// space rather than displacing each other; losing any fragment loses the
+    /// whole payload.
+    pub async fn send(&self, data: Vec<u8>) -> Result<(), QuicDatagramException> {
+        let max_size = self.shared.connection.max_datagram_size()
+            .ok_or(QuicDatagramException::UnsupportedByPeer)?;
+
+        if !self.shared.config.fragmentation {
+            let mut datagram = Vec::with_capacity(data.len() + 8);
+            encode_varint(&mut datagram, self.flow_id);
+            if datagram.len() + data.len() > max_size {
+                return Err(QuicDatagramException::TooLarge {
+                    max_size: max_size.saturating_sub(datagram.len()),
+                });
+            }
+            datagram.extend_from_slice(&data);
+            return self.shared.connection.send_datagram_wait(Bytes::from(datagram)).await
+                .map_err(QuicDatagramException::from);
+        }
+
+        let max_message_size = self.shared.config.max_message_size;
+        if data.len() > max_message_size {
+            return Err(QuicDatagramException::TooLarge { max_size: max_message_size });
+        }
+
+        let message_id = self.next_message_id.fetch_add(1, Ordering::Relaxed) & MAX_VARINT;
+        // Size fragments for the largest count the message could need
+        let worst_header = self.header_len(message_id, data.len().max(1) as u64);
+        let fragment_size = max_size.saturating_sub(worst_header);
+        if fragment_size == 0 {
+            return Err(QuicDatagramException::TooLarge { max_size: 0 });
+        }
+
+        let fragments: Vec<&[u8]> = if data.is_empty() {
+            vec![&[]]
+        } else {
+            data.chunks(fragment_size).collect()
+        };
+        let count = fragments.len() as u64;
+
+        for (index, fragment) in fragments.into_iter().enumerate() {
+            let mut datagram = Vec::with_capacity(worst_header + fragment.len());
+            encode_varint(&mut datagram, self.flow_id);
+            encode_varint(&mut datagram, message_id);
+            encode_varint(&mut datagram, index as u64);
+            encode_varint(&mut datagram, count);
+            datagram.extend_from_slice(fragment);
+            self.shared.connection.send_datagram_wait(Bytes::from(datagram)).await
+                .map_err(QuicDatagramException::from)?;
+        }
+        Ok(())
+    }
+
+    /// Wait for payloads and take everything queued on this channel
+    ///
+    /// Returns at least one payload, or the connection end once the queue is
+    /// empty. Fails if the channel was closed.
+    pub async fn next_batch(&self) -> Result<QuicDatagramBatch, QuicError> {
+        self.queue.next_batch().await
+    }
+
+    /// Close the channel, discarding queued payloads and freeing its flow ID
+    pub fn close(&self) {
+        self.queue.close();
+        let mut state = self.shared.state.lock().unwrap();
+        if state.channels.get(&self.flow_id).is_some_and(|queue| Arc::ptr_eq(queue, &self.queue)) {
+            state.channels.remove(&self.flow_id);
+        }
+    }
+
+    fn header_len(&self, message_id: u64, count: u64) -> usize {
+        let mut header = Vec::new();
+        encode_varint(&mut header, self.flow_id);
+        if self.shared.config.fragmentation {
+            encode_varint(&mut header, message_id);
+            encode_varint(&mut header, count.saturating_sub(1));
+            encode_varint(&mut header, count);
+        }
+        header.len()
+    }
+}
+
+impl Drop for QuicDatagramChannel {
+    fn drop(&mut self) {
+        self.close();
+    }
+}
+
+/// Read datagrams and hand them to their channels until the connection ends
+async fn route_datagrams(shared: Arc<MuxShared>) {
+    let reassembly_timeout = Duration::from_millis(shared.config.reassembly_timeout_ms);
+    let mut partial: HashMap<(u64, u64), PartialMessage> = HashMap::new();
+
+    loop {
+        let datagram = match shared.connection.read_datagram().await {
+            Ok(datagram) => datagram,
+            Err(error) => {
+                let end = QuicConnectionEnd::from(error);
+                let mut state = shared.state.lock().unwrap();
+                for queue in state.channels.values() {
+                    queue.finish(end.clone());
+                }
+                state.end = Some(end);
+                return;
+            }
+        };
+
+        let Some((flow_id, len)) = decode_varint(&datagram) else {
+            continue;
+        };
+        let Some(queue) = shared.state.lock().unwrap().channels.get(&flow_id).cloned() else {
+            continue;
+        };
+        let payload = &datagram[len..];
+
+        if !shared.config.fragmentation {
+            queue.push(payload.to_vec());
+            continue;
+        }
+
+        partial.retain(|_, message| message.started.elapsed() < reassembly_timeout);
+        if let Some(message) = reassemble(&mut partial, flow_id, payload, shared.config.max_message_size) {
+            queue.push(message);
+        }
+    }
+}
+
+/// Add a fragment, returning the message once all of its fragments arrived
+fn reassemble(
+    partial: &mut HashMap<(u64, u64), PartialMessage>,
+    flow_id: u64,
+    payload: &[u8],
+    max_message_size: usize,
+) -> Option<Vec<u8>> {
+    let mut pos = 0;
+    let mut next = || {
+        let (value, len) = decode_varint(&payload[pos..])?;
+        pos += len;
+        Some(value)
+    };
+    let (message_id, index, count) = (next()?, next()?, next()?);
+    let fragment = &payload[pos..];
+
+    // The count comes from the peer and sizes the fragment table, so bound it first
+    let max_count = max_message_size.div_ceil(MIN_FRAGMENT_SIZE).max(1) as u64;
+    if count == 0 || index >= count || count > max_count {
+        return None;
+    }
+    if count == 1 {
+        return Some(fragment.to_vec());
+    }
+
+    if !partial.contains_key(&(flow_id, message_id)) && partial.len() >= MAX_PARTIAL_MESSAGES {
+        return None;
+    }
+    let message = partial.entry((flow_id, message_id)).or_insert_with(|| PartialMessage {
+        fragments: vec![None; count as usize],
+        received: 0,
+        size: 0,
+        started: Instant::now(),
+    });
+    if message.fragments.len() as u64 != count || message.fragments[index as usize].is_some() {
+        return None;
+    }
+
+    message.size += fragment.len();
+    if message.size > max_message_size {
+        partial.remove(&(flow_id, message_id));
+        return None;
+    }
+    message.fragments[index as usize] = Some(fragment.to_vec());
+    message.received += 1;
+    if message.received < message.fragments.len() {
+        return None;
+    }
+
+    let message = partial.remove(&(flow_id, message_id))?;
+    Some(message.fragments.into_iter().flatten().flatten().collect())
+}
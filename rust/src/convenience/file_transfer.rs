@@ -0,0 +1,593 @@
+//! File Transfer API - Resumable, verified file uploads over QUIC
+//!
+//! The sender announces a file on a control stream with a manifest of its
+//! name, size and SHA-256 digest. The receiver answers with the offset up to
+//! which it already holds the file contiguously, and the sender transfers the
+//! rest in chunks on parallel bidirectional streams, each acknowledged once
+//! written. The digest identifies the transfer, so sending the same file again
+//! after a connection loss resumes it. Finally the receiver verifies the
+//! digest and moves the file into place.
+
+use flutter_rust_bridge::frb;
+use crate::convenience::framing::{read_frame, write_frame};
+use crate::convenience::server::ServerCounters;
+use crate::convenience::varint::{decode_varint, encode_varint};
+use crate::core::QuicConnection;
+use crate::errors::{QuicFileTransferErrorKind, QuicFileTransferException};
+use futures::{StreamExt, TryStreamExt};
+use std::collections::{BTreeMap, HashMap};
+use std::io::{Read, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex as StdMutex};
+use tokio::io::{AsyncReadExt, AsyncSeekExt, AsyncWriteExt};
+use tokio::sync::Mutex;
+use tokio::task::{JoinHandle, JoinSet};
+use tokio_util::sync::CancellationToken;
+
+/// Stream error code sent when an upload is cancelled
+pub const FILE_TRANSFER_CANCELLED_ERROR_CODE: u32 = 0x10;
+
+const MESSAGE_MANIFEST: u8 = 0x01;
+const MESSAGE_CHUNK: u8 = 0x02;
+const MESSAGE_COMPLETE: u8 = 0x03;
+const REPLY_OK: u8 = 0x00;
+const REPLY_ERROR: u8 = 0x01;
+/// Largest control frame, bounding file names
+const MAX_CONTROL_FRAME: usize = 64 * 1024;
+/// Largest chunk a receiver accepts
+const MAX_CHUNK_SIZE: usize = 16 * 1024 * 1024;
+const DIGEST_LEN: usize = 32;
+const READ_BUFFER_SIZE: usize = 64 * 1024;
+
+type FileId = [u8; DIGEST_LEN];
+
+/// Configuration for QuicFileUpload
+#[derive(Debug, Clone)]
+pub struct QuicFileTransferConfig {
+    /// Bytes sent per chunk stream, at most 16 MiB
+    pub chunk_size: usize,
+    /// Chunk streams in flight at once
+    pub parallel_streams: usize,
+}
+
+impl Default for QuicFileTransferConfig {
+    fn default() -> Self {
+        Self {
+            chunk_size: 1024 * 1024,
+            parallel_streams: 4,
+        }
+    }
+}
+
+/// Progress of a file upload
+#[derive(Debug, Clone, Default)]
+pub struct QuicFileTransferProgress {
+    /// Bytes the receiver holds, including those from earlier attempts
+    pub bytes_transferred: u64,
+    /// Size of the file
+    pub total_bytes: u64,
+    /// Offset the upload resumed from, 0 for a fresh upload
+    pub resumed_from: u64,
+}
+
+#[derive(Debug, Default)]
+struct ProgressCounters {
+    bytes_transferred: AtomicU64,
+    total_bytes: AtomicU64,
+    resumed_from: AtomicU64,
+}
+
+impl ProgressCounters {
+    fn snapshot(&self) -> QuicFileTransferProgress {
+        QuicFileTransferProgress {
+            bytes_transferred: self.bytes_transferred.load(Ordering::Relaxed),
+            total_bytes: self.total_bytes.load(Ordering::Relaxed),
+            resumed_from: self.resumed_from.load(Ordering::Relaxed),
+        }
+    }
+}
+
+fn error(kind: QuicFileTransferErrorKind, message: impl Into<String>) -> QuicFileTransferException {
+    QuicFileTransferException::new(kind, message.into())
+}
+
+fn connection_error(e: impl std::fmt::Debug) -> QuicFileTransferException {
+    error(QuicFileTransferErrorKind::Connection, format!("{:?}", e))
+}
+
+fn error_reply(e: &QuicFileTransferException) -> Vec<u8> {
+    let mut reply = vec![REPLY_ERROR, e.kind as u8];
+    reply.extend_from_slice(e.message.as_bytes());
+    reply
+}
+
+/// Split a reply frame into its body, or the error the peer reported
+fn parse_reply(reply: Option<Vec<u8>>) -> Result<Vec<u8>, QuicFileTransferException> {
+    let reply = reply.ok_or_else(|| error(QuicFileTransferErrorKind::Connection, "Receiver closed the stream"))?;
+    match reply.split_first() {
+        Some((&REPLY_OK, body)) => Ok(body.to_vec()),
+        Some((&REPLY_ERROR, [kind, message @ ..])) => {
+            let kind = match *kind {
+                k if k == QuicFileTransferErrorKind::Io as u8 => QuicFileTransferErrorKind::Io,
+                k if k == QuicFileTransferErrorKind::ChecksumMismatch as u8 => QuicFileTransferErrorKind::ChecksumMismatch,
+                _ => QuicFileTransferErrorKind::Rejected,
+            };
+            Err(error(kind, format!("Receiver: {}", String::from_utf8_lossy(message))))
+        }
+        _ => Err(error(QuicFileTransferErrorKind::Connection, "Malformed reply")),
+    }
+}
+
+/// Compute the size and SHA-256 digest of a file
+fn digest_file(path: &Path) -> Result<(u64, FileId), QuicFileTransferException> {
+    let mut file = std::fs::File::open(path)?;
+    let mut context = ring::digest::Context::new(&ring::digest::SHA256);
+    let mut buffer = vec![0u8; READ_BUFFER_SIZE];
+    let mut size = 0u64;
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        context.update(&buffer[..read]);
+        size += read as u64;
+    }
+
+    let mut digest = [0u8; DIGEST_LEN];
+    digest.copy_from_slice(context.finish().as_ref());
+    Ok((size, digest))
+}
+
+/// Handle to a file upload running in the background
+///
+/// Progress counts bytes the receiver acknowledged. If the upload fails with
+/// a `Connection` error, uploading the same file again, on this or a new
+/// connection to the same receiver, continues where the receiver left off.
+///
+/// # Example
+/// ```dart
+/// final upload = await fileUploadStart(
+///   connection: connection,
+///   path: '/storage/video.mp4',
+///   config: await fileTransferConfigNew(),
+///   onProgress: (p) => print('${p.bytesTransferred} / ${p.totalBytes}'),
+/// );
+/// await fileUploadWait(upload: upload);
+/// ```
+#[frb(opaque)]
+pub struct QuicFileUpload {
+    progress: Arc<ProgressCounters>,
+    cancel: CancellationToken,
+    task: Mutex<Option<JoinHandle<Result<(), QuicFileTransferException>>>>,
+}
+
+impl QuicFileUpload {
+    /// Start uploading the file at `path` to the receiver on the other end of `connection`
+    ///
+    /// The file is first read once to compute its digest. `on_progress` is
+    /// called whenever the receiver acknowledges a chunk.
+    pub fn start<P>(
+        connection: &QuicConnection,
+        path: String,
+        config: QuicFileTransferConfig,
+        on_progress: P,
+    ) -> Result<Self, QuicFileTransferException>
+    where
+        P: Fn(QuicFileTransferProgress) + Send + Sync + 'static,
+    {
+        if config.chunk_size == 0 || config.chunk_size > MAX_CHUNK_SIZE || config.parallel_streams == 0 {
+            return Err(error(
+                QuicFileTransferErrorKind::Rejected,
+                "chunk_size must be between 1 byte and 16 MiB and parallel_streams at least 1",
+            ));
+        }
+
+        let progress = Arc::new(ProgressCounters::default());
+        let cancel = CancellationToken::new();
+        let task = {
+            let connection = connection.inner().clone();
+            let progress = progress.clone();
+            let cancel = cancel.clone();
+            tokio::spawn(async move {
+                tokio::select! {
+                    _ = cancel.cancelled() => Err(error(QuicFileTransferErrorKind::Cancelled, "Upload was cancelled")),
+                    result = upload(connection, PathBuf::from(path), config, &progress, &on_progress) => result,
+                }
+            })
+        };
+
+        Ok(Self {
+            progress,
+            cancel,
+            task: Mutex::new(Some(task)),
+        })
+    }
+
+    /// Get the current progress
+    pub fn progress(&self) -> QuicFileTransferProgress {
+        self.progress.snapshot()
+    }
+
+    /// Cancel the upload; the receiver keeps what it acknowledged for a later resume
+    pub fn cancel(&self) {
+        self.cancel.cancel();
+    }
+
+    /// Wait for the receiver to verify the file and return the final progress
+    ///
+    /// Only the first call receives the result; later calls fail.
+    pub async fn wait(&self) -> Result<QuicFileTransferProgress, QuicFileTransferException> {
+        let task = self.task.lock().await.take()
+            .ok_or_else(|| error(QuicFileTransferErrorKind::Cancelled, "Upload result was already taken"))?;
+
+        task.await
+            .map_err(|e| error(QuicFileTransferErrorKind::Cancelled, format!("Upload task failed: {:?}", e)))??;
+        Ok(self.progress.snapshot())
+    }
+}
+
+async fn upload<P>(
+    connection: quinn::Connection,
+    path: PathBuf,
+    config: QuicFileTransferConfig,
+    progress: &ProgressCounters,
+    on_progress: &P,
+) -> Result<(), QuicFileTransferException>
+where
+    P: Fn(QuicFileTransferProgress),
+{
+    let name = path.file_name()
+        .and_then(|name| name.to_str())
+        .ok_or_else(|| error(QuicFileTransferErrorKind::Io, "Path has no UTF-8 file name"))?
+        .to_string();
+    let (size, id) = {
+        let path = path.clone();
+        tokio::task::spawn_blocking(move || digest_file(&path)).await
+            .map_err(|e| error(QuicFileTransferErrorKind::Io, format!("Digest task failed: {:?}", e)))??
+    };
+    progress.total_bytes.store(size, Ordering::Relaxed);
+
+    let (mut control_send, mut control_recv) = connection.open_bi().await.map_err(connection_error)?;
+    let mut manifest = vec![MESSAGE_MANIFEST];
+    manifest.extend_from_slice(&id);
+    encode_varint(&mut manifest, size);
+    manifest.extend_from_slice(name.as_bytes());
+    write_frame(&mut control_send, &manifest, MAX_CONTROL_FRAME).await?;
+
+    let reply = parse_reply(read_frame(&mut control_recv, MAX_CONTROL_FRAME).await?)?;
+    let resumed_from = decode_varint(&reply)
+        .map(|(offset, _)| offset.min(size))
+        .ok_or_else(|| error(QuicFileTransferErrorKind::Connection, "Malformed manifest reply"))?;
+    progress.resumed_from.store(resumed_from, Ordering::Relaxed);
+    progress.bytes_transferred.store(resumed_from, Ordering::Relaxed);
+    on_progress(progress.snapshot());
+
+    let chunk_size = config.chunk_size as u64;
+    let offsets = (resumed_from..size).step_by(config.chunk_size);
+    futures::stream::iter(offsets)
+        .map(|offset| {
+            let len = chunk_size.min(size - offset);
+            send_chunk(&connection, &path, id, offset, len)
+        })
+        .buffer_unordered(config.parallel_streams)
+        .try_for_each(|len| {
+            progress.bytes_transferred.fetch_add(len, Ordering::Relaxed);
+            on_progress(progress.snapshot());
+            async { Ok(()) }
+        })
+        .await?;
+
+    write_frame(&mut control_send, &[MESSAGE_COMPLETE], MAX_CONTROL_FRAME).await?;
+    parse_reply(read_frame(&mut control_recv, MAX_CONTROL_FRAME).await?)?;
+    let _ = control_send.finish();
+    Ok(())
+}
+
+/// Send one chunk on its own stream and wait for the receiver to acknowledge it
+async fn send_chunk(
+    connection: &quinn::Connection,
+    path: &Path,
+    id: FileId,
+    offset: u64,
+    len: u64,
+) -> Result<u64, QuicFileTransferException> {
+    let mut data = vec![0u8; len as usize];
+    let mut file = tokio::fs::File::open(path).await?;
+    file.seek(SeekFrom::Start(offset)).await?;
+    file.read_exact(&mut data).await?;
+
+    let (mut send, mut recv) = connection.open_bi().await.map_err(connection_error)?;
+    let result = async {
+        let mut header = vec![MESSAGE_CHUNK];
+        header.extend_from_slice(&id);
+        encode_varint(&mut header, offset);
+        encode_varint(&mut header, len);
+        write_frame(&mut send, &header, MAX_CONTROL_FRAME).await?;
+        send.write_all(&data).await.map_err(connection_error)?;
+        let _ = send.finish();
+        parse_reply(read_frame(&mut recv, MAX_CONTROL_FRAME).await?)
+    }.await;
+
+    if result.is_err() {
+        let _ = send.reset(FILE_TRANSFER_CANCELLED_ERROR_CODE.into());
+    }
+    result.map(|_| len)
+}
+
+struct IncomingState {
+    /// Bytes held contiguously from the start of the file
+    acknowledged: u64,
+    /// Chunks written beyond `acknowledged`, by offset, with their length
+    written: BTreeMap<u64, u64>,
+}
+
+struct IncomingFile {
+    size: u64,
+    digest: FileId,
+    destination: PathBuf,
+    part_path: PathBuf,
+    ack_path: PathBuf,
+    file: Mutex<tokio::fs::File>,
+    state: StdMutex<IncomingState>,
+}
+
+struct ReceiverShared {
+    directory: PathBuf,
+    max_file_size: u64,
+    files: StdMutex<HashMap<FileId, Arc<IncomingFile>>>,
+}
+
+/// Receives uploaded files into a directory
+///
+/// Files are written to a hidden `.part` file next to their destination, with
+/// the acknowledged offset in a `.ack` file, so uploads resume even after the
+/// receiving process restarts. A file replaces an existing one of the same
+/// name once its digest was verified. Serve it with
+/// `SimpleQuicServer::serve_files`, or on a single connection with
+/// `serve_connection`.
+#[derive(Clone)]
+#[frb(opaque)]
+pub struct QuicFileReceiver {
+    shared: Arc<ReceiverShared>,
+}
+
+impl QuicFileReceiver {
+    /// Create a receiver storing files in `directory`, which must exist
+    pub fn new(directory: String, max_file_size: u64) -> Result<Self, QuicFileTransferException> {
+        let directory = PathBuf::from(directory);
+        if !directory.is_dir() {
+            return Err(error(QuicFileTransferErrorKind::Io, format!("{} is not a directory", directory.display())));
+        }
+
+        Ok(Self {
+            shared: Arc::new(ReceiverShared {
+                directory,
+                max_file_size,
+                files: StdMutex::new(HashMap::new()),
+            }),
+        })
+    }
+
+    /// Receive files uploaded on `connection` until it closes
+    pub async fn serve_connection(&self, connection: &QuicConnection) {
+        self.serve(connection.inner().clone(), CancellationToken::new(), Arc::default()).await;
+    }
+
+    pub(crate) async fn serve(&self, connection: quinn::Connection, shutdown: CancellationToken, counters: Arc<ServerCounters>) {
+        // Dropping the set stops the streams of this connection
+        let mut streams = JoinSet::new();
+        loop {
+            let accepted = tokio::select! {
+                _ = shutdown.cancelled() => break,
+                accepted = connection.accept_bi() => accepted,
+            };
+            let Ok((send, recv)) = accepted else {
+                break;
+            };
+            streams.spawn(serve_stream(self.shared.clone(), send, recv, counters.clone()));
+        }
+    }
+}
+
+async fn serve_stream(
+    shared: Arc<ReceiverShared>,
+    mut send: quinn::SendStream,
+    mut recv: quinn::RecvStream,
+    counters: Arc<ServerCounters>,
+) {
+    let Ok(Some(first)) = read_frame(&mut recv, MAX_CONTROL_FRAME).await else {
+        return;
+    };
+
+    if first.first() == Some(&MESSAGE_CHUNK) {
+        let reply = match receive_chunk(&shared, &first[1..], &mut recv).await {
+            Ok(len) => {
+                counters.bytes_received.fetch_add(len, Ordering::Relaxed);
+                vec![REPLY_OK]
+            }
+            Err(e) => {
+                tracing::debug!("File chunk rejected: {}", e);
+                let _ = recv.stop(FILE_TRANSFER_CANCELLED_ERROR_CODE.into());
+                error_reply(&e)
+            }
+        };
+        let _ = write_frame(&mut send, &reply, MAX_CONTROL_FRAME).await;
+        let _ = send.finish();
+        return;
+    }
+
+    let file = match open_incoming(&shared, &first).await {
+        Ok(file) => file,
+        Err(e) => {
+            tracing::debug!("File manifest rejected: {}", e);
+            let _ = write_frame(&mut send, &error_reply(&e), MAX_CONTROL_FRAME).await;
+            counters.requests_failed.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+    };
+    let mut reply = vec![REPLY_OK];
+    encode_varint(&mut reply, file.state.lock().unwrap().acknowledged);
+    if write_frame(&mut send, &reply, MAX_CONTROL_FRAME).await.is_err() {
+        return;
+    }
+
+    // The upload ends with a completion message once all chunks were acknowledged
+    if let Ok(Some(message)) = read_frame(&mut recv, MAX_CONTROL_FRAME).await {
+        if message.first() != Some(&MESSAGE_COMPLETE) {
+            return;
+        }
+        let reply = match complete(&shared, &file).await {
+            Ok(()) => {
+                counters.requests_handled.fetch_add(1, Ordering::Relaxed);
+                vec![REPLY_OK]
+            }
+            Err(e) => {
+                tracing::debug!("File upload failed: {}", e);
+                counters.requests_failed.fetch_add(1, Ordering::Relaxed);
+                error_reply(&e)
+            }
+        };
+        let _ = write_frame(&mut send, &reply, MAX_CONTROL_FRAME).await;
+        let _ = send.finish();
+    }
+}
+
+fn rejected(message: impl Into<String>) -> QuicFileTransferException {
+    error(QuicFileTransferErrorKind::Rejected, message)
+}
+
+/// Look up or create the incoming file announced by a manifest
+async fn open_incoming(shared: &ReceiverShared, manifest: &[u8]) -> Result<Arc<IncomingFile>, QuicFileTransferException> {
+    let body = manifest.strip_prefix(&[MESSAGE_MANIFEST])
+        .filter(|body| body.len() > DIGEST_LEN)
+        .ok_or_else(|| rejected("Malformed manifest"))?;
+    let mut digest = [0u8; DIGEST_LEN];
+    digest.copy_from_slice(&body[..DIGEST_LEN]);
+    let (size, len) = decode_varint(&body[DIGEST_LEN..]).ok_or_else(|| rejected("Malformed manifest"))?;
+    let name = std::str::from_utf8(&body[DIGEST_LEN + len..]).map_err(|_| rejected("File name is not UTF-8"))?;
+
+    if name.is_empty() || name == "." || name == ".." || name.contains(['/', '\\', '\0']) {
+        return Err(rejected(format!("Invalid file name {:?}", name)));
+    }
+    if size > shared.max_file_size {
+        return Err(rejected(format!("File of {} bytes exceeds the limit of {} bytes", size, shared.max_file_size)));
+    }
+
+    if let Some(file) = shared.files.lock().unwrap().get(&digest) {
+        return Ok(file.clone());
+    }
+
+    // Name the partial files after the digest, so different uploads never share one
+    let hex: String = digest[..8].iter().map(|byte| format!("{:02x}", byte)).collect();
+    let part_path = shared.directory.join(format!(".{}.{}.part", name, hex));
+    let ack_path = shared.directory.join(format!(".{}.{}.ack", name, hex));
+
+    let acknowledged = match tokio::fs::read(&ack_path).await {
+        Ok(ack) if tokio::fs::try_exists(&part_path).await.unwrap_or(false) => {
+            ack.try_into().map(u64::from_be_bytes).unwrap_or(0).min(size)
+        }
+        _ => 0,
+    };
+    let file = tokio::fs::OpenOptions::new().create(true).truncate(false).write(true).open(&part_path).await?;
+    file.set_len(size).await?;
+
+    let incoming = Arc::new(IncomingFile {
+        size,
+        digest,
+        destination: shared.directory.join(name),
+        part_path,
+        ack_path,
+        file: Mutex::new(file),
+        state: StdMutex::new(IncomingState {
+            acknowledged,
+            written: BTreeMap::new(),
+        }),
+    });
+    // Another connection may have announced the same file meanwhile
+    let mut files = shared.files.lock().unwrap();
+    Ok(files.entry(digest).or_insert(incoming).clone())
+}
+
+/// Write a chunk to its file, returning its length
+async fn receive_chunk(
+    shared: &ReceiverShared,
+    header: &[u8],
+    recv: &mut quinn::RecvStream,
+) -> Result<u64, QuicFileTransferException> {
+    if header.len() <= DIGEST_LEN {
+        return Err(rejected("Malformed chunk header"));
+    }
+    let mut digest = [0u8; DIGEST_LEN];
+    digest.copy_from_slice(&header[..DIGEST_LEN]);
+    let mut pos = DIGEST_LEN;
+    let mut next_varint = || {
+        let (value, len) = decode_varint(&header[pos..])?;
+        pos += len;
+        Some(value)
+    };
+    let (Some(offset), Some(len)) = (next_varint(), next_varint()) else {
+        return Err(rejected("Malformed chunk header"));
+    };
+
+    let file = shared.files.lock().unwrap().get(&digest).cloned()
+        .ok_or_else(|| rejected("Chunk for a file without manifest"))?;
+    if len == 0 || len > MAX_CHUNK_SIZE as u64 || offset.checked_add(len).is_none_or(|end| end > file.size) {
+        return Err(rejected(format!("Chunk of {} bytes at {} is out of range", len, offset)));
+    }
+
+    let mut data = vec![0u8; len as usize];
+    recv.read_exact(&mut data).await.map_err(connection_error)?;
+
+    let mut handle = file.file.lock().await;
+    handle.seek(SeekFrom::Start(offset)).await?;
+    handle.write_all(&data).await?;
+
+    let acknowledged = {
+        let mut state = file.state.lock().unwrap();
+        if offset >= state.acknowledged {
+            state.written.insert(offset, len);
+        }
+        let before = state.acknowledged;
+        loop {
+            let next = state.acknowledged;
+            let Some(len) = state.written.remove(&next) else {
+                break;
+            };
+            state.acknowledged += len;
+        }
+        (state.acknowledged > before).then_some(state.acknowledged)
+    };
+
+    // Persist progress only once the data it covers is on disk
+    if let Some(acknowledged) = acknowledged {
+        handle.sync_data().await?;
+        tokio::fs::write(&file.ack_path, acknowledged.to_be_bytes()).await?;
+    }
+    Ok(len)
+}
+
+/// Verify a fully received file and move it to its destination
+async fn complete(shared: &ReceiverShared, file: &IncomingFile) -> Result<(), QuicFileTransferException> {
+    let mut handle = file.file.lock().await;
+    let acknowledged = file.state.lock().unwrap().acknowledged;
+    if acknowledged < file.size {
+        return Err(rejected(format!("Only {} of {} bytes were received", acknowledged, file.size)));
+    }
+
+    handle.flush().await?;
+    let part_path = file.part_path.clone();
+    let (_, digest) = tokio::task::spawn_blocking(move || digest_file(&part_path)).await
+        .map_err(|e| error(QuicFileTransferErrorKind::Io, format!("Digest task failed: {:?}", e)))??;
+
+    shared.files.lock().unwrap().remove(&file.digest);
+    let _ = tokio::fs::remove_file(&file.ack_path).await;
+    if digest != file.digest {
+        let _ = tokio::fs::remove_file(&file.part_path).await;
+        return Err(error(QuicFileTransferErrorKind::ChecksumMismatch, "SHA-256 digest does not match the manifest"));
+    }
+
+    tokio::fs::rename(&file.part_path, &file.destination).await?;
+    drop(handle);
+    Ok(())
+}
@@ -0,0 +1,135 @@
+//! Framing API - Length-prefixed messages over QUIC streams
+//!
+//! Each message is sent as its length in bytes, encoded as a QUIC
+//! variable-length integer, followed by the message itself.
+
+use flutter_rust_bridge::frb;
+use crate::convenience::varint::{decode_varint, encode_varint};
+use crate::core::{QuicSendStream, QuicRecvStream};
+use crate::errors::{QuicFrameErrorKind, QuicFrameException};
+use tokio::sync::Mutex;
+
+/// Write one length-prefixed message
+pub(crate) async fn write_frame(
+    stream: &mut quinn::SendStream,
+    message: &[u8],
+    max_message_size: usize,
+) -> Result<(), QuicFrameException> {
+    if message.len() > max_message_size {
+        return Err(QuicFrameException::new(
+            QuicFrameErrorKind::TooLarge,
+            format!("Message of {} bytes exceeds max_message_size of {} bytes", message.len(), max_message_size),
+        ));
+    }
+
+    let mut frame = Vec::with_capacity(message.len() + 8);
+    encode_varint(&mut frame, message.len() as u64);
+    frame.extend_from_slice(message);
+    stream.write_all(&frame).await?;
+    Ok(())
+}
+
+/// Read one length-prefixed message, or `None` if the stream ended between messages
+pub(crate) async fn read_frame(
+    stream: &mut quinn::RecvStream,
+    max_message_size: usize,
+) -> Result<Option<Vec<u8>>, QuicFrameException> {
+    let mut prefix = [0u8; 8];
+    if stream.read(&mut prefix[..1]).await? != Some(1) {
+        return Ok(None);
+    }
+
+    let prefix_len = 1 << (prefix[0] >> 6);
+    read_exact(stream, &mut prefix[1..prefix_len]).await?;
+    let (len, _) = decode_varint(&prefix[..prefix_len])
+        .expect("prefix holds a complete varint");
+
+    if len > max_message_size as u64 {
+        return Err(QuicFrameException::new(
+            QuicFrameErrorKind::TooLarge,
+            format!("Frame of {} bytes exceeds max_message_size of {} bytes", len, max_message_size),
+        ));
+    }
+
+    let mut message = vec![0u8; len as usize];
+    read_exact(stream, &mut message).await?;
+    Ok(Some(message))
+}
+
+async fn read_exact(stream: &mut quinn::RecvStream, buf: &mut [u8]) -> Result<(), QuicFrameException> {
+    stream.read_exact(buf).await.map_err(|error| match error {
+        quinn::ReadExactError::FinishedEarly(read) => QuicFrameException::new(
+            QuicFrameErrorKind::Truncated,
+            format!("Stream ended {} bytes into a {} byte frame part", read, buf.len()),
+        ),
+        quinn::ReadExactError::ReadError(error) => QuicFrameException::from(error),
+    })
+}
+
+/// Sends and receives length-prefixed messages on a QUIC stream pair
+///
+/// Either half may be absent, e.g. for unidirectional streams. Sending and
+/// receiving use separate locks, so a message can be sent while a receive is
+/// pending. After a receive error the stream's framing is lost and it should
+/// be discarded.
+///
+/// # Example
+/// ```dart
+/// final (_, send, recv) = await connectionOpenBi(connection: connection);
+/// final framed = await framedStreamNew(send: send, recv: recv, maxMessageSize: BigInt.from(65536));
+/// await framedStreamSend(stream: framed, message: utf8.encode('hello'));
+/// final reply = await framedStreamRecv(stream: framed);
+/// ```
+#[frb(opaque)]
+pub struct QuicFramedStream {
+    send: Option<Mutex<quinn::SendStream>>,
+    recv: Option<Mutex<quinn::RecvStream>>,
+    max_message_size: usize,
+}
+
+impl QuicFramedStream {
+    /// Create a framed stream over the given halves
+    ///
+    /// # Arguments
+    /// * `send` - Stream to send messages on, if any
+    /// * `recv` - Stream to receive messages from, if any
+    /// * `max_message_size` - Largest message accepted in either direction
+    pub fn new(send: Option<QuicSendStream>, recv: Option<QuicRecvStream>, max_message_size: usize) -> Self {
+        Self {
+            send: send.map(|stream| Mutex::new(stream.into_inner())),
+            recv: recv.map(|stream| Mutex::new(stream.into_inner())),
+            max_message_size,
+        }
+    }
+
+    /// Send one message
+    pub async fn send(&self, message: Vec<u8>) -> Result<(), QuicFrameException> {
+        let mut stream = self.send_half()?.lock().await;
+        write_frame(&mut stream, &message, self.max_message_size).await
+    }
+
+    /// Receive the next message, or `None` once the peer finished the stream
+    ///
+    /// Fails with `Truncated` if the stream ends inside a frame and with
+    /// `TooLarge` if a frame exceeds the maximum message size.
+    pub async fn recv(&self) -> Result<Option<Vec<u8>>, QuicFrameException> {
+        let recv = self.recv.as_ref().ok_or_else(|| {
+            QuicFrameException::new(QuicFrameErrorKind::Read, "Framed stream has no receive half".to_string())
+        })?;
+        let mut stream = recv.lock().await;
+        read_frame(&mut stream, self.max_message_size).await
+    }
+
+    /// Finish the send half, so the peer receives `None` after the last message
+    pub async fn finish(&self) -> Result<(), QuicFrameException> {
+        self.send_half()?.lock().await.finish().map_err(|_| {
+            QuicFrameException::new(QuicFrameErrorKind::Write, "Stream was already finished".to_string())
+        })
+    }
+
+    fn send_half(&self) -> Result<&Mutex<quinn::SendStream>, QuicFrameException> {
+        self.send.as_ref().ok_or_else(|| {
+            QuicFrameException::new(QuicFrameErrorKind::Write, "Framed stream has no send half".to_string())
+        })
+    }
+}
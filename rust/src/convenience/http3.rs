@@ -0,0 +1,496 @@
+//! HTTP/3 Client API - HTTP semantics over QUIC connections
+
+use flutter_rust_bridge::frb;
+use crate::core::{QuicEndpoint, QuicConnection};
+use crate::errors::QuicError;
+use bytes::{Buf, Bytes};
+use futures::future::{BoxFuture, FutureExt, Shared};
+use futures::{Stream, StreamExt};
+use http::header::{HeaderMap, HeaderName, HeaderValue};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio_util::sync::CancellationToken;
+
+/// ALPN protocol identifier for HTTP/3
+pub const HTTP3_ALPN: &[u8] = b"h3";
+
+type H3SendRequest = h3::client::SendRequest<h3_quinn::OpenStreams, Bytes>;
+type H3RecvStream = h3::client::RequestStream<h3_quinn::RecvStream, Bytes>;
+type H3SendStream = h3::client::RequestStream<h3_quinn::SendStream<Bytes>, Bytes>;
+
+/// Configuration for Http3Client
+#[derive(Debug, Clone)]
+pub struct Http3ClientConfig {
+    /// Connection timeout in milliseconds
+    pub connect_timeout_ms: u64,
+    /// Request timeout in milliseconds, covering the whole buffered exchange
+    pub request_timeout_ms: u64,
+    /// Maximum response body size in bytes for buffered requests
+    pub max_response_size: usize,
+    /// Maximum size of a received header or trailer section in bytes
+    pub max_field_section_size: u64,
+}
+
+impl Default for Http3ClientConfig {
+    fn default() -> Self {
+        Self {
+            connect_timeout_ms: 5000,
+            request_timeout_ms: 30000,
+            max_response_size: 1024 * 1024,
+            max_field_section_size: 16 * 1024,
+        }
+    }
+}
+
+/// A single HTTP header or trailer field
+///
+/// Fields are kept as an ordered list so repeated names such as `set-cookie`
+/// survive the round trip.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Http3Header {
+    pub name: String,
+    pub value: String,
+}
+
+/// An HTTP/3 request
+#[derive(Debug, Clone)]
+pub struct Http3Request {
+    /// Request method (e.g., "GET", "POST")
+    pub method: String,
+    /// Absolute https URL (e.g., "https://127.0.0.1:4433/api/items")
+    pub url: String,
+    pub headers: Vec<Http3Header>,
+    /// Request body; ignored by `send_streaming`, which takes the body as a stream
+    pub body: Vec<u8>,
+    /// Trailer fields sent after the body
+    pub trailers: Vec<Http3Header>,
+}
+
+impl Http3Request {
+    /// Create a request without headers, body or trailers
+    pub fn new(method: String, url: String) -> Self {
+        Self {
+            method,
+            url,
+            headers: Vec::new(),
+            body: Vec::new(),
+            trailers: Vec::new(),
+        }
+    }
+}
+
+/// A buffered HTTP/3 response
+#[derive(Debug, Clone)]
+pub struct Http3Response {
+    pub status: u16,
+    pub headers: Vec<Http3Header>,
+    pub body: Vec<u8>,
+    /// Trailer fields, empty if the server sent none
+    pub trailers: Vec<Http3Header>,
+}
+
+/// Established HTTP/3 connection to one authority
+#[derive(Clone)]
+struct Http3Connection {
+    connection: QuicConnection,
+    send_request: H3SendRequest,
+}
+
+/// Handshake or established connection, shared by every request for the same authority
+type SharedConnection = Shared<BoxFuture<'static, Result<Http3Connection, QuicError>>>;
+
+/// HTTP/3 client built on QuicEndpoint
+///
+/// Requests use QPACK-encoded headers on a connection negotiated with the `h3`
+/// ALPN. One connection is kept per authority, as RFC 9114 recommends, and
+/// requests to it are multiplexed as separate streams. A connection found closed
+/// is replaced on the next request. Uses insecure configuration suitable for
+/// testing, like QuicClient.
+///
+/// # Example
+/// ```dart
+/// final client = await http3ClientCreate(config: await http3ClientConfigNew());
+/// final request = await http3RequestNew(method: 'GET', url: 'https://127.0.0.1:4433/');
+/// final (_, response) = await http3ClientSend(client: client, request: request);
+/// print('${response.status}: ${utf8.decode(response.body)}');
+/// ```
+#[frb(opaque)]
+pub struct Http3Client {
+    endpoint: Arc<QuicEndpoint>,
+    config: Http3ClientConfig,
+    // Keyed by host:port; completed entries stay cached until their connection closes
+    connections: Mutex<HashMap<String, SharedConnection>>,
+}
+
+impl Http3Client {
+    /// Create a new Http3Client with the given configuration
+    pub fn create(config: Http3ClientConfig) -> Result<Self, QuicError> {
+        let endpoint = QuicEndpoint::client_with_alpn(vec![HTTP3_ALPN.to_vec()])?;
+
+        Ok(Self {
+            endpoint: Arc::new(endpoint),
+            config,
+            connections: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Get the current configuration
+    pub fn config(&self) -> Http3ClientConfig {
+        self.config.clone()
+    }
+
+    /// Send a request and buffer the whole response
+    ///
+    /// Fails with `ResponseTooLong` if the response body exceeds
+    /// `max_response_size`, or if the exchange takes longer than
+    /// `request_timeout_ms`. Non-2xx statuses are returned as
+    /// responses, not errors.
+    pub async fn send(&self, request: Http3Request) -> Result<Http3Response, QuicError> {
+        let timeout_duration = Duration::from_millis(self.config.request_timeout_ms);
+
+        match tokio::time::timeout(timeout_duration, self.send_buffered(request)).await {
+            Ok(result) => result,
+            Err(_) => Err(QuicError::Network("Request timed out".to_string())),
+        }
+    }
+
+    async fn send_buffered(&self, request: Http3Request) -> Result<Http3Response, QuicError> {
+        let (head, trailers) = build_request(&request)?;
+        let mut stream = self.open_request(&request.url, head).await?;
+
+        if !request.body.is_empty() {
+            stream.send_data(Bytes::from(request.body)).await
+                .map_err(|e| QuicError::Stream(format!("Failed to send request body: {:?}", e)))?;
+        }
+        finish_request(&mut stream, trailers).await?;
+
+        let response = stream.recv_response().await
+            .map_err(|e| QuicError::Stream(format!("Failed to receive response: {:?}", e)))?;
+
+        let max_response_size = self.config.max_response_size;
+        let mut body = Vec::new();
+        while let Some(mut chunk) = stream.recv_data().await
+            .map_err(|e| QuicError::Stream(format!("Failed to read response: {:?}", e)))?
+        {
+            if body.len() + chunk.remaining() > max_response_size {
+                stream.stop_sending(h3::error::Code::H3_REQUEST_CANCELLED);
+                return Err(QuicError::ResponseTooLong { limit: max_response_size });
+            }
+            body.extend_from_slice(&chunk.copy_to_bytes(chunk.remaining()));
+        }
+
+        let trailers = stream.recv_trailers().await
+            .map_err(|e| QuicError::Stream(format!("Failed to read trailers: {:?}", e)))?;
+
+        Ok(Http3Response {
+            status: response.status().as_u16(),
+            headers: to_fields(response.headers()),
+            body,
+            trailers: trailers.as_ref().map(to_fields).unwrap_or_default(),
+        })
+    }
+
+    /// Send a request whose body and response are streamed in chunks
+    ///
+    /// Returns once the response headers arrived. `body` is written in the
+    /// background as flow control allows, followed by `request.trailers`;
+    /// `request.body` is ignored. The response body is read on demand through the
+    /// returned handle, so an unread response stalls the server rather than
+    /// filling memory. `request_timeout_ms` only covers the wait for headers.
+    pub async fn send_streaming<S>(&self, request: Http3Request, body: S) -> Result<Http3ResponseStream, QuicError>
+    where
+        S: Stream<Item = Vec<u8>> + Send + 'static,
+    {
+        let (head, trailers) = build_request(&request)?;
+        let stream = self.open_request(&request.url, head).await?;
+        let (send_stream, mut recv_stream) = stream.split();
+
+        let cancel = CancellationToken::new();
+        tokio::spawn(upload(send_stream, body, trailers, cancel.clone()));
+
+        let timeout_duration = Duration::from_millis(self.config.request_timeout_ms);
+        let response = match tokio::time::timeout(timeout_duration, recv_stream.recv_response()).await {
+            Ok(Ok(response)) => response,
+            Ok(Err(e)) => {
+                cancel.cancel();
+                return Err(QuicError::Stream(format!("Failed to receive response: {:?}", e)));
+            }
+            Err(_) => {
+                cancel.cancel();
+                recv_stream.stop_sending(h3::error::Code::H3_REQUEST_CANCELLED);
+                return Err(QuicError::Network("Request timed out".to_string()));
+            }
+        };
+
+        Ok(Http3ResponseStream {
+            status: response.status().as_u16(),
+            headers: to_fields(response.headers()),
+            body: tokio::sync::Mutex::new(recv_stream),
+            cancel,
+        })
+    }
+
+    /// Close every pooled connection
+    ///
+    /// Requests in flight on them fail; later requests reconnect.
+    pub fn close(&self) {
+        let connections = std::mem::take(&mut *self.connections.lock().unwrap());
+        for pending in connections.into_values() {
+            if let Some(Ok(connection)) = pending.peek() {
+                connection.connection.inner().close(0u32.into(), b"client closed");
+            }
+        }
+    }
+
+    /// Open a request stream and send the request headers
+    ///
+    /// If the cached connection turns out to be closed, it is dropped and the
+    /// headers are sent once more on a fresh connection.
+    async fn open_request(
+        &self,
+        url: &str,
+        head: http::Request<()>,
+    ) -> Result<h3::client::RequestStream<h3_quinn::BidiStream<Bytes>, Bytes>, QuicError> {
+        let (mut connection, reused) = self.get_connection(url).await?;
+
+        match connection.send_request.send_request(head.clone()).await {
+            Ok(stream) => Ok(stream),
+            Err(error) if connection.connection.close_reason().is_some() => {
+                self.evict_connection(&connection.connection);
+                if !reused {
+                    return Err(QuicError::Stream(format!("Failed to send request: {:?}", error)));
+                }
+
+                let (mut connection, _) = self.get_connection(url).await?;
+                connection.send_request.send_request(head).await
+                    .map_err(|e| QuicError::Stream(format!("Failed to send request: {:?}", e)))
+            }
+            Err(error) => Err(QuicError::Stream(format!("Failed to send request: {:?}", error))),
+        }
+    }
+
+    /// Get the connection for the authority of `url`, connecting if needed
+    ///
+    /// Concurrent requests to an authority without a live connection share one
+    /// handshake. Returns whether the connection was already established.
+    async fn get_connection(&self, url: &str) -> Result<(Http3Connection, bool), QuicError> {
+        let uri: http::Uri = url.parse()
+            .map_err(|e| QuicError::Connection(format!("Invalid URL: {:?}", e)))?;
+        let host = uri.host()
+            .ok_or_else(|| QuicError::Connection("URL must contain a host".to_string()))?;
+        // IPv6 literals keep their brackets so the address parses as host:port
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+        let port = uri.port_u16().unwrap_or(443);
+        let host_key = if host.contains(':') { format!("[{}]:{}", host, port) } else { format!("{}:{}", host, port) };
+
+        let (pending, reused) = {
+            let mut connections = self.connections.lock().unwrap();
+            match connections.get(&host_key) {
+                Some(pending) => match pending.peek() {
+                    Some(Ok(connection)) if connection.connection.close_reason().is_none() => (pending.clone(), true),
+                    // A handshake in progress
+                    None => (pending.clone(), false),
+                    // Failed handshake or closed connection
+                    Some(_) => {
+                        let pending = self.connect(host_key.clone(), host.to_string());
+                        connections.insert(host_key, pending.clone());
+                        (pending, false)
+                    }
+                },
+                None => {
+                    let pending = self.connect(host_key.clone(), host.to_string());
+                    connections.insert(host_key, pending.clone());
+                    (pending, false)
+                }
+            }
+        };
+
+        Ok((pending.await?, reused))
+    }
+
+    /// Start the QUIC and HTTP/3 handshakes with `addr`
+    fn connect(&self, addr: String, server_name: String) -> SharedConnection {
+        let endpoint = self.endpoint.clone();
+        let connect_timeout = Duration::from_millis(self.config.connect_timeout_ms);
+        let max_field_section_size = self.config.max_field_section_size;
+
+        async move {
+            let connection = tokio::time::timeout(connect_timeout, endpoint.connect(addr, server_name)).await
+                .map_err(|_| QuicError::Connection("Connection timeout".to_string()))??;
+
+            let (mut driver, send_request) = h3::client::builder()
+                .max_field_section_size(max_field_section_size)
+                .build(h3_quinn::Connection::new(connection.inner().clone()))
+                .await
+                .map_err(|e| QuicError::Connection(format!("Failed to establish HTTP/3 connection: {:?}", e)))?;
+
+            // The driver handles the control streams and ends with the connection
+            tokio::spawn(async move {
+                let _ = driver.wait_idle().await;
+            });
+
+            Ok(Http3Connection { connection, send_request })
+        }
+        .boxed()
+        .shared()
+    }
+
+    /// Forget a cached connection, e.g. after it was lost
+    fn evict_connection(&self, connection: &QuicConnection) {
+        let id = connection.stable_id();
+        self.connections.lock().unwrap().retain(|_, pending| {
+            !matches!(pending.peek(), Some(Ok(cached)) if cached.connection.stable_id() == id)
+        });
+    }
+}
+
+/// Response of a streaming HTTP/3 request, with the body still to be read
+///
+/// Chunks are read from the QUIC stream only when `next_chunk` is called.
+#[frb(opaque)]
+pub struct Http3ResponseStream {
+    status: u16,
+    headers: Vec<Http3Header>,
+    body: tokio::sync::Mutex<H3RecvStream>,
+    cancel: CancellationToken,
+}
+
+impl Http3ResponseStream {
+    /// Get the response status code
+    pub fn status(&self) -> u16 {
+        self.status
+    }
+
+    /// Get the response header fields
+    pub fn headers(&self) -> Vec<Http3Header> {
+        self.headers.clone()
+    }
+
+    /// Read the next response body chunk, or `None` at the end of the body
+    pub async fn next_chunk(&self) -> Result<Option<Vec<u8>>, QuicError> {
+        let mut body = self.body.lock().await;
+
+        let chunk = tokio::select! {
+            _ = self.cancel.cancelled() => None,
+            chunk = body.recv_data() => Some(match chunk {
+                Ok(Some(mut chunk)) => Ok(Some(chunk.copy_to_bytes(chunk.remaining()).to_vec())),
+                Ok(None) => Ok(None),
+                Err(e) => Err(QuicError::Stream(format!("Failed to read response: {:?}", e))),
+            }),
+        };
+
+        chunk.unwrap_or_else(|| {
+            body.stop_sending(h3::error::Code::H3_REQUEST_CANCELLED);
+            Err(QuicError::Stream("Request was cancelled".to_string()))
+        })
+    }
+
+    /// Read the trailer fields once the body has been read to the end
+    pub async fn trailers(&self) -> Result<Vec<Http3Header>, QuicError> {
+        let trailers = self.body.lock().await.recv_trailers().await
+            .map_err(|e| QuicError::Stream(format!("Failed to read trailers: {:?}", e)))?;
+        Ok(trailers.as_ref().map(to_fields).unwrap_or_default())
+    }
+
+    /// Cancel the request, resetting the stream in both directions
+    pub fn cancel(&self) {
+        self.cancel.cancel();
+        if let Ok(mut body) = self.body.try_lock() {
+            body.stop_sending(h3::error::Code::H3_REQUEST_CANCELLED);
+        }
+    }
+}
+
+impl Drop for Http3ResponseStream {
+    fn drop(&mut self) {
+        // An unfinished upload has nobody left to answer
+        self.cancel.cancel();
+    }
+}
+
+/// Write a streamed request body and trailers, resetting the stream on cancellation
+async fn upload<S>(mut stream: H3SendStream, body: S, trailers: Option<HeaderMap>, cancel: CancellationToken)
+where
+    S: Stream<Item = Vec<u8>> + Send + 'static,
+{
+    let send = async {
+        let mut body = Box::pin(body);
+        while let Some(chunk) = body.next().await {
+            stream.send_data(Bytes::from(chunk)).await
+                .map_err(|e| QuicError::Stream(format!("Failed to send request body: {:?}", e)))?;
+        }
+        finish_request(&mut stream, trailers).await
+    };
+
+    let result = tokio::select! {
+        _ = cancel.cancelled() => Err(QuicError::Stream("Request was cancelled".to_string())),
+        result = send => result,
+    };
+
+    if let Err(error) = result {
+        tracing::debug!("HTTP/3 request body upload failed: {:?}", error);
+        stream.stop_stream(h3::error::Code::H3_REQUEST_CANCELLED);
+    }
+}
+
+/// Send trailers if there are any, then finish the request stream
+async fn finish_request<S>(stream: &mut h3::client::RequestStream<S, Bytes>, trailers: Option<HeaderMap>) -> Result<(), QuicError>
+where
+    S: h3::quic::SendStream<Bytes>,
+{
+    if let Some(trailers) = trailers {
+        stream.send_trailers(trailers).await
+            .map_err(|e| QuicError::Stream(format!("Failed to send trailers: {:?}", e)))?;
+    }
+    stream.finish().await
+        .map_err(|e| QuicError::Stream(format!("Failed to finish request: {:?}", e)))
+}
+
+/// Build the request head and trailer map of `request`
+fn build_request(request: &Http3Request) -> Result<(http::Request<()>, Option<HeaderMap>), QuicError> {
+    let method = http::Method::from_bytes(request.method.as_bytes())
+        .map_err(|e| QuicError::Config(format!("Invalid method: {:?}", e)))?;
+    let uri: http::Uri = request.url.parse()
+        .map_err(|e| QuicError::Connection(format!("Invalid URL: {:?}", e)))?;
+    if uri.scheme() != Some(&http::uri::Scheme::HTTPS) || uri.authority().is_none() {
+        return Err(QuicError::Connection(format!("URL must be an absolute https URL: {}", request.url)));
+    }
+
+    let mut head = http::Request::builder()
+        .method(method)
+        .uri(uri)
+        .body(())
+        .map_err(|e| QuicError::Config(format!("Invalid request: {:?}", e)))?;
+    *head.headers_mut() = to_header_map(&request.headers)?;
+
+    let trailers = if request.trailers.is_empty() {
+        None
+    } else {
+        Some(to_header_map(&request.trailers)?)
+    };
+
+    Ok((head, trailers))
+}
+
+pub(crate) fn to_header_map(fields: &[Http3Header]) -> Result<HeaderMap, QuicError> {
+    let mut map = HeaderMap::with_capacity(fields.len());
+    for field in fields {
+        let name = HeaderName::from_bytes(field.name.as_bytes())
+            .map_err(|e| QuicError::Config(format!("Invalid header name {:?}: {:?}", field.name, e)))?;
+        let value = HeaderValue::from_str(&field.value)
+            .map_err(|e| QuicError::Config(format!("Invalid value for header {}: {:?}", field.name, e)))?;
+        map.append(name, value);
+    }
+    Ok(map)
+}
+
+/// Convert a header map to fields, replacing non-UTF-8 bytes in values
+pub(crate) fn to_fields(map: &HeaderMap) -> Vec<Http3Header> {
+    map.iter()
+        .map(|(name, value)| Http3Header {
+            name: name.as_str().to_string(),
+            value: String::from_utf8_lossy(value.as_bytes()).into_owned(),
+        })
+        .collect()
+}
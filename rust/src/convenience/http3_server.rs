@@ -0,0 +1,343 @@
+//! HTTP/3 Server API - Request routing for SimpleQuicServer
+
+use flutter_rust_bridge::frb;
+use crate::convenience::http3::{to_fields, to_header_map, Http3Header};
+use crate::convenience::server::ServerCounters;
+use crate::errors::QuicError;
+use bytes::{Buf, Bytes};
+use futures::future::BoxFuture;
+use std::future::Future;
+use std::sync::atomic::Ordering;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+type H3ServerSendStream = h3::server::RequestStream<h3_quinn::SendStream<Bytes>, Bytes>;
+type H3ServerRecvStream = h3::server::RequestStream<h3_quinn::RecvStream, Bytes>;
+
+pub(crate) type RouteHandler =
+    Arc<dyn Fn(Http3ServerRequest, Http3ServerStream) -> BoxFuture<'static, Result<(), String>> + Send + Sync>;
+
+/// Head of a request received by the HTTP/3 server
+#[derive(Debug, Clone)]
+pub struct Http3ServerRequest {
+    pub method: String,
+    /// Request path without the query (e.g., "/api/items")
+    pub path: String,
+    /// Query string without the leading `?`, if any
+    pub query: Option<String>,
+    pub headers: Vec<Http3Header>,
+}
+
+impl Http3ServerRequest {
+    fn from_head(request: &http::Request<()>) -> Self {
+        Self {
+            method: request.method().as_str().to_string(),
+            path: request.uri().path().to_string(),
+            query: request.uri().query().map(str::to_string),
+            headers: to_fields(request.headers()),
+        }
+    }
+}
+
+struct Route {
+    method: String,
+    path: String,
+    prefix: bool,
+    handler: RouteHandler,
+}
+
+impl Route {
+    fn matches_path(&self, path: &str) -> bool {
+        if self.prefix {
+            path.starts_with(&self.path)
+        } else {
+            path == self.path
+        }
+    }
+}
+
+/// Routes HTTP/3 requests to handlers by method and path
+///
+/// A route path matches exactly, or as a prefix when it ends in `*` (e.g.
+/// "/files/*"). Routes are tried in the order they were added. Requests whose
+/// path matches no route are answered with 404, and requests whose path only
+/// matches routes for other methods with 405.
+#[derive(Default)]
+#[frb(opaque)]
+pub struct Http3Router {
+    routes: Vec<Route>,
+}
+
+impl Http3Router {
+    /// Create a router without routes
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a route
+    ///
+    /// The handler receives the request head and a stream for reading the request
+    /// body and writing the response. If it returns without finishing the
+    /// response, the server finishes it; if it fails before sending a response,
+    /// the server answers 500.
+    ///
+    /// # Arguments
+    /// * `method` - Request method, matched case-sensitively (e.g., "GET")
+    /// * `path` - Exact path, or a prefix followed by `*`
+    /// * `handler` - Called for each matching request
+    pub fn route<F, Fut>(&mut self, method: String, path: String, handler: F) -> &mut Self
+    where
+        F: Fn(Http3ServerRequest, Http3ServerStream) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), String>> + Send + 'static,
+    {
+        let (path, prefix) = match path.strip_suffix('*') {
+            Some(prefix) => (prefix.to_string(), true),
+            None => (path, false),
+        };
+
+        self.routes.push(Route {
+            method,
+            path,
+            prefix,
+            handler: Arc::new(move |request, stream| Box::pin(handler(request, stream))),
+        });
+        self
+    }
+
+    /// Find the handler for a request, or the status and `allow` header to answer with
+    fn resolve(&self, method: &str, path: &str) -> Result<&RouteHandler, (u16, Vec<Http3Header>)> {
+        let mut allowed: Vec<&str> = Vec::new();
+
+        for route in self.routes.iter().filter(|route| route.matches_path(path)) {
+            if route.method == method {
+                return Ok(&route.handler);
+            }
+            if !allowed.contains(&route.method.as_str()) {
+                allowed.push(&route.method);
+            }
+        }
+
+        if allowed.is_empty() {
+            Err((404, Vec::new()))
+        } else {
+            Err((405, vec![Http3Header {
+                name: "allow".to_string(),
+                value: allowed.join(", "),
+            }]))
+        }
+    }
+}
+
+struct ResponseState {
+    stream: H3ServerSendStream,
+    response_sent: bool,
+    finished: bool,
+}
+
+struct StreamShared {
+    request: Mutex<H3ServerRecvStream>,
+    response: Mutex<ResponseState>,
+    max_request_size: usize,
+    counters: Arc<ServerCounters>,
+}
+
+/// Request body and response of a single HTTP/3 exchange
+///
+/// Reading and writing go through separate halves of the stream, so a handler
+/// can answer while the request body is still arriving. Clones share the stream.
+#[derive(Clone)]
+#[frb(opaque)]
+pub struct Http3ServerStream {
+    inner: Arc<StreamShared>,
+}
+
+impl Http3ServerStream {
+    fn new(
+        stream: h3::server::RequestStream<h3_quinn::BidiStream<Bytes>, Bytes>,
+        max_request_size: usize,
+        counters: Arc<ServerCounters>,
+    ) -> Self {
+        let (send, recv) = stream.split();
+
+        Self {
+            inner: Arc::new(StreamShared {
+                request: Mutex::new(recv),
+                response: Mutex::new(ResponseState {
+                    stream: send,
+                    response_sent: false,
+                    finished: false,
+                }),
+                max_request_size,
+                counters,
+            }),
+        }
+    }
+
+    /// Read the next request body chunk, or `None` at the end of the body
+    pub async fn next_chunk(&self) -> Result<Option<Vec<u8>>, QuicError> {
+        let mut request = self.inner.request.lock().await;
+        self.recv_chunk(&mut request).await
+    }
+
+    /// Read the whole request body
+    ///
+    /// A body larger than `max_request_size` is answered with 413, unless a
+    /// response was already sent, and fails the read.
+    pub async fn read_body(&self) -> Result<Vec<u8>, QuicError> {
+        let mut request = self.inner.request.lock().await;
+        let max_request_size = self.inner.max_request_size;
+        let mut body = Vec::new();
+
+        while let Some(chunk) = self.recv_chunk(&mut request).await? {
+            if body.len() + chunk.len() > max_request_size {
+                request.stop_sending(h3::error::Code::H3_NO_ERROR);
+                drop(request);
+                if !self.inner.response.lock().await.response_sent {
+                    self.respond(413, Vec::new(), Vec::new()).await?;
+                }
+                return Err(QuicError::Stream(format!(
+                    "Request too long: exceeds max_request_size of {} bytes", max_request_size
+                )));
+            }
+            body.extend(chunk);
+        }
+
+        Ok(body)
+    }
+
+    /// Read the request trailer fields once the body has been read to the end
+    pub async fn trailers(&self) -> Result<Vec<Http3Header>, QuicError> {
+        let trailers = self.inner.request.lock().await.recv_trailers().await
+            .map_err(|e| QuicError::Stream(format!("Failed to read trailers: {:?}", e)))?;
+        Ok(trailers.as_ref().map(to_fields).unwrap_or_default())
+    }
+
+    /// Send the response status and header fields
+    pub async fn send_response(&self, status: u16, headers: Vec<Http3Header>) -> Result<(), QuicError> {
+        let mut response = self.inner.response.lock().await;
+        if response.response_sent {
+            return Err(QuicError::Stream("Response was already sent".to_string()));
+        }
+
+        let mut head = http::Response::builder()
+            .status(status)
+            .body(())
+            .map_err(|e| QuicError::Config(format!("Invalid response: {:?}", e)))?;
+        *head.headers_mut() = to_header_map(&headers)?;
+
+        response.stream.send_response(head).await
+            .map_err(|e| QuicError::Stream(format!("Failed to send response: {:?}", e)))?;
+        response.response_sent = true;
+        Ok(())
+    }
+
+    /// Send a response body chunk, waiting for flow control if needed
+    pub async fn send_data(&self, chunk: Vec<u8>) -> Result<(), QuicError> {
+        let mut response = self.inner.response.lock().await;
+        Self::check_writable(&response)?;
+
+        let len = chunk.len() as u64;
+        response.stream.send_data(Bytes::from(chunk)).await
+            .map_err(|e| QuicError::Stream(format!("Failed to send data: {:?}", e)))?;
+        self.inner.counters.bytes_sent.fetch_add(len, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Send the trailer fields, if any, and finish the response
+    pub async fn finish(&self, trailers: Vec<Http3Header>) -> Result<(), QuicError> {
+        let mut response = self.inner.response.lock().await;
+        Self::check_writable(&response)?;
+
+        if !trailers.is_empty() {
+            response.stream.send_trailers(to_header_map(&trailers)?).await
+                .map_err(|e| QuicError::Stream(format!("Failed to send trailers: {:?}", e)))?;
+        }
+        response.stream.finish().await
+            .map_err(|e| QuicError::Stream(format!("Failed to finish response: {:?}", e)))?;
+        response.finished = true;
+        Ok(())
+    }
+
+    /// Send a complete response with a buffered body
+    pub async fn respond(&self, status: u16, headers: Vec<Http3Header>, body: Vec<u8>) -> Result<(), QuicError> {
+        self.send_response(status, headers).await?;
+        if !body.is_empty() {
+            self.send_data(body).await?;
+        }
+        self.finish(Vec::new()).await
+    }
+
+    /// Complete the response after the handler returned, reporting whether it was answered
+    ///
+    /// A missing response becomes a 500. A started response is finished if the
+    /// handler succeeded and reset otherwise, so a truncated body is not mistaken
+    /// for a complete one.
+    pub(crate) async fn complete(&self, result: Result<(), String>) -> bool {
+        let (response_sent, finished) = {
+            let response = self.inner.response.lock().await;
+            (response.response_sent, response.finished)
+        };
+
+        if finished {
+            return true;
+        }
+        if !response_sent {
+            let _ = self.respond(500, Vec::new(), Vec::new()).await;
+            return false;
+        }
+        if result.is_ok() && self.finish(Vec::new()).await.is_ok() {
+            return true;
+        }
+
+        self.inner.response.lock().await.stream.stop_stream(h3::error::Code::H3_INTERNAL_ERROR);
+        false
+    }
+
+    async fn recv_chunk(&self, request: &mut H3ServerRecvStream) -> Result<Option<Vec<u8>>, QuicError> {
+        let chunk = request.recv_data().await
+            .map_err(|e| QuicError::Stream(format!("Failed to read request: {:?}", e)))?
+            .map(|mut chunk| chunk.copy_to_bytes(chunk.remaining()).to_vec());
+
+        if let Some(chunk) = &chunk {
+            self.inner.counters.bytes_received.fetch_add(chunk.len() as u64, Ordering::Relaxed);
+        }
+        Ok(chunk)
+    }
+
+    fn check_writable(response: &ResponseState) -> Result<(), QuicError> {
+        if !response.response_sent {
+            return Err(QuicError::Stream("Response headers must be sent before the body".to_string()));
+        }
+        if response.finished {
+            return Err(QuicError::Stream("Response was already finished".to_string()));
+        }
+        Ok(())
+    }
+}
+
+/// Resolve a request and pick its handler, answering unroutable requests directly
+///
+/// Fails with whether the request was answered if it could not be read or has
+/// no route.
+pub(crate) async fn resolve_request(
+    resolver: h3::server::RequestResolver<h3_quinn::Connection, Bytes>,
+    router: &Http3Router,
+    max_request_size: usize,
+    counters: Arc<ServerCounters>,
+) -> Result<(RouteHandler, Http3ServerRequest, Http3ServerStream), bool> {
+    let (request, stream) = match resolver.resolve_request().await {
+        Ok(resolved) => resolved,
+        Err(e) => {
+            tracing::debug!("Failed to read HTTP/3 request: {:?}", e);
+            return Err(false);
+        }
+    };
+
+    let head = Http3ServerRequest::from_head(&request);
+    let stream = Http3ServerStream::new(stream, max_request_size, counters);
+
+    match router.resolve(&head.method, &head.path) {
+        Ok(handler) => Ok((handler.clone(), head, stream)),
+        Err((status, headers)) => Err(stream.respond(status, headers, Vec::new()).await.is_ok()),
+    }
+}
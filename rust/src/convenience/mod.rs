@@ -6,5 +6,28 @@
 pub mod client;
 pub mod server;
 pub mod pool;
+pub mod streaming;
+pub mod http3;
+pub mod http3_server;
+pub mod webtransport;
+pub mod datagram_channel;
+pub mod framing;
+pub mod rpc;
+pub mod pubsub;
+pub mod file_transfer;
+pub mod reassembly;
+pub(crate) mod varint;
 
-pub use client::{QuicClient, QuicClientConfig}; 
\ No newline at end of file
+pub use client::{QuicClient, QuicClientConfig}; 
+pub use server::{SimpleQuicServer, SimpleQuicServerConfig, SimpleQuicServerStats};
+pub use pool::{QuicConnectionPool, QuicConnectionPoolConfig, QuicConnectionPoolStats, QuicAuthorityPoolStats};
+pub use streaming::{QuicStreamingTransfer, QuicTransferProgress};
+pub use http3::{Http3Client, Http3ClientConfig, Http3Header, Http3Request, Http3Response, Http3ResponseStream};
+pub use http3_server::{Http3Router, Http3ServerRequest, Http3ServerStream};
+pub use webtransport::{WebTransportCloseInfo, WebTransportSession};
+pub use datagram_channel::{QuicDatagramChannel, QuicDatagramChannelConfig, QuicDatagramMux};
+pub use framing::QuicFramedStream;
+pub use rpc::{QuicRpcCall, QuicRpcCallOptions, QuicRpcClient, QuicRpcClientConfig, QuicRpcCode, QuicRpcMetadata, QuicRpcRegistry, QuicRpcRequest, QuicRpcResponder, QuicRpcStatus};
+pub use pubsub::{QuicPubSubBroker, QuicPubSubBrokerConfig, QuicPubSubClient, QuicPubSubDelivery, QuicPubSubMessage, QuicPubSubSubscribeOptions, QuicPubSubSubscription};
+pub use file_transfer::{QuicFileReceiver, QuicFileTransferConfig, QuicFileTransferProgress, QuicFileUpload};
+pub use reassembly::QuicStreamReassembler;
@@ -1,8 +1,350 @@
 //! Connection Pool API - Managed QUIC connections
 
 use flutter_rust_bridge::frb;
+use crate::core::{QuicEndpoint, QuicConnection};
+use crate::errors::QuicError;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, Weak};
+use std::time::{Duration, Instant};
 
+/// Configuration for QuicConnectionPool
+#[derive(Debug, Clone)]
+pub struct QuicConnectionPoolConfig {
+    /// Maximum number of connections kept per authority (host:port)
+    pub max_connections_per_authority: usize,
+    /// Number of concurrent users after which a connection counts as saturated
+    ///
+    /// Keep this at or below the server's `max_concurrent_bidi_streams`.
+    pub max_streams_per_connection: u32,
+    /// Time an unused connection stays pooled, in milliseconds
+    pub idle_timeout_ms: u64,
+    /// Interval of the background idle eviction, in milliseconds (0 disables it)
+    pub eviction_interval_ms: u64,
+}
+
+impl Default for QuicConnectionPoolConfig {
+    fn default() -> Self {
+        Self {
+            max_connections_per_authority: 4,
+            max_streams_per_connection: 100,
+            idle_timeout_ms: 60000,
+            eviction_interval_ms: 10000,
+        }
+    }
+}
+
+/// Pool statistics for a single authority
+#[derive(Debug, Clone)]
+pub struct QuicAuthorityPoolStats {
+    pub authority: String,
+    /// Pooled connections, live or not yet pruned
+    pub connections: u64,
+    /// Acquired connections not yet released, summed over all connections
+    pub active_streams: u64,
+}
+
+/// Snapshot of QuicConnectionPool state and counters
+#[derive(Debug, Clone, Default)]
+pub struct QuicConnectionPoolStats {
+    pub authorities: Vec<QuicAuthorityPoolStats>,
+    /// Acquisitions served by a pooled connection
+    pub hits: u64,
+    /// Acquisitions that opened a new connection
+    pub misses: u64,
+    /// Connections closed after staying unused for `idle_timeout_ms`
+    pub evicted_idle: u64,
+    /// Connections dropped because the peer or the transport had closed them
+    pub evicted_dead: u64,
+}
+
+#[derive(Debug)]
+struct PoolEntry {
+    connection: QuicConnection,
+    active_streams: u32,
+    last_used: Instant,
+}
+
+impl PoolEntry {
+    fn is_alive(&self) -> bool {
+        self.connection.close_reason().is_none()
+    }
+
+    fn is_idle(&self, now: Instant, idle_timeout: Duration) -> bool {
+        self.active_streams == 0 && now.duration_since(self.last_used) >= idle_timeout
+    }
+}
+
+#[derive(Debug, Default)]
+struct PoolCounters {
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evicted_idle: AtomicU64,
+    evicted_dead: AtomicU64,
+}
+
+#[derive(Debug)]
+struct PoolShared {
+    config: QuicConnectionPoolConfig,
+    authorities: Mutex<HashMap<String, Vec<PoolEntry>>>,
+    counters: PoolCounters,
+}
+
+/// Managed pool of QUIC connections keyed by authority (host:port)
+///
+/// `acquire` hands out the live connection with the fewest users, opening a new
+/// one only when every pooled connection is saturated. Each acquisition must be
+/// paired with a `release` once the caller's streams are done. Connections whose
+/// `close_reason` is set are pruned, and connections left unused for
+/// `idle_timeout_ms` are closed, both whenever their authority is used and on a
+/// background timer.
+///
+/// # Example
+/// ```dart
+/// final pool = await connectionPoolNew(config: await connectionPoolConfigNew());
+/// final (pooled, client, connection) = await connectionPoolAcquire(
+///   pool: pool, endpoint: endpoint, addr: '127.0.0.1:4433', serverName: 'localhost');
+/// // ... open streams ...
+/// await connectionPoolRelease(pool: pooled, connection: connection);
+/// ```
+#[derive(Debug, Clone)]
 #[frb(opaque)]
 pub struct QuicConnectionPool {
-    // Connection pool implementation
-} 
\ No newline at end of file
+    inner: Arc<PoolShared>,
+}
+
+impl QuicConnectionPool {
+    /// Create an empty pool
+    ///
+    /// The idle eviction timer only runs if this is called within a Tokio runtime;
+    /// otherwise idle connections are only evicted when their authority is next used
+    /// or `evict_idle` is called.
+    pub fn new(config: QuicConnectionPoolConfig) -> Self {
+        let inner = Arc::new(PoolShared {
+            config,
+            authorities: Mutex::new(HashMap::new()),
+            counters: PoolCounters::default(),
+        });
+
+        let interval = inner.config.eviction_interval_ms;
+        if interval > 0 {
+            if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+                runtime.spawn(run_idle_eviction(Arc::downgrade(&inner), Duration::from_millis(interval)));
+            }
+        }
+
+        Self { inner }
+    }
+
+    /// Get the pool configuration
+    pub fn config(&self) -> QuicConnectionPoolConfig {
+        self.inner.config.clone()
+    }
+
+    /// Acquire a connection to `addr`, connecting through `endpoint` if needed
+    ///
+    /// The returned connection counts as in use until passed to `release`.
+    ///
+    /// # Arguments
+    /// * `endpoint` - Client endpoint used when a new connection is required
+    /// * `addr` - Authority of the server (e.g., "127.0.0.1:4433"), also the pool key
+    /// * `server_name` - TLS server name for new connections
+    pub async fn acquire(
+        &self,
+        endpoint: &QuicEndpoint,
+        addr: String,
+        server_name: String,
+    ) -> Result<QuicConnection, QuicError> {
+        if let Some(connection) = self.try_acquire(&addr) {
+            return Ok(connection);
+        }
+
+        let connection = endpoint.connect(addr.clone(), server_name).await?;
+        self.inner.counters.misses.fetch_add(1, Ordering::Relaxed);
+        self.insert_acquired(addr, connection.clone());
+        Ok(connection)
+    }
+
+    /// Acquire an unsaturated pooled connection to `addr` without connecting
+    pub fn try_acquire(&self, addr: &str) -> Option<QuicConnection> {
+        let mut authorities = self.inner.authorities.lock().unwrap();
+        let entries = authorities.get_mut(addr)?;
+        self.inner.prune(entries);
+
+        let max_streams = self.inner.config.max_streams_per_connection;
+        let entry = entries.iter_mut()
+            .filter(|entry| entry.active_streams < max_streams)
+            .min_by_key(|entry| entry.active_streams)?;
+
+        entry.active_streams += 1;
+        entry.last_used = Instant::now();
+        self.inner.counters.hits.fetch_add(1, Ordering::Relaxed);
+        Some(entry.connection.clone())
+    }
+
+    /// Return a connection obtained from `acquire` or `try_acquire`
+    pub fn release(&self, connection: &QuicConnection) {
+        let id = connection.stable_id();
+        let mut authorities = self.inner.authorities.lock().unwrap();
+
+        if let Some(entry) = authorities.values_mut()
+            .flat_map(|entries| entries.iter_mut())
+            .find(|entry| entry.connection.stable_id() == id)
+        {
+            entry.active_streams = entry.active_streams.saturating_sub(1);
+            entry.last_used = Instant::now();
+        }
+    }
+
+    /// Count another user of a pooled `connection`, as if `try_acquire` returned it
+    ///
+    /// Returns false if the connection is not pooled, in which case it needs no `release`.
+    pub(crate) fn share(&self, connection: &QuicConnection) -> bool {
+        let id = connection.stable_id();
+        let mut authorities = self.inner.authorities.lock().unwrap();
+
+        match authorities.values_mut()
+            .flat_map(|entries| entries.iter_mut())
+            .find(|entry| entry.connection.stable_id() == id)
+        {
+            Some(entry) => {
+                entry.active_streams += 1;
+                entry.last_used = Instant::now();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Remove a connection from the pool, e.g. after it failed with `ConnectionLost`
+    ///
+    /// Returns whether the connection was pooled. The connection itself is left open.
+    pub fn remove(&self, connection: &QuicConnection) -> bool {
+        let id = connection.stable_id();
+        let mut authorities = self.inner.authorities.lock().unwrap();
+
+        let mut removed = false;
+        authorities.retain(|_, entries| {
+            let before = entries.len();
+            entries.retain(|entry| entry.connection.stable_id() != id);
+            removed |= entries.len() != before;
+            !entries.is_empty()
+        });
+        removed
+    }
+
+    /// Close and remove connections unused for longer than `idle_timeout_ms`
+    ///
+    /// Returns the number of connections evicted.
+    pub fn evict_idle(&self) -> usize {
+        self.inner.evict_idle()
+    }
+
+    /// Remove every connection from the pool without closing them
+    pub fn clear(&self) {
+        self.inner.authorities.lock().unwrap().clear();
+    }
+
+    /// Get a snapshot of the pool state and counters
+    pub fn stats(&self) -> QuicConnectionPoolStats {
+        let authorities = self.inner.authorities.lock().unwrap();
+        let counters = &self.inner.counters;
+
+        QuicConnectionPoolStats {
+            authorities: authorities.iter()
+                .map(|(authority, entries)| QuicAuthorityPoolStats {
+                    authority: authority.clone(),
+                    connections: entries.len() as u64,
+                    active_streams: entries.iter().map(|entry| u64::from(entry.active_streams)).sum(),
+                })
+                .collect(),
+            hits: counters.hits.load(Ordering::Relaxed),
+            misses: counters.misses.load(Ordering::Relaxed),
+            evicted_idle: counters.evicted_idle.load(Ordering::Relaxed),
+            evicted_dead: counters.evicted_dead.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Pool a freshly opened connection that the caller is already using
+    fn insert_acquired(&self, addr: String, connection: QuicConnection) {
+        let mut authorities = self.inner.authorities.lock().unwrap();
+        let entries = authorities.entry(addr).or_default();
+        self.inner.prune(entries);
+
+        // Make room by closing the least recently used connection nobody is using
+        if entries.len() >= self.inner.config.max_connections_per_authority {
+            let oldest_unused = entries.iter()
+                .enumerate()
+                .filter(|(_, entry)| entry.active_streams == 0)
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(index, _)| index);
+
+            match oldest_unused {
+                Some(index) => {
+                    let entry = entries.remove(index);
+                    entry.connection.inner().close(0u32.into(), b"evicted from pool");
+                }
+                // Every pooled connection is busy; serve this one unpooled
+                None => return,
+            }
+        }
+
+        entries.push(PoolEntry {
+            connection,
+            active_streams: 1,
+            last_used: Instant::now(),
+        });
+    }
+}
+
+impl PoolShared {
+    /// Drop closed connections and close idle ones, returning the number of idle evictions
+    fn prune(&self, entries: &mut Vec<PoolEntry>) -> usize {
+        let now = Instant::now();
+        let idle_timeout = Duration::from_millis(self.config.idle_timeout_ms);
+        let mut evicted_idle = 0;
+        let mut evicted_dead = 0;
+
+        entries.retain(|entry| {
+            if !entry.is_alive() {
+                evicted_dead += 1;
+                return false;
+            }
+            if entry.is_idle(now, idle_timeout) {
+                entry.connection.inner().close(0u32.into(), b"idle");
+                evicted_idle += 1;
+                return false;
+            }
+            true
+        });
+
+        self.counters.evicted_idle.fetch_add(evicted_idle as u64, Ordering::Relaxed);
+        self.counters.evicted_dead.fetch_add(evicted_dead as u64, Ordering::Relaxed);
+        evicted_idle
+    }
+
+    fn evict_idle(&self) -> usize {
+        let mut evicted_idle = 0;
+        let mut authorities = self.authorities.lock().unwrap();
+        authorities.retain(|_, entries| {
+            evicted_idle += self.prune(entries);
+            !entries.is_empty()
+        });
+        evicted_idle
+    }
+}
+
+/// Periodically evict idle connections until the pool is dropped
+async fn run_idle_eviction(pool: Weak<PoolShared>, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    // The first tick completes immediately
+    ticker.tick().await;
+
+    loop {
+        ticker.tick().await;
+        let Some(pool) = pool.upgrade() else {
+            break;
+        };
+        pool.evict_idle();
+    }
+}
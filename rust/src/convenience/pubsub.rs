@@ -0,0 +1,665 @@
+//! Pub/Sub API - Topic subscriptions over a single QUIC connection
+//!
+//! A client sends subscribe and unsubscribe commands on one bidirectional
+//! control stream, each answered before the next is sent. Messages of a topic
+//! are delivered on a unidirectional stream opened by the broker, or as
+//! datagrams for lossy subscriptions, tagged with the subscription ID the
+//! client chose. Every message carries its topic's sequence number, so a
+//! client can resume after the last message it saw.
+
+use flutter_rust_bridge::frb;
+use crate::convenience::framing::{read_frame, write_frame};
+use crate::convenience::server::ServerCounters;
+use crate::convenience::varint::{decode_varint, encode_varint};
+use crate::core::QuicConnection;
+use crate::errors::QuicError;
+use bytes::Bytes;
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex as StdMutex};
+use tokio::sync::{mpsc, watch, Mutex};
+use tokio::task::JoinSet;
+use tokio_util::sync::CancellationToken;
+use tokio_util::task::AbortOnDropHandle;
+
+const COMMAND_SUBSCRIBE: u8 = 0x01;
+const COMMAND_UNSUBSCRIBE: u8 = 0x02;
+const REPLY_OK: u8 = 0x00;
+const REPLY_ERROR: u8 = 0x01;
+/// Largest control frame, bounding topic names
+const MAX_CONTROL_FRAME: usize = 64 * 1024;
+/// Most retained messages a delivery task takes from its topic at once
+const DELIVERY_BATCH: usize = 64;
+
+/// How the messages of a subscription are delivered
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuicPubSubDelivery {
+    /// In order on a dedicated stream; a slow subscriber holds up only its own stream
+    Stream,
+    /// As datagrams, which may be lost or reordered and must fit a single datagram
+    Datagram,
+}
+
+/// Configuration for QuicPubSubBroker
+#[derive(Debug, Clone)]
+pub struct QuicPubSubBrokerConfig {
+    /// Messages kept per topic for resuming subscribers and slow readers
+    pub history_size: usize,
+    /// Largest message accepted by `publish`, in bytes
+    pub max_message_size: usize,
+}
+
+impl Default for QuicPubSubBrokerConfig {
+    fn default() -> Self {
+        Self {
+            history_size: 1024,
+            max_message_size: 1024 * 1024,
+        }
+    }
+}
+
+/// Options for `QuicPubSubClient::subscribe`
+#[derive(Debug, Clone)]
+pub struct QuicPubSubSubscribeOptions {
+    pub delivery: QuicPubSubDelivery,
+    /// Replay retained messages published after this sequence number;
+    /// `None` delivers only messages published from now on
+    pub resume_after: Option<u64>,
+    /// Messages held until the application takes them
+    ///
+    /// Stream deliveries then wait for the application, datagrams are dropped.
+    pub buffer_capacity: usize,
+    /// Largest message accepted from the broker, in bytes
+    pub max_message_size: usize,
+}
+
+impl Default for QuicPubSubSubscribeOptions {
+    fn default() -> Self {
+        Self {
+            delivery: QuicPubSubDelivery::Stream,
+            resume_after: None,
+            buffer_capacity: 256,
+            max_message_size: 1024 * 1024,
+        }
+    }
+}
+
+/// A message received on a subscription
+#[derive(Debug, Clone)]
+pub struct QuicPubSubMessage {
+    /// Position of the message in its topic, starting at 1
+    ///
+    /// A gap means messages were missed, e.g. because the subscriber fell more
+    /// than `history_size` messages behind or a datagram was lost.
+    pub sequence: u64,
+    pub payload: Vec<u8>,
+}
+
+struct TopicLog {
+    next_sequence: u64,
+    history: VecDeque<(u64, Bytes)>,
+}
+
+struct Topic {
+    log: StdMutex<TopicLog>,
+    /// Sequence number of the newest message, to wake delivery tasks
+    latest: watch::Sender<u64>,
+}
+
+impl Topic {
+    /// Retained messages published after `after`, oldest first
+    fn messages_after(&self, after: u64) -> Vec<(u64, Bytes)> {
+        let log = self.log.lock().unwrap();
+        let skip = log.history.partition_point(|(sequence, _)| *sequence <= after);
+        log.history.iter().skip(skip).take(DELIVERY_BATCH).cloned().collect()
+    }
+
+    /// Where a new subscription starts: the latest sequence number, the one to
+    /// deliver after, and how many requested messages are no longer retained
+    fn start(&self, resume_after: Option<u64>) -> (u64, u64, u64) {
+        let log = self.log.lock().unwrap();
+        let latest = log.next_sequence - 1;
+        let after = resume_after.unwrap_or(latest).min(latest);
+        let oldest = log.history.front().map_or(latest + 1, |(sequence, _)| *sequence);
+        (latest, after, oldest.saturating_sub(after + 1))
+    }
+}
+
+struct BrokerShared {
+    config: QuicPubSubBrokerConfig,
+    topics: StdMutex<HashMap<String, Arc<Topic>>>,
+}
+
+impl BrokerShared {
+    fn topic(&self, name: &str) -> Arc<Topic> {
+        let mut topics = self.topics.lock().unwrap();
+        topics.entry(name.to_string()).or_insert_with(|| {
+            Arc::new(Topic {
+                log: StdMutex::new(TopicLog {
+                    next_sequence: 1,
+                    history: VecDeque::new(),
+                }),
+                latest: watch::Sender::new(0),
+            })
+        }).clone()
+    }
+}
+
+/// Topics and their retained messages, served with `SimpleQuicServer::serve_pubsub`
+///
+/// The broker is shared: publishing on it reaches the subscribers of every
+/// connection the server accepted. Sequence numbers restart with the broker.
+///
+/// # Example
+/// ```dart
+/// final broker = await pubSubBrokerNew(config: await pubSubBrokerConfigNew());
+/// server = await simpleQuicServerServePubsub(server: server, broker: broker);
+/// await pubSubBrokerPublish(broker: broker, topic: 'cpu', payload: utf8.encode('42%'));
+/// ```
+#[derive(Clone)]
+#[frb(opaque)]
+pub struct QuicPubSubBroker {
+    shared: Arc<BrokerShared>,
+}
+
+impl QuicPubSubBroker {
+    /// Create a broker without topics
+    pub fn new(config: QuicPubSubBrokerConfig) -> Result<Self, QuicError> {
+        if config.history_size == 0 {
+            return Err(QuicError::Config("history_size must be at least 1".to_string()));
+        }
+
+        Ok(Self {
+            shared: Arc::new(BrokerShared {
+                config,
+                topics: StdMutex::new(HashMap::new()),
+            }),
+        })
+    }
+
+    /// Publish a message to the subscribers of `topic`, returning its sequence number
+    pub fn publish(&self, topic: String, payload: Vec<u8>) -> Result<u64, QuicError> {
+        let config = &self.shared.config;
+        if payload.len() > config.max_message_size {
+            return Err(QuicError::Config(format!(
+                "Message of {} bytes exceeds max_message_size of {} bytes",
+                payload.len(),
+                config.max_message_size,
+            )));
+        }
+
+        let topic = self.shared.topic(&topic);
+        let sequence = {
+            let mut log = topic.log.lock().unwrap();
+            let sequence = log.next_sequence;
+            log.next_sequence += 1;
+            log.history.push_back((sequence, Bytes::from(payload)));
+            if log.history.len() > config.history_size {
+                log.history.pop_front();
+            }
+            sequence
+        };
+        topic.latest.send_replace(sequence);
+        Ok(sequence)
+    }
+
+    /// Get the sequence number of the newest message of `topic`, or 0 if none was published
+    pub fn latest_sequence(&self, topic: String) -> u64 {
+        let topics = self.shared.topics.lock().unwrap();
+        topics.get(&topic).map_or(0, |topic| *topic.latest.borrow())
+    }
+}
+
+/// A subscription on a broker connection: its ID and delivery task
+type ActiveSubscriptions = Arc<StdMutex<HashMap<String, (u64, AbortOnDropHandle<()>)>>>;
+
+/// Answer the control streams of one subscriber connection until it closes or the server shuts down
+///
+/// Subscriptions end with the connection; at most `max_subscriptions` are
+/// active at once.
+pub(crate) async fn serve_connection(
+    broker: &QuicPubSubBroker,
+    connection: quinn::Connection,
+    max_subscriptions: usize,
+    shutdown: CancellationToken,
+    counters: Arc<ServerCounters>,
+) {
+    let subscriptions: ActiveSubscriptions = Arc::default();
+    // Dropping the set stops the control streams, and with them every delivery task
+    let mut control_streams = JoinSet::new();
+
+    loop {
+        let streams = tokio::select! {
+            _ = shutdown.cancelled() => break,
+            streams = connection.accept_bi() => streams,
+        };
+        let Ok((send, recv)) = streams else {
+            break;
+        };
+
+        control_streams.spawn(serve_control_stream(
+            broker.shared.clone(),
+            connection.clone(),
+            send,
+            recv,
+            subscriptions.clone(),
+            max_subscriptions,
+            counters.clone(),
+        ));
+    }
+}
+
+async fn serve_control_stream(
+    broker: Arc<BrokerShared>,
+    connection: quinn::Connection,
+    mut send: quinn::SendStream,
+    mut recv: quinn::RecvStream,
+    subscriptions: ActiveSubscriptions,
+    max_subscriptions: usize,
+    counters: Arc<ServerCounters>,
+) {
+    while let Ok(Some(command)) = read_frame(&mut recv, MAX_CONTROL_FRAME).await {
+        let reply = match handle_command(&broker, &connection, &command, &subscriptions, max_subscriptions, &counters) {
+            Ok(reply) => {
+                counters.requests_handled.fetch_add(1, Ordering::Relaxed);
+                reply
+            }
+            Err(message) => {
+                tracing::debug!("Pub/sub command failed: {}", message);
+                counters.requests_failed.fetch_add(1, Ordering::Relaxed);
+                let mut reply = vec![REPLY_ERROR];
+                reply.extend_from_slice(message.as_bytes());
+                reply
+            }
+        };
+
+        if write_frame(&mut send, &reply, MAX_CONTROL_FRAME).await.is_err() {
+            return;
+        }
+    }
+}
+
+/// Apply a control command, returning the reply frame or an error message
+fn handle_command(
+    broker: &Arc<BrokerShared>,
+    connection: &quinn::Connection,
+    command: &[u8],
+    subscriptions: &ActiveSubscriptions,
+    max_subscriptions: usize,
+    counters: &Arc<ServerCounters>,
+) -> Result<Vec<u8>, String> {
+    let mut pos = 1;
+    let mut next_varint = || {
+        let (value, len) = decode_varint(command.get(pos..)?)?;
+        pos += len;
+        Some(value)
+    };
+
+    match command.first() {
+        Some(&COMMAND_SUBSCRIBE) => {
+            let (Some(id), Some(delivery), Some(resume)) = (next_varint(), next_varint(), next_varint()) else {
+                return Err("Malformed subscribe command".to_string());
+            };
+            let name = std::str::from_utf8(&command[pos..]).map_err(|_| "Topic is not UTF-8".to_string())?;
+            let delivery = match delivery {
+                0 => QuicPubSubDelivery::Stream,
+                1 if connection.max_datagram_size().is_some() => QuicPubSubDelivery::Datagram,
+                1 => return Err("Datagrams are not supported on this connection".to_string()),
+                _ => return Err(format!("Unknown delivery mode {}", delivery)),
+            };
+
+            let mut active = subscriptions.lock().unwrap();
+            if !active.contains_key(name) && active.len() >= max_subscriptions {
+                return Err(format!("At most {} subscriptions are allowed", max_subscriptions));
+            }
+
+            let topic = broker.topic(name);
+            let (latest, after, missed) = topic.start(resume.checked_sub(1));
+            let task = tokio::spawn(deliver(connection.clone(), topic, id, after, delivery, counters.clone()));
+            // Replaces, and so stops, an earlier subscription to the same topic
+            active.insert(name.to_string(), (id, AbortOnDropHandle::new(task)));
+
+            let mut reply = vec![REPLY_OK];
+            encode_varint(&mut reply, latest);
+            encode_varint(&mut reply, missed);
+            Ok(reply)
+        }
+        Some(&COMMAND_UNSUBSCRIBE) => {
+            let id = next_varint().ok_or_else(|| "Malformed unsubscribe command".to_string())?;
+            let name = std::str::from_utf8(&command[pos..]).map_err(|_| "Topic is not UTF-8".to_string())?;
+
+            let mut active = subscriptions.lock().unwrap();
+            if active.get(name).is_some_and(|(active_id, _)| *active_id == id) {
+                active.remove(name);
+            }
+            Ok(vec![REPLY_OK])
+        }
+        _ => Err("Unknown command".to_string()),
+    }
+}
+
+/// Send the messages of `topic` published after `after` until the subscription ends
+async fn deliver(
+    connection: quinn::Connection,
+    topic: Arc<Topic>,
+    id: u64,
+    mut after: u64,
+    delivery: QuicPubSubDelivery,
+    counters: Arc<ServerCounters>,
+) {
+    let mut stream = None;
+    if delivery == QuicPubSubDelivery::Stream {
+        let mut header = Vec::new();
+        encode_varint(&mut header, id);
+        let Ok(mut opened) = connection.open_uni().await else {
+            return;
+        };
+        if write_frame(&mut opened, &header, usize::MAX).await.is_err() {
+            return;
+        }
+        stream = Some(opened);
+    }
+
+    let mut latest = topic.latest.subscribe();
+    loop {
+        // Mark the current sequence number seen before looking, so a message
+        // published after the lookup wakes the task
+        latest.borrow_and_update();
+        let messages = topic.messages_after(after);
+        if messages.is_empty() {
+            if latest.changed().await.is_err() {
+                return;
+            }
+            continue;
+        }
+
+        for (sequence, payload) in messages {
+            let mut message = Vec::with_capacity(payload.len() + 8);
+            if stream.is_none() {
+                encode_varint(&mut message, id);
+            }
+            encode_varint(&mut message, sequence);
+            message.extend_from_slice(&payload);
+            after = sequence;
+
+            let sent = match &mut stream {
+                Some(stream) => write_frame(stream, &message, usize::MAX).await.is_ok(),
+                // A message too large for a datagram is skipped, leaving a sequence gap
+                None => match connection.send_datagram(Bytes::from(message)) {
+                    Ok(()) => true,
+                    Err(quinn::SendDatagramError::TooLarge) => continue,
+                    Err(_) => false,
+                },
+            };
+            if !sent {
+                return;
+            }
+            counters.bytes_sent.fetch_add(payload.len() as u64, Ordering::Relaxed);
+        }
+    }
+}
+
+struct SubscriptionEntry {
+    sender: mpsc::Sender<QuicPubSubMessage>,
+    max_message_size: usize,
+}
+
+struct ClientShared {
+    connection: quinn::Connection,
+    control: Mutex<(quinn::SendStream, quinn::RecvStream)>,
+    subscriptions: StdMutex<HashMap<u64, SubscriptionEntry>>,
+    next_id: AtomicU64,
+}
+
+impl ClientShared {
+    /// Send a control command and wait for its reply
+    async fn command(&self, command: Vec<u8>) -> Result<Vec<u8>, QuicError> {
+        let mut control = self.control.lock().await;
+        let (send, recv) = &mut *control;
+
+        write_frame(send, &command, MAX_CONTROL_FRAME).await
+            .map_err(|e| QuicError::Stream(format!("Failed to send pub/sub command: {}", e)))?;
+        let reply = read_frame(recv, MAX_CONTROL_FRAME).await
+            .map_err(|e| QuicError::Stream(format!("Failed to read pub/sub reply: {}", e)))?
+            .ok_or_else(|| QuicError::Stream("Broker closed the control stream".to_string()))?;
+
+        match reply.split_first() {
+            Some((&REPLY_OK, body)) => Ok(body.to_vec()),
+            Some((&REPLY_ERROR, message)) => Err(QuicError::Stream(String::from_utf8_lossy(message).into_owned())),
+            _ => Err(QuicError::Stream("Malformed pub/sub reply".to_string())),
+        }
+    }
+
+    /// End all subscriptions once the connection is gone
+    fn end_subscriptions(&self) {
+        self.subscriptions.lock().unwrap().clear();
+    }
+}
+
+/// Client subscribing to the topics of a broker
+///
+/// The client takes over the connection's incoming unidirectional streams and
+/// datagrams, so they should not be accepted or read elsewhere. After a
+/// reconnect, resume a topic by subscribing on the new connection with
+/// `resume_after` set to the previous subscription's `last_sequence`.
+///
+/// # Example
+/// ```dart
+/// final (_, client) = await pubSubClientConnect(connection: connection);
+/// final cpu = await pubSubClientSubscribe(
+///   client: client, topic: 'cpu', options: await pubSubSubscribeOptionsNew());
+/// await for (final message in quicPubSubMessages(cpu)) {
+///   print('${message.sequence}: ${utf8.decode(message.payload)}');
+/// }
+/// ```
+#[frb(opaque)]
+pub struct QuicPubSubClient {
+    shared: Arc<ClientShared>,
+    _streams: AbortOnDropHandle<()>,
+    _datagrams: AbortOnDropHandle<()>,
+}
+
+impl QuicPubSubClient {
+    /// Open the control stream to the broker on the other end of `connection`
+    pub async fn connect(connection: &QuicConnection) -> Result<Self, QuicError> {
+        let connection = connection.inner().clone();
+        let control = connection.open_bi().await
+            .map_err(|e| QuicError::Stream(format!("Failed to open control stream: {:?}", e)))?;
+
+        let shared = Arc::new(ClientShared {
+            connection,
+            control: Mutex::new(control),
+            subscriptions: StdMutex::new(HashMap::new()),
+            next_id: AtomicU64::new(0),
+        });
+
+        Ok(Self {
+            _streams: AbortOnDropHandle::new(tokio::spawn(accept_deliveries(shared.clone()))),
+            _datagrams: AbortOnDropHandle::new(tokio::spawn(receive_datagrams(shared.clone()))),
+            shared,
+        })
+    }
+
+    /// Subscribe to `topic`
+    ///
+    /// Replaces an earlier subscription of this client to the same topic.
+    pub async fn subscribe(
+        &self,
+        topic: String,
+        options: QuicPubSubSubscribeOptions,
+    ) -> Result<QuicPubSubSubscription, QuicError> {
+        if options.buffer_capacity == 0 {
+            return Err(QuicError::Config("buffer_capacity must be at least 1".to_string()));
+        }
+
+        let id = self.shared.next_id.fetch_add(1, Ordering::Relaxed);
+        let (sender, receiver) = mpsc::channel(options.buffer_capacity);
+        // Register first, as deliveries may arrive before the reply
+        self.shared.subscriptions.lock().unwrap().insert(id, SubscriptionEntry {
+            sender,
+            max_message_size: options.max_message_size,
+        });
+
+        let mut command = vec![COMMAND_SUBSCRIBE];
+        encode_varint(&mut command, id);
+        encode_varint(&mut command, match options.delivery {
+            QuicPubSubDelivery::Stream => 0,
+            QuicPubSubDelivery::Datagram => 1,
+        });
+        encode_varint(&mut command, options.resume_after.map_or(0, |after| after.saturating_add(1)));
+        command.extend_from_slice(topic.as_bytes());
+
+        let reply = match self.shared.command(command).await {
+            Ok(reply) => reply,
+            Err(e) => {
+                self.shared.subscriptions.lock().unwrap().remove(&id);
+                return Err(e);
+            }
+        };
+        let (latest, missed) = decode_varint(&reply)
+            .and_then(|(latest, len)| Some((latest, decode_varint(&reply[len..])?.0)))
+            .ok_or_else(|| QuicError::Stream("Malformed subscribe reply".to_string()))?;
+
+        Ok(QuicPubSubSubscription {
+            id,
+            topic,
+            shared: self.shared.clone(),
+            receiver: Mutex::new(receiver),
+            last_sequence: AtomicU64::new(options.resume_after.unwrap_or(latest).min(latest)),
+            missed,
+            unsubscribed: AtomicBool::new(false),
+        })
+    }
+}
+
+/// Messages of one topic received by a QuicPubSubClient
+///
+/// Dropping the subscription unsubscribes in the background.
+#[frb(opaque)]
+pub struct QuicPubSubSubscription {
+    id: u64,
+    topic: String,
+    shared: Arc<ClientShared>,
+    receiver: Mutex<mpsc::Receiver<QuicPubSubMessage>>,
+    last_sequence: AtomicU64,
+    missed: u64,
+    unsubscribed: AtomicBool,
+}
+
+impl QuicPubSubSubscription {
+    /// Get the subscribed topic
+    pub fn topic(&self) -> String {
+        self.topic.clone()
+    }
+
+    /// Get the number of requested messages the broker no longer retained when subscribing
+    pub fn missed(&self) -> u64 {
+        self.missed
+    }
+
+    /// Get the sequence number to resume after: the newest message received,
+    /// or where the subscription started if none was
+    pub fn last_sequence(&self) -> u64 {
+        self.last_sequence.load(Ordering::Relaxed)
+    }
+
+    /// Wait for the next message, or `None` once unsubscribed or the connection closed
+    pub async fn next_message(&self) -> Option<QuicPubSubMessage> {
+        let message = self.receiver.lock().await.recv().await?;
+        self.last_sequence.fetch_max(message.sequence, Ordering::Relaxed);
+        Some(message)
+    }
+
+    /// Stop receiving messages of this topic
+    ///
+    /// Messages already received can still be taken with `next_message`.
+    pub async fn unsubscribe(&self) -> Result<(), QuicError> {
+        if self.unsubscribed.swap(true, Ordering::Relaxed) {
+            return Ok(());
+        }
+        self.shared.subscriptions.lock().unwrap().remove(&self.id);
+        self.shared.command(unsubscribe_command(self.id, &self.topic)).await.map(|_| ())
+    }
+}
+
+impl Drop for QuicPubSubSubscription {
+    fn drop(&mut self) {
+        if self.unsubscribed.swap(true, Ordering::Relaxed) {
+            return;
+        }
+        self.shared.subscriptions.lock().unwrap().remove(&self.id);
+
+        if let Ok(runtime) = tokio::runtime::Handle::try_current() {
+            let shared = self.shared.clone();
+            let command = unsubscribe_command(self.id, &self.topic);
+            runtime.spawn(async move {
+                let _ = shared.command(command).await;
+            });
+        }
+    }
+}
+
+fn unsubscribe_command(id: u64, topic: &str) -> Vec<u8> {
+    let mut command = vec![COMMAND_UNSUBSCRIBE];
+    encode_varint(&mut command, id);
+    command.extend_from_slice(topic.as_bytes());
+    command
+}
+
+/// Accept the broker's delivery streams and read each into its subscription
+async fn accept_deliveries(shared: Arc<ClientShared>) {
+    let mut readers = JoinSet::new();
+    while let Ok(stream) = shared.connection.accept_uni().await {
+        readers.spawn(read_deliveries(shared.clone(), stream));
+    }
+    shared.end_subscriptions();
+}
+
+async fn read_deliveries(shared: Arc<ClientShared>, mut stream: quinn::RecvStream) {
+    let header = read_frame(&mut stream, 8).await;
+    let entry = match header.ok().flatten().and_then(|header| decode_varint(&header)) {
+        Some((id, _)) => shared.subscriptions.lock().unwrap()
+            .get(&id)
+            .map(|entry| (entry.sender.clone(), entry.max_message_size)),
+        None => None,
+    };
+    let Some((sender, max_message_size)) = entry else {
+        let _ = stream.stop(0u32.into());
+        return;
+    };
+
+    while let Ok(Some(frame)) = read_frame(&mut stream, max_message_size.saturating_add(8)).await {
+        let Some(message) = decode_message(&frame) else {
+            break;
+        };
+        // Waiting for buffer space holds back the stream through flow control
+        if sender.send(message).await.is_err() {
+            break;
+        }
+    }
+    let _ = stream.stop(0u32.into());
+}
+
+async fn receive_datagrams(shared: Arc<ClientShared>) {
+    while let Ok(datagram) = shared.connection.read_datagram().await {
+        let Some((id, len)) = decode_varint(&datagram) else {
+            continue;
+        };
+        let Some(message) = decode_message(&datagram[len..]) else {
+            continue;
+        };
+        if let Some(entry) = shared.subscriptions.lock().unwrap().get(&id) {
+            // A full buffer drops the datagram, as the network might have
+            let _ = entry.sender.try_send(message);
+        }
+    }
+    shared.end_subscriptions();
+}
+
+fn decode_message(frame: &[u8]) -> Option<QuicPubSubMessage> {
+    let (sequence, len) = decode_varint(frame)?;
+    Some(QuicPubSubMessage {
+        sequence,
+        payload: frame[len..].to_vec(),
+    })
+}
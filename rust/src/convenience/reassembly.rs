@@ -0,0 +1,109 @@
+//! Reassembly API - Contiguous data from unordered stream reads
+//!
+//! Unordered reads return stream data as soon as it arrives, tagged with its
+//! offset. The reassembler puts those chunks back into stream order for
+//! callers that need contiguous bytes.
+
+use flutter_rust_bridge::frb;
+use crate::core::QuicStreamChunk;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+
+/// Buffers out-of-order stream chunks and releases them in stream order
+///
+/// Chunks may be pushed in any order and may overlap or repeat; every stream
+/// byte is released exactly once. Data ahead of the contiguous offset is held
+/// in memory, which the stream's receive window bounds for data read from a
+/// single QUIC stream.
+///
+/// # Example
+/// ```dart
+/// final reassembler = await streamReassemblerNew();
+/// while (true) {
+///   final (_, chunk) = await recvStreamReadChunk(stream: recv, maxLength: BigInt.from(65536), ordered: false);
+///   if (chunk == null) break;
+///   cacheSegment(chunk.offset, chunk.data);
+///   player.append(await streamReassemblerPush(reassembler: reassembler, chunk: chunk));
+/// }
+/// ```
+#[frb(opaque)]
+#[derive(Default)]
+pub struct QuicStreamReassembler {
+    state: Mutex<ReassemblyState>,
+}
+
+#[derive(Default)]
+struct ReassemblyState {
+    contiguous_offset: u64,
+    pending: BTreeMap<u64, Vec<u8>>,
+    buffered_bytes: usize,
+}
+
+impl QuicStreamReassembler {
+    /// Create a reassembler for a stream read from offset 0
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a chunk and return the data that became contiguous
+    ///
+    /// # Returns
+    ///
+    /// The bytes following the previously released data, or an empty buffer if
+    /// the chunk leaves a gap or only repeats released data.
+    pub fn push(&self, chunk: QuicStreamChunk) -> Vec<u8> {
+        let mut guard = self.state.lock().unwrap();
+        let state = &mut *guard;
+        let Some(data) = trim_released(state.contiguous_offset, chunk.offset, chunk.data) else {
+            return Vec::new();
+        };
+
+        let offset = chunk.offset.max(state.contiguous_offset);
+        if offset > state.contiguous_offset {
+            // Keep the longer copy when the same offset arrives twice
+            let previous_len = state.pending.get(&offset).map_or(0, Vec::len);
+            if data.len() > previous_len {
+                state.buffered_bytes += data.len() - previous_len;
+                state.pending.insert(offset, data);
+            }
+            return Vec::new();
+        }
+
+        let mut released = data;
+        state.contiguous_offset += released.len() as u64;
+        while let Some(entry) = state.pending.first_entry() {
+            if *entry.key() > state.contiguous_offset {
+                break;
+            }
+            let (offset, data) = entry.remove_entry();
+            state.buffered_bytes -= data.len();
+            if let Some(data) = trim_released(state.contiguous_offset, offset, data) {
+                state.contiguous_offset += data.len() as u64;
+                released.extend_from_slice(&data);
+            }
+        }
+        released
+    }
+
+    /// Stream offset up to which data has been released
+    pub fn contiguous_offset(&self) -> u64 {
+        self.state.lock().unwrap().contiguous_offset
+    }
+
+    /// Bytes held back until the gap before them is filled
+    pub fn buffered_bytes(&self) -> usize {
+        self.state.lock().unwrap().buffered_bytes
+    }
+}
+
+/// Drop the part of `data` before `released`, or all of it if nothing is new
+fn trim_released(released: u64, offset: u64, mut data: Vec<u8>) -> Option<Vec<u8>> {
+    let end = offset + data.len() as u64;
+    if end <= released {
+        return None;
+    }
+    if offset < released {
+        data.drain(..(released - offset) as usize);
+    }
+    Some(data)
+}
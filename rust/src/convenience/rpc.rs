@@ -0,0 +1,529 @@
+//! RPC API - Request/response calls multiplexed over one QUIC connection
+//!
+//! Every call uses its own bidirectional stream carrying length-prefixed frames.
+//! The client sends a header frame (method, deadline, metadata) and a payload
+//! frame, then finishes its side. The server answers with any number of message
+//! frames followed by one status frame. Cancelling a call resets its stream.
+
+use flutter_rust_bridge::frb;
+use crate::convenience::framing::{read_frame, write_frame};
+use crate::convenience::server::ServerCounters;
+use crate::convenience::varint::{decode_varint, encode_varint};
+use crate::core::QuicConnection;
+use crate::errors::{QuicFrameErrorKind, QuicFrameException};
+use futures::future::BoxFuture;
+use std::collections::HashMap;
+use std::future::Future;
+use std::sync::atomic::Ordering;
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+use tokio::sync::Mutex;
+use tokio_util::sync::CancellationToken;
+use tokio_util::task::AbortOnDropHandle;
+
+/// Stream error code sent when a call is cancelled or misses its deadline
+pub const RPC_CANCELLED_ERROR_CODE: u32 = 0x10;
+
+const FRAME_MESSAGE: u8 = 0x00;
+const FRAME_STATUS: u8 = 0x01;
+
+pub(crate) type RpcHandler =
+    Arc<dyn Fn(QuicRpcRequest, QuicRpcResponder) -> BoxFuture<'static, Result<(), QuicRpcStatus>> + Send + Sync>;
+
+/// Outcome of a call, numbered like gRPC status codes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuicRpcCode {
+    Ok,
+    Cancelled,
+    Unknown,
+    InvalidArgument,
+    DeadlineExceeded,
+    NotFound,
+    AlreadyExists,
+    PermissionDenied,
+    ResourceExhausted,
+    FailedPrecondition,
+    Aborted,
+    OutOfRange,
+    Unimplemented,
+    Internal,
+    Unavailable,
+    DataLoss,
+    Unauthenticated,
+}
+
+const CODES: [QuicRpcCode; 17] = [
+    QuicRpcCode::Ok,
+    QuicRpcCode::Cancelled,
+    QuicRpcCode::Unknown,
+    QuicRpcCode::InvalidArgument,
+    QuicRpcCode::DeadlineExceeded,
+    QuicRpcCode::NotFound,
+    QuicRpcCode::AlreadyExists,
+    QuicRpcCode::PermissionDenied,
+    QuicRpcCode::ResourceExhausted,
+    QuicRpcCode::FailedPrecondition,
+    QuicRpcCode::Aborted,
+    QuicRpcCode::OutOfRange,
+    QuicRpcCode::Unimplemented,
+    QuicRpcCode::Internal,
+    QuicRpcCode::Unavailable,
+    QuicRpcCode::DataLoss,
+    QuicRpcCode::Unauthenticated,
+];
+
+/// Status a call ended with; every failed call reports one
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+#[error("RPC failed with {code:?}: {message}")]
+pub struct QuicRpcStatus {
+    pub code: QuicRpcCode,
+    pub message: String,
+}
+
+impl QuicRpcStatus {
+    /// Create a status with a message
+    pub fn new(code: QuicRpcCode, message: impl Into<String>) -> Self {
+        Self { code, message: message.into() }
+    }
+
+    fn is_ok(&self) -> bool {
+        self.code == QuicRpcCode::Ok
+    }
+}
+
+impl From<QuicFrameException> for QuicRpcStatus {
+    fn from(error: QuicFrameException) -> Self {
+        let code = match error.kind {
+            QuicFrameErrorKind::TooLarge => QuicRpcCode::ResourceExhausted,
+            QuicFrameErrorKind::Truncated => QuicRpcCode::DataLoss,
+            QuicFrameErrorKind::Read | QuicFrameErrorKind::Write => QuicRpcCode::Unavailable,
+        };
+        Self::new(code, error.message)
+    }
+}
+
+/// A metadata entry sent with a call
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuicRpcMetadata {
+    pub key: String,
+    pub value: String,
+}
+
+/// Per-call settings
+#[derive(Debug, Clone, Default)]
+pub struct QuicRpcCallOptions {
+    /// Time allowed for the whole call in milliseconds, overriding the client default
+    pub deadline_ms: Option<u64>,
+    pub metadata: Vec<QuicRpcMetadata>,
+}
+
+/// Configuration for QuicRpcClient
+#[derive(Debug, Clone)]
+pub struct QuicRpcClientConfig {
+    /// Largest request or response message in bytes
+    pub max_message_size: usize,
+    /// Deadline for calls without one of their own, in milliseconds
+    pub default_deadline_ms: Option<u64>,
+}
+
+impl Default for QuicRpcClientConfig {
+    fn default() -> Self {
+        Self {
+            max_message_size: 1024 * 1024,
+            default_deadline_ms: Some(30000),
+        }
+    }
+}
+
+/// A call received by the server
+#[derive(Debug, Clone)]
+pub struct QuicRpcRequest {
+    pub method: String,
+    pub metadata: Vec<QuicRpcMetadata>,
+    pub payload: Vec<u8>,
+    /// Time left until the caller's deadline when the call arrived, in milliseconds
+    pub deadline_ms: Option<u64>,
+}
+
+fn encode_header(method: &str, deadline_ms: Option<u64>, metadata: &[QuicRpcMetadata]) -> Vec<u8> {
+    let mut header = Vec::new();
+    let put_string = |header: &mut Vec<u8>, value: &str| {
+        encode_varint(header, value.len() as u64);
+        header.extend_from_slice(value.as_bytes());
+    };
+
+    put_string(&mut header, method);
+    // Zero means no deadline
+    encode_varint(&mut header, deadline_ms.map_or(0, |deadline| deadline.saturating_add(1)));
+    encode_varint(&mut header, metadata.len() as u64);
+    for entry in metadata {
+        put_string(&mut header, &entry.key);
+        put_string(&mut header, &entry.value);
+    }
+    header
+}
+
+fn decode_header(header: &[u8]) -> Option<(String, Option<u64>, Vec<QuicRpcMetadata>)> {
+    let mut pos = 0;
+    let next_varint = |pos: &mut usize| {
+        let (value, len) = decode_varint(header.get(*pos..)?)?;
+        *pos += len;
+        Some(value)
+    };
+    let next_string = |pos: &mut usize, len: u64| {
+        let end = pos.checked_add(usize::try_from(len).ok()?)?;
+        let value = String::from_utf8(header.get(*pos..end)?.to_vec()).ok()?;
+        *pos = end;
+        Some(value)
+    };
+
+    let len = next_varint(&mut pos)?;
+    let method = next_string(&mut pos, len)?;
+    let deadline_ms = next_varint(&mut pos)?.checked_sub(1);
+    let count = next_varint(&mut pos)?;
+
+    let mut metadata = Vec::new();
+    for _ in 0..count {
+        let len = next_varint(&mut pos)?;
+        let key = next_string(&mut pos, len)?;
+        let len = next_varint(&mut pos)?;
+        let value = next_string(&mut pos, len)?;
+        metadata.push(QuicRpcMetadata { key, value });
+    }
+    Some((method, deadline_ms, metadata))
+}
+
+async fn write_status(stream: &mut quinn::SendStream, status: &QuicRpcStatus) -> Result<(), QuicFrameException> {
+    let mut frame = vec![FRAME_STATUS];
+    encode_varint(&mut frame, status.code as u64);
+    frame.extend_from_slice(status.message.as_bytes());
+    write_frame(stream, &frame, usize::MAX).await
+}
+
+fn decode_status(frame: &[u8]) -> QuicRpcStatus {
+    let Some((code, len)) = decode_varint(frame) else {
+        return QuicRpcStatus::new(QuicRpcCode::Internal, "Malformed status frame");
+    };
+    let code = CODES.get(code as usize).copied().unwrap_or(QuicRpcCode::Unknown);
+    QuicRpcStatus::new(code, String::from_utf8_lossy(&frame[len..]))
+}
+
+/// Client for calls over one QUIC connection
+///
+/// Calls run concurrently, each on its own bidirectional stream, so a slow
+/// call does not hold up others. Failed calls return a `QuicRpcStatus`;
+/// transport failures are reported as `Unavailable`.
+///
+/// # Example
+/// ```dart
+/// final client = await rpcClientNew(connection: connection, config: await rpcClientConfigNew());
+/// final reply = await rpcClientCall(
+///   client: client, method: 'users.get', payload: utf8.encode('42'),
+///   options: await rpcCallOptionsNew());
+/// ```
+#[frb(opaque)]
+pub struct QuicRpcClient {
+    connection: QuicConnection,
+    config: QuicRpcClientConfig,
+}
+
+impl QuicRpcClient {
+    /// Create a client calling the server on the other end of `connection`
+    pub fn new(connection: QuicConnection, config: QuicRpcClientConfig) -> Self {
+        Self { connection, config }
+    }
+
+    /// Make a call expecting exactly one response message
+    pub async fn call(
+        &self,
+        method: String,
+        payload: Vec<u8>,
+        options: QuicRpcCallOptions,
+    ) -> Result<Vec<u8>, QuicRpcStatus> {
+        let call = self.call_streaming(method, payload, options).await?;
+
+        let response = call.next_message().await?
+            .ok_or_else(|| QuicRpcStatus::new(QuicRpcCode::Internal, "Server sent no response"))?;
+        match call.next_message().await? {
+            None => Ok(response),
+            Some(_) => Err(QuicRpcStatus::new(QuicRpcCode::Internal, "Server sent more than one response")),
+        }
+    }
+
+    /// Make a call whose response messages are read one at a time
+    ///
+    /// Returns once the request has been sent.
+    pub async fn call_streaming(
+        &self,
+        method: String,
+        payload: Vec<u8>,
+        options: QuicRpcCallOptions,
+    ) -> Result<QuicRpcCall, QuicRpcStatus> {
+        let deadline_ms = options.deadline_ms.or(self.config.default_deadline_ms);
+        let deadline = deadline_ms.map(|deadline| Instant::now() + Duration::from_millis(deadline));
+
+        let (mut send, recv) = self.connection.inner().open_bi().await
+            .map_err(|e| QuicRpcStatus::new(QuicRpcCode::Unavailable, format!("Failed to open stream: {:?}", e)))?;
+
+        let header = encode_header(&method, deadline_ms, &options.metadata);
+        let max_message_size = self.config.max_message_size;
+        let sent = with_deadline(deadline, async {
+            write_frame(&mut send, &header, usize::MAX).await?;
+            write_frame(&mut send, &payload, max_message_size).await?;
+            Ok(())
+        }).await;
+        if let Err(status) = sent {
+            let _ = send.reset(RPC_CANCELLED_ERROR_CODE.into());
+            return Err(status);
+        }
+        let _ = send.finish();
+
+        Ok(QuicRpcCall {
+            recv: Mutex::new(recv),
+            send: StdMutex::new(send),
+            max_message_size,
+            deadline,
+            cancel: CancellationToken::new(),
+            outcome: StdMutex::new(None),
+        })
+    }
+}
+
+/// Run `future` until `deadline`, failing with `DeadlineExceeded` after it
+async fn with_deadline<T>(
+    deadline: Option<Instant>,
+    future: impl Future<Output = Result<T, QuicFrameException>>,
+) -> Result<T, QuicRpcStatus> {
+    match deadline {
+        Some(deadline) => tokio::time::timeout_at(deadline.into(), future).await
+            .map_err(|_| QuicRpcStatus::new(QuicRpcCode::DeadlineExceeded, "Deadline exceeded"))?
+            .map_err(QuicRpcStatus::from),
+        None => future.await.map_err(QuicRpcStatus::from),
+    }
+}
+
+/// An in-flight call started with `call_streaming`
+///
+/// Dropping the call before it completed cancels it.
+#[frb(opaque)]
+pub struct QuicRpcCall {
+    recv: Mutex<quinn::RecvStream>,
+    send: StdMutex<quinn::SendStream>,
+    max_message_size: usize,
+    deadline: Option<Instant>,
+    cancel: CancellationToken,
+    /// Set once the call completed, failed or was cancelled
+    outcome: StdMutex<Option<Result<(), QuicRpcStatus>>>,
+}
+
+impl QuicRpcCall {
+    /// Read the next response message
+    ///
+    /// Returns `None` once the server finished the call successfully, and the
+    /// server's status if it failed. Missing the deadline cancels the call.
+    pub async fn next_message(&self) -> Result<Option<Vec<u8>>, QuicRpcStatus> {
+        if let Some(outcome) = self.outcome() {
+            return outcome.map(|()| None);
+        }
+
+        let mut recv = self.recv.lock().await;
+        let frame = tokio::select! {
+            _ = self.cancel.cancelled() => Err(QuicRpcStatus::new(QuicRpcCode::Cancelled, "Call was cancelled")),
+            frame = with_deadline(self.deadline, read_frame(&mut recv, self.max_message_size.saturating_add(1))) => frame,
+        };
+
+        let status = match frame {
+            Ok(Some(frame)) => match frame.split_first() {
+                Some((&FRAME_MESSAGE, message)) => return Ok(Some(message.to_vec())),
+                Some((&FRAME_STATUS, status)) => decode_status(status),
+                _ => QuicRpcStatus::new(QuicRpcCode::Internal, "Malformed response frame"),
+            },
+            Ok(None) => QuicRpcStatus::new(QuicRpcCode::Internal, "Stream ended without a status"),
+            Err(status) => status,
+        };
+
+        if !status.is_ok() {
+            let _ = recv.stop(RPC_CANCELLED_ERROR_CODE.into());
+            let _ = self.send.lock().unwrap().reset(RPC_CANCELLED_ERROR_CODE.into());
+        }
+        let outcome = if status.is_ok() { Ok(()) } else { Err(status) };
+        self.outcome.lock().unwrap().get_or_insert(outcome).clone().map(|()| None)
+    }
+
+    /// Cancel the call, resetting its stream so the server stops the handler
+    pub fn cancel(&self) {
+        let mut outcome = self.outcome.lock().unwrap();
+        if outcome.is_none() {
+            *outcome = Some(Err(QuicRpcStatus::new(QuicRpcCode::Cancelled, "Call was cancelled")));
+            let _ = self.send.lock().unwrap().reset(RPC_CANCELLED_ERROR_CODE.into());
+            // A pending next_message holds the receive half and stops it itself
+            if let Ok(mut recv) = self.recv.try_lock() {
+                let _ = recv.stop(RPC_CANCELLED_ERROR_CODE.into());
+            }
+        }
+        self.cancel.cancel();
+    }
+
+    fn outcome(&self) -> Option<Result<(), QuicRpcStatus>> {
+        self.outcome.lock().unwrap().clone()
+    }
+}
+
+impl Drop for QuicRpcCall {
+    fn drop(&mut self) {
+        self.cancel();
+    }
+}
+
+/// Handlers of an RPC server by method name
+///
+/// Serve it with `SimpleQuicServer::serve_rpc`. Calls to methods without a
+/// handler fail with `Unimplemented`.
+#[derive(Default)]
+#[frb(opaque)]
+pub struct QuicRpcRegistry {
+    handlers: HashMap<String, RpcHandler>,
+}
+
+impl QuicRpcRegistry {
+    /// Create a registry without handlers
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a handler that may send any number of response messages
+    ///
+    /// The handler's result becomes the call's status. Replaces an earlier
+    /// handler for the same method.
+    pub fn register<F, Fut>(&mut self, method: String, handler: F) -> &mut Self
+    where
+        F: Fn(QuicRpcRequest, QuicRpcResponder) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<(), QuicRpcStatus>> + Send + 'static,
+    {
+        self.handlers.insert(method, Arc::new(move |request, responder| Box::pin(handler(request, responder))));
+        self
+    }
+
+    /// Register a handler answering with a single response message
+    pub fn register_unary<F, Fut>(&mut self, method: String, handler: F) -> &mut Self
+    where
+        F: Fn(QuicRpcRequest) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Vec<u8>, QuicRpcStatus>> + Send + 'static,
+    {
+        let handler = Arc::new(handler);
+        self.register(method, move |request, responder| {
+            let handler = handler.clone();
+            async move {
+                let response = handler(request).await?;
+                responder.send(response).await
+            }
+        })
+    }
+}
+
+/// Sends the response messages of a call on the server
+#[derive(Clone)]
+#[frb(opaque)]
+pub struct QuicRpcResponder {
+    stream: Arc<Mutex<quinn::SendStream>>,
+    max_message_size: usize,
+    counters: Arc<ServerCounters>,
+}
+
+impl QuicRpcResponder {
+    /// Send one response message, waiting for flow control if needed
+    pub async fn send(&self, message: Vec<u8>) -> Result<(), QuicRpcStatus> {
+        let mut frame = Vec::with_capacity(message.len() + 1);
+        frame.push(FRAME_MESSAGE);
+        frame.extend_from_slice(&message);
+
+        let mut stream = self.stream.lock().await;
+        write_frame(&mut stream, &frame, self.max_message_size.saturating_add(1)).await?;
+        self.counters.bytes_sent.fetch_add(message.len() as u64, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+/// Read a call, run its handler and send the status, reporting whether it succeeded
+///
+/// The handler is stopped if the client cancels the call, the earlier of
+/// `request_timeout` and the caller's deadline passes, or `shutdown` resolves.
+pub(crate) async fn serve_call(
+    registry: &QuicRpcRegistry,
+    mut send: quinn::SendStream,
+    mut recv: quinn::RecvStream,
+    max_message_size: usize,
+    request_timeout: Duration,
+    counters: Arc<ServerCounters>,
+    shutdown: impl Future<Output = ()>,
+) -> bool {
+    let deadline = Instant::now() + request_timeout;
+    let read = with_deadline(Some(deadline), async {
+        let header = read_frame(&mut recv, max_message_size).await?;
+        let payload = read_frame(&mut recv, max_message_size).await?;
+        Ok((header, payload))
+    }).await;
+
+    let (method, deadline_ms, metadata, payload) = match read {
+        Ok((Some(header), Some(payload))) => match decode_header(&header) {
+            Some((method, deadline_ms, metadata)) => (method, deadline_ms, metadata, payload),
+            None => return finish_call(&mut send, QuicRpcStatus::new(QuicRpcCode::InvalidArgument, "Malformed call header")).await,
+        },
+        Ok(_) => return finish_call(&mut send, QuicRpcStatus::new(QuicRpcCode::InvalidArgument, "Incomplete call")).await,
+        Err(status) => return finish_call(&mut send, status).await,
+    };
+    counters.bytes_received.fetch_add(payload.len() as u64, Ordering::Relaxed);
+
+    let Some(handler) = registry.handlers.get(&method) else {
+        let status = QuicRpcStatus::new(QuicRpcCode::Unimplemented, format!("Unknown method {}", method));
+        return finish_call(&mut send, status).await;
+    };
+
+    let deadline = match deadline_ms {
+        Some(deadline_ms) => deadline.min(Instant::now() + Duration::from_millis(deadline_ms)),
+        None => deadline,
+    };
+    let request = QuicRpcRequest {
+        method,
+        metadata,
+        payload,
+        deadline_ms: Some(deadline.saturating_duration_since(Instant::now()).as_millis() as u64),
+    };
+
+    let cancelled = send.stopped();
+    let stream = Arc::new(Mutex::new(send));
+    let responder = QuicRpcResponder {
+        stream: stream.clone(),
+        max_message_size,
+        counters,
+    };
+
+    // Run the handler as its own task so a panic fails only this call and
+    // cancellation or the deadline stops it
+    let handler_task = AbortOnDropHandle::new(tokio::spawn(handler(request, responder)));
+    let status = tokio::select! {
+        result = tokio::time::timeout_at(deadline.into(), handler_task) => match result {
+            Ok(Ok(Ok(()))) => QuicRpcStatus::new(QuicRpcCode::Ok, ""),
+            Ok(Ok(Err(status))) => status,
+            Ok(Err(e)) => QuicRpcStatus::new(QuicRpcCode::Internal, format!("Handler panicked: {}", e)),
+            Err(_) => QuicRpcStatus::new(QuicRpcCode::DeadlineExceeded, "Deadline exceeded"),
+        },
+        _ = shutdown => QuicRpcStatus::new(QuicRpcCode::Unavailable, "Server shut down"),
+        _ = cancelled => {
+            tracing::debug!("RPC call cancelled by the client");
+            return false;
+        }
+    };
+
+    let mut send = stream.lock().await;
+    finish_call(&mut send, status).await
+}
+
+async fn finish_call(send: &mut quinn::SendStream, status: QuicRpcStatus) -> bool {
+    if !status.is_ok() {
+        tracing::debug!("RPC call failed: {}", status);
+    }
+    let sent = write_status(send, &status).await.is_ok() && send.finish().is_ok();
+    sent && status.is_ok()
+}
@@ -1,8 +1,687 @@
 //! Convenience Server API - Simple QUIC server interface
 
 use flutter_rust_bridge::frb;
+use crate::convenience::http3::HTTP3_ALPN;
+use crate::convenience::http3_server::{self, Http3Router};
+use crate::convenience::file_transfer::QuicFileReceiver;
+use crate::convenience::pubsub::{self, QuicPubSubBroker};
+use crate::convenience::rpc::{self, QuicRpcRegistry};
+use crate::core::{QuicEndpoint, QuicConnection, QuicSendStream, QuicRecvStream, QuicServerConfig};
+use crate::errors::{QuicError, QuicReadToEndException};
+use crate::models::certificate::{generate_self_signed, CertificateChain, CertificateKeyAlgorithm, SelfSignedCertificate};
+use futures::future::BoxFuture;
+use std::future::Future;
+use std::net::SocketAddr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+use tokio_util::sync::CancellationToken;
+use tokio_util::task::{AbortOnDropHandle, TaskTracker};
 
+/// Stream error code sent when a request exceeds `max_request_size`
+pub const REQUEST_TOO_LARGE_ERROR_CODE: u32 = 1;
+/// Stream error code sent when the handler fails or panics
+pub const HANDLER_FAILED_ERROR_CODE: u32 = 2;
+/// Stream error code sent when the handler exceeds `request_timeout_ms`
+pub const REQUEST_TIMEOUT_ERROR_CODE: u32 = 3;
+
+/// Validity of certificates generated by `SimpleQuicServer::bind_self_signed`
+const SELF_SIGNED_VALIDITY_DAYS: u32 = 30;
+
+/// Configuration for SimpleQuicServer
+#[derive(Debug, Clone)]
+pub struct SimpleQuicServerConfig {
+    /// Maximum number of requests handled at once on a single connection
+    pub max_concurrent_streams_per_connection: u32,
+    /// Maximum number of requests handled at once across all connections
+    pub max_concurrent_requests: u32,
+    /// Maximum request size in bytes; larger requests are rejected
+    ///
+    /// In HTTP/3 mode this applies to bodies read with `read_body`.
+    pub max_request_size: usize,
+    /// Time allowed for reading a request and running the handler, in milliseconds
+    ///
+    /// In HTTP/3 mode this covers the whole handler, including streamed bodies.
+    pub request_timeout_ms: u64,
+    /// Time `shutdown` waits for in-flight requests, in milliseconds
+    pub shutdown_timeout_ms: u64,
+}
+
+impl Default for SimpleQuicServerConfig {
+    fn default() -> Self {
+        Self {
+            max_concurrent_streams_per_connection: 100,
+            max_concurrent_requests: 1000,
+            max_request_size: 1024 * 1024,
+            request_timeout_ms: 30000,
+            shutdown_timeout_ms: 5000,
+        }
+    }
+}
+
+/// Snapshot of SimpleQuicServer counters
+#[derive(Debug, Clone, Default)]
+pub struct SimpleQuicServerStats {
+    /// Connections accepted since the server was bound
+    pub connections_accepted: u64,
+    /// Connections currently open
+    pub active_connections: u64,
+    /// Requests answered with a response
+    pub requests_handled: u64,
+    /// Requests rejected, timed out or failed in the handler
+    pub requests_failed: u64,
+    /// Requests currently being read or handled
+    pub active_requests: u64,
+    /// Request bytes received
+    pub bytes_received: u64,
+    /// Response bytes sent
+    pub bytes_sent: u64,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct ServerCounters {
+    connections_accepted: AtomicU64,
+    active_connections: AtomicU64,
+    pub(crate) requests_handled: AtomicU64,
+    pub(crate) requests_failed: AtomicU64,
+    active_requests: AtomicU64,
+    pub(crate) bytes_received: AtomicU64,
+    pub(crate) bytes_sent: AtomicU64,
+}
+
+impl ServerCounters {
+    fn snapshot(&self) -> SimpleQuicServerStats {
+        SimpleQuicServerStats {
+            connections_accepted: self.connections_accepted.load(Ordering::Relaxed),
+            active_connections: self.active_connections.load(Ordering::Relaxed),
+            requests_handled: self.requests_handled.load(Ordering::Relaxed),
+            requests_failed: self.requests_failed.load(Ordering::Relaxed),
+            active_requests: self.active_requests.load(Ordering::Relaxed),
+            bytes_received: self.bytes_received.load(Ordering::Relaxed),
+            bytes_sent: self.bytes_sent.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Decrements a gauge counter when dropped, so early returns and panics are counted
+struct GaugeGuard<'a>(&'a AtomicU64);
+
+impl<'a> GaugeGuard<'a> {
+    fn new(gauge: &'a AtomicU64) -> Self {
+        gauge.fetch_add(1, Ordering::Relaxed);
+        Self(gauge)
+    }
+}
+
+impl Drop for GaugeGuard<'_> {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::Relaxed);
+    }
+}
+
+type RequestHandler = Arc<dyn Fn(Vec<u8>) -> BoxFuture<'static, Result<Vec<u8>, String>> + Send + Sync>;
+
+/// How requests on accepted connections are answered
+#[derive(Clone)]
+enum ServerHandler {
+    /// One request per bidirectional stream, read to the end
+    Raw(RequestHandler),
+    /// HTTP/3 requests routed by method and path
+    Http3(Arc<Http3Router>),
+    /// RPC calls routed by method name, one per bidirectional stream
+    Rpc(Arc<QuicRpcRegistry>),
+    /// Topic subscriptions delivered from a broker
+    PubSub(QuicPubSubBroker),
+    /// File uploads stored by a receiver
+    Files(QuicFileReceiver),
+}
+
+/// High-level QUIC server answering one request per bidirectional stream
+///
+/// SimpleQuicServer is the counterpart of `QuicClient::send`: each stream the
+/// client opens carries one request, which is read to the end and passed to the
+/// handler. The handler's bytes are written back and the stream is finished.
+/// Requests that are too large, time out or fail in the handler are answered
+/// with a stream reset carrying one of the `*_ERROR_CODE` constants.
+///
+/// With `serve_http3` the server speaks HTTP/3 instead, routing requests to
+/// handlers by method and path. Clients must negotiate the `h3` ALPN, so bind
+/// with `bind_http3_self_signed` or a `QuicServerConfig` offering `h3`.
+/// `serve_rpc` answers RPC calls from a `QuicRpcClient`, and `serve_pubsub`
+/// delivers a broker's topics to `QuicPubSubClient`s. `serve_files` stores
+/// uploads from `QuicFileUpload`.
+///
+/// # Example
+/// ```dart
+/// final (server, certificate) = await simpleQuicServerBindSelfSigned(
+///   addr: '127.0.0.1:4433',
+///   subjectAltNames: ['localhost'],
+///   config: await simpleQuicServerConfigNew(),
+/// );
+/// await simpleQuicServerServe(server: server, handler: (request) async => request);
+/// ```
 #[frb(opaque)]
 pub struct SimpleQuicServer {
-    // High-level server implementation
-} 
\ No newline at end of file
+    endpoint: Arc<QuicEndpoint>,
+    config: SimpleQuicServerConfig,
+    counters: Arc<ServerCounters>,
+    shutdown: CancellationToken,
+    requests: TaskTracker,
+    serving: Mutex<bool>,
+}
+
+impl SimpleQuicServer {
+    /// Bind a server endpoint with the given TLS configuration
+    ///
+    /// Must be called from within a Tokio runtime, which drives the endpoint.
+    ///
+    /// # Arguments
+    /// * `server_config` - Certificate, ALPN and transport settings
+    /// * `addr` - Local address to listen on (e.g., "0.0.0.0:4433")
+    /// * `config` - Request handling limits
+    pub fn bind(
+        server_config: QuicServerConfig,
+        addr: String,
+        config: SimpleQuicServerConfig,
+    ) -> Result<Self, QuicError> {
+        if config.max_concurrent_streams_per_connection == 0 || config.max_concurrent_requests == 0 {
+            return Err(QuicError::Config("Concurrency limits must be greater than zero".to_string()));
+        }
+
+        let endpoint = QuicEndpoint::bind_server(server_config, addr)?;
+
+        Ok(Self {
+            endpoint: Arc::new(endpoint),
+            config,
+            counters: Arc::new(ServerCounters::default()),
+            shutdown: CancellationToken::new(),
+            requests: TaskTracker::new(),
+            serving: Mutex::new(false),
+        })
+    }
+
+    /// Bind a development server with a freshly generated self-signed certificate
+    ///
+    /// Returns the certificate so clients can pin its SHA-256 fingerprint.
+    ///
+    /// # Arguments
+    /// * `addr` - Local address to listen on (e.g., "127.0.0.1:4433")
+    /// * `subject_alt_names` - Names the certificate is valid for, e.g. `["localhost"]`
+    /// * `config` - Request handling limits
+    pub fn bind_self_signed(
+        addr: String,
+        subject_alt_names: Vec<String>,
+        config: SimpleQuicServerConfig,
+    ) -> Result<(Self, SelfSignedCertificate), QuicError> {
+        Self::bind_self_signed_with_alpn(addr, subject_alt_names, Vec::new(), config)
+    }
+
+    /// Bind a development HTTP/3 server with a freshly generated self-signed certificate
+    ///
+    /// Same as `bind_self_signed`, with `h3` offered during the handshake so that
+    /// HTTP/3 clients can connect. Serve it with `serve_http3`.
+    pub fn bind_http3_self_signed(
+        addr: String,
+        subject_alt_names: Vec<String>,
+        config: SimpleQuicServerConfig,
+    ) -> Result<(Self, SelfSignedCertificate), QuicError> {
+        Self::bind_self_signed_with_alpn(addr, subject_alt_names, vec![HTTP3_ALPN.to_vec()], config)
+    }
+
+    fn bind_self_signed_with_alpn(
+        addr: String,
+        subject_alt_names: Vec<String>,
+        alpn_protocols: Vec<Vec<u8>>,
+        config: SimpleQuicServerConfig,
+    ) -> Result<(Self, SelfSignedCertificate), QuicError> {
+        let certificate = generate_self_signed(
+            subject_alt_names,
+            SELF_SIGNED_VALIDITY_DAYS,
+            CertificateKeyAlgorithm::EcdsaP256,
+        )?;
+
+        let server_config = if alpn_protocols.is_empty() {
+            let cert_chain = CertificateChain {
+                certificates: certificate.certificate_chain.certificates.clone(),
+            };
+            let key = crate::models::certificate::PrivateKey {
+                key_data: certificate.private_key.key_data.clone(),
+                format: certificate.private_key.format,
+            };
+            QuicServerConfig::with_certificate(cert_chain, key)
+        } else {
+            QuicServerConfig::with_crypto(
+                certificate.certificate_chain.certificates.clone(),
+                certificate.private_key.key_data.clone(),
+                alpn_protocols,
+            )
+        }
+        .map_err(QuicError::Tls)?;
+
+        let server = Self::bind(server_config, addr, config)?;
+        Ok((server, certificate))
+    }
+
+    /// Start answering requests with `handler`
+    ///
+    /// The handler receives the complete request bytes and returns the response
+    /// bytes, or an error message which resets the stream. The accept loop runs
+    /// on the current Tokio runtime until `shutdown` is called. A server can only
+    /// serve one handler.
+    pub fn serve<F, Fut>(&self, handler: F) -> Result<(), QuicError>
+    where
+        F: Fn(Vec<u8>) -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<Vec<u8>, String>> + Send + 'static,
+    {
+        let handler: RequestHandler = Arc::new(move |request| Box::pin(handler(request)));
+        self.start(ServerHandler::Raw(handler))
+    }
+
+    /// Start answering HTTP/3 requests with the handlers of `router`
+    ///
+    /// Connections must have negotiated the `h3` ALPN. Each handler runs under
+    /// the same concurrency limits and `request_timeout_ms` as in `serve`; a
+    /// handler that fails or times out before responding is answered with 500.
+    /// As with `serve`, a server can only serve once.
+    pub fn serve_http3(&self, router: Http3Router) -> Result<(), QuicError> {
+        self.start(ServerHandler::Http3(Arc::new(router)))
+    }
+
+    /// Start answering RPC calls with the handlers of `registry`
+    ///
+    /// Each call runs under the same concurrency limits as in `serve`, and its
+    /// handler is stopped after `request_timeout_ms` or the caller's deadline,
+    /// whichever comes first. Requests larger than `max_request_size` fail with
+    /// `ResourceExhausted`. As with `serve`, a server can only serve once.
+    pub fn serve_rpc(&self, registry: QuicRpcRegistry) -> Result<(), QuicError> {
+        self.start(ServerHandler::Rpc(Arc::new(registry)))
+    }
+
+    /// Start delivering the topics of `broker` to subscribing clients
+    ///
+    /// Each connection may hold up to `max_concurrent_streams_per_connection`
+    /// subscriptions. Subscribe and unsubscribe commands count as requests in
+    /// `stats`, and delivered payloads as bytes sent. As with `serve`, a server
+    /// can only serve once.
+    pub fn serve_pubsub(&self, broker: &QuicPubSubBroker) -> Result<(), QuicError> {
+        self.start(ServerHandler::PubSub(broker.clone()))
+    }
+
+    /// Start receiving file uploads into the directory of `receiver`
+    ///
+    /// Verified files count as handled requests in `stats`, rejected or
+    /// mismatching ones as failed, and chunk bytes as bytes received. As with
+    /// `serve`, a server can only serve once.
+    pub fn serve_files(&self, receiver: &QuicFileReceiver) -> Result<(), QuicError> {
+        self.start(ServerHandler::Files(receiver.clone()))
+    }
+
+    /// Run the accept loop on the current Tokio runtime until shutdown
+    fn start(&self, handler: ServerHandler) -> Result<(), QuicError> {
+        if self.shutdown.is_cancelled() {
+            return Err(QuicError::Endpoint("Server has been shut down".to_string()));
+        }
+
+        {
+            let mut serving = self.serving.lock().unwrap();
+            if *serving {
+                return Err(QuicError::Endpoint("Server is already serving a handler".to_string()));
+            }
+            *serving = true;
+        }
+
+        let server = ServerContext {
+            config: self.config.clone(),
+            counters: self.counters.clone(),
+            shutdown: self.shutdown.clone(),
+            requests: self.requests.clone(),
+            request_permits: Arc::new(Semaphore::new(self.config.max_concurrent_requests as usize)),
+            handler,
+        };
+        let endpoint = self.endpoint.clone();
+
+        tokio::spawn(async move {
+            loop {
+                let connection = tokio::select! {
+                    _ = server.shutdown.cancelled() => break,
+                    accepted = endpoint.accept() => accepted,
+                };
+
+                match connection {
+                    Ok(Some(connection)) => {
+                        tokio::spawn(server.clone().serve_connection(connection));
+                    }
+                    Ok(None) => break,
+                    // A failed handshake only affects that client
+                    Err(e) => tracing::debug!("Rejected incoming connection: {}", e),
+                }
+            }
+        });
+
+        Ok(())
+    }
+
+    /// Get the local address the server is listening on
+    pub fn local_addr(&self) -> Result<SocketAddr, QuicError> {
+        self.endpoint.local_addr()
+    }
+
+    /// Get the current configuration
+    pub fn config(&self) -> SimpleQuicServerConfig {
+        self.config.clone()
+    }
+
+    /// Get a snapshot of the server counters
+    pub fn stats(&self) -> SimpleQuicServerStats {
+        self.counters.snapshot()
+    }
+
+    /// Stop accepting connections and requests, then close the endpoint
+    ///
+    /// In-flight requests get up to `shutdown_timeout_ms` to finish before all
+    /// connections are closed. Returns the number of requests that were still
+    /// running when the timeout expired.
+    pub async fn shutdown(&self) -> u64 {
+        self.shutdown.cancel();
+        // Refuse new connections while existing requests drain
+        self.endpoint.inner().set_server_config(None);
+
+        self.requests.close();
+        let timeout = Duration::from_millis(self.config.shutdown_timeout_ms);
+        let abandoned = match tokio::time::timeout(timeout, self.requests.wait()).await {
+            Ok(()) => 0,
+            Err(_) => self.requests.len() as u64,
+        };
+
+        self.endpoint.inner().close(0u32.into(), b"server shutdown");
+        let _ = tokio::time::timeout(timeout, self.endpoint.inner().wait_idle()).await;
+
+        abandoned
+    }
+}
+
+/// State shared by the accept loop and connection tasks
+#[derive(Clone)]
+struct ServerContext {
+    config: SimpleQuicServerConfig,
+    counters: Arc<ServerCounters>,
+    shutdown: CancellationToken,
+    requests: TaskTracker,
+    request_permits: Arc<Semaphore>,
+    handler: ServerHandler,
+}
+
+impl ServerContext {
+    async fn serve_connection(self, connection: QuicConnection) {
+        self.counters.connections_accepted.fetch_add(1, Ordering::Relaxed);
+        let _active = GaugeGuard::new(&self.counters.active_connections);
+
+        match self.handler.clone() {
+            ServerHandler::Raw(handler) => {
+                self.serve_streams(connection, move |server, send_stream, recv_stream| {
+                    let handler = handler.clone();
+                    async move { server.serve_request(&handler, send_stream, recv_stream).await }
+                }).await
+            }
+            ServerHandler::Http3(router) => self.serve_http3(connection, router).await,
+            ServerHandler::Rpc(registry) => {
+                self.serve_streams(connection, move |server, send_stream, recv_stream| {
+                    let registry = registry.clone();
+                    async move { server.serve_rpc_call(&registry, send_stream, recv_stream).await }
+                }).await
+            }
+            ServerHandler::PubSub(broker) => {
+                pubsub::serve_connection(
+                    &broker,
+                    connection.inner().clone(),
+                    self.config.max_concurrent_streams_per_connection as usize,
+                    self.shutdown.clone(),
+                    self.counters.clone(),
+                ).await
+            }
+            ServerHandler::Files(receiver) => {
+                receiver.serve(connection.inner().clone(), self.shutdown.clone(), self.counters.clone()).await
+            }
+        }
+    }
+
+    /// Wait for a free slot on this connection and in the server, or `None` on shutdown
+    async fn acquire_permits(&self, stream_permits: &Arc<Semaphore>) -> Option<(OwnedSemaphorePermit, OwnedSemaphorePermit)> {
+        let acquired = tokio::select! {
+            _ = self.shutdown.cancelled() => return None,
+            permits = async {
+                let stream_permit = stream_permits.clone().acquire_owned().await;
+                let request_permit = self.request_permits.clone().acquire_owned().await;
+                (stream_permit, request_permit)
+            } => permits,
+        };
+
+        match acquired {
+            (Ok(stream_permit), Ok(request_permit)) => Some((stream_permit, request_permit)),
+            _ => None,
+        }
+    }
+
+    /// Accept bidirectional streams and serve each with `serve_stream` as its own task
+    async fn serve_streams<F, Fut>(&self, connection: QuicConnection, serve_stream: F)
+    where
+        F: Fn(ServerContext, QuicSendStream, QuicRecvStream) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let stream_permits = Arc::new(Semaphore::new(
+            self.config.max_concurrent_streams_per_connection as usize,
+        ));
+
+        loop {
+            // Wait for capacity before accepting, so excess streams queue in flow control
+            let Some(permits) = self.acquire_permits(&stream_permits).await else {
+                break;
+            };
+
+            let streams = tokio::select! {
+                _ = self.shutdown.cancelled() => break,
+                streams = connection.accept_bi() => streams,
+            };
+            let Ok((send_stream, recv_stream)) = streams else {
+                break;
+            };
+
+            let served = serve_stream(self.clone(), send_stream, recv_stream);
+            self.requests.spawn(async move {
+                served.await;
+                drop(permits);
+            });
+        }
+    }
+
+    async fn serve_http3(&self, connection: QuicConnection, router: Arc<Http3Router>) {
+        let h3_connection = h3::server::builder()
+            .build(h3_quinn::Connection::new(connection.inner().clone()))
+            .await;
+        let mut h3_connection = match h3_connection {
+            Ok(h3_connection) => h3_connection,
+            Err(e) => {
+                tracing::debug!("HTTP/3 handshake failed: {:?}", e);
+                return;
+            }
+        };
+
+        let stream_permits = Arc::new(Semaphore::new(
+            self.config.max_concurrent_streams_per_connection as usize,
+        ));
+        let connection_requests = TaskTracker::new();
+
+        loop {
+            let Some(permits) = self.acquire_permits(&stream_permits).await else {
+                break;
+            };
+
+            let accepted = tokio::select! {
+                _ = self.shutdown.cancelled() => break,
+                accepted = h3_connection.accept() => accepted,
+            };
+            let resolver = match accepted {
+                Ok(Some(resolver)) => resolver,
+                Ok(None) => break,
+                Err(e) => {
+                    tracing::debug!("HTTP/3 connection ended: {:?}", e);
+                    break;
+                }
+            };
+
+            let server = self.clone();
+            let router = router.clone();
+            self.requests.spawn(connection_requests.track_future(async move {
+                server.serve_http3_request(resolver, &router).await;
+                drop(permits);
+            }));
+        }
+
+        if self.shutdown.is_cancelled() {
+            // Tell the client to stop sending requests on this connection
+            let _ = h3_connection.shutdown(0).await;
+        }
+
+        // Dropping the HTTP/3 connection closes it, so let its requests finish first
+        connection_requests.close();
+        connection_requests.wait().await;
+    }
+
+    async fn serve_http3_request(
+        &self,
+        resolver: h3::server::RequestResolver<h3_quinn::Connection, bytes::Bytes>,
+        router: &Http3Router,
+    ) {
+        let _active = GaugeGuard::new(&self.counters.active_requests);
+
+        let resolved = http3_server::resolve_request(
+            resolver,
+            router,
+            self.config.max_request_size,
+            self.counters.clone(),
+        ).await;
+        let (handler, request, stream) = match resolved {
+            Ok(resolved) => resolved,
+            Err(answered) => {
+                let counter = if answered { &self.counters.requests_handled } else { &self.counters.requests_failed };
+                counter.fetch_add(1, Ordering::Relaxed);
+                return;
+            }
+        };
+
+        // Run the handler as its own task so a panic fails only this request
+        // and a request timeout stops it
+        let handler_task = AbortOnDropHandle::new(tokio::spawn(handler(request, stream.clone())));
+        let timeout = Duration::from_millis(self.config.request_timeout_ms);
+        let result = tokio::select! {
+            result = tokio::time::timeout(timeout, handler_task) => match result {
+                Ok(Ok(result)) => result,
+                Ok(Err(e)) => Err(format!("Request handler panicked: {}", e)),
+                Err(_) => Err("Request timed out".to_string()),
+            },
+            _ = self.shutdown_deadline() => Err("Server shut down".to_string()),
+        };
+
+        if let Err(message) = &result {
+            tracing::debug!("HTTP/3 request handler failed: {}", message);
+        }
+
+        let counter = if stream.complete(result).await {
+            &self.counters.requests_handled
+        } else {
+            &self.counters.requests_failed
+        };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    async fn serve_request(&self, handler: &RequestHandler, mut send_stream: QuicSendStream, mut recv_stream: QuicRecvStream) {
+        let _active = GaugeGuard::new(&self.counters.active_requests);
+        let timeout = Duration::from_millis(self.config.request_timeout_ms);
+
+        let response = match tokio::time::timeout(timeout, self.handle(handler, &mut recv_stream)).await {
+            Ok(Ok(response)) => response,
+            Ok(Err(error_code)) => {
+                return self.fail(&mut send_stream, &mut recv_stream, error_code);
+            }
+            Err(_) => {
+                return self.fail(&mut send_stream, &mut recv_stream, REQUEST_TIMEOUT_ERROR_CODE);
+            }
+        };
+
+        let response_len = response.len() as u64;
+        let sent = match send_stream.write_all(response).await {
+            Ok(()) => send_stream.finish().is_ok(),
+            Err(_) => false,
+        };
+
+        if sent {
+            self.counters.bytes_sent.fetch_add(response_len, Ordering::Relaxed);
+            self.counters.requests_handled.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.counters.requests_failed.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Read the request and run the handler, returning the stream error code on failure
+    async fn handle(&self, handler: &RequestHandler, recv_stream: &mut QuicRecvStream) -> Result<Vec<u8>, u32> {
+        let request = match recv_stream.read_to_end(self.config.max_request_size).await {
+            Ok(request) => request,
+            Err(QuicReadToEndException::TooLong) => return Err(REQUEST_TOO_LARGE_ERROR_CODE),
+            Err(e) => {
+                tracing::debug!("Failed to read request: {}", e);
+                return Err(HANDLER_FAILED_ERROR_CODE);
+            }
+        };
+        self.counters.bytes_received.fetch_add(request.len() as u64, Ordering::Relaxed);
+
+        // Run the handler as its own task so a panic fails only this request
+        // and a request timeout stops it
+        let handler_task = AbortOnDropHandle::new(tokio::spawn(handler(request)));
+        let result = tokio::select! {
+            result = handler_task => result,
+            _ = self.shutdown_deadline() => return Err(HANDLER_FAILED_ERROR_CODE),
+        };
+
+        match result {
+            Ok(Ok(response)) => Ok(response),
+            Ok(Err(message)) => {
+                tracing::debug!("Request handler failed: {}", message);
+                Err(HANDLER_FAILED_ERROR_CODE)
+            }
+            Err(e) => {
+                tracing::warn!("Request handler panicked: {}", e);
+                Err(HANDLER_FAILED_ERROR_CODE)
+            }
+        }
+    }
+
+    async fn serve_rpc_call(&self, registry: &QuicRpcRegistry, send_stream: QuicSendStream, recv_stream: QuicRecvStream) {
+        let _active = GaugeGuard::new(&self.counters.active_requests);
+
+        let succeeded = rpc::serve_call(
+            registry,
+            send_stream.into_inner(),
+            recv_stream.into_inner(),
+            self.config.max_request_size,
+            Duration::from_millis(self.config.request_timeout_ms),
+            self.counters.clone(),
+            self.shutdown_deadline(),
+        ).await;
+
+        let counter = if succeeded { &self.counters.requests_handled } else { &self.counters.requests_failed };
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Resolves once shutdown has been requested and its grace period has passed
+    async fn shutdown_deadline(&self) {
+        self.shutdown.cancelled().await;
+        tokio::time::sleep(Duration::from_millis(self.config.shutdown_timeout_ms)).await;
+    }
+
+    fn fail(&self, send_stream: &mut QuicSendStream, recv_stream: &mut QuicRecvStream, error_code: u32) {
+        let _ = recv_stream.inner_mut().stop(error_code.into());
+        let _ = send_stream.inner_mut().reset(error_code.into());
+        self.counters.requests_failed.fetch_add(1, Ordering::Relaxed);
+    }
+}
@@ -0,0 +1,250 @@
+//! Streaming request bodies and responses for QuicClient
+
+use flutter_rust_bridge::frb;
+use crate::core::{QuicSendStream, QuicRecvStream};
+use crate::errors::QuicError;
+use futures::{FutureExt, Stream, StreamExt};
+use std::future::Future;
+use std::panic::AssertUnwindSafe;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tokio::task::JoinHandle;
+use tokio_util::sync::CancellationToken;
+
+/// Stream error code sent to the server when a transfer is cancelled or fails locally
+pub const TRANSFER_CANCELLED_ERROR_CODE: u32 = 0x10;
+
+/// Largest response chunk handed to the caller at once
+const RESPONSE_CHUNK_SIZE: usize = 64 * 1024;
+
+/// Progress of a streaming transfer
+#[derive(Debug, Clone, Default)]
+pub struct QuicTransferProgress {
+    /// Request body bytes written to the stream
+    pub bytes_sent: u64,
+    /// Response body bytes received so far
+    pub bytes_received: u64,
+    /// Declared request body length, if the caller knew it up front
+    pub total_to_send: Option<u64>,
+    /// Response body length, if the caller knew it up front or the response has ended
+    pub expected_total_received: Option<u64>,
+}
+
+#[derive(Debug, Default)]
+struct ProgressCounters {
+    bytes_sent: AtomicU64,
+    bytes_received: AtomicU64,
+    total_to_send: Option<u64>,
+    response_length: Option<u64>,
+    response_finished: AtomicBool,
+}
+
+impl ProgressCounters {
+    fn snapshot(&self) -> QuicTransferProgress {
+        let bytes_received = self.bytes_received.load(Ordering::Relaxed);
+        let finished = self.response_finished.load(Ordering::Relaxed);
+        QuicTransferProgress {
+            bytes_sent: self.bytes_sent.load(Ordering::Relaxed),
+            bytes_received,
+            total_to_send: self.total_to_send,
+            expected_total_received: self.response_length
+                .or_else(|| finished.then_some(bytes_received)),
+        }
+    }
+}
+
+/// Handle to a request whose body and response are streamed in chunks
+///
+/// The transfer runs in the background once started. Request chunks are only
+/// pulled from the body when QUIC flow control has room for them, and the next
+/// response chunk is only read after the previous one was consumed, so neither
+/// side is buffered in full. `cancel` resets the underlying QUIC stream.
+#[frb(opaque)]
+pub struct QuicStreamingTransfer {
+    progress: Arc<ProgressCounters>,
+    cancel: CancellationToken,
+    task: Mutex<Option<JoinHandle<Result<(), QuicError>>>>,
+}
+
+impl QuicStreamingTransfer {
+    /// Start streaming `body` on an open bidirectional stream
+    ///
+    /// `on_response_chunk` is awaited for each response chunk before more is read;
+    /// `on_progress` is called after every chunk in either direction. If
+    /// `upload_length` or `response_length` is set, a request or response body of
+    /// any other length fails the transfer.
+    /// `on_finish` runs once the transfer has ended, successfully or not.
+    pub(crate) fn start<S, C, CFut, P, F>(
+        (mut send_stream, mut recv_stream): (QuicSendStream, QuicRecvStream),
+        upload_length: Option<u64>,
+        response_length: Option<u64>,
+        body: S,
+        on_response_chunk: C,
+        on_progress: P,
+        on_finish: F,
+    ) -> Self
+    where
+        S: Stream<Item = Vec<u8>> + Send + 'static,
+        C: Fn(Vec<u8>) -> CFut + Send + Sync + 'static,
+        CFut: Future<Output = ()> + Send + 'static,
+        P: Fn(QuicTransferProgress) + Send + Sync + 'static,
+        F: FnOnce() + Send + 'static,
+    {
+        let progress = Arc::new(ProgressCounters {
+            total_to_send: upload_length,
+            response_length,
+            ..Default::default()
+        });
+        let cancel = CancellationToken::new();
+
+        let task = {
+            let progress = progress.clone();
+            let cancel = cancel.clone();
+            tokio::spawn(async move {
+                let transfer = async {
+                    tokio::try_join!(
+                        upload(&mut send_stream, body, &progress, &on_progress),
+                        download(&mut recv_stream, &progress, &on_response_chunk, &on_progress),
+                    )
+                };
+
+                let result = tokio::select! {
+                    _ = cancel.cancelled() => Err(QuicError::Stream("Transfer was cancelled".to_string())),
+                    // A panicking callback must not leave a half-sent body looking complete
+                    result = AssertUnwindSafe(transfer).catch_unwind() => match result {
+                        Ok(result) => result.map(|_| ()),
+                        Err(_) => Err(QuicError::Stream("Transfer callback panicked".to_string())),
+                    },
+                };
+
+                if result.is_err() {
+                    let code = TRANSFER_CANCELLED_ERROR_CODE.into();
+                    let _ = send_stream.inner_mut().reset(code);
+                    let _ = recv_stream.inner_mut().stop(code);
+                }
+                on_finish();
+                result
+            })
+        };
+
+        Self {
+            progress,
+            cancel,
+            task: Mutex::new(Some(task)),
+        }
+    }
+
+    /// Get the current progress
+    pub fn progress(&self) -> QuicTransferProgress {
+        self.progress.snapshot()
+    }
+
+    /// Cancel the transfer, resetting the QUIC stream in both directions
+    pub fn cancel(&self) {
+        self.cancel.cancel();
+    }
+
+    /// Wait for the transfer to complete and return the final progress
+    ///
+    /// Only the first call receives the result; later calls fail.
+    pub async fn wait(&self) -> Result<QuicTransferProgress, QuicError> {
+        let task = self.task.lock().await.take()
+            .ok_or_else(|| QuicError::Stream("Transfer result was already taken".to_string()))?;
+
+        task.await
+            .map_err(|e| QuicError::Stream(format!("Transfer task failed: {:?}", e)))??;
+        Ok(self.progress.snapshot())
+    }
+}
+
+async fn upload<S, P>(
+    send_stream: &mut QuicSendStream,
+    body: S,
+    progress: &ProgressCounters,
+    on_progress: &P,
+) -> Result<(), QuicError>
+where
+    S: Stream<Item = Vec<u8>>,
+    P: Fn(QuicTransferProgress),
+{
+    let mut body = Box::pin(body);
+
+    while let Some(chunk) = body.next().await {
+        let sent = progress.bytes_sent.load(Ordering::Relaxed) + chunk.len() as u64;
+        if let Some(total) = progress.total_to_send {
+            if sent > total {
+                return Err(QuicError::Stream(format!(
+                    "Request body is longer than the declared {} bytes", total
+                )));
+            }
+        }
+
+        send_stream.write_all(chunk).await
+            .map_err(|e| QuicError::Stream(format!("Failed to send data: {:?}", e)))?;
+        progress.bytes_sent.store(sent, Ordering::Relaxed);
+        on_progress(progress.snapshot());
+    }
+
+    if let Some(total) = progress.total_to_send {
+        let sent = progress.bytes_sent.load(Ordering::Relaxed);
+        if sent != total {
+            return Err(QuicError::Stream(format!(
+                "Request body ended after {} of the declared {} bytes", sent, total
+            )));
+        }
+    }
+
+    send_stream.finish()
+        .map_err(|e| QuicError::Stream(format!("Failed to finish send stream: {:?}", e)))
+}
+
+async fn download<C, CFut, P>(
+    recv_stream: &mut QuicRecvStream,
+    progress: &ProgressCounters,
+    on_response_chunk: &C,
+    on_progress: &P,
+) -> Result<(), QuicError>
+where
+    C: Fn(Vec<u8>) -> CFut,
+    CFut: Future<Output = ()>,
+    P: Fn(QuicTransferProgress),
+{
+    loop {
+        let chunk = recv_stream.read(RESPONSE_CHUNK_SIZE).await
+            .map_err(|e| QuicError::Stream(format!("Failed to read response: {:?}", e)))?;
+        let Some(chunk) = chunk else {
+            break;
+        };
+
+        let received = progress.bytes_received.load(Ordering::Relaxed) + chunk.len() as u64;
+        if let Some(total) = progress.response_length {
+            if received > total {
+                return Err(QuicError::Stream(format!(
+                    "Response body is longer than the expected {} bytes", total
+                )));
+            }
+        }
+
+        progress.bytes_received.store(received, Ordering::Relaxed);
+        on_response_chunk(chunk).await;
+        on_progress(progress.snapshot());
+    }
+
+    let received = progress.bytes_received.load(Ordering::Relaxed);
+    match progress.response_length {
+        Some(total) if received != total => Err(QuicError::Stream(format!(
+            "Response body ended after {} of the expected {} bytes", received, total
+        ))),
+        Some(_) => {
+            progress.response_finished.store(true, Ordering::Relaxed);
+            Ok(())
+        }
+        None => {
+            // The response length is only known now, so report it once more
+            progress.response_finished.store(true, Ordering::Relaxed);
+            on_progress(progress.snapshot());
+            Ok(())
+        }
+    }
+}
@@ -0,0 +1,616 @@
+//! WebTransport Client API - Sessions over HTTP/3 extended CONNECT
+//!
+//! The HTTP/3 connection, SETTINGS exchange and extended CONNECT request are
+//! handled by `h3`. Session-scoped streams (draft-ietf-webtrans-http3) are
+//! split off before they reach h3, datagrams are routed by quarter stream ID
+//! through a `QuicDatagramMux`, and the session ends with the
+//! CLOSE_WEBTRANSPORT_SESSION capsule.
+
+use flutter_rust_bridge::frb;
+use crate::convenience::datagram_channel::{QuicDatagramChannel, QuicDatagramChannelConfig, QuicDatagramMux};
+use crate::convenience::http3::{to_header_map, Http3Header, HTTP3_ALPN};
+use crate::convenience::varint::{decode_varint, encode_varint};
+use crate::core::{QuicEndpoint, QuicConnection, QuicSendStream, QuicRecvStream};
+use crate::errors::QuicError;
+use bytes::{Buf, Bytes};
+use h3::client::{RequestStream, SendRequest};
+use h3::quic::{ConnectionErrorIncoming, StreamErrorIncoming, StreamId};
+use h3::ConnectionState;
+use http::{HeaderValue, Method, Request};
+use std::borrow::Cow;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::task::{ready, Context, Poll};
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot, watch, Mutex};
+use tokio_util::task::AbortOnDropHandle;
+use url::Url;
+
+// WebTransport stream types and capsules (draft-ietf-webtrans-http3)
+const STREAM_TYPE_WEBTRANSPORT_UNI: u64 = 0x54;
+const SIGNAL_WEBTRANSPORT_BIDI: u64 = 0x41;
+const CAPSULE_CLOSE_WEBTRANSPORT_SESSION: u64 = 0x2843;
+const H3_STREAM_CREATION_ERROR: u32 = 0x0103;
+
+/// Longest close reason allowed by the protocol, in bytes
+const MAX_CLOSE_REASON_LEN: usize = 1024;
+/// Time allowed for the server's SETTINGS and the CONNECT response
+const HANDSHAKE_TIMEOUT: Duration = Duration::from_secs(10);
+/// Time `close` waits for the server to end the CONNECT stream
+const CLOSE_FLUSH_TIMEOUT: Duration = Duration::from_secs(1);
+/// Read size for the HTTP/3 control and QPACK streams handed to h3
+const H3_READ_BUFFER_SIZE: usize = 4096;
+
+/// Why a WebTransport session ended
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct WebTransportCloseInfo {
+    /// Application error code from the close capsule, 0 if none was sent
+    pub code: u32,
+    pub reason: String,
+}
+
+/// A WebTransport session established with extended CONNECT over HTTP/3
+///
+/// Streams opened or accepted through the session are plain `QuicSendStream`
+/// and `QuicRecvStream` handles whose WebTransport header has already been
+/// written or read, so the usual stream functions apply. Datagrams are scoped
+/// to the session. Each session uses its own QUIC connection, which is closed
+/// with the session; its datagrams belong to the session, so the connection's
+/// own datagram functions should not be used.
+///
+/// # Example
+/// ```dart
+/// final endpoint = await createClientEndpointWithAlpn(alpnProtocols: [utf8.encode('h3')]);
+/// final (_, session) = await webtransportSessionConnect(
+///   endpoint: endpoint, url: 'https://127.0.0.1:4433/chat', headers: []);
+/// final (send, recv) = await webtransportSessionOpenBi(session: session);
+/// await webtransportSessionSendDatagram(session: session, data: utf8.encode('ping'));
+/// ```
+#[frb(opaque)]
+pub struct WebTransportSession {
+    connection: QuicConnection,
+    session_id: u64,
+    // Dropping the last request sender closes the HTTP/3 connection
+    _send_request: SendRequest<h3_quinn::OpenStreams, Bytes>,
+    connect_stream: Mutex<Option<RequestStream<h3_quinn::SendStream<Bytes>, Bytes>>>,
+    incoming_bi: Mutex<mpsc::UnboundedReceiver<(u64, quinn::SendStream, quinn::RecvStream)>>,
+    incoming_uni: Mutex<mpsc::UnboundedReceiver<(u64, quinn::RecvStream)>>,
+    // The mux routes the connection's datagrams and must outlive the channel
+    _datagram_mux: QuicDatagramMux,
+    datagrams: QuicDatagramChannel,
+    pending_datagrams: Mutex<VecDeque<Vec<u8>>>,
+    closed: watch::Receiver<Option<WebTransportCloseInfo>>,
+    close_sender: watch::Sender<Option<WebTransportCloseInfo>>,
+    session_task: Mutex<Option<AbortOnDropHandle<()>>>,
+    _tasks: Vec<AbortOnDropHandle<()>>,
+}
+
+impl WebTransportSession {
+    /// Open a session to `url`
+    ///
+    /// `endpoint` must offer the `h3` ALPN, e.g. one created with
+    /// `QuicEndpoint::client_with_alpn`. Fails if the server does not enable
+    /// WebTransport in its SETTINGS or answers the CONNECT with a non-2xx status.
+    ///
+    /// # Arguments
+    /// * `endpoint` - Client endpoint used for the session's connection
+    /// * `url` - https URL of the WebTransport endpoint (e.g., "https://127.0.0.1:4433/chat")
+    /// * `headers` - Extra request header fields, such as `origin`
+    pub async fn connect(
+        endpoint: &QuicEndpoint,
+        url: String,
+        headers: Vec<Http3Header>,
+    ) -> Result<Self, QuicError> {
+        let parsed_url = Url::parse(&url)
+            .map_err(|e| QuicError::Connection(format!("Invalid URL: {:?}", e)))?;
+        if parsed_url.scheme() != "https" {
+            return Err(QuicError::Connection("WebTransport URLs must use https".to_string()));
+        }
+        let host = parsed_url.host_str()
+            .ok_or_else(|| QuicError::Connection("URL must contain a host".to_string()))?;
+        let port = parsed_url.port().unwrap_or(443);
+
+        let mut request = Request::builder()
+            .method(Method::CONNECT)
+            .uri(parsed_url.as_str())
+            .extension(h3::ext::Protocol::WEB_TRANSPORT)
+            .body(())
+            .map_err(|e| QuicError::Config(format!("Invalid request: {:?}", e)))?;
+        *request.headers_mut() = to_header_map(&headers)?;
+        request.headers_mut().insert("sec-webtransport-http3-draft02", HeaderValue::from_static("1"));
+
+        let connection = endpoint.connect(format!("{}:{}", host, port), host.to_string()).await?;
+        let conn = connection.inner().clone();
+        let alpn = conn.handshake_data()
+            .and_then(|data| data.downcast::<quinn::crypto::rustls::HandshakeData>().ok())
+            .and_then(|data| data.protocol);
+        if alpn.as_deref() != Some(HTTP3_ALPN) {
+            conn.close(0u32.into(), b"no h3");
+            return Err(QuicError::Connection("Server did not negotiate the h3 ALPN".to_string()));
+        }
+
+        // Routing starts before the CONNECT so no session datagram is missed
+        let datagram_mux = QuicDatagramMux::new(&connection, QuicDatagramChannelConfig::default())?;
+
+        let (h3_streams, recv_streams) = mpsc::unbounded_channel();
+        let (bi_sender, incoming_bi) = mpsc::unbounded_channel();
+        let (uni_sender, incoming_uni) = mpsc::unbounded_channel();
+        let mut tasks = vec![
+            AbortOnDropHandle::new(tokio::spawn(accept_uni_streams(conn.clone(), h3_streams, uni_sender))),
+            AbortOnDropHandle::new(tokio::spawn(accept_bi_streams(conn.clone(), bi_sender))),
+        ];
+
+        let transport = H3Transport {
+            conn: conn.clone(),
+            quic: h3_quinn::Connection::new(conn.clone()),
+            recv_streams,
+        };
+        let (driver, mut send_request) = h3::client::builder()
+            .enable_extended_connect(true)
+            .enable_datagram(true)
+            .build::<_, _, Bytes>(transport)
+            .await
+            .map_err(|e| QuicError::Connection(format!("Failed to establish HTTP/3 connection: {:?}", e)))?;
+        let (settings_sender, settings_receiver) = oneshot::channel();
+        tasks.push(AbortOnDropHandle::new(tokio::spawn(drive_connection(driver, settings_sender))));
+
+        // Extended CONNECT may only be sent once the server has enabled WebTransport
+        let supports_webtransport = tokio::time::timeout(HANDSHAKE_TIMEOUT, settings_receiver).await
+            .map_err(|_| QuicError::Connection("Timed out waiting for HTTP/3 SETTINGS".to_string()))?
+            .map_err(|_| QuicError::Connection("Connection closed before HTTP/3 SETTINGS".to_string()))?;
+        if !supports_webtransport {
+            conn.close(0u32.into(), b"no webtransport");
+            return Err(QuicError::Connection("Server does not support WebTransport".to_string()));
+        }
+
+        let stream = send_request.send_request(request).await
+            .map_err(|e| QuicError::Connection(format!("Failed to send CONNECT request: {:?}", e)))?;
+        let session_id = stream.id().into_inner();
+        let datagrams = datagram_mux.open_channel(session_id / 4)?;
+
+        let (connect_send, mut connect_recv) = stream.split();
+        let response = tokio::time::timeout(HANDSHAKE_TIMEOUT, connect_recv.recv_response()).await
+            .map_err(|_| QuicError::Connection("Timed out waiting for the CONNECT response".to_string()))?
+            .map_err(|e| QuicError::Connection(format!("Failed to receive the CONNECT response: {:?}", e)))?;
+        if !response.status().is_success() {
+            conn.close(0u32.into(), b"session rejected");
+            return Err(QuicError::Connection(format!(
+                "WebTransport session rejected with status {}", response.status().as_u16()
+            )));
+        }
+
+        let (close_sender, closed) = watch::channel(None);
+        let session_task = AbortOnDropHandle::new(tokio::spawn(watch_session(
+            conn.clone(),
+            connect_recv,
+            close_sender.clone(),
+        )));
+
+        Ok(Self {
+            connection,
+            session_id,
+            _send_request: send_request,
+            connect_stream: Mutex::new(Some(connect_send)),
+            incoming_bi: Mutex::new(incoming_bi),
+            incoming_uni: Mutex::new(incoming_uni),
+            _datagram_mux: datagram_mux,
+            datagrams,
+            pending_datagrams: Mutex::new(VecDeque::new()),
+            closed,
+            close_sender,
+            session_task: Mutex::new(Some(session_task)),
+            _tasks: tasks,
+        })
+    }
+
+    /// Get the session ID, which is the stream ID of the CONNECT request
+    pub fn session_id(&self) -> u64 {
+        self.session_id
+    }
+
+    /// Get the QUIC connection carrying the session, e.g. for statistics
+    pub fn connection(&self) -> QuicConnection {
+        self.connection.clone()
+    }
+
+    /// Open a bidirectional stream within the session
+    pub async fn open_bi(&self) -> Result<(QuicSendStream, QuicRecvStream), QuicError> {
+        self.check_open()?;
+        let (mut send, recv) = self.connection.inner().open_bi().await
+            .map_err(|e| QuicError::Connection(format!("Failed to open bidirectional stream: {:?}", e)))?;
+
+        let mut header = Vec::new();
+        encode_varint(&mut header, SIGNAL_WEBTRANSPORT_BIDI);
+        encode_varint(&mut header, self.session_id);
+        send.write_all(&header).await
+            .map_err(|e| QuicError::Stream(format!("Failed to send stream header: {:?}", e)))?;
+
+        Ok((QuicSendStream::new(send), QuicRecvStream::new(recv)))
+    }
+
+    /// Open a unidirectional stream within the session
+    pub async fn open_uni(&self) -> Result<QuicSendStream, QuicError> {
+        self.check_open()?;
+        let mut send = self.connection.inner().open_uni().await
+            .map_err(|e| QuicError::Connection(format!("Failed to open unidirectional stream: {:?}", e)))?;
+
+        let mut header = Vec::new();
+        encode_varint(&mut header, STREAM_TYPE_WEBTRANSPORT_UNI);
+        encode_varint(&mut header, self.session_id);
+        send.write_all(&header).await
+            .map_err(|e| QuicError::Stream(format!("Failed to send stream header: {:?}", e)))?;
+
+        Ok(QuicSendStream::new(send))
+    }
+
+    /// Accept the next bidirectional stream the server opened within the session
+    pub async fn accept_bi(&self) -> Result<(QuicSendStream, QuicRecvStream), QuicError> {
+        let mut incoming = self.incoming_bi.lock().await;
+        loop {
+            let (session_id, mut send, mut recv) = incoming.recv().await
+                .ok_or_else(|| self.closed_error())?;
+            if session_id == self.session_id {
+                return Ok((QuicSendStream::new(send), QuicRecvStream::new(recv)));
+            }
+            let _ = send.reset(H3_STREAM_CREATION_ERROR.into());
+            let _ = recv.stop(H3_STREAM_CREATION_ERROR.into());
+        }
+    }
+
+    /// Accept the next unidirectional stream the server opened within the session
+    pub async fn accept_uni(&self) -> Result<QuicRecvStream, QuicError> {
+        let mut incoming = self.incoming_uni.lock().await;
+        loop {
+            let (session_id, mut recv) = incoming.recv().await
+                .ok_or_else(|| self.closed_error())?;
+            if session_id == self.session_id {
+                return Ok(QuicRecvStream::new(recv));
+            }
+            let _ = recv.stop(H3_STREAM_CREATION_ERROR.into());
+        }
+    }
+
+    /// Send an unreliable datagram within the session
+    ///
+    /// Fails if the payload exceeds `max_datagram_size`.
+    pub async fn send_datagram(&self, data: Vec<u8>) -> Result<(), QuicError> {
+        self.check_open()?;
+        self.datagrams.send(data).await
+            .map_err(|e| QuicError::Connection(format!("Failed to send datagram: {:?}", e)))
+    }
+
+    /// Receive the next datagram sent within the session
+    ///
+    /// Datagrams are queued with the default `QuicDatagramChannelConfig`
+    /// capacity, dropping the oldest when the application falls behind.
+    pub async fn read_datagram(&self) -> Result<Vec<u8>, QuicError> {
+        let mut pending = self.pending_datagrams.lock().await;
+        loop {
+            if let Some(datagram) = pending.pop_front() {
+                return Ok(datagram);
+            }
+            let batch = self.datagrams.next_batch().await?;
+            if batch.datagrams.is_empty() && batch.end.is_some() {
+                return Err(self.closed_error());
+            }
+            pending.extend(batch.datagrams);
+        }
+    }
+
+    /// Get the largest datagram payload that can currently be sent, if datagrams are supported
+    pub fn max_datagram_size(&self) -> Option<usize> {
+        self.datagrams.max_payload_size()
+    }
+
+    /// Close the session with an application error code and reason
+    ///
+    /// Sends CLOSE_WEBTRANSPORT_SESSION, then closes the underlying connection.
+    /// Reasons longer than 1024 bytes are truncated.
+    pub async fn close(&self, code: u32, reason: String) {
+        let mut reason = reason.into_bytes();
+        reason.truncate(MAX_CLOSE_REASON_LEN);
+        let connect_stream = self.connect_stream.lock().await.take();
+
+        self.close_sender.send_if_modified(|closed| {
+            if closed.is_some() {
+                return false;
+            }
+            *closed = Some(WebTransportCloseInfo {
+                code,
+                reason: String::from_utf8_lossy(&reason).into_owned(),
+            });
+            true
+        });
+
+        if let Some(mut connect_stream) = connect_stream {
+            let mut capsule = code.to_be_bytes().to_vec();
+            capsule.extend_from_slice(&reason);
+            let mut payload = Vec::new();
+            encode_varint(&mut payload, CAPSULE_CLOSE_WEBTRANSPORT_SESSION);
+            encode_varint(&mut payload, capsule.len() as u64);
+            payload.extend_from_slice(&capsule);
+
+            // The server answers by ending the CONNECT stream, which the watcher waits for
+            if connect_stream.send_data(Bytes::from(payload)).await.is_ok() && connect_stream.finish().await.is_ok() {
+                if let Some(session_task) = self.session_task.lock().await.take() {
+                    let _ = tokio::time::timeout(CLOSE_FLUSH_TIMEOUT, session_task).await;
+                }
+            }
+        }
+
+        self.connection.inner().close(0u32.into(), b"session closed");
+    }
+
+    /// Wait until the session is closed by either side and return why
+    pub async fn closed(&self) -> WebTransportCloseInfo {
+        let mut closed = self.closed.clone();
+        let info = match closed.wait_for(Option::is_some).await {
+            Ok(info) => info.clone().unwrap_or_default(),
+            // The watcher only stops once the session was marked closed
+            Err(_) => WebTransportCloseInfo::default(),
+        };
+        info
+    }
+
+    fn check_open(&self) -> Result<(), QuicError> {
+        if self.closed.borrow().is_some() {
+            return Err(self.closed_error());
+        }
+        Ok(())
+    }
+
+    fn closed_error(&self) -> QuicError {
+        match &*self.closed.borrow() {
+            Some(info) => QuicError::Connection(format!(
+                "WebTransport session closed with code {}: {}", info.code, info.reason
+            )),
+            None => QuicError::Connection("WebTransport session closed".to_string()),
+        }
+    }
+}
+
+/// QUIC transport handed to h3, which only sees the HTTP/3 streams
+///
+/// WebTransport streams are taken by the session's accept tasks first, so h3
+/// neither drops the unidirectional ones nor rejects the bidirectional ones.
+struct H3Transport {
+    conn: quinn::Connection,
+    quic: h3_quinn::Connection,
+    recv_streams: mpsc::UnboundedReceiver<H3RecvStream>,
+}
+
+impl h3::quic::Connection<Bytes> for H3Transport {
+    type RecvStream = H3RecvStream;
+    type OpenStreams = h3_quinn::OpenStreams;
+
+    fn poll_accept_recv(&mut self, cx: &mut Context<'_>) -> Poll<Result<H3RecvStream, ConnectionErrorIncoming>> {
+        match self.recv_streams.poll_recv(cx) {
+            Poll::Ready(Some(stream)) => Poll::Ready(Ok(stream)),
+            // The accept task only stops once the connection is gone
+            Poll::Ready(None) => Poll::Ready(Err(match self.conn.close_reason() {
+                Some(error) => connection_error(error),
+                None => ConnectionErrorIncoming::InternalError("Stream dispatch stopped".to_string()),
+            })),
+            Poll::Pending => Poll::Pending,
+        }
+    }
+
+    fn poll_accept_bidi(&mut self, _cx: &mut Context<'_>) -> Poll<Result<Self::BidiStream, ConnectionErrorIncoming>> {
+        // Servers only open bidirectional streams for WebTransport
+        Poll::Pending
+    }
+
+    fn opener(&self) -> h3_quinn::OpenStreams {
+        h3::quic::Connection::<Bytes>::opener(&self.quic)
+    }
+}
+
+impl h3::quic::OpenStreams<Bytes> for H3Transport {
+    type BidiStream = h3_quinn::BidiStream<Bytes>;
+    type SendStream = h3_quinn::SendStream<Bytes>;
+
+    fn poll_open_bidi(&mut self, cx: &mut Context<'_>) -> Poll<Result<Self::BidiStream, StreamErrorIncoming>> {
+        self.quic.poll_open_bidi(cx)
+    }
+
+    fn poll_open_send(&mut self, cx: &mut Context<'_>) -> Poll<Result<Self::SendStream, StreamErrorIncoming>> {
+        self.quic.poll_open_send(cx)
+    }
+
+    fn close(&mut self, code: h3::error::Code, reason: &[u8]) {
+        h3::quic::OpenStreams::<Bytes>::close(&mut self.quic, code, reason)
+    }
+}
+
+/// Unidirectional stream whose already-read stream type is replayed to h3
+struct H3RecvStream {
+    prefix: Option<Bytes>,
+    stream: quinn::RecvStream,
+    buf: Vec<u8>,
+}
+
+impl h3::quic::RecvStream for H3RecvStream {
+    type Buf = Bytes;
+
+    fn poll_data(&mut self, cx: &mut Context<'_>) -> Poll<Result<Option<Bytes>, StreamErrorIncoming>> {
+        if let Some(prefix) = self.prefix.take() {
+            return Poll::Ready(Ok(Some(prefix)));
+        }
+        match ready!(self.stream.poll_read(cx, &mut self.buf)) {
+            Ok(0) => Poll::Ready(Ok(None)),
+            Ok(len) => Poll::Ready(Ok(Some(Bytes::copy_from_slice(&self.buf[..len])))),
+            Err(quinn::ReadError::Reset(code)) => Poll::Ready(Err(StreamErrorIncoming::StreamTerminated {
+                error_code: code.into_inner(),
+            })),
+            Err(quinn::ReadError::ConnectionLost(error)) => Poll::Ready(Err(
+                StreamErrorIncoming::ConnectionErrorIncoming { connection_error: connection_error(error) },
+            )),
+            Err(error) => Poll::Ready(Err(StreamErrorIncoming::Unknown(Box::new(error)))),
+        }
+    }
+
+    fn stop_sending(&mut self, error_code: u64) {
+        if let Ok(code) = quinn::VarInt::from_u64(error_code) {
+            let _ = self.stream.stop(code);
+        }
+    }
+
+    fn recv_id(&self) -> StreamId {
+        StreamId::try_from(quinn::VarInt::from(self.stream.id()).into_inner())
+            .expect("QUIC stream IDs are valid HTTP/3 stream IDs")
+    }
+}
+
+fn connection_error(error: quinn::ConnectionError) -> ConnectionErrorIncoming {
+    match error {
+        quinn::ConnectionError::ApplicationClosed(close) => ConnectionErrorIncoming::ApplicationClose {
+            error_code: close.error_code.into_inner(),
+        },
+        quinn::ConnectionError::TimedOut => ConnectionErrorIncoming::Timeout,
+        error => ConnectionErrorIncoming::Undefined(Arc::new(error)),
+    }
+}
+
+/// Drive the HTTP/3 connection, reporting whether the server enables WebTransport
+async fn drive_connection(
+    mut driver: h3::client::Connection<H3Transport, Bytes>,
+    settings: oneshot::Sender<bool>,
+) {
+    let mut settings = Some(settings);
+    let _ = std::future::poll_fn(|cx| {
+        let closed = driver.poll_close(cx);
+        // h3 only borrows the settings once the server's SETTINGS frame arrived
+        if let Cow::Borrowed(received) = driver.settings() {
+            if let Some(sender) = settings.take() {
+                let _ = sender.send(received.enable_webtransport());
+            }
+        }
+        closed
+    })
+    .await;
+}
+
+/// Dispatch unidirectional streams from the server until the connection closes
+///
+/// WebTransport streams go to the session; all others are handed to h3.
+async fn accept_uni_streams(
+    conn: quinn::Connection,
+    h3_streams: mpsc::UnboundedSender<H3RecvStream>,
+    sessions: mpsc::UnboundedSender<(u64, quinn::RecvStream)>,
+) {
+    while let Ok(mut recv) = conn.accept_uni().await {
+        let h3_streams = h3_streams.clone();
+        let sessions = sessions.clone();
+
+        // Stream headers are read in their own task so a slow stream blocks no other
+        tokio::spawn(async move {
+            let Ok(Some(stream_type)) = read_varint_bytes(&mut recv).await else {
+                return;
+            };
+
+            match decode_varint(&stream_type) {
+                Some((STREAM_TYPE_WEBTRANSPORT_UNI, _)) => {
+                    if let Ok(Some(session_id)) = read_varint(&mut recv).await {
+                        let _ = sessions.send((session_id, recv));
+                    }
+                }
+                _ => {
+                    let _ = h3_streams.send(H3RecvStream {
+                        prefix: Some(Bytes::from(stream_type)),
+                        stream: recv,
+                        buf: vec![0; H3_READ_BUFFER_SIZE],
+                    });
+                }
+            }
+        });
+    }
+}
+
+/// Dispatch bidirectional streams from the server until the connection closes
+async fn accept_bi_streams(
+    conn: quinn::Connection,
+    sessions: mpsc::UnboundedSender<(u64, quinn::SendStream, quinn::RecvStream)>,
+) {
+    while let Ok((mut send, mut recv)) = conn.accept_bi().await {
+        let sessions = sessions.clone();
+
+        tokio::spawn(async move {
+            match read_varint(&mut recv).await {
+                Ok(Some(SIGNAL_WEBTRANSPORT_BIDI)) => {
+                    if let Ok(Some(session_id)) = read_varint(&mut recv).await {
+                        let _ = sessions.send((session_id, send, recv));
+                    }
+                }
+                // HTTP/3 servers may not open bidirectional streams otherwise
+                _ => {
+                    let _ = send.reset(H3_STREAM_CREATION_ERROR.into());
+                    let _ = recv.stop(H3_STREAM_CREATION_ERROR.into());
+                }
+            }
+        });
+    }
+}
+
+/// Read the CONNECT stream after the response until the server ends the session
+async fn watch_session(
+    conn: quinn::Connection,
+    mut connect_recv: RequestStream<h3_quinn::RecvStream, Bytes>,
+    closed: watch::Sender<Option<WebTransportCloseInfo>>,
+) {
+    let mut info = WebTransportCloseInfo::default();
+    let mut capsules = Vec::new();
+
+    while let Ok(Some(mut data)) = connect_recv.recv_data().await {
+        capsules.extend_from_slice(&data.copy_to_bytes(data.remaining()));
+
+        // Capsules may span DATA frames; parse every complete one
+        while let Some((capsule_type, value, len)) = decode_capsule(&capsules) {
+            if capsule_type == CAPSULE_CLOSE_WEBTRANSPORT_SESSION && value.len() >= 4 {
+                info.code = u32::from_be_bytes([value[0], value[1], value[2], value[3]]);
+                info.reason = String::from_utf8_lossy(&value[4..]).into_owned();
+            }
+            capsules.drain(..len);
+        }
+    }
+
+    let changed = closed.send_if_modified(|closed| {
+        if closed.is_some() {
+            return false;
+        }
+        *closed = Some(info);
+        true
+    });
+    if changed {
+        conn.close(0u32.into(), b"session closed");
+    }
+}
+
+/// Read a variable-length integer, or `None` if the stream ended before it
+async fn read_varint(recv: &mut quinn::RecvStream) -> Result<Option<u64>, QuicError> {
+    let bytes = read_varint_bytes(recv).await?;
+    Ok(bytes.and_then(|bytes| decode_varint(&bytes).map(|(value, _)| value)))
+}
+
+/// Read the encoded bytes of a variable-length integer
+async fn read_varint_bytes(recv: &mut quinn::RecvStream) -> Result<Option<Vec<u8>>, QuicError> {
+    let mut buf = [0u8; 8];
+    match recv.read(&mut buf[..1]).await {
+        Ok(Some(1)) => {}
+        Ok(_) => return Ok(None),
+        Err(e) => return Err(QuicError::Stream(format!("Failed to read stream: {:?}", e))),
+    }
+
+    let len = 1 << (buf[0] >> 6);
+    recv.read_exact(&mut buf[1..len]).await
+        .map_err(|e| QuicError::Stream(format!("Failed to read stream: {:?}", e)))?;
+    Ok(Some(buf[..len].to_vec()))
+}
+
+/// Decode one complete capsule as (type, value, encoded length)
+fn decode_capsule(buf: &[u8]) -> Option<(u64, &[u8], usize)> {
+    let (capsule_type, type_len) = decode_varint(buf)?;
+    let (len, len_len) = decode_varint(&buf[type_len..])?;
+    let start = type_len + len_len;
+    let end = start.checked_add(usize::try_from(len).ok()?)?;
+    Some((capsule_type, buf.get(start..end)?, end))
+}
@@ -0,0 +1,146 @@
+//! SNI certificate selection - per-server-name certificates that can be replaced at runtime
+
+use flutter_rust_bridge::frb;
+use crate::errors::QuicError;
+use crate::models::certificate::{CertificateChain, PrivateKey};
+use rustls::server::{ClientHello, ResolvesServerCert};
+use rustls::sign::CertifiedKey;
+use rustls_pki_types::CertificateDer;
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+/// Registry of server certificates selected by the client's SNI
+///
+/// Server configs built with `QuicServerConfig::with_cert_resolver` share this
+/// registry, so adding or replacing a certificate applies to the next handshake
+/// without restarting the endpoint. Names may use a leading wildcard label
+/// (`*.example.com`). Clients that send no SNI, or an unknown name, get the
+/// default certificate if one is set.
+#[derive(Clone)]
+#[frb(opaque)]
+pub struct QuicCertificateResolver {
+    inner: Arc<SniResolver>,
+}
+
+impl QuicCertificateResolver {
+    /// Create an empty resolver
+    pub fn new() -> Self {
+        Self {
+            inner: Arc::new(SniResolver::default()),
+        }
+    }
+
+    /// Add or replace the certificate served for `server_name`
+    pub fn add(
+        &self,
+        server_name: String,
+        cert_chain: CertificateChain,
+        key: PrivateKey,
+    ) -> Result<(), QuicError> {
+        let certified_key = certified_key(cert_chain, key)?;
+        self.inner.by_name.write().unwrap()
+            .insert(server_name.to_ascii_lowercase(), certified_key);
+        Ok(())
+    }
+
+    /// Stop serving a certificate for `server_name`, returning whether one was registered
+    pub fn remove(&self, server_name: String) -> bool {
+        self.inner.by_name.write().unwrap()
+            .remove(&server_name.to_ascii_lowercase())
+            .is_some()
+    }
+
+    /// Set the certificate used when no registered name matches
+    pub fn set_default(&self, cert_chain: CertificateChain, key: PrivateKey) -> Result<(), QuicError> {
+        let certified_key = certified_key(cert_chain, key)?;
+        *self.inner.default.write().unwrap() = Some(certified_key);
+        Ok(())
+    }
+
+    /// Server names with a registered certificate
+    pub fn server_names(&self) -> Vec<String> {
+        self.inner.by_name.read().unwrap().keys().cloned().collect()
+    }
+
+    /// Get the resolver as a rustls `ResolvesServerCert`
+    pub(crate) fn as_rustls(&self) -> Arc<dyn ResolvesServerCert> {
+        self.inner.clone()
+    }
+}
+
+impl Default for QuicCertificateResolver {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for QuicCertificateResolver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+#[derive(Default)]
+struct SniResolver {
+    by_name: RwLock<HashMap<String, Arc<CertifiedKey>>>,
+    default: RwLock<Option<Arc<CertifiedKey>>>,
+}
+
+impl SniResolver {
+    fn lookup(&self, server_name: &str) -> Option<Arc<CertifiedKey>> {
+        let server_name = server_name.to_ascii_lowercase();
+        let by_name = self.by_name.read().unwrap();
+
+        if let Some(key) = by_name.get(&server_name) {
+            return Some(key.clone());
+        }
+
+        // Wildcards cover exactly one label: `*.example.com` matches `a.example.com`
+        let (_, parent) = server_name.split_once('.')?;
+        by_name.get(&format!("*.{}", parent)).cloned()
+    }
+}
+
+impl fmt::Debug for SniResolver {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SniResolver")
+            .field("server_names", &self.by_name.read().unwrap().keys().collect::<Vec<_>>())
+            .field("has_default", &self.default.read().unwrap().is_some())
+            .finish()
+    }
+}
+
+impl ResolvesServerCert for SniResolver {
+    fn resolve(&self, client_hello: ClientHello<'_>) -> Option<Arc<CertifiedKey>> {
+        client_hello
+            .server_name()
+            .and_then(|name| self.lookup(name))
+            .or_else(|| self.default.read().unwrap().clone())
+    }
+}
+
+fn certified_key(cert_chain: CertificateChain, key: PrivateKey) -> Result<Arc<CertifiedKey>, QuicError> {
+    if cert_chain.certificates.is_empty() {
+        return Err(QuicError::Tls("Certificate chain is empty".to_string()));
+    }
+
+    let signing_key = rustls::crypto::ring::sign::any_supported_type(&key.to_der())
+        .map_err(|e| QuicError::Tls(format!("Unsupported private key: {:?}", e)))?;
+
+    let certificates: Vec<CertificateDer<'static>> = cert_chain.certificates
+        .into_iter()
+        .map(CertificateDer::from)
+        .collect();
+
+    let certified_key = CertifiedKey::new(certificates, signing_key);
+    match certified_key.keys_match() {
+        // Signing keys that cannot report their public key are accepted unchecked
+        Ok(()) | Err(rustls::Error::InconsistentKeys(rustls::InconsistentKeys::Unknown)) => {}
+        Err(e) => {
+            return Err(QuicError::Tls(format!("Private key does not match the certificate: {:?}", e)));
+        }
+    }
+
+    Ok(Arc::new(certified_key))
+}
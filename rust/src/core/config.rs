@@ -1,6 +1,10 @@
 //! Configuration builders for QUIC endpoints and connections
 
 use flutter_rust_bridge::frb;
+use crate::core::keylog::QuicKeyLog;
+use crate::core::cert_resolver::QuicCertificateResolver;
+use crate::models::certificate::{CertificateChain, PrivateKey};
+use std::net::{SocketAddrV4, SocketAddrV6};
 use std::sync::Arc;
 use std::time::Duration;
 
@@ -8,6 +12,7 @@ use std::time::Duration;
 #[frb(opaque)]
 pub struct QuicServerConfig {
     inner: quinn::ServerConfig,
+    use_retry: bool,
 }
 
 impl QuicServerConfig {
@@ -21,7 +26,7 @@ impl QuicServerConfig {
         // Convert Vec<Vec<u8>> to Vec<CertificateDer>
         let cert_chain: Vec<CertificateDer> = cert_chain
             .into_iter()
-            .map(|cert| CertificateDer::from(cert))
+            .map(CertificateDer::from)
             .collect();
         
         // Convert key bytes to PrivateKeyDer
@@ -31,14 +36,55 @@ impl QuicServerConfig {
         let server_config = quinn::ServerConfig::with_single_cert(cert_chain, private_key)
             .map_err(|e| format!("Failed to create server config: {:?}", e))?;
         
-        Ok(Self { inner: server_config })
+        Ok(Self::from_inner(server_config))
+    }
+    
+    /// Create a new server config from a loaded certificate chain and private key
+    ///
+    /// Unlike `with_single_cert`, the key encoding is taken from `key.format` instead of guessed.
+    pub fn with_certificate(
+        cert_chain: CertificateChain,
+        key: PrivateKey,
+    ) -> Result<Self, String> {
+        use rustls_pki_types::CertificateDer;
+        
+        let certificates: Vec<CertificateDer> = cert_chain.certificates
+            .into_iter()
+            .map(CertificateDer::from)
+            .collect();
+        
+        let server_config = quinn::ServerConfig::with_single_cert(certificates, key.to_der())
+            .map_err(|e| format!("Failed to create server config: {:?}", e))?;
+        
+        Ok(Self::from_inner(server_config))
     }
     
     /// Create a new server config with a crypto provider and certificate
+    ///
+    /// Debug builds log TLS secrets to `SSLKEYLOGFILE` when it is set.
     pub fn with_crypto(
         cert_chain: Vec<Vec<u8>>,
         key: Vec<u8>,
         alpn_protocols: Vec<Vec<u8>>,
+    ) -> Result<Self, String> {
+        Self::build_with_crypto(cert_chain, key, alpn_protocols, QuicKeyLog::build_default())
+    }
+    
+    /// Create a new server config that logs TLS secrets to `key_log`
+    pub fn with_crypto_and_key_log(
+        cert_chain: Vec<Vec<u8>>,
+        key: Vec<u8>,
+        alpn_protocols: Vec<Vec<u8>>,
+        key_log: QuicKeyLog,
+    ) -> Result<Self, String> {
+        Self::build_with_crypto(cert_chain, key, alpn_protocols, Some(key_log))
+    }
+    
+    fn build_with_crypto(
+        cert_chain: Vec<Vec<u8>>,
+        key: Vec<u8>,
+        alpn_protocols: Vec<Vec<u8>>,
+        key_log: Option<QuicKeyLog>,
     ) -> Result<Self, String> {
         use rustls_pki_types::{CertificateDer, PrivateKeyDer};
         use rustls::ServerConfig as RustlsServerConfig;
@@ -46,7 +92,7 @@ impl QuicServerConfig {
         // Convert certificate chain
         let cert_chain: Vec<CertificateDer> = cert_chain
             .into_iter()
-            .map(|cert| CertificateDer::from(cert))
+            .map(CertificateDer::from)
             .collect();
         
         // Convert private key
@@ -60,16 +106,138 @@ impl QuicServerConfig {
             .map_err(|e| format!("Failed to create TLS config: {:?}", e))?;
         
         crypto_config.alpn_protocols = alpn_protocols;
+        if let Some(key_log) = key_log {
+            crypto_config.key_log = key_log.as_rustls();
+        }
         
         let server_config = quinn::ServerConfig::with_crypto(Arc::new(
             quinn::crypto::rustls::QuicServerConfig::try_from(crypto_config)
                 .map_err(|e| format!("Failed to create QUIC server config: {:?}", e))?
         ));
         
-        Ok(Self { inner: server_config })
+        Ok(Self::from_inner(server_config))
+    }
+    
+    /// Create a new server config that picks certificates by SNI from `resolver`
+    ///
+    /// Certificates added to the resolver later are used for subsequent handshakes.
+    /// Debug builds log TLS secrets to `SSLKEYLOGFILE` when it is set.
+    pub fn with_cert_resolver(
+        resolver: QuicCertificateResolver,
+        alpn_protocols: Vec<Vec<u8>>,
+    ) -> Result<Self, String> {
+        Self::build_with_cert_resolver(resolver, alpn_protocols, QuicKeyLog::build_default())
+    }
+    
+    /// Create a new server config that picks certificates by SNI and logs TLS secrets to `key_log`
+    pub fn with_cert_resolver_and_key_log(
+        resolver: QuicCertificateResolver,
+        alpn_protocols: Vec<Vec<u8>>,
+        key_log: QuicKeyLog,
+    ) -> Result<Self, String> {
+        Self::build_with_cert_resolver(resolver, alpn_protocols, Some(key_log))
+    }
+    
+    fn build_with_cert_resolver(
+        resolver: QuicCertificateResolver,
+        alpn_protocols: Vec<Vec<u8>>,
+        key_log: Option<QuicKeyLog>,
+    ) -> Result<Self, String> {
+        let mut crypto_config = rustls::ServerConfig::builder()
+            .with_no_client_auth()
+            .with_cert_resolver(resolver.as_rustls());
+        
+        crypto_config.alpn_protocols = alpn_protocols;
+        if let Some(key_log) = key_log {
+            crypto_config.key_log = key_log.as_rustls();
+        }
+        
+        let server_config = quinn::ServerConfig::with_crypto(Arc::new(
+            quinn::crypto::rustls::QuicServerConfig::try_from(crypto_config)
+                .map_err(|e| format!("Failed to create QUIC server config: {:?}", e))?
+        ));
+        
+        Ok(Self::from_inner(server_config))
+    }
+    
+    fn from_inner(inner: quinn::ServerConfig) -> Self {
+        Self {
+            inner,
+            use_retry: false,
+        }
+    }
+    
+    /// Set the transport config used for incoming connections
+    pub fn transport_config(&mut self, config: QuicTransportConfig) -> &mut Self {
+        self.inner.transport_config(Arc::new(config.into_inner()));
+        self
+    }
+    
+    /// Set the maximum number of incoming connections buffered before they are accepted
+    pub fn max_incoming(&mut self, max_incoming: usize) -> &mut Self {
+        self.inner.max_incoming(max_incoming);
+        self
+    }
+    
+    /// Set how long retry tokens remain valid
+    pub fn retry_token_lifetime(&mut self, lifetime_ms: u64) -> &mut Self {
+        self.inner.retry_token_lifetime(Duration::from_millis(lifetime_ms));
+        self
+    }
+    
+    /// Allow or forbid clients to migrate to a new address
+    pub fn migration(&mut self, allow: bool) -> &mut Self {
+        self.inner.migration(allow);
+        self
+    }
+    
+    /// Set the IPv4 preferred address advertised to clients
+    pub fn preferred_address_v4(&mut self, addr: Option<String>) -> Result<(), String> {
+        let addr = addr.map(|addr| addr.parse::<SocketAddrV4>())
+            .transpose()
+            .map_err(|e| format!("Invalid IPv4 preferred address: {:?}", e))?;
+        
+        self.inner.preferred_address_v4(addr);
+        Ok(())
+    }
+    
+    /// Set the IPv6 preferred address advertised to clients
+    pub fn preferred_address_v6(&mut self, addr: Option<String>) -> Result<(), String> {
+        let addr = addr.map(|addr| addr.parse::<SocketAddrV6>())
+            .transpose()
+            .map_err(|e| format!("Invalid IPv6 preferred address: {:?}", e))?;
+        
+        self.inner.preferred_address_v6(addr);
+        Ok(())
+    }
+    
+    /// Set the maximum number of bytes buffered for a single incoming connection
+    pub fn incoming_buffer_size(&mut self, size: u64) -> &mut Self {
+        self.inner.incoming_buffer_size(size);
+        self
+    }
+    
+    /// Set the maximum number of bytes buffered across all incoming connections
+    pub fn incoming_buffer_size_total(&mut self, size: u64) -> &mut Self {
+        self.inner.incoming_buffer_size_total(size);
+        self
+    }
+    
+    /// Require clients to prove their address with a Retry before accepting them
+    ///
+    /// Applied by `QuicEndpoint::accept` on endpoints created from this config.
+    pub fn use_retry(&mut self, enabled: bool) -> &mut Self {
+        self.use_retry = enabled;
+        self
+    }
+    
+    /// Whether accepted connections must complete address validation first
+    pub fn retry_enabled(&self) -> bool {
+        self.use_retry
     }
     
     /// Get the inner Quinn ServerConfig
+    #[allow(dead_code)]
     pub(crate) fn inner(&self) -> &quinn::ServerConfig {
         &self.inner
     }
@@ -172,6 +340,7 @@ impl QuicTransportConfig {
     }
     
     /// Get the inner Quinn TransportConfig
+    #[allow(dead_code)]
     pub(crate) fn inner(&self) -> &quinn::TransportConfig {
         &self.inner
     }
@@ -251,6 +420,7 @@ impl QuicEndpointConfig {
     }
     
     /// Get the inner Quinn EndpointConfig
+    #[allow(dead_code)]
     pub(crate) fn inner(&self) -> &quinn::EndpointConfig {
         &self.inner
     }
@@ -2,6 +2,7 @@
 
 use flutter_rust_bridge::frb;
 use crate::core::stream::{QuicSendStream, QuicRecvStream};
+use crate::core::datagram::{QuicDatagramSubscription, QuicDatagramSubscriptionConfig};
 use crate::errors::{QuicError, QuicDatagramException};
 use std::net::{SocketAddr, IpAddr};
 use std::time::Duration;
@@ -38,6 +39,26 @@ impl QuicConnection {
         Ok(QuicSendStream::new(send_stream))
     }
 
+    /// Accept the next bidirectional stream opened by the peer
+    pub async fn accept_bi(&self) -> Result<(QuicSendStream, QuicRecvStream), QuicError> {
+        let (send_stream, recv_stream) = self.inner
+            .accept_bi()
+            .await
+            .map_err(|e| QuicError::Connection(format!("Failed to accept bidirectional stream: {:?}", e)))?;
+        
+        Ok((QuicSendStream::new(send_stream), QuicRecvStream::new(recv_stream)))
+    }
+    
+    /// Accept the next unidirectional stream opened by the peer
+    pub async fn accept_uni(&self) -> Result<QuicRecvStream, QuicError> {
+        let recv_stream = self.inner
+            .accept_uni()
+            .await
+            .map_err(|e| QuicError::Connection(format!("Failed to accept unidirectional stream: {:?}", e)))?;
+        
+        Ok(QuicRecvStream::new(recv_stream))
+    }
+
     // Datagram operations
     
     /// Send an unreliable datagram
@@ -72,6 +93,17 @@ impl QuicConnection {
         }
     }
 
+    /// Receive datagrams in the background into a bounded queue
+    ///
+    /// Use this instead of calling `read_datagram` in a loop; the subscription
+    /// also reports why the connection ended.
+    pub fn subscribe_datagrams(
+        &self,
+        config: QuicDatagramSubscriptionConfig,
+    ) -> Result<QuicDatagramSubscription, QuicError> {
+        QuicDatagramSubscription::start(self.inner.clone(), config)
+    }
+
     /// Get the space available in the datagram send buffer
     pub fn datagram_send_buffer_space(&self) -> usize {
         self.inner.datagram_send_buffer_space()
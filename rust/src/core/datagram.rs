@@ -0,0 +1,248 @@
+//! Core Datagram API - Buffered delivery of received datagrams
+
+use flutter_rust_bridge::frb;
+use crate::errors::QuicError;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use tokio::sync::Notify;
+use tokio_util::task::AbortOnDropHandle;
+
+/// What a datagram subscription does when its queue is full
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuicDatagramDropPolicy {
+    /// Discard the oldest queued datagram to make room for the new one
+    DropOldest,
+    /// Discard the newly received datagram
+    DropNewest,
+}
+
+/// Configuration for a datagram subscription
+#[derive(Debug, Clone)]
+pub struct QuicDatagramSubscriptionConfig {
+    /// Maximum number of datagrams held until the application takes them
+    pub capacity: usize,
+    pub drop_policy: QuicDatagramDropPolicy,
+}
+
+impl Default for QuicDatagramSubscriptionConfig {
+    fn default() -> Self {
+        Self {
+            capacity: 256,
+            drop_policy: QuicDatagramDropPolicy::DropOldest,
+        }
+    }
+}
+
+/// How a connection ended, mirroring `quinn::ConnectionError`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuicConnectionEndKind {
+    /// The peer's QUIC version is not supported
+    VersionMismatch,
+    /// The peer violated the QUIC specification
+    TransportError,
+    /// The peer closed the connection at the transport level
+    ConnectionClosed,
+    /// The peer's application closed the connection
+    ApplicationClosed,
+    /// The peer reset the connection
+    Reset,
+    /// No packets were received within the idle timeout
+    TimedOut,
+    /// The connection was closed locally
+    LocallyClosed,
+    /// No connection IDs were left to use
+    CidsExhausted,
+}
+
+/// Why a connection ended
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QuicConnectionEnd {
+    pub kind: QuicConnectionEndKind,
+    /// Error code sent with the close, for transport and application closes
+    pub error_code: Option<u64>,
+    pub reason: String,
+}
+
+impl From<quinn::ConnectionError> for QuicConnectionEnd {
+    fn from(error: quinn::ConnectionError) -> Self {
+        let (kind, error_code, reason) = match error {
+            quinn::ConnectionError::VersionMismatch => {
+                (QuicConnectionEndKind::VersionMismatch, None, String::new())
+            }
+            quinn::ConnectionError::TransportError(error) => {
+                (QuicConnectionEndKind::TransportError, Some(u64::from(error.code)), error.reason)
+            }
+            quinn::ConnectionError::ConnectionClosed(close) => (
+                QuicConnectionEndKind::ConnectionClosed,
+                Some(u64::from(close.error_code)),
+                String::from_utf8_lossy(&close.reason).into_owned(),
+            ),
+            quinn::ConnectionError::ApplicationClosed(close) => (
+                QuicConnectionEndKind::ApplicationClosed,
+                Some(close.error_code.into_inner()),
+                String::from_utf8_lossy(&close.reason).into_owned(),
+            ),
+            quinn::ConnectionError::Reset => (QuicConnectionEndKind::Reset, None, String::new()),
+            quinn::ConnectionError::TimedOut => (QuicConnectionEndKind::TimedOut, None, String::new()),
+            quinn::ConnectionError::LocallyClosed => {
+                (QuicConnectionEndKind::LocallyClosed, None, String::new())
+            }
+            quinn::ConnectionError::CidsExhausted => {
+                (QuicConnectionEndKind::CidsExhausted, None, String::new())
+            }
+        };
+
+        Self { kind, error_code, reason }
+    }
+}
+
+/// Datagrams taken from a subscription in one call
+#[derive(Debug, Clone, Default)]
+pub struct QuicDatagramBatch {
+    /// Received datagrams, oldest first
+    pub datagrams: Vec<Vec<u8>>,
+    /// Datagrams discarded by the drop policy since the previous batch
+    pub dropped: u64,
+    /// Why the connection ended, once it has; no datagrams follow this batch
+    pub end: Option<QuicConnectionEnd>,
+}
+
+#[derive(Default)]
+struct QueueState {
+    datagrams: VecDeque<Vec<u8>>,
+    dropped: u64,
+    end: Option<QuicConnectionEnd>,
+    closed: bool,
+}
+
+/// Bounded datagram queue shared between a receive task and its consumer
+pub(crate) struct DatagramQueue {
+    state: Mutex<QueueState>,
+    notify: Notify,
+    capacity: usize,
+    drop_policy: QuicDatagramDropPolicy,
+}
+
+impl DatagramQueue {
+    pub(crate) fn new(capacity: usize, drop_policy: QuicDatagramDropPolicy) -> Result<Self, QuicError> {
+        if capacity == 0 {
+            return Err(QuicError::Config("Datagram queue capacity must be at least 1".to_string()));
+        }
+
+        Ok(Self {
+            state: Mutex::new(QueueState::default()),
+            notify: Notify::new(),
+            capacity,
+            drop_policy,
+        })
+    }
+
+    /// Queue a datagram, applying the drop policy if the queue is full
+    pub(crate) fn push(&self, datagram: Vec<u8>) {
+        let mut state = self.state.lock().unwrap();
+        if state.closed {
+            return;
+        }
+
+        if state.datagrams.len() >= self.capacity {
+            state.dropped += 1;
+            match self.drop_policy {
+                QuicDatagramDropPolicy::DropOldest => {
+                    state.datagrams.pop_front();
+                }
+                QuicDatagramDropPolicy::DropNewest => return,
+            }
+        }
+        state.datagrams.push_back(datagram);
+        drop(state);
+        self.notify.notify_one();
+    }
+
+    /// Record that the connection ended; queued datagrams are still delivered
+    pub(crate) fn finish(&self, end: QuicConnectionEnd) {
+        self.state.lock().unwrap().end.get_or_insert(end);
+        self.notify.notify_one();
+    }
+
+    /// Wait for datagrams and take everything queued
+    pub(crate) async fn next_batch(&self) -> Result<QuicDatagramBatch, QuicError> {
+        loop {
+            {
+                let mut state = self.state.lock().unwrap();
+                if state.closed {
+                    return Err(QuicError::Connection("Datagram queue was closed".to_string()));
+                }
+                if !state.datagrams.is_empty() || state.end.is_some() {
+                    return Ok(QuicDatagramBatch {
+                        datagrams: state.datagrams.drain(..).collect(),
+                        dropped: std::mem::take(&mut state.dropped),
+                        end: state.end.clone(),
+                    });
+                }
+            }
+            self.notify.notified().await;
+        }
+    }
+
+    /// Discard queued datagrams and fail current and later `next_batch` calls
+    pub(crate) fn close(&self) {
+        let mut state = self.state.lock().unwrap();
+        state.closed = true;
+        state.datagrams.clear();
+        drop(state);
+        self.notify.notify_one();
+    }
+}
+
+/// Receives datagrams in the background into a bounded queue
+///
+/// Datagrams are read from the connection as they arrive, whether or not the
+/// application is currently taking them; once `capacity` are queued the drop
+/// policy applies. The subscription consumes the connection's datagrams, so
+/// `read_datagram` should not be used alongside it.
+#[frb(opaque)]
+pub struct QuicDatagramSubscription {
+    queue: Arc<DatagramQueue>,
+    task: AbortOnDropHandle<()>,
+}
+
+impl QuicDatagramSubscription {
+    pub(crate) fn start(
+        connection: quinn::Connection,
+        config: QuicDatagramSubscriptionConfig,
+    ) -> Result<Self, QuicError> {
+        let queue = Arc::new(DatagramQueue::new(config.capacity, config.drop_policy)?);
+        let task = tokio::spawn(receive_datagrams(connection, queue.clone()));
+
+        Ok(Self {
+            queue,
+            task: AbortOnDropHandle::new(task),
+        })
+    }
+
+    /// Wait for datagrams and take everything queued
+    ///
+    /// Returns at least one datagram, or the connection end once the queue is
+    /// empty. Fails if the subscription was closed.
+    pub async fn next_batch(&self) -> Result<QuicDatagramBatch, QuicError> {
+        self.queue.next_batch().await
+    }
+
+    /// Stop receiving and discard queued datagrams, failing a pending `next_batch`
+    pub fn close(&self) {
+        self.task.abort();
+        self.queue.close();
+    }
+}
+
+async fn receive_datagrams(connection: quinn::Connection, queue: Arc<DatagramQueue>) {
+    loop {
+        match connection.read_datagram().await {
+            Ok(datagram) => queue.push(datagram.to_vec()),
+            Err(error) => {
+                queue.finish(QuicConnectionEnd::from(error));
+                return;
+            }
+        }
+    }
+}
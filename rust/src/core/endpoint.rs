@@ -2,6 +2,7 @@
 
 use flutter_rust_bridge::frb;
 use crate::core::connection::QuicConnection;
+use crate::core::keylog::QuicKeyLog;
 use crate::errors::QuicError;
 use std::net::{SocketAddr, Ipv4Addr};
 use std::sync::Arc;
@@ -9,6 +10,7 @@ use std::sync::Arc;
 #[frb(opaque)]
 pub struct QuicEndpoint {
     inner: quinn::Endpoint,
+    use_retry: bool,
 }
 
 impl QuicEndpoint {
@@ -17,19 +19,44 @@ impl QuicEndpoint {
         let rt = tokio::runtime::Runtime::new()
             .map_err(|e| QuicError::Endpoint(format!("Failed to create runtime: {:?}", e)))?;
         
-        rt.block_on(async {
-            let addr: SocketAddr = addr.parse()
-                .map_err(|e| QuicError::Config(format!("Invalid address: {:?}", e)))?;
-            
-            let endpoint = quinn::Endpoint::server(config.into_inner(), addr)
-                .map_err(|e| QuicError::Endpoint(format!("Failed to create server endpoint: {:?}", e)))?;
-            
-            Ok(Self { inner: endpoint })
-        })
+        rt.block_on(async { Self::bind_server(config, addr) })
+    }
+    
+    /// Create a server endpoint driven by the current Tokio runtime
+    ///
+    /// Unlike `server`, the endpoint keeps running for as long as the caller's runtime does.
+    pub(crate) fn bind_server(config: crate::core::config::QuicServerConfig, addr: String) -> Result<Self, QuicError> {
+        let addr: SocketAddr = addr.parse()
+            .map_err(|e| QuicError::Config(format!("Invalid address: {:?}", e)))?;
+        
+        let use_retry = config.retry_enabled();
+        let endpoint = quinn::Endpoint::server(config.into_inner(), addr)
+            .map_err(|e| QuicError::Endpoint(format!("Failed to create server endpoint: {:?}", e)))?;
+        
+        Ok(Self { inner: endpoint, use_retry })
     }
 
     /// Create a new client endpoint with insecure configuration (for testing)
+    ///
+    /// Debug builds log TLS secrets to `SSLKEYLOGFILE` when it is set.
     pub fn client() -> Result<Self, QuicError> {
+        Self::build_client(QuicKeyLog::build_default(), Vec::new())
+    }
+    
+    /// Create a new client endpoint that logs TLS secrets to `key_log`
+    pub fn client_with_key_log(key_log: QuicKeyLog) -> Result<Self, QuicError> {
+        Self::build_client(Some(key_log), Vec::new())
+    }
+    
+    /// Create a new client endpoint that offers `alpn_protocols` during the handshake
+    ///
+    /// Servers that require ALPN, such as HTTP/3 servers expecting `h3`, refuse
+    /// connections from endpoints created with `client()`.
+    pub fn client_with_alpn(alpn_protocols: Vec<Vec<u8>>) -> Result<Self, QuicError> {
+        Self::build_client(QuicKeyLog::build_default(), alpn_protocols)
+    }
+    
+    fn build_client(key_log: Option<QuicKeyLog>, alpn_protocols: Vec<Vec<u8>>) -> Result<Self, QuicError> {
         // Ensure crypto provider is installed
         if rustls::crypto::CryptoProvider::get_default().is_none() {
             rustls::crypto::ring::default_provider()
@@ -38,10 +65,15 @@ impl QuicEndpoint {
         }
         
         // Create insecure client config
-        let crypto = rustls::ClientConfig::builder()
+        let mut crypto = rustls::ClientConfig::builder()
             .dangerous()
             .with_custom_certificate_verifier(SkipServerVerification::new())
             .with_no_client_auth();
+        crypto.alpn_protocols = alpn_protocols;
+        
+        if let Some(key_log) = key_log {
+            crypto.key_log = key_log.as_rustls();
+        }
             
         let mut config = quinn::ClientConfig::new(Arc::new(
             quinn::crypto::rustls::QuicClientConfig::try_from(crypto)
@@ -60,7 +92,7 @@ impl QuicEndpoint {
             
         endpoint.set_default_client_config(config);
         
-        Ok(Self { inner: endpoint })
+        Ok(Self { inner: endpoint, use_retry: false })
     }
     
     /// Connect to a server
@@ -77,6 +109,42 @@ impl QuicEndpoint {
         Ok(QuicConnection::new(connection))
     }
     
+    /// Replace the server config used for new incoming connections
+    ///
+    /// Existing connections keep the certificate, ALPN and transport settings they
+    /// were accepted with.
+    pub fn set_server_config(&mut self, config: crate::core::config::QuicServerConfig) {
+        self.use_retry = config.retry_enabled();
+        self.inner.set_server_config(Some(config.into_inner()));
+    }
+    
+    /// Accept the next incoming connection
+    ///
+    /// Returns `None` once the endpoint is closed. If the server config enabled
+    /// `use_retry`, clients whose address is not yet validated are sent a Retry first.
+    pub async fn accept(&self) -> Result<Option<QuicConnection>, QuicError> {
+        while let Some(incoming) = self.inner.accept().await {
+            if self.use_retry && incoming.may_retry() {
+                incoming.retry()
+                    .map_err(|e| QuicError::Connection(format!("Failed to send retry: {:?}", e)))?;
+                continue;
+            }
+            
+            let connection = incoming.await
+                .map_err(|e| QuicError::Connection(format!("Failed to accept connection: {:?}", e)))?;
+            
+            return Ok(Some(QuicConnection::new(connection)));
+        }
+        
+        Ok(None)
+    }
+    
+    /// Get the local address the endpoint is bound to
+    pub fn local_addr(&self) -> Result<SocketAddr, QuicError> {
+        self.inner.local_addr()
+            .map_err(|e| QuicError::Endpoint(format!("Failed to get local address: {:?}", e)))
+    }
+    
     /// Get a reference to the inner Quinn endpoint
     pub(crate) fn inner(&self) -> &quinn::Endpoint {
         &self.inner
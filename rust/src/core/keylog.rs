@@ -0,0 +1,121 @@
+//! TLS key logging - NSS key log output for decrypting packet captures
+
+use flutter_rust_bridge::frb;
+use crate::errors::QuicError;
+use std::fmt;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::sync::{Arc, Mutex};
+
+/// Environment variable read by `QuicKeyLog::from_env()`
+pub const SSLKEYLOGFILE_ENV: &str = "SSLKEYLOGFILE";
+
+/// TLS secret logger compatible with Wireshark's "(Pre)-Master-Secret log" option
+///
+/// Every secret negotiated by rustls is emitted as one NSS key log line
+/// (`<LABEL> <client_random hex> <secret hex>`). Key logging is opt-in: pass a
+/// `QuicKeyLog` to `QuicEndpoint::client_with_key_log` or
+/// `QuicServerConfig::with_crypto_and_key_log`. Debug builds additionally honor
+/// `SSLKEYLOGFILE` by default; release builds never log unless asked to.
+#[derive(Clone)]
+#[frb(opaque)]
+pub struct QuicKeyLog {
+    inner: Arc<NssKeyLog>,
+}
+
+impl QuicKeyLog {
+    /// Append key log lines to the file at `path`, creating it if needed
+    pub fn file(path: String) -> Result<Self, QuicError> {
+        let file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&path)
+            .map_err(|e| QuicError::Config(format!("Failed to open key log file {}: {:?}", path, e)))?;
+
+        Ok(Self::with_sink(KeyLogSink::File(Mutex::new(file))))
+    }
+
+    /// Deliver each key log line to `callback`
+    pub fn callback(callback: impl Fn(String) + Send + Sync + 'static) -> Self {
+        Self::with_sink(KeyLogSink::Callback(Box::new(callback)))
+    }
+
+    /// Append key log lines to the file named by `SSLKEYLOGFILE`, if it is set
+    pub fn from_env() -> Option<Self> {
+        let path = std::env::var_os(SSLKEYLOGFILE_ENV)?;
+        match Self::file(path.to_string_lossy().into_owned()) {
+            Ok(key_log) => Some(key_log),
+            Err(e) => {
+                tracing::warn!("Ignoring {}: {}", SSLKEYLOGFILE_ENV, e);
+                None
+            }
+        }
+    }
+
+    /// Key log used when none is given explicitly
+    ///
+    /// Debug builds honor `SSLKEYLOGFILE`; release builds never log secrets by default.
+    pub fn build_default() -> Option<Self> {
+        if cfg!(debug_assertions) {
+            Self::from_env()
+        } else {
+            None
+        }
+    }
+
+    fn with_sink(sink: KeyLogSink) -> Self {
+        Self {
+            inner: Arc::new(NssKeyLog { sink }),
+        }
+    }
+
+    /// Get the key log as a rustls `KeyLog`
+    pub(crate) fn as_rustls(&self) -> Arc<dyn rustls::KeyLog> {
+        self.inner.clone()
+    }
+}
+
+impl fmt::Debug for QuicKeyLog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.inner.fmt(f)
+    }
+}
+
+enum KeyLogSink {
+    File(Mutex<File>),
+    Callback(Box<dyn Fn(String) + Send + Sync>),
+}
+
+struct NssKeyLog {
+    sink: KeyLogSink,
+}
+
+impl fmt::Debug for NssKeyLog {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = match self.sink {
+            KeyLogSink::File(_) => "file",
+            KeyLogSink::Callback(_) => "callback",
+        };
+        f.debug_struct("NssKeyLog").field("sink", &kind).finish()
+    }
+}
+
+impl rustls::KeyLog for NssKeyLog {
+    fn log(&self, label: &str, client_random: &[u8], secret: &[u8]) {
+        let line = format!("{} {} {}", label, to_hex(client_random), to_hex(secret));
+
+        match &self.sink {
+            KeyLogSink::File(file) => {
+                let mut file = file.lock().unwrap();
+                if let Err(e) = writeln!(file, "{}", line).and_then(|_| file.flush()) {
+                    tracing::warn!("Failed to write key log line: {:?}", e);
+                }
+            }
+            KeyLogSink::Callback(callback) => callback(line),
+        }
+    }
+}
+
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
@@ -7,8 +7,14 @@ pub mod endpoint;
 pub mod connection;
 pub mod stream;
 pub mod config;
+pub mod keylog;
+pub mod cert_resolver;
+pub mod datagram;
 
 pub use endpoint::QuicEndpoint;
 pub use connection::{QuicConnection, QuicConnectionStats, QuicPathStats, QuicFrameStats, QuicUdpStats};
-pub use stream::{QuicSendStream, QuicRecvStream};
-pub use config::{QuicServerConfig, QuicTransportConfig, QuicEndpointConfig}; 
\ No newline at end of file
+pub use stream::{QuicChunksWritten, QuicRecvStream, QuicSendStream, QuicSendStreamStats, QuicStreamChunk};
+pub use config::{QuicServerConfig, QuicTransportConfig, QuicEndpointConfig};
+pub use keylog::QuicKeyLog;
+pub use cert_resolver::QuicCertificateResolver; 
+pub use datagram::{QuicDatagramSubscription, QuicDatagramSubscriptionConfig, QuicDatagramDropPolicy, QuicDatagramBatch, QuicConnectionEnd, QuicConnectionEndKind};
\ No newline at end of file
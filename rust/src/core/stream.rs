@@ -2,16 +2,66 @@
 
 use flutter_rust_bridge::frb;
 use crate::errors::{QuicWriteException, QuicReadException, QuicReadToEndException};
+use bytes::Bytes;
+use futures::FutureExt;
+
+/// Data received on a stream, with its position in the stream
+#[derive(Debug, Clone)]
+pub struct QuicStreamChunk {
+    /// Stream offset of the first byte of `data`
+    pub offset: u64,
+    pub data: Vec<u8>,
+}
+
+impl From<quinn::Chunk> for QuicStreamChunk {
+    fn from(chunk: quinn::Chunk) -> Self {
+        Self {
+            offset: chunk.offset,
+            // Takes over the buffer without copying when quinn holds no other reference to it
+            data: Vec::from(chunk.bytes),
+        }
+    }
+}
+
+/// How much of a multi-buffer write was accepted by the stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuicChunksWritten {
+    /// Number of bytes written
+    pub bytes: usize,
+    /// Number of buffers written in full
+    pub chunks: usize,
+}
+
+impl From<quinn::Written> for QuicChunksWritten {
+    fn from(written: quinn::Written) -> Self {
+        Self { bytes: written.bytes, chunks: written.chunks }
+    }
+}
+
+/// Write-side statistics of a send stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuicSendStreamStats {
+    /// Bytes handed to the stream through this wrapper, whether sent yet or not
+    pub bytes_queued: u64,
+    /// Whether the stream was finished and the peer acknowledged all of its data
+    ///
+    /// QUIC implementations track acknowledgement per packet, and quinn
+    /// reports it for a stream only as a whole once it is finished.
+    pub acknowledged: bool,
+    /// Error code the peer stopped the stream with, if it did
+    pub stop_error_code: Option<u64>,
+}
 
 #[frb(opaque)]
 pub struct QuicSendStream {
     inner: quinn::SendStream,
+    bytes_queued: u64,
 }
 
 impl QuicSendStream {
     /// Create a new QuicSendStream wrapping a Quinn send stream
     pub fn new(stream: quinn::SendStream) -> Self {
-        Self { inner: stream }
+        Self { inner: stream, bytes_queued: 0 }
     }
     
     /// Write data to the stream
@@ -32,10 +82,8 @@ impl QuicSendStream {
     /// Returns a `QuicWriteException` if the stream has been stopped by the peer
     /// or the connection has been lost.
     pub async fn write(&mut self, data: Vec<u8>) -> Result<usize, QuicWriteException> {
-        match self.inner.write(&data).await {
-            Ok(bytes_written) => Ok(bytes_written),
-            Err(write_error) => Err(QuicWriteException::from(write_error)),
-        }
+        let written = self.write_chunks_bytes(&mut [Bytes::from(data)]).await?;
+        Ok(written.bytes)
     }
     
     /// Write all data to the stream
@@ -53,9 +101,92 @@ impl QuicSendStream {
     /// Returns a `QuicWriteException` if the stream has been stopped by the peer
     /// or the connection has been lost.
     pub async fn write_all(&mut self, data: Vec<u8>) -> Result<(), QuicWriteException> {
-        match self.inner.write_all(&data).await {
-            Ok(()) => Ok(()),
-            Err(write_error) => Err(QuicWriteException::from(write_error)),
+        self.write_all_chunks_bytes(&mut [Bytes::from(data)]).await
+    }
+    
+    /// Write several buffers to the stream in one call
+    /// 
+    /// The buffers are sent back to back, as if they had been concatenated,
+    /// without copying them into one allocation.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `chunks` - The buffers to write, in order
+    /// 
+    /// # Returns
+    /// 
+    /// How many bytes and whole buffers were written. This may be less than
+    /// all of them if the stream's send buffer is full.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns a `QuicWriteException` if the stream has been stopped by the peer
+    /// or the connection has been lost.
+    pub async fn write_chunks(&mut self, chunks: Vec<Vec<u8>>) -> Result<QuicChunksWritten, QuicWriteException> {
+        let mut chunks: Vec<Bytes> = chunks.into_iter().map(Bytes::from).collect();
+        self.write_chunks_bytes(&mut chunks).await
+    }
+    
+    /// Write several buffers to the stream in their entirety
+    /// 
+    /// # Arguments
+    /// 
+    /// * `chunks` - The buffers to write, in order
+    /// 
+    /// # Errors
+    /// 
+    /// Returns a `QuicWriteException` if the stream has been stopped by the peer
+    /// or the connection has been lost.
+    pub async fn write_all_chunks(&mut self, chunks: Vec<Vec<u8>>) -> Result<(), QuicWriteException> {
+        let mut chunks: Vec<Bytes> = chunks.into_iter().map(Bytes::from).collect();
+        self.write_all_chunks_bytes(&mut chunks).await
+    }
+    
+    /// Write shared `Bytes` buffers to the stream without copying them
+    /// 
+    /// Same as `write_chunks`, for Rust callers. Buffers written in full are
+    /// left empty and a partially written one keeps its unwritten suffix.
+    pub async fn write_chunks_bytes(&mut self, chunks: &mut [Bytes]) -> Result<QuicChunksWritten, QuicWriteException> {
+        let written = self.inner.write_chunks(chunks).await?;
+        self.bytes_queued += written.bytes as u64;
+        Ok(written.into())
+    }
+    
+    /// Write shared `Bytes` buffers to the stream in their entirety without copying them
+    /// 
+    /// Same as `write_all_chunks`, for Rust callers.
+    pub async fn write_all_chunks_bytes(&mut self, mut chunks: &mut [Bytes]) -> Result<(), QuicWriteException> {
+        while !chunks.is_empty() {
+            let written = self.write_chunks_bytes(chunks).await?;
+            chunks = &mut chunks[written.chunks..];
+        }
+        Ok(())
+    }
+    
+    /// Abandon the stream
+    /// 
+    /// Stops sending, including retransmissions of data that was already
+    /// written, and tells the peer the stream was reset with `error_code`.
+    /// 
+    /// # Errors
+    /// 
+    /// Returns a `QuicWriteException` if the stream has already been finished
+    /// or reset.
+    pub fn reset(&mut self, error_code: u32) -> Result<(), QuicWriteException> {
+        self.inner.reset(error_code.into())?;
+        Ok(())
+    }
+    
+    /// Get write-side statistics of the stream
+    pub fn stats(&self) -> QuicSendStreamStats {
+        let stopped = self.inner.stopped().now_or_never();
+        QuicSendStreamStats {
+            bytes_queued: self.bytes_queued,
+            acknowledged: matches!(stopped, Some(Ok(None))),
+            stop_error_code: match stopped {
+                Some(Ok(Some(code))) => Some(code.into_inner()),
+                _ => None,
+            },
         }
     }
     
@@ -77,14 +208,21 @@ impl QuicSendStream {
     }
     
     /// Get a reference to the inner Quinn send stream
+    #[allow(dead_code)]
     pub(crate) fn inner(&self) -> &quinn::SendStream {
         &self.inner
     }
     
     /// Get a mutable reference to the inner Quinn send stream
+    #[allow(dead_code)]
     pub(crate) fn inner_mut(&mut self) -> &mut quinn::SendStream {
         &mut self.inner
     }
+
+    /// Take the inner Quinn send stream
+    pub(crate) fn into_inner(self) -> quinn::SendStream {
+        self.inner
+    }
 }
 
 #[frb(opaque)]
@@ -117,15 +255,65 @@ impl QuicRecvStream {
     /// Returns a `QuicReadException` if the stream has been reset by the peer,
     /// the connection has been lost, or other read errors occur.
     pub async fn read(&mut self, max_length: usize) -> Result<Option<Vec<u8>>, QuicReadException> {
-        let mut buf = vec![0u8; max_length];
-        match self.inner.read(&mut buf).await {
-            Ok(Some(bytes_read)) => {
-                buf.truncate(bytes_read);
-                Ok(Some(buf))
+        // Reading a chunk avoids allocating and zeroing `max_length` bytes for small reads
+        let chunk = self.read_chunk_bytes(max_length, true).await?;
+        Ok(chunk.map(|(_, bytes)| Vec::from(bytes)))
+    }
+    
+    /// Read the next chunk of data from the stream
+    /// 
+    /// Returns data as quinn received it, without copying it into a scratch
+    /// buffer first.
+    /// 
+    /// # Arguments
+    /// 
+    /// * `max_length` - Maximum number of bytes to return
+    /// * `ordered` - Whether to return data in stream order. Unordered reads
+    ///   return whatever arrived first; once one was made, ordered reads fail
+    ///   with `IllegalOrderedRead`.
+    /// 
+    /// # Returns
+    /// 
+    /// Returns `Some(QuicStreamChunk)` with the data and its stream offset, or
+    /// `None` if the stream has been finished by the peer.
+    pub async fn read_chunk(&mut self, max_length: usize, ordered: bool) -> Result<Option<QuicStreamChunk>, QuicReadException> {
+        let chunk = self.inner.read_chunk(max_length, ordered).await?;
+        Ok(chunk.map(QuicStreamChunk::from))
+    }
+    
+    /// Read the next chunk as shared `Bytes` with its stream offset
+    /// 
+    /// Same as `read_chunk`, for Rust callers that can use quinn's buffer
+    /// without any copy.
+    pub async fn read_chunk_bytes(&mut self, max_length: usize, ordered: bool) -> Result<Option<(u64, Bytes)>, QuicReadException> {
+        let chunk = self.inner.read_chunk(max_length, ordered).await?;
+        Ok(chunk.map(|chunk| (chunk.offset, chunk.bytes)))
+    }
+    
+    /// Read all chunks that are available in stream order, waiting for at least one
+    /// 
+    /// Returns up to `max_chunks` chunks from a single wait, so high-throughput
+    /// receivers need fewer calls.
+    /// 
+    /// # Returns
+    /// 
+    /// Returns `Some` with at least one chunk, or `None` if the stream has been
+    /// finished by the peer.
+    pub async fn read_chunks(&mut self, max_chunks: usize) -> Result<Option<Vec<QuicStreamChunk>>, QuicReadException> {
+        let Some(first) = self.read_chunk(usize::MAX, true).await? else {
+            return Ok(None);
+        };
+
+        let mut chunks = vec![first];
+        while chunks.len() < max_chunks {
+            // Take only data that is already there; the end of the stream or an
+            // error is reported by the next call
+            match self.inner.read_chunk(usize::MAX, true).now_or_never() {
+                Some(Ok(Some(chunk))) => chunks.push(chunk.into()),
+                _ => break,
             }
-            Ok(None) => Ok(None), // Stream finished
-            Err(read_error) => Err(QuicReadException::from(read_error)),
         }
+        Ok(Some(chunks))
     }
     
     /// Read all remaining data from the stream
@@ -154,14 +342,21 @@ impl QuicRecvStream {
     }
     
     /// Get a reference to the inner Quinn receive stream
+    #[allow(dead_code)]
     pub(crate) fn inner(&self) -> &quinn::RecvStream {
         &self.inner
     }
     
     /// Get a mutable reference to the inner Quinn receive stream
+    #[allow(dead_code)]
     pub(crate) fn inner_mut(&mut self) -> &mut quinn::RecvStream {
         &mut self.inner
     }
+
+    /// Take the inner Quinn receive stream
+    pub(crate) fn into_inner(self) -> quinn::RecvStream {
+        self.inner
+    }
 }
 
 // Legacy QuicStream for compatibility
@@ -5,7 +5,7 @@
 
 use thiserror::Error;
 
-#[derive(Error, Debug)]
+#[derive(Error, Debug, Clone)]
 pub enum QuicError {
     #[error("Connection error: {0}")]
     Connection(String),
@@ -27,6 +27,9 @@ pub enum QuicError {
     
     #[error("Write error: {0}")]
     Write(String),
+    
+    #[error("Response too long: exceeds the limit of {limit} bytes")]
+    ResponseTooLong { limit: usize },
 }
 
 /// Write operation errors when sending data on a QUIC stream
@@ -146,4 +149,83 @@ impl From<quinn::SendDatagramError> for QuicDatagramException {
     }
 }
 
- 
\ No newline at end of file
+ 
+/// Kind of a framed message error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuicFrameErrorKind {
+    /// A message exceeds the maximum message size
+    TooLarge,
+    /// The stream ended in the middle of a frame
+    Truncated,
+    /// Reading the underlying stream failed
+    Read,
+    /// Writing the underlying stream failed
+    Write,
+}
+
+/// Framed message errors when sending or receiving length-prefixed messages
+#[derive(Error, Debug, Clone)]
+#[error("{message}")]
+pub struct QuicFrameException {
+    pub kind: QuicFrameErrorKind,
+    pub message: String,
+}
+
+impl QuicFrameException {
+    pub(crate) fn new(kind: QuicFrameErrorKind, message: String) -> Self {
+        Self { kind, message }
+    }
+}
+
+impl From<quinn::ReadError> for QuicFrameException {
+    fn from(error: quinn::ReadError) -> Self {
+        Self::new(QuicFrameErrorKind::Read, QuicReadException::from(error).to_string())
+    }
+}
+
+impl From<quinn::WriteError> for QuicFrameException {
+    fn from(error: quinn::WriteError) -> Self {
+        Self::new(QuicFrameErrorKind::Write, QuicWriteException::from(error).to_string())
+    }
+}
+
+/// Kind of a file transfer error
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuicFileTransferErrorKind {
+    /// Reading or writing a local file failed
+    Io,
+    /// The connection or one of its streams failed; the transfer can be resumed
+    Connection,
+    /// The receiver refused the file, e.g. because of its name or size
+    Rejected,
+    /// The received file does not match the SHA-256 digest of the manifest
+    ChecksumMismatch,
+    /// The transfer was cancelled
+    Cancelled,
+}
+
+/// File transfer errors when sending or receiving files
+#[derive(Error, Debug, Clone)]
+#[error("{message}")]
+pub struct QuicFileTransferException {
+    pub kind: QuicFileTransferErrorKind,
+    pub message: String,
+}
+
+impl QuicFileTransferException {
+    pub(crate) fn new(kind: QuicFileTransferErrorKind, message: String) -> Self {
+        Self { kind, message }
+    }
+}
+
+impl From<std::io::Error> for QuicFileTransferException {
+    fn from(error: std::io::Error) -> Self {
+        Self::new(QuicFileTransferErrorKind::Io, error.to_string())
+    }
+}
+
+impl From<QuicFrameException> for QuicFileTransferException {
+    fn from(error: QuicFrameException) -> Self {
+        Self::new(QuicFileTransferErrorKind::Connection, error.message)
+    }
+}
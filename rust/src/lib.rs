@@ -1,3 +1,6 @@
+// The baseline tests keep placeholder bindings and imports; allow them in test builds only
+#![cfg_attr(test, allow(unused_imports, unused_variables, clippy::single_component_path_imports))]
+
 
 
 pub mod api;
@@ -5,6 +8,7 @@ pub mod core;
 pub mod convenience;
 pub mod models;
 pub mod errors;
+pub mod logging;
 mod frb_generated;
 
 // Re-export convenience API for easy access
@@ -66,4 +70,1310 @@ mod tests {
             }
         }
     }
+    
+    #[test]
+    fn test_self_signed_certificate_server_config() {
+        use super::core::QuicServerConfig;
+        use super::models::certificate::{generate_self_signed, CertificateKeyAlgorithm, PrivateKey};
+        
+        let generated = generate_self_signed(
+            vec!["localhost".to_string(), "127.0.0.1".to_string()],
+            30,
+            CertificateKeyAlgorithm::EcdsaP256,
+        ).expect("Failed to generate certificate");
+        assert_eq!(generated.sha256_fingerprint.len(), 64);
+        
+        QuicServerConfig::with_certificate(generated.certificate_chain, generated.private_key)
+            .expect("Generated certificate should be accepted by the server config");
+        
+        assert!(PrivateKey::from_pem(b"not a pem file").is_err());
+    }
+    
+    #[test]
+    fn test_pem_chains_key_errors_and_pkcs12() {
+        use super::models::certificate::{load_pkcs12, CertificateChain, PrivateKey, PrivateKeyFormat};
+        use p12_keystore::{KeyStore, KeyStoreEntry, PrivateKeyChain};
+        
+        let leaf = rcgen::generate_simple_self_signed(vec!["leaf.test".to_string()]).unwrap();
+        let issuer = rcgen::generate_simple_self_signed(vec!["issuer.test".to_string()]).unwrap();
+        let params = "-----BEGIN EC PARAMETERS-----\nBggqhkjOPQMBBw==\n-----END EC PARAMETERS-----\n";
+        let block = |label: &str, body: &str| format!("-----BEGIN {label}-----\n{body}\n-----END {label}-----\n");
+        
+        // The chain keeps its order, skipping the key and blocks rustls does not know
+        let pem = format!("{}{}{}{}", params, leaf.cert.pem(), leaf.signing_key.serialize_pem(), issuer.cert.pem());
+        let chain = CertificateChain::from_pem(pem.as_bytes()).unwrap();
+        assert_eq!(chain.certificates, vec![leaf.cert.der().to_vec(), issuer.cert.der().to_vec()]);
+        let key = PrivateKey::from_pem(pem.as_bytes()).unwrap();
+        assert_eq!((key.key_data, key.format), (leaf.signing_key.serialize_der(), PrivateKeyFormat::Pkcs8));
+        
+        // Errors count every block, including the unknown EC PARAMETERS one
+        let error = |pem: String| PrivateKey::from_pem(pem.as_bytes()).err().unwrap().to_string();
+        let message = error(format!("{}{}", params, block("ENCRYPTED PRIVATE KEY", "MAA=")));
+        assert!(message.contains("PEM block 2 is an encrypted private key"), "{}", message);
+        let legacy = "Proc-Type: 4,ENCRYPTED\nDEK-Info: AES-128-CBC,00000000000000000000000000000000\n\nMAA=";
+        let message = error(block("RSA PRIVATE KEY", legacy));
+        assert!(message.contains("PEM block 1 is an encrypted private key"), "{}", message);
+        let message = error(format!("{}{}", params, block("DSA PRIVATE KEY", "MAA=")));
+        assert!(message.contains("PEM block 2 is an unsupported DSA PRIVATE KEY block"), "{}", message);
+        let message = error(format!("{}{}{}", params, leaf.cert.pem(), block("CERTIFICATE", "!!!")));
+        assert!(message.contains("Failed to parse PEM block 3"), "{}", message);
+        let message = error(format!("{}{}", pem, leaf.signing_key.serialize_pem()));
+        assert!(message.contains("PEM block 5 is a second private key"), "{}", message);
+        
+        // PKCS#12 bundles load with the right password only
+        let mut keystore = KeyStore::new();
+        let certificate = p12_keystore::Certificate::from_der(leaf.cert.der()).unwrap();
+        let entry = PrivateKeyChain::new(leaf.signing_key.serialize_der(), [1u8; 20], [certificate]);
+        keystore.add_entry("leaf", KeyStoreEntry::PrivateKeyChain(entry));
+        let bundle = keystore.writer("secret").write().unwrap();
+        let (chain, key) = load_pkcs12(&bundle, "secret").unwrap();
+        assert_eq!(chain.certificates, vec![leaf.cert.der().to_vec()]);
+        assert_eq!(key.key_data, leaf.signing_key.serialize_der());
+        assert!(load_pkcs12(&bundle, "wrong").is_err());
+    }
+    
+    #[tokio::test]
+    async fn test_cert_resolver_selects_by_sni_and_swaps_live() {
+        use super::core::{QuicCertificateResolver, QuicConnection, QuicServerConfig};
+        use super::models::certificate::{generate_self_signed, CertificateKeyAlgorithm};
+        use rustls_pki_types::CertificateDer;
+        
+        // Each certificate as its leaf DER, chain and key
+        let generate = |name: &str| {
+            let generated = generate_self_signed(vec![name.to_string()], 30, CertificateKeyAlgorithm::EcdsaP256).unwrap();
+            (generated.certificate_chain.certificates[0].clone(), generated.certificate_chain, generated.private_key)
+        };
+        let (exact, exact_chain, exact_key) = generate("a.test");
+        let (wildcard, wildcard_chain, wildcard_key) = generate("*.wild.test");
+        let (fallback, fallback_chain, fallback_key) = generate("default.test");
+        let (replacement, replacement_chain, replacement_key) = generate("a.test");
+        let (swapped, swapped_chain, swapped_key) = generate("swapped.test");
+        
+        let resolver = QuicCertificateResolver::new();
+        resolver.add("A.test".to_string(), exact_chain, exact_key).unwrap();
+        resolver.add("*.wild.test".to_string(), wildcard_chain, wildcard_key).unwrap();
+        resolver.set_default(fallback_chain, fallback_key).unwrap();
+        let config = QuicServerConfig::with_cert_resolver(resolver.clone(), Vec::new()).unwrap();
+        let mut endpoint = QuicEndpoint::bind_server(config, "127.0.0.1:0".to_string()).unwrap();
+        let addr = endpoint.local_addr().unwrap().to_string();
+        let client = QuicEndpoint::client().unwrap();
+        
+        // Returns both ends of the connection and the leaf certificate the server presented
+        async fn handshake(endpoint: &QuicEndpoint, client: &QuicEndpoint, addr: &str, server_name: &str) -> ((QuicConnection, QuicConnection), Vec<u8>) {
+            let (accepted, connected) = tokio::join!(
+                endpoint.accept(),
+                client.connect(addr.to_string(), server_name.to_string()),
+            );
+            let server_connection = accepted.unwrap().unwrap();
+            let connection = connected.unwrap();
+            let identity = connection.inner().peer_identity().unwrap();
+            let chain = identity.downcast::<Vec<CertificateDer<'static>>>().unwrap();
+            let leaf = chain[0].to_vec();
+            ((server_connection, connection), leaf)
+        }
+        
+        assert_eq!(handshake(&endpoint, &client, &addr, "a.test").await.1, exact);
+        assert_eq!(handshake(&endpoint, &client, &addr, "x.wild.test").await.1, wildcard);
+        // Wildcards cover one label only; unknown names and IP addresses (no SNI) get the default
+        assert_eq!(handshake(&endpoint, &client, &addr, "y.x.wild.test").await.1, fallback);
+        assert_eq!(handshake(&endpoint, &client, &addr, "other.test").await.1, fallback);
+        assert_eq!(handshake(&endpoint, &client, &addr, "127.0.0.1").await.1, fallback);
+        
+        // Changes to the shared resolver apply to the next handshake
+        resolver.add("a.test".to_string(), replacement_chain, replacement_key).unwrap();
+        let ((kept_server, kept), leaf) = handshake(&endpoint, &client, &addr, "a.test").await;
+        assert_eq!(leaf, replacement);
+        assert!(resolver.remove("A.TEST".to_string()));
+        assert_eq!(handshake(&endpoint, &client, &addr, "a.test").await.1, fallback);
+        
+        // Swapping the whole config leaves existing connections untouched
+        let config = QuicServerConfig::with_certificate(swapped_chain, swapped_key).unwrap();
+        endpoint.set_server_config(config);
+        assert_eq!(handshake(&endpoint, &client, &addr, "x.wild.test").await.1, swapped);
+        let mut send = kept.open_uni().await.unwrap();
+        send.write_all(b"still open".to_vec()).await.unwrap();
+        send.finish().unwrap();
+        let mut recv = kept_server.accept_uni().await.unwrap();
+        assert_eq!(recv.read_to_end(16).await.unwrap(), b"still open");
+    }
+    
+    #[tokio::test]
+    async fn test_key_log_callback_receives_handshake_secrets() {
+        use super::core::{QuicCertificateResolver, QuicKeyLog, QuicServerConfig};
+        use super::models::certificate::{generate_self_signed, CertificateKeyAlgorithm};
+        use std::sync::{Arc, Mutex};
+        use std::time::Duration;
+        
+        // Lines are recorded when the Dart future runs, not when it is created
+        let lines = Arc::new(Mutex::new(Vec::new()));
+        let recorded = lines.clone();
+        let key_log = super::api::bridge::key_log_callback(move |line| {
+            let recorded = recorded.clone();
+            Box::pin(async move { recorded.lock().unwrap().push(line) })
+        }).await;
+        
+        // SNI-resolved servers log their own secrets
+        let server_lines = Arc::new(Mutex::new(Vec::new()));
+        let server_recorded = server_lines.clone();
+        let server_key_log = QuicKeyLog::callback(move |line| server_recorded.lock().unwrap().push(line));
+        let generated = generate_self_signed(vec!["localhost".to_string()], 30, CertificateKeyAlgorithm::EcdsaP256)
+            .expect("Failed to generate certificate");
+        let resolver = QuicCertificateResolver::new();
+        resolver.set_default(generated.certificate_chain, generated.private_key).unwrap();
+        let server_config = QuicServerConfig::with_cert_resolver_and_key_log(resolver, Vec::new(), server_key_log)
+            .expect("Failed to create server config");
+        let endpoint = QuicEndpoint::bind_server(server_config, "127.0.0.1:0".to_string()).unwrap();
+        let addr = endpoint.local_addr().unwrap();
+        let client = super::api::bridge::create_client_endpoint_with_key_log(key_log).await.unwrap();
+        let (accepted, connected) = tokio::join!(
+            endpoint.accept(),
+            client.connect(addr.to_string(), "localhost".to_string()),
+        );
+        accepted.unwrap().unwrap();
+        connected.unwrap();
+        
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while lines.lock().unwrap().len() < 4 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        }).await.expect("key log lines were never delivered");
+        let lines = lines.lock().unwrap();
+        assert!(lines.iter().any(|line| line.starts_with("CLIENT_HANDSHAKE_TRAFFIC_SECRET ")));
+        assert!(lines.iter().any(|line| line.starts_with("CLIENT_TRAFFIC_SECRET_0 ")));
+        assert!(lines.iter().all(|line| line.split(' ').count() == 3));
+        assert!(server_lines.lock().unwrap().iter().any(|line| line.starts_with("SERVER_TRAFFIC_SECRET_0 ")));
+    }
+    
+    #[test]
+    fn test_logging_filter_reload_and_connection_ids() {
+        use super::logging::{init_logging, set_log_filter};
+        use std::sync::{Arc, Mutex};
+        
+        // The subscriber is global, so only this test installs it and it keeps to its own target
+        assert!(set_log_filter("info").is_err());
+        assert!(init_logging("log_test=loud", |_| {}).is_err());
+        
+        let records = Arc::new(Mutex::new(Vec::new()));
+        let recorded = records.clone();
+        init_logging("off,log_test=info", move |record| {
+            if record.target == "log_test" {
+                recorded.lock().unwrap().push((record.message, record.connection_id));
+            }
+        }).expect("Failed to initialize logging");
+        let messages = || records.lock().unwrap().iter().map(|(message, _)| message.clone()).collect::<Vec<_>>();
+        
+        tracing::info!(target: "log_test", "kept");
+        tracing::debug!(target: "log_test", "filtered");
+        assert_eq!(messages(), ["kept"]);
+        
+        assert!(set_log_filter("log_test=loud").is_err());
+        set_log_filter("off,log_test=debug").expect("Failed to reload filter");
+        tracing::debug!(target: "log_test", "shown");
+        assert_eq!(messages(), ["kept", "shown"]);
+        
+        // Events inside quinn's per-connection `drive` span carry its id
+        let span = tracing::info_span!(target: "log_test", "drive", id = 7);
+        span.in_scope(|| tracing::info!(target: "log_test", "inside"));
+        let records = records.lock().unwrap();
+        assert_eq!(records[0].1, None);
+        assert_eq!(records.last().unwrap(), &("inside".to_string(), Some("7".to_string())));
+    }
+    
+    #[tokio::test]
+    async fn test_server_config_setters_and_endpoint_from_bridge() {
+        use super::api::bridge;
+        use super::models::certificate::{generate_self_signed, CertificateKeyAlgorithm};
+        
+        let generated = generate_self_signed(vec!["localhost".to_string()], 30, CertificateKeyAlgorithm::EcdsaP256)
+            .expect("Failed to generate certificate");
+        let certificates = generated.certificate_chain.certificates;
+        let key = generated.private_key.key_data;
+        
+        let config = bridge::server_config_with_single_cert(certificates.clone(), key.clone()).unwrap();
+        let config = bridge::server_config_transport_config(config, bridge::transport_config_new());
+        let config = bridge::server_config_max_incoming(config, 16);
+        let config = bridge::server_config_retry_token_lifetime(config, 5_000);
+        let config = bridge::server_config_migration(config, true);
+        let config = bridge::server_config_incoming_buffer_size(config, 64 * 1024);
+        let config = bridge::server_config_incoming_buffer_size_total(config, 1024 * 1024);
+        let config = bridge::server_config_preferred_address_v4(config, Some("127.0.0.1:4433".to_string())).unwrap();
+        let config = bridge::server_config_preferred_address_v6(config, None).unwrap();
+        let (config, enabled) = bridge::server_config_retry_enabled(config);
+        assert!(!enabled);
+        let config = bridge::server_config_use_retry(config, true);
+        let (config, enabled) = bridge::server_config_retry_enabled(config);
+        assert!(enabled);
+        
+        let invalid = bridge::server_config_with_single_cert(certificates, key).unwrap();
+        assert!(bridge::server_config_preferred_address_v4(invalid, Some("[::1]:443".to_string())).is_err());
+        
+        // The endpoint outlives the call that created it and accepts after a Retry
+        let endpoint = bridge::create_server_endpoint(config, "127.0.0.1:0".to_string()).await.unwrap();
+        let addr = endpoint.local_addr().unwrap();
+        let client = QuicEndpoint::client().unwrap();
+        let (accepted, connected) = tokio::join!(
+            bridge::endpoint_accept(endpoint),
+            client.connect(addr.to_string(), "localhost".to_string()),
+        );
+        let (_endpoint, server_connection) = accepted.unwrap();
+        let client_connection = connected.unwrap();
+        
+        let mut send = client_connection.open_uni().await.unwrap();
+        send.write_all(b"ping".to_vec()).await.unwrap();
+        send.finish().unwrap();
+        let mut recv = server_connection.unwrap().accept_uni().await.unwrap();
+        assert_eq!(recv.read_to_end(16).await.unwrap(), b"ping");
+    }
+    
+    #[tokio::test]
+    async fn test_simple_server_answers_client_requests() {
+        use super::convenience::{SimpleQuicServer, SimpleQuicServerConfig};
+        use super::QuicClient;
+        
+        let (server, _certificate) = SimpleQuicServer::bind_self_signed(
+            "127.0.0.1:0".to_string(),
+            vec!["localhost".to_string()],
+            SimpleQuicServerConfig::default(),
+        ).expect("Failed to bind server");
+        server.serve(|request| async move {
+            if request == b"fail" {
+                return Err("rejected".to_string());
+            }
+            Ok(request.to_ascii_uppercase())
+        }).expect("Failed to start server");
+        assert!(server.serve(|request| async move { Ok(request) }).is_err());
+        
+        let url = format!("https://{}/", server.local_addr().unwrap());
+        let client = QuicClient::create().expect("Failed to create client");
+        assert_eq!(client.send(url.clone(), "hello".to_string()).await.unwrap(), "HELLO");
+        assert!(client.send(url, "fail".to_string()).await.is_err());
+        
+        let stats = server.stats();
+        assert_eq!(stats.connections_accepted, 1);
+        assert_eq!(stats.requests_handled, 1);
+        assert_eq!(stats.requests_failed, 1);
+        assert_eq!(stats.bytes_received, 9);
+        assert_eq!(stats.bytes_sent, 5);
+        
+        assert_eq!(server.shutdown().await, 0);
+    }
+    
+    #[tokio::test]
+    async fn test_client_replaces_connection_closed_by_server() {
+        use super::core::QuicServerConfig;
+        use super::models::certificate::{generate_self_signed, CertificateKeyAlgorithm};
+        use super::QuicClient;
+        
+        let generated = generate_self_signed(vec!["localhost".to_string()], 1, CertificateKeyAlgorithm::EcdsaP256)
+            .expect("Failed to generate certificate");
+        let config = QuicServerConfig::with_certificate(generated.certificate_chain, generated.private_key).unwrap();
+        let server = QuicEndpoint::bind_server(config, "127.0.0.1:0".to_string()).unwrap();
+        let url = format!("https://{}/", server.local_addr().unwrap());
+        
+        // Answer one request per connection, then close the connection
+        let server_task = tokio::spawn(async move {
+            let mut accepted = 0;
+            while let Ok(Some(connection)) = server.accept().await {
+                accepted += 1;
+                let (mut send, mut recv) = connection.accept_bi().await.unwrap();
+                let request = recv.read_to_end(1024).await.unwrap();
+                send.write_all(request).await.unwrap();
+                send.finish().unwrap();
+                let _ = send.inner_mut().stopped().await;
+                connection.inner().close(0u32.into(), b"done");
+                if accepted == 2 {
+                    return accepted;
+                }
+            }
+            accepted
+        });
+        
+        let client = QuicClient::create().expect("Failed to create client");
+        assert_eq!(client.send(url.clone(), "first".to_string()).await.unwrap(), "first");
+        assert_eq!(client.send(url, "second".to_string()).await.unwrap(), "second");
+        assert_eq!(server_task.await.unwrap(), 2);
+    }
+    
+    #[tokio::test]
+    async fn test_client_coalesces_concurrent_connects() {
+        use super::convenience::{SimpleQuicServer, SimpleQuicServerConfig};
+        use super::QuicClient;
+        
+        let (server, _certificate) = SimpleQuicServer::bind_self_signed(
+            "127.0.0.1:0".to_string(),
+            vec!["localhost".to_string()],
+            SimpleQuicServerConfig::default(),
+        ).expect("Failed to bind server");
+        server.serve(|request| async move { Ok(request) }).expect("Failed to start server");
+        let url = format!("https://{}/", server.local_addr().unwrap());
+        
+        let client = QuicClient::create().expect("Failed to create client");
+        let requests = (0..20).map(|i| client.send(url.clone(), i.to_string()));
+        let responses = futures::future::join_all(requests).await;
+        for (i, response) in responses.into_iter().enumerate() {
+            assert_eq!(response.unwrap(), i.to_string());
+        }
+        
+        assert_eq!(server.stats().connections_accepted, 1);
+        let authority = url.trim_start_matches("https://").trim_end_matches('/').to_string();
+        assert_eq!(client.pool_stats(), [(authority, 1)].into_iter().collect());
+        client.clear_pool();
+        assert!(client.pool_stats().is_empty());
+        server.shutdown().await;
+    }
+    
+    #[tokio::test]
+    async fn test_client_binary_bodies_and_response_limit() {
+        use super::convenience::{SimpleQuicServer, SimpleQuicServerConfig};
+        use super::errors::QuicError;
+        use super::{QuicClient, QuicClientConfig};
+        
+        let (server, _certificate) = SimpleQuicServer::bind_self_signed(
+            "127.0.0.1:0".to_string(),
+            vec!["localhost".to_string()],
+            SimpleQuicServerConfig::default(),
+        ).expect("Failed to bind server");
+        server.serve(|request| async move { Ok(request) }).expect("Failed to start server");
+        let url = format!("https://{}/", server.local_addr().unwrap());
+        
+        let client = QuicClient::create_with_config(QuicClientConfig {
+            max_response_size: 8,
+            ..Default::default()
+        }).expect("Failed to create client");
+        
+        let binary = vec![0xff, 0x00, 0xfe];
+        assert_eq!(client.send_bytes(url.clone(), binary.clone()).await.unwrap(), binary);
+        assert!(client.send(url.clone(), "\u{ff}".to_string()).await.is_ok());
+        
+        let error = client.send_bytes(url, vec![0; 9]).await.unwrap_err();
+        assert!(matches!(error, QuicError::ResponseTooLong { limit: 8 }), "{}", error);
+        
+        server.shutdown().await;
+    }
+    
+    #[tokio::test]
+    async fn test_client_streaming_transfer_and_cancel() {
+        use super::convenience::{SimpleQuicServer, SimpleQuicServerConfig};
+        use super::QuicClient;
+        use std::sync::{Arc, Mutex};
+        
+        let (server, _certificate) = SimpleQuicServer::bind_self_signed(
+            "127.0.0.1:0".to_string(),
+            vec!["localhost".to_string()],
+            SimpleQuicServerConfig::default(),
+        ).expect("Failed to bind server");
+        server.serve(|request| async move { Ok(request) }).expect("Failed to start server");
+        let url = format!("https://{}/", server.local_addr().unwrap());
+        let client = QuicClient::create().expect("Failed to create client");
+        
+        let chunks = vec![vec![1u8; 100_000], vec![2u8; 50_000], vec![3u8; 10]];
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let sink = received.clone();
+        let transfer = client.send_streaming(
+            url.clone(),
+            Some(150_010),
+            None,
+            futures::stream::iter(chunks.clone()),
+            move |chunk| {
+                sink.lock().unwrap().extend(chunk);
+                async {}
+            },
+            |_| {},
+        ).await.expect("Failed to start transfer");
+        
+        let progress = transfer.wait().await.expect("Transfer failed");
+        assert_eq!((progress.bytes_sent, progress.bytes_received), (150_010, 150_010));
+        assert_eq!(progress.expected_total_received, Some(150_010));
+        assert_eq!(*received.lock().unwrap(), chunks.concat());
+        
+        // A response longer than declared fails the transfer
+        let transfer = client.send_streaming(
+            url.clone(),
+            None,
+            Some(2),
+            futures::stream::iter(vec![b"abc".to_vec()]),
+            |_| async {},
+            |_| {},
+        ).await.expect("Failed to start transfer");
+        assert!(transfer.wait().await.is_err());
+        
+        // A body that never ends only stops when cancelled
+        let transfer = client.send_streaming(url.clone(), None, None, futures::stream::pending(), |_| async {}, |_| {})
+            .await
+            .expect("Failed to start transfer");
+        transfer.cancel();
+        assert!(transfer.wait().await.is_err());
+        
+        // Progress reaches Dart callbacks through the bridge
+        let body = Arc::new(Mutex::new(Some(b"abc".to_vec())));
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let recorded = events.clone();
+        let (_client, transfer) = super::api::bridge::quic_client_send_streaming(
+            client,
+            url,
+            Some(3),
+            Some(3),
+            move || {
+                let chunk = body.lock().unwrap().take();
+                Box::pin(async move { chunk })
+            },
+            |_| Box::pin(async {}),
+            move |progress| {
+                let recorded = recorded.clone();
+                Box::pin(async move { recorded.lock().unwrap().push(progress) })
+            },
+        ).await.expect("Failed to start transfer");
+        transfer.wait().await.expect("Transfer failed");
+        
+        tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            while events.lock().unwrap().iter().all(|progress| progress.bytes_received < 3) {
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            }
+        }).await.expect("progress was never delivered");
+        {
+            let events = events.lock().unwrap();
+            assert!(events.iter().all(|progress| progress.expected_total_received == Some(3)));
+            assert!(events.iter().any(|progress| progress.bytes_sent == 3));
+        }
+        
+        server.shutdown().await;
+    }
+    
+    #[tokio::test]
+    async fn test_http3_client_request_and_streaming_response() {
+        use super::convenience::{Http3Client, Http3ClientConfig, Http3Header, Http3Request};
+        use super::core::QuicServerConfig;
+        use super::errors::QuicError;
+        use super::models::certificate::{generate_self_signed, CertificateKeyAlgorithm};
+        use bytes::{Buf, Bytes};
+        
+        let generated = generate_self_signed(vec!["localhost".to_string()], 30, CertificateKeyAlgorithm::EcdsaP256)
+            .expect("Failed to generate certificate");
+        let server_config = QuicServerConfig::with_crypto(
+            generated.certificate_chain.certificates,
+            generated.private_key.key_data,
+            vec![b"h3".to_vec()],
+        ).expect("Failed to create server config");
+        let endpoint = QuicEndpoint::bind_server(server_config, "127.0.0.1:0".to_string()).unwrap();
+        let addr = endpoint.local_addr().unwrap();
+        
+        // Echo server answering with the method and path in headers and the body in trailers
+        tokio::spawn(async move {
+            while let Ok(Some(connection)) = endpoint.accept().await {
+                tokio::spawn(async move {
+                    let mut h3 = h3::server::Connection::<_, Bytes>::new(h3_quinn::Connection::new(connection.inner().clone()))
+                        .await
+                        .unwrap();
+                    while let Ok(Some(resolver)) = h3.accept().await {
+                        tokio::spawn(async move {
+                            let (request, mut stream) = resolver.resolve_request().await.unwrap();
+                            let mut body = Vec::new();
+                            while let Some(mut chunk) = stream.recv_data().await.unwrap() {
+                                body.extend_from_slice(&chunk.copy_to_bytes(chunk.remaining()));
+                            }
+                            let response = http::Response::builder()
+                                .status(201)
+                                .header("x-method", request.method().as_str())
+                                .header("x-path", request.uri().path())
+                                .body(())
+                                .unwrap();
+                            stream.send_response(response).await.unwrap();
+                            stream.send_data(Bytes::from(body.clone())).await.unwrap();
+                            let mut trailers = http::HeaderMap::new();
+                            trailers.insert("x-length", body.len().into());
+                            stream.send_trailers(trailers).await.unwrap();
+                        });
+                    }
+                });
+            }
+        });
+        
+        let client = Http3Client::create(Http3ClientConfig::default()).expect("Failed to create client");
+        let url = format!("https://{}/items?id=1", addr);
+        let field = |name: &str, value: &str| Http3Header { name: name.to_string(), value: value.to_string() };
+        
+        let mut request = Http3Request::new("PUT".to_string(), url.clone());
+        request.headers.push(field("content-type", "text/plain"));
+        request.body = b"hello h3".to_vec();
+        let response = client.send(request).await.expect("Request failed");
+        assert_eq!(response.status, 201);
+        assert!(response.headers.contains(&field("x-method", "PUT")));
+        assert!(response.headers.contains(&field("x-path", "/items")));
+        assert_eq!(response.body, b"hello h3");
+        assert_eq!(response.trailers, vec![field("x-length", "8")]);
+        
+        let chunks = vec![vec![7u8; 100_000], vec![8u8; 20_000]];
+        let response = client.send_streaming(Http3Request::new("POST".to_string(), url.clone()), futures::stream::iter(chunks.clone()))
+            .await
+            .expect("Streaming request failed");
+        assert_eq!(response.status(), 201);
+        let mut body = Vec::new();
+        while let Some(chunk) = response.next_chunk().await.unwrap() {
+            body.extend(chunk);
+        }
+        assert_eq!(body, chunks.concat());
+        assert_eq!(response.trailers().await.unwrap(), vec![field("x-length", "120000")]);
+        
+        let small = Http3Client::create(Http3ClientConfig {
+            max_response_size: 10,
+            ..Default::default()
+        }).unwrap();
+        let mut request = Http3Request::new("POST".to_string(), url);
+        request.body = vec![0u8; 11];
+        let error = small.send(request).await.unwrap_err();
+        assert!(matches!(error, QuicError::ResponseTooLong { limit: 10 }));
+        small.close();
+    }
+    
+    #[tokio::test]
+    async fn test_http3_server_routes_requests() {
+        use super::convenience::{Http3Client, Http3ClientConfig, Http3Header, Http3Request, Http3Router};
+        use super::convenience::{SimpleQuicServer, SimpleQuicServerConfig};
+        
+        let mut router = Http3Router::new();
+        router
+            .route("GET".to_string(), "/hello".to_string(), |request, stream| async move {
+                let greeting = format!("hello {}", request.query.unwrap_or_default());
+                let headers = vec![Http3Header { name: "content-type".to_string(), value: "text/plain".to_string() }];
+                stream.respond(200, headers, greeting.into_bytes()).await.map_err(|e| e.to_string())
+            })
+            .route("POST".to_string(), "/echo/*".to_string(), |_, stream| async move {
+                // Stream the body back as it arrives, then report its length in a trailer
+                stream.send_response(200, Vec::new()).await.map_err(|e| e.to_string())?;
+                let mut length = 0;
+                while let Some(chunk) = stream.next_chunk().await.map_err(|e| e.to_string())? {
+                    length += chunk.len();
+                    stream.send_data(chunk).await.map_err(|e| e.to_string())?;
+                }
+                let trailers = vec![Http3Header { name: "x-length".to_string(), value: length.to_string() }];
+                stream.finish(trailers).await.map_err(|e| e.to_string())
+            })
+            .route("GET".to_string(), "/fail".to_string(), |_, _| async { Err("broken".to_string()) });
+        
+        let (server, _certificate) = SimpleQuicServer::bind_http3_self_signed(
+            "127.0.0.1:0".to_string(),
+            vec!["localhost".to_string()],
+            SimpleQuicServerConfig::default(),
+        ).expect("Failed to bind server");
+        server.serve_http3(router).expect("Failed to start server");
+        let base = format!("https://{}", server.local_addr().unwrap());
+        let client = Http3Client::create(Http3ClientConfig::default()).expect("Failed to create client");
+        let request = |method: &str, path: &str| Http3Request::new(method.to_string(), format!("{}{}", base, path));
+        
+        let response = client.send(request("GET", "/hello?name=h3")).await.expect("Request failed");
+        assert_eq!((response.status, response.body.as_slice()), (200, b"hello name=h3".as_slice()));
+        assert!(response.headers.iter().any(|h| h.name == "content-type" && h.value == "text/plain"));
+        
+        let chunks = vec![vec![5u8; 80_000], vec![6u8; 30_000]];
+        let response = client.send_streaming(request("POST", "/echo/upload"), futures::stream::iter(chunks.clone()))
+            .await
+            .expect("Streaming request failed");
+        let mut body = Vec::new();
+        while let Some(chunk) = response.next_chunk().await.unwrap() {
+            body.extend(chunk);
+        }
+        assert_eq!(body, chunks.concat());
+        assert_eq!(response.trailers().await.unwrap()[0].value, "110000");
+        
+        assert_eq!(client.send(request("GET", "/missing")).await.unwrap().status, 404);
+        let not_allowed = client.send(request("DELETE", "/hello")).await.unwrap();
+        assert_eq!(not_allowed.status, 405);
+        assert_eq!(not_allowed.headers[0].value, "GET");
+        assert_eq!(client.send(request("GET", "/fail")).await.unwrap().status, 500);
+        
+        let stats = server.stats();
+        assert_eq!((stats.requests_handled, stats.requests_failed), (4, 1));
+        assert_eq!(stats.bytes_received, 110_000);
+        
+        server.shutdown().await;
+    }
+    
+    #[tokio::test]
+    async fn test_webtransport_session_streams_and_datagrams() {
+        use super::convenience::varint::{decode_varint, encode_varint};
+        use super::convenience::{WebTransportCloseInfo, WebTransportSession};
+        use super::core::QuicServerConfig;
+        use super::models::certificate::{generate_self_signed, CertificateKeyAlgorithm};
+        use bytes::{Buf, Bytes};
+        
+        let generated = generate_self_signed(vec!["localhost".to_string()], 30, CertificateKeyAlgorithm::EcdsaP256)
+            .expect("Failed to generate certificate");
+        let server_config = QuicServerConfig::with_crypto(
+            generated.certificate_chain.certificates,
+            generated.private_key.key_data,
+            vec![b"h3".to_vec()],
+        ).expect("Failed to create server config");
+        let endpoint = QuicEndpoint::bind_server(server_config, "127.0.0.1:0".to_string()).unwrap();
+        let addr = endpoint.local_addr().unwrap();
+        
+        // h3 accepts the session; streams and datagrams are echoed on the raw connection
+        let (capsule_sender, capsule_receiver) = tokio::sync::oneshot::channel();
+        tokio::spawn(async move {
+            let connection = endpoint.accept().await.unwrap().unwrap();
+            let conn = connection.inner().clone();
+            let mut h3 = h3::server::builder()
+                .enable_webtransport(true)
+                .enable_extended_connect(true)
+                .enable_datagram(true)
+                .max_webtransport_sessions(1)
+                .build::<_, Bytes>(h3_quinn::Connection::new(conn.clone()))
+                .await
+                .unwrap();
+            let (request, mut stream) = h3.accept().await.unwrap().unwrap().resolve_request().await.unwrap();
+            assert_eq!(request.method(), http::Method::CONNECT);
+            assert_eq!(request.extensions().get::<h3::ext::Protocol>(), Some(&h3::ext::Protocol::WEB_TRANSPORT));
+            assert_eq!(request.uri().path(), "/chat");
+            stream.send_response(http::Response::builder().status(200).body(()).unwrap()).await.unwrap();
+            
+            let (mut send, mut recv) = conn.accept_bi().await.unwrap();
+            let data = recv.read_to_end(1024).await.unwrap();
+            let (signal, signal_len) = decode_varint(&data).unwrap();
+            let (session_id, id_len) = decode_varint(&data[signal_len..]).unwrap();
+            assert_eq!(signal, 0x41);
+            send.write_all(&data[signal_len + id_len..]).await.unwrap();
+            send.finish().unwrap();
+            
+            // A datagram for another session (quarter stream ID 1) must not reach this one
+            let datagram = conn.read_datagram().await.unwrap();
+            conn.send_datagram(Bytes::from_static(b"\x01other")).unwrap();
+            conn.send_datagram(datagram).unwrap();
+            
+            let mut push = Vec::new();
+            encode_varint(&mut push, 0x54);
+            encode_varint(&mut push, session_id);
+            push.extend_from_slice(b"push");
+            let mut uni = conn.open_uni().await.unwrap();
+            uni.write_all(&push).await.unwrap();
+            uni.finish().unwrap();
+            
+            let mut capsule = stream.recv_data().await.unwrap().unwrap();
+            capsule_sender.send(capsule.copy_to_bytes(capsule.remaining()).to_vec()).unwrap();
+            stream.finish().await.unwrap();
+            conn.closed().await;
+            drop(h3);
+        });
+        
+        let client = QuicEndpoint::client_with_alpn(vec![b"h3".to_vec()]).unwrap();
+        let session = WebTransportSession::connect(&client, format!("https://{}/chat", addr), Vec::new())
+            .await
+            .expect("Failed to open session");
+        assert_eq!(session.session_id(), 0);
+        
+        let (mut send, mut recv) = session.open_bi().await.unwrap();
+        send.write_all(b"hello wt".to_vec()).await.unwrap();
+        send.finish().unwrap();
+        assert_eq!(recv.read_to_end(1024).await.unwrap(), b"hello wt");
+        
+        session.send_datagram(b"ping".to_vec()).await.unwrap();
+        assert_eq!(session.read_datagram().await.unwrap(), b"ping");
+        
+        let mut push = session.accept_uni().await.unwrap();
+        assert_eq!(push.read_to_end(1024).await.unwrap(), b"push");
+        
+        session.close(7, "bye".to_string()).await;
+        let expected = WebTransportCloseInfo { code: 7, reason: "bye".to_string() };
+        assert_eq!(session.closed().await, expected);
+        // CLOSE_WEBTRANSPORT_SESSION capsule: type 0x2843, length 7, code 7, "bye"
+        assert_eq!(capsule_receiver.await.unwrap(), [0x68, 0x43, 7, 0, 0, 0, 7, b'b', b'y', b'e']);
+        assert!(session.open_uni().await.is_err());
+    }
+    
+    #[tokio::test]
+    async fn test_datagram_subscription_drops_and_reports_end() {
+        use super::core::{QuicConnectionEndKind, QuicDatagramDropPolicy, QuicDatagramSubscriptionConfig, QuicServerConfig};
+        use super::models::certificate::{generate_self_signed, CertificateKeyAlgorithm};
+        
+        let generated = generate_self_signed(vec!["localhost".to_string()], 30, CertificateKeyAlgorithm::EcdsaP256)
+            .expect("Failed to generate certificate");
+        let server_config = QuicServerConfig::with_single_cert(
+            generated.certificate_chain.certificates,
+            generated.private_key.key_data,
+        ).expect("Failed to create server config");
+        let endpoint = QuicEndpoint::bind_server(server_config, "127.0.0.1:0".to_string()).unwrap();
+        let addr = endpoint.local_addr().unwrap();
+        
+        let (close_sender, close_receiver) = tokio::sync::oneshot::channel::<()>();
+        tokio::spawn(async move {
+            let connection = endpoint.accept().await.unwrap().unwrap();
+            for i in 0..5u8 {
+                connection.send_datagram(vec![i]).unwrap();
+            }
+            let mut done = connection.open_uni().await.unwrap();
+            done.finish().unwrap();
+            let _ = close_receiver.await;
+            connection.inner().close(42u32.into(), b"bye");
+            connection.inner().closed().await;
+        });
+        
+        let client = QuicEndpoint::client().unwrap();
+        let connection = client.connect(addr.to_string(), "localhost".to_string()).await.unwrap();
+        let subscription = connection.subscribe_datagrams(QuicDatagramSubscriptionConfig {
+            capacity: 2,
+            drop_policy: QuicDatagramDropPolicy::DropOldest,
+        }).unwrap();
+        
+        // Let every datagram reach the queue before taking any
+        connection.accept_uni().await.unwrap().read_to_end(16).await.unwrap();
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        let batch = subscription.next_batch().await.unwrap();
+        assert_eq!(batch.datagrams, vec![vec![3], vec![4]]);
+        assert_eq!(batch.dropped, 3);
+        assert!(batch.end.is_none());
+        
+        close_sender.send(()).unwrap();
+        let end = subscription.next_batch().await.unwrap().end.expect("Connection end should be reported");
+        assert_eq!(end.kind, QuicConnectionEndKind::ApplicationClosed);
+        assert_eq!(end.error_code, Some(42));
+        assert_eq!(end.reason, "bye");
+        
+        subscription.close();
+        assert!(subscription.next_batch().await.is_err());
+    }
+    
+    #[tokio::test]
+    async fn test_datagram_channels_route_and_fragment() {
+        use super::convenience::varint::encode_varint;
+        use super::convenience::{QuicDatagramChannelConfig, QuicDatagramMux};
+        use super::core::QuicServerConfig;
+        use super::errors::QuicDatagramException;
+        use super::models::certificate::{generate_self_signed, CertificateKeyAlgorithm};
+        
+        let generated = generate_self_signed(vec!["localhost".to_string()], 30, CertificateKeyAlgorithm::EcdsaP256)
+            .expect("Failed to generate certificate");
+        let server_config = QuicServerConfig::with_single_cert(
+            generated.certificate_chain.certificates,
+            generated.private_key.key_data,
+        ).expect("Failed to create server config");
+        let endpoint = QuicEndpoint::bind_server(server_config, "127.0.0.1:0".to_string()).unwrap();
+        let addr = endpoint.local_addr().unwrap();
+        let client = QuicEndpoint::client().unwrap();
+        let (accepted, connected) = tokio::join!(
+            endpoint.accept(),
+            client.connect(addr.to_string(), "localhost".to_string()),
+        );
+        let (server_connection, client_connection) = (accepted.unwrap().unwrap(), connected.unwrap());
+        
+        let config = QuicDatagramChannelConfig { fragmentation: true, ..Default::default() };
+        let server_mux = QuicDatagramMux::new(&server_connection, config.clone()).unwrap();
+        let client_mux = QuicDatagramMux::new(&client_connection, config).unwrap();
+        let (server_a, server_b) = (server_mux.open_channel(1).unwrap(), server_mux.open_channel(300).unwrap());
+        let (client_a, client_b) = (client_mux.open_channel(1).unwrap(), client_mux.open_channel(300).unwrap());
+        assert!(client_mux.open_channel(1).is_err());
+        
+        // A payload several datagrams long is fragmented and reassembled on its own channel
+        let large: Vec<u8> = (0..5000u32).map(|i| i as u8).collect();
+        client_b.send(large.clone()).await.unwrap();
+        client_a.send(b"small".to_vec()).await.unwrap();
+        assert_eq!(server_a.next_batch().await.unwrap().datagrams, vec![b"small".to_vec()]);
+        assert_eq!(server_b.next_batch().await.unwrap().datagrams, vec![large]);
+        
+        // Fragment counts beyond what max_message_size needs are discarded unallocated
+        for index in 0..100u64 {
+            // Flow 1, message 9, fragment `index` of 100, one byte each
+            let mut forged = Vec::new();
+            for value in [1, 9, index, 100] {
+                encode_varint(&mut forged, value);
+            }
+            forged.push(index as u8);
+            server_connection.inner().send_datagram(forged.into()).unwrap();
+        }
+        server_a.send(b"after".to_vec()).await.unwrap();
+        assert_eq!(client_a.next_batch().await.unwrap().datagrams, vec![b"after".to_vec()]);
+        
+        // Without fragmentation the datagram's raw layout is the flow ID followed by the payload
+        let raw_mux = QuicDatagramMux::new(&server_connection, QuicDatagramChannelConfig::default()).unwrap();
+        drop(server_mux);
+        let raw = raw_mux.open_channel(300).unwrap();
+        let oversized = vec![0u8; raw.max_payload_size().unwrap() + 1];
+        assert!(matches!(raw.send(oversized).await, Err(QuicDatagramException::TooLarge { .. })));
+        drop(client_mux);
+        raw.send(b"raw".to_vec()).await.unwrap();
+        let received = client_connection.inner().read_datagram().await.unwrap();
+        assert_eq!(&received[..], b"\x41\x2craw");
+    }
+    
+    #[tokio::test]
+    async fn test_framed_stream_messages_and_errors() {
+        use super::convenience::QuicFramedStream;
+        use super::core::QuicServerConfig;
+        use super::errors::QuicFrameErrorKind;
+        use super::models::certificate::{generate_self_signed, CertificateKeyAlgorithm};
+        
+        let generated = generate_self_signed(vec!["localhost".to_string()], 30, CertificateKeyAlgorithm::EcdsaP256)
+            .expect("Failed to generate certificate");
+        let server_config = QuicServerConfig::with_single_cert(
+            generated.certificate_chain.certificates,
+            generated.private_key.key_data,
+        ).expect("Failed to create server config");
+        let endpoint = QuicEndpoint::bind_server(server_config, "127.0.0.1:0".to_string()).unwrap();
+        let addr = endpoint.local_addr().unwrap();
+        let client = QuicEndpoint::client().unwrap();
+        let (accepted, connected) = tokio::join!(
+            endpoint.accept(),
+            client.connect(addr.to_string(), "localhost".to_string()),
+        );
+        let (server_connection, client_connection) = (accepted.unwrap().unwrap(), connected.unwrap());
+        
+        // Messages keep their boundaries regardless of how the stream chunks them
+        let (send, recv) = client_connection.open_bi().await.unwrap();
+        let client_framed = QuicFramedStream::new(Some(send), Some(recv), 100_000);
+        let messages = vec![b"first".to_vec(), Vec::new(), vec![9u8; 70_000]];
+        for message in &messages {
+            client_framed.send(message.clone()).await.unwrap();
+        }
+        client_framed.finish().await.unwrap();
+        
+        let (send, recv) = server_connection.accept_bi().await.unwrap();
+        let server_framed = QuicFramedStream::new(Some(send), Some(recv), 100_000);
+        for message in &messages {
+            assert_eq!(server_framed.recv().await.unwrap().as_ref(), Some(message));
+        }
+        assert_eq!(server_framed.recv().await.unwrap(), None);
+        
+        let error = client_framed.send(vec![0u8; 100_001]).await.unwrap_err();
+        assert_eq!(error.kind, QuicFrameErrorKind::TooLarge);
+        
+        // A frame above the receiver's limit, and a stream ending inside a frame
+        let mut raw = client_connection.open_uni().await.unwrap();
+        raw.write_all(vec![0x44, 0x00, b'a', b'b']).await.unwrap();
+        raw.finish().unwrap();
+        let small = QuicFramedStream::new(None, Some(server_connection.accept_uni().await.unwrap()), 10);
+        assert_eq!(small.recv().await.unwrap_err().kind, QuicFrameErrorKind::TooLarge);
+        
+        let mut raw = client_connection.open_uni().await.unwrap();
+        raw.write_all(vec![0x05, b'a', b'b']).await.unwrap();
+        raw.finish().unwrap();
+        let truncated = QuicFramedStream::new(None, Some(server_connection.accept_uni().await.unwrap()), 10);
+        assert_eq!(truncated.recv().await.unwrap_err().kind, QuicFrameErrorKind::Truncated);
+    }
+    
+    #[tokio::test]
+    async fn test_recv_stream_chunk_reads() {
+        use super::core::QuicServerConfig;
+        use super::errors::QuicReadException;
+        use super::models::certificate::{generate_self_signed, CertificateKeyAlgorithm};
+        
+        let generated = generate_self_signed(vec!["localhost".to_string()], 30, CertificateKeyAlgorithm::EcdsaP256)
+            .expect("Failed to generate certificate");
+        let server_config = QuicServerConfig::with_single_cert(
+            generated.certificate_chain.certificates,
+            generated.private_key.key_data,
+        ).expect("Failed to create server config");
+        let endpoint = QuicEndpoint::bind_server(server_config, "127.0.0.1:0".to_string()).unwrap();
+        let addr = endpoint.local_addr().unwrap();
+        let client = QuicEndpoint::client().unwrap();
+        let (accepted, connected) = tokio::join!(
+            endpoint.accept(),
+            client.connect(addr.to_string(), "localhost".to_string()),
+        );
+        let (server_connection, client_connection) = (accepted.unwrap().unwrap(), connected.unwrap());
+        let payload: Vec<u8> = (0..200_000u32).map(|i| i as u8).collect();
+        
+        // Ordered chunks carry contiguous offsets and add up to the payload
+        let mut send = client_connection.open_uni().await.unwrap();
+        send.write_all(payload.clone()).await.unwrap();
+        send.finish().unwrap();
+        let mut recv = server_connection.accept_uni().await.unwrap();
+        let first = recv.read_chunk(10, true).await.unwrap().unwrap();
+        assert_eq!((first.offset, first.data.as_slice()), (0, &payload[..10]));
+        let mut received = first.data;
+        while let Some(chunks) = recv.read_chunks(8).await.unwrap() {
+            assert!(!chunks.is_empty() && chunks.len() <= 8);
+            for chunk in chunks {
+                assert_eq!(chunk.offset, received.len() as u64);
+                received.extend_from_slice(&chunk.data);
+            }
+        }
+        assert_eq!(received, payload);
+        
+        // Unordered chunks can be placed by offset, and rule out ordered reads
+        let mut send = client_connection.open_uni().await.unwrap();
+        send.write_all(payload.clone()).await.unwrap();
+        send.finish().unwrap();
+        let mut recv = server_connection.accept_uni().await.unwrap();
+        let mut reassembled = vec![0u8; payload.len()];
+        let mut total = 0;
+        while let Some(chunk) = recv.read_chunk(usize::MAX, false).await.unwrap() {
+            let start = chunk.offset as usize;
+            reassembled[start..start + chunk.data.len()].copy_from_slice(&chunk.data);
+            total += chunk.data.len();
+        }
+        assert_eq!((total, reassembled), (payload.len(), payload.clone()));
+        
+        let mut send = client_connection.open_uni().await.unwrap();
+        send.write_all(b"abc".to_vec()).await.unwrap();
+        let mut recv = server_connection.accept_uni().await.unwrap();
+        assert!(recv.read_chunk(1, false).await.unwrap().is_some());
+        assert!(matches!(recv.read(10).await, Err(QuicReadException::IllegalOrderedRead)));
+    }
+    
+    #[tokio::test]
+    async fn test_send_stream_chunk_writes_and_stats() {
+        use super::core::QuicServerConfig;
+        use super::models::certificate::{generate_self_signed, CertificateKeyAlgorithm};
+        
+        let generated = generate_self_signed(vec!["localhost".to_string()], 30, CertificateKeyAlgorithm::EcdsaP256)
+            .expect("Failed to generate certificate");
+        let server_config = QuicServerConfig::with_single_cert(
+            generated.certificate_chain.certificates,
+            generated.private_key.key_data,
+        ).expect("Failed to create server config");
+        let endpoint = QuicEndpoint::bind_server(server_config, "127.0.0.1:0".to_string()).unwrap();
+        let addr = endpoint.local_addr().unwrap();
+        let client = QuicEndpoint::client().unwrap();
+        let (accepted, connected) = tokio::join!(
+            endpoint.accept(),
+            client.connect(addr.to_string(), "localhost".to_string()),
+        );
+        let (server_connection, client_connection) = (accepted.unwrap().unwrap(), connected.unwrap());
+        
+        // Header and body go out back to back without being concatenated first
+        let mut send = client_connection.open_uni().await.unwrap();
+        let written = send.write_chunks(vec![b"head".to_vec(), Vec::new(), b"er".to_vec()]).await.unwrap();
+        assert_eq!((written.bytes, written.chunks), (6, 3));
+        send.write_all_chunks(vec![b":".to_vec(), vec![7u8; 100_000]]).await.unwrap();
+        let stats = send.stats();
+        assert_eq!((stats.bytes_queued, stats.acknowledged, stats.stop_error_code), (100_007, false, None));
+        send.finish().unwrap();
+        
+        let mut recv = server_connection.accept_uni().await.unwrap();
+        let received = recv.read_to_end(200_000).await.unwrap();
+        assert_eq!((&received[..7], received.len()), (&b"header:"[..], 100_007));
+        
+        // The peer's acknowledgement of the finished stream shows up in the stats
+        tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            while !send.stats().acknowledged {
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            }
+        }).await.expect("stream data was never acknowledged");
+        
+        // A stopped stream reports the peer's error code
+        let mut send = client_connection.open_uni().await.unwrap();
+        send.write_all(b"x".to_vec()).await.unwrap();
+        let mut recv = server_connection.accept_uni().await.unwrap();
+        recv.read(1).await.unwrap();
+        recv.inner_mut().stop(42u32.into()).unwrap();
+        tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            while send.stats().stop_error_code != Some(42) {
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            }
+        }).await.expect("stop was never reported");
+    }
+    
+    #[test]
+    fn test_stream_reassembler_releases_data_in_order() {
+        use super::convenience::QuicStreamReassembler;
+        use super::core::QuicStreamChunk;
+        
+        let chunk = |offset: u64, data: &[u8]| QuicStreamChunk { offset, data: data.to_vec() };
+        let reassembler = QuicStreamReassembler::new();
+        assert!(reassembler.push(chunk(6, b"ghij")).is_empty());
+        assert!(reassembler.push(chunk(3, b"def")).is_empty());
+        assert_eq!(reassembler.buffered_bytes(), 7);
+        
+        // Filling the gap releases everything buffered behind it
+        assert_eq!(reassembler.push(chunk(0, b"abc")), b"abcdefghij");
+        assert_eq!((reassembler.contiguous_offset(), reassembler.buffered_bytes()), (10, 0));
+        
+        // Repeated and overlapping data is released only once
+        assert!(reassembler.push(chunk(2, b"cdef")).is_empty());
+        assert!(reassembler.push(chunk(14, b"op")).is_empty());
+        assert!(reassembler.push(chunk(12, b"mnop")).is_empty());
+        assert_eq!(reassembler.push(chunk(8, b"ijkl")), b"klmnop");
+        assert_eq!((reassembler.contiguous_offset(), reassembler.buffered_bytes()), (16, 0));
+    }
+    
+    #[tokio::test]
+    async fn test_rpc_calls_stream_and_fail_with_status() {
+        use super::convenience::{QuicRpcCallOptions, QuicRpcClient, QuicRpcClientConfig, QuicRpcCode};
+        use super::convenience::{QuicRpcMetadata, QuicRpcRegistry, QuicRpcStatus};
+        use super::convenience::{SimpleQuicServer, SimpleQuicServerConfig};
+        use std::time::Duration;
+        
+        let mut registry = QuicRpcRegistry::new();
+        registry
+            .register_unary("greet".to_string(), |request| async move {
+                let name = String::from_utf8(request.payload).unwrap();
+                let greeting = request.metadata.iter().find(|entry| entry.key == "greeting").unwrap();
+                Ok(format!("{} {}", greeting.value, name).into_bytes())
+            })
+            .register("count".to_string(), |request, responder| async move {
+                for i in 0..request.payload[0] {
+                    responder.send(vec![i]).await?;
+                }
+                Ok(())
+            })
+            .register("forever".to_string(), |request, responder| async move {
+                // Report the deadline left, which the server caps at request_timeout_ms
+                responder.send(request.deadline_ms.unwrap().to_be_bytes().to_vec()).await?;
+                std::future::pending().await
+            })
+            .register_unary("reject".to_string(), |_| async {
+                Err(QuicRpcStatus::new(QuicRpcCode::PermissionDenied, "no"))
+            });
+        
+        let (server, _certificate) = SimpleQuicServer::bind_self_signed(
+            "127.0.0.1:0".to_string(),
+            vec!["localhost".to_string()],
+            SimpleQuicServerConfig::default(),
+        ).expect("Failed to bind server");
+        server.serve_rpc(registry).expect("Failed to start server");
+        
+        let endpoint = QuicEndpoint::client().unwrap();
+        let connection = endpoint.connect(server.local_addr().unwrap().to_string(), "localhost".to_string()).await.unwrap();
+        let client = QuicRpcClient::new(connection, QuicRpcClientConfig::default());
+        
+        let options = QuicRpcCallOptions {
+            deadline_ms: None,
+            metadata: vec![QuicRpcMetadata { key: "greeting".to_string(), value: "hi".to_string() }],
+        };
+        let reply = client.call("greet".to_string(), b"quic".to_vec(), options).await.unwrap();
+        assert_eq!(reply, b"hi quic");
+        
+        let call = client.call_streaming("count".to_string(), vec![3], Default::default()).await.unwrap();
+        for i in 0..3 {
+            assert_eq!(call.next_message().await.unwrap(), Some(vec![i]));
+        }
+        assert_eq!(call.next_message().await.unwrap(), None);
+        
+        let error = client.call("reject".to_string(), Vec::new(), Default::default()).await.unwrap_err();
+        assert_eq!(error, QuicRpcStatus::new(QuicRpcCode::PermissionDenied, "no"));
+        let error = client.call("missing".to_string(), Vec::new(), Default::default()).await.unwrap_err();
+        assert_eq!(error.code, QuicRpcCode::Unimplemented);
+        
+        // Both a missed deadline and cancellation stop the server's handler
+        let options = QuicRpcCallOptions { deadline_ms: Some(200), metadata: Vec::new() };
+        let call = client.call_streaming("forever".to_string(), Vec::new(), options).await.unwrap();
+        let deadline_ms = u64::from_be_bytes(call.next_message().await.unwrap().unwrap().try_into().unwrap());
+        assert!(deadline_ms > 0 && deadline_ms <= 200);
+        assert_eq!(call.next_message().await.unwrap_err().code, QuicRpcCode::DeadlineExceeded);
+        
+        let call = client.call_streaming("forever".to_string(), Vec::new(), Default::default()).await.unwrap();
+        assert!(call.next_message().await.unwrap().is_some());
+        call.cancel();
+        assert_eq!(call.next_message().await.unwrap_err().code, QuicRpcCode::Cancelled);
+        
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        let stats = server.stats();
+        assert_eq!((stats.requests_handled, stats.requests_failed), (2, 4));
+        assert_eq!(stats.active_requests, 0);
+        
+        server.shutdown().await;
+    }
+    
+    #[tokio::test]
+    async fn test_pubsub_delivers_topics_and_resumes() {
+        use super::convenience::{QuicPubSubBroker, QuicPubSubBrokerConfig, QuicPubSubClient};
+        use super::convenience::{QuicPubSubDelivery, QuicPubSubSubscribeOptions};
+        use super::convenience::{SimpleQuicServer, SimpleQuicServerConfig};
+        
+        let broker = QuicPubSubBroker::new(QuicPubSubBrokerConfig { history_size: 3, ..Default::default() }).unwrap();
+        let (server, _certificate) = SimpleQuicServer::bind_self_signed(
+            "127.0.0.1:0".to_string(),
+            vec!["localhost".to_string()],
+            SimpleQuicServerConfig::default(),
+        ).expect("Failed to bind server");
+        server.serve_pubsub(&broker).expect("Failed to start server");
+        let addr = server.local_addr().unwrap().to_string();
+        
+        let endpoint = QuicEndpoint::client().unwrap();
+        let connection = endpoint.connect(addr.clone(), "localhost".to_string()).await.unwrap();
+        let client = QuicPubSubClient::connect(&connection).await.unwrap();
+        
+        assert_eq!(broker.publish("cpu".to_string(), b"old".to_vec()).unwrap(), 1);
+        let cpu = client.subscribe("cpu".to_string(), Default::default()).await.unwrap();
+        let lossy = client.subscribe("gps".to_string(), QuicPubSubSubscribeOptions {
+            delivery: QuicPubSubDelivery::Datagram,
+            ..Default::default()
+        }).await.unwrap();
+        assert_eq!(cpu.last_sequence(), 1);
+        
+        // Only messages published after subscribing are delivered
+        broker.publish("cpu".to_string(), b"10%".to_vec()).unwrap();
+        broker.publish("gps".to_string(), b"here".to_vec()).unwrap();
+        broker.publish("cpu".to_string(), b"20%".to_vec()).unwrap();
+        let message = cpu.next_message().await.unwrap();
+        assert_eq!((message.sequence, message.payload), (2, b"10%".to_vec()));
+        assert_eq!(lossy.next_message().await.unwrap().payload, b"here");
+        
+        // A new connection resumes after the last message seen; older ones are
+        // reported missed once they fall out of the history
+        let last = cpu.last_sequence();
+        assert_eq!(last, 2);
+        connection.inner().close(0u32.into(), b"reconnect");
+        // Losing the connection ends the subscription after buffered messages
+        while cpu.next_message().await.is_some() {}
+        drop(client);
+        
+        for load in ["30%", "40%", "50%"] {
+            broker.publish("cpu".to_string(), load.as_bytes().to_vec()).unwrap();
+        }
+        let connection = endpoint.connect(addr, "localhost".to_string()).await.unwrap();
+        let client = QuicPubSubClient::connect(&connection).await.unwrap();
+        let resumed = client.subscribe("cpu".to_string(), QuicPubSubSubscribeOptions {
+            resume_after: Some(last),
+            ..Default::default()
+        }).await.unwrap();
+        assert_eq!(resumed.missed(), 1);
+        for sequence in 4..=6 {
+            assert_eq!(resumed.next_message().await.unwrap().sequence, sequence);
+        }
+        
+        resumed.unsubscribe().await.unwrap();
+        assert!(resumed.next_message().await.is_none());
+        assert_eq!(server.stats().requests_handled, 4);
+        
+        server.shutdown().await;
+    }
+    
+    #[tokio::test]
+    async fn test_file_upload_verifies_and_resumes() {
+        use super::convenience::{QuicFileReceiver, QuicFileTransferConfig, QuicFileUpload};
+        use super::convenience::{SimpleQuicServer, SimpleQuicServerConfig};
+        use super::errors::QuicFileTransferErrorKind;
+        
+        let root = std::env::temp_dir().join(format!("flutter_quic_upload_{}", std::process::id()));
+        let (outbox, inbox) = (root.join("outbox"), root.join("inbox"));
+        std::fs::create_dir_all(&outbox).unwrap();
+        std::fs::create_dir_all(&inbox).unwrap();
+        let contents: Vec<u8> = (0..1_000_000u32).map(|i| (i * 7 % 251) as u8).collect();
+        let path = outbox.join("video.bin");
+        std::fs::write(&path, &contents).unwrap();
+        
+        let receiver = QuicFileReceiver::new(inbox.to_string_lossy().into_owned(), 10_000_000).unwrap();
+        let (server, _certificate) = SimpleQuicServer::bind_self_signed(
+            "127.0.0.1:0".to_string(),
+            vec!["localhost".to_string()],
+            SimpleQuicServerConfig::default(),
+        ).expect("Failed to bind server");
+        server.serve_files(&receiver).expect("Failed to start server");
+        let addr = server.local_addr().unwrap().to_string();
+        let endpoint = QuicEndpoint::client().unwrap();
+        let config = QuicFileTransferConfig { chunk_size: 64 * 1024, parallel_streams: 1 };
+        
+        // Cancel once the first chunk was acknowledged, then resume on a new connection
+        let connection = endpoint.connect(addr.clone(), "localhost".to_string()).await.unwrap();
+        let (acked, mut acks) = tokio::sync::mpsc::unbounded_channel();
+        let upload = QuicFileUpload::start(&connection, path.to_string_lossy().into_owned(), config.clone(), move |progress| {
+            let _ = acked.send(progress.bytes_transferred);
+        }).unwrap();
+        while acks.recv().await.unwrap() == 0 {}
+        upload.cancel();
+        assert_eq!(upload.wait().await.unwrap_err().kind, QuicFileTransferErrorKind::Cancelled);
+        connection.inner().close(0u32.into(), b"lost");
+        
+        // The resumed upload reports progress to a Dart callback through the bridge
+        let connection = endpoint.connect(addr, "localhost".to_string()).await.unwrap();
+        let (delivered, mut deliveries) = tokio::sync::mpsc::unbounded_channel();
+        let upload = super::api::bridge::file_upload_start(
+            &connection,
+            path.to_string_lossy().into_owned(),
+            config,
+            move |progress| {
+                let delivered = delivered.clone();
+                Box::pin(async move {
+                    let _ = delivered.send(progress.bytes_transferred);
+                })
+            },
+        ).await.unwrap();
+        let progress = upload.wait().await.unwrap();
+        tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            while deliveries.recv().await.unwrap() < contents.len() as u64 {}
+        }).await.expect("progress was never delivered");
+        assert!(progress.resumed_from >= 64 * 1024);
+        assert_eq!(progress.bytes_transferred, contents.len() as u64);
+        assert_eq!(std::fs::read(inbox.join("video.bin")).unwrap(), contents);
+        assert_eq!(std::fs::read_dir(&inbox).unwrap().count(), 1);
+        assert_eq!(server.stats().requests_handled, 1);
+        
+        server.shutdown().await;
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+    
+    #[tokio::test]
+    async fn test_connection_pool_prefers_least_loaded_live_connection() {
+        use super::convenience::{QuicConnectionPool, QuicConnectionPoolConfig, SimpleQuicServer, SimpleQuicServerConfig};
+        
+        let (server, _certificate) = SimpleQuicServer::bind_self_signed(
+            "127.0.0.1:0".to_string(),
+            vec!["localhost".to_string()],
+            SimpleQuicServerConfig::default(),
+        ).expect("Failed to bind server");
+        server.serve(|request| async move { Ok(request) }).expect("Failed to start server");
+        let addr = server.local_addr().unwrap().to_string();
+        
+        let endpoint = QuicEndpoint::client().expect("Failed to create client endpoint");
+        let pool = QuicConnectionPool::new(QuicConnectionPoolConfig {
+            max_streams_per_connection: 1,
+            ..Default::default()
+        });
+        
+        // A saturated connection makes the pool open a second one
+        let first = pool.acquire(&endpoint, addr.clone(), "localhost".to_string()).await.unwrap();
+        let second = pool.acquire(&endpoint, addr.clone(), "localhost".to_string()).await.unwrap();
+        assert_ne!(first.stable_id(), second.stable_id());
+        
+        pool.release(&first);
+        let reused = pool.try_acquire(&addr).expect("Released connection should be reused");
+        assert_eq!(reused.stable_id(), first.stable_id());
+        
+        // Closed connections are pruned instead of handed out
+        pool.release(&second);
+        second.inner().close(0u32.into(), b"test");
+        assert!(pool.try_acquire(&addr).is_none());
+        
+        let stats = pool.stats();
+        assert_eq!((stats.hits, stats.misses, stats.evicted_dead), (1, 2, 1));
+        assert_eq!(stats.authorities[0].connections, 1);
+        assert_eq!(stats.authorities[0].active_streams, 1);
+        
+        server.shutdown().await;
+    }
+    
+    #[tokio::test]
+    async fn test_connection_pool_saturation_and_background_eviction() {
+        use super::convenience::{QuicConnectionPool, QuicConnectionPoolConfig, SimpleQuicServer, SimpleQuicServerConfig};
+        use std::time::Duration;
+        
+        let (server, _certificate) = SimpleQuicServer::bind_self_signed(
+            "127.0.0.1:0".to_string(),
+            vec!["localhost".to_string()],
+            SimpleQuicServerConfig::default(),
+        ).expect("Failed to bind server");
+        server.serve(|request| async move { Ok(request) }).expect("Failed to start server");
+        let addr = server.local_addr().unwrap().to_string();
+        
+        let endpoint = QuicEndpoint::client().expect("Failed to create client endpoint");
+        let pool = QuicConnectionPool::new(QuicConnectionPoolConfig {
+            max_streams_per_connection: 2,
+            idle_timeout_ms: 100,
+            eviction_interval_ms: 20,
+            ..Default::default()
+        });
+        
+        // The only connection is handed out until it carries max_streams_per_connection users
+        let idle = pool.acquire(&endpoint, addr.clone(), "localhost".to_string()).await.unwrap();
+        let shared = pool.try_acquire(&addr).expect("Connection below the stream limit should be shared");
+        assert_eq!(shared.stable_id(), idle.stable_id());
+        assert!(pool.try_acquire(&addr).is_none());
+        
+        let dead = pool.acquire(&endpoint, addr.clone(), "localhost".to_string()).await.unwrap();
+        assert_ne!(dead.stable_id(), idle.stable_id());
+        pool.release(&idle);
+        pool.release(&shared);
+        dead.inner().close(0u32.into(), b"test");
+        
+        // The background timer drops the closed connection and closes the idle one without any pool call
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while pool.stats().evicted_idle + pool.stats().evicted_dead < 2 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        }).await.expect("connections were never evicted");
+        let stats = pool.stats();
+        assert_eq!((stats.evicted_idle, stats.evicted_dead), (1, 1));
+        assert!(stats.authorities.is_empty());
+        assert!(idle.close_reason().is_some());
+        
+        server.shutdown().await;
+    }
 }
@@ -0,0 +1,201 @@
+//! Logging bridge - forwards `tracing` output to Dart
+//!
+//! Installs a global tracing subscriber whose records (from quinn and from
+//! flutter_quic itself) are delivered to a callback, typically a Dart `Stream`.
+//! The filter uses `EnvFilter` directive syntax and can be changed at runtime.
+
+use crate::errors::QuicError;
+use std::fmt;
+use std::sync::{Mutex, OnceLock, RwLock};
+use tracing::field::{Field, Visit};
+use tracing::span::{Attributes, Id};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context, Layer, SubscriberExt};
+use tracing_subscriber::registry::LookupSpan;
+use tracing_subscriber::{reload, EnvFilter, Registry};
+
+/// Severity of a log record
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuicLogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl From<&Level> for QuicLogLevel {
+    fn from(level: &Level) -> Self {
+        match *level {
+            Level::TRACE => QuicLogLevel::Trace,
+            Level::DEBUG => QuicLogLevel::Debug,
+            Level::INFO => QuicLogLevel::Info,
+            Level::WARN => QuicLogLevel::Warn,
+            Level::ERROR => QuicLogLevel::Error,
+        }
+    }
+}
+
+/// Structured field attached to a log record
+#[derive(Debug, Clone)]
+pub struct QuicLogField {
+    pub name: String,
+    pub value: String,
+}
+
+/// A single tracing event
+#[derive(Debug, Clone)]
+pub struct QuicLogRecord {
+    pub level: QuicLogLevel,
+    /// Module path or explicit target of the event (e.g. `quinn_proto::connection`)
+    pub target: String,
+    pub message: String,
+    pub fields: Vec<QuicLogField>,
+    /// Connection the event belongs to, taken from the enclosing connection span
+    pub connection_id: Option<String>,
+    /// Unix timestamp in milliseconds
+    pub timestamp_ms: i64,
+}
+
+type LogCallback = Box<dyn Fn(QuicLogRecord) + Send + Sync>;
+
+struct DartLogger {
+    callback: RwLock<LogCallback>,
+    filter: reload::Handle<EnvFilter, Registry>,
+}
+
+static LOGGER: OnceLock<DartLogger> = OnceLock::new();
+static INIT_LOCK: Mutex<()> = Mutex::new(());
+
+/// Install the logging bridge, delivering records that pass `filter` to `callback`
+///
+/// The first call installs the global tracing subscriber; later calls replace the
+/// callback and filter. Fails if another global subscriber was installed first.
+///
+/// # Arguments
+/// * `filter` - `EnvFilter` directives, e.g. `"info,quinn=debug,flutter_quic=trace"`
+/// * `callback` - Receives every record that passes the filter
+pub fn init_logging(
+    filter: &str,
+    callback: impl Fn(QuicLogRecord) + Send + Sync + 'static,
+) -> Result<(), QuicError> {
+    let filter = parse_filter(filter)?;
+    let _guard = INIT_LOCK.lock().unwrap();
+
+    if let Some(logger) = LOGGER.get() {
+        *logger.callback.write().unwrap() = Box::new(callback);
+        return logger.filter
+            .reload(filter)
+            .map_err(|e| QuicError::Config(format!("Failed to update log filter: {:?}", e)));
+    }
+
+    let (filter_layer, filter_handle) = reload::Layer::new(filter);
+    let subscriber = Registry::default()
+        .with(filter_layer)
+        .with(DartLayer);
+    tracing::subscriber::set_global_default(subscriber)
+        .map_err(|e| QuicError::Config(format!("Failed to install tracing subscriber: {:?}", e)))?;
+
+    let _ = LOGGER.set(DartLogger {
+        callback: RwLock::new(Box::new(callback)),
+        filter: filter_handle,
+    });
+    Ok(())
+}
+
+/// Replace the filter directives of the installed logging bridge
+pub fn set_log_filter(filter: &str) -> Result<(), QuicError> {
+    let logger = LOGGER.get()
+        .ok_or_else(|| QuicError::Config("Logging has not been initialized".to_string()))?;
+
+    logger.filter
+        .reload(parse_filter(filter)?)
+        .map_err(|e| QuicError::Config(format!("Failed to update log filter: {:?}", e)))
+}
+
+fn parse_filter(filter: &str) -> Result<EnvFilter, QuicError> {
+    EnvFilter::try_new(filter)
+        .map_err(|e| QuicError::Config(format!("Invalid log filter '{}': {}", filter, e)))
+}
+
+/// Connection id recorded on a span, looked up by events inside it
+struct SpanConnectionId(String);
+
+/// Layer forwarding events to the installed callback
+struct DartLayer;
+
+impl<S> Layer<S> for DartLayer
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+{
+    fn on_new_span(&self, attrs: &Attributes<'_>, id: &Id, ctx: Context<'_, S>) {
+        let mut visitor = FieldVisitor::default();
+        attrs.record(&mut visitor);
+
+        // quinn drives each connection inside a `drive` span whose `id` is the connection handle
+        let is_connection_span = attrs.metadata().name() == "drive";
+        let connection_id = visitor.fields.into_iter().find(|field| {
+            field.name == "connection_id" || (is_connection_span && field.name == "id")
+        });
+
+        if let (Some(field), Some(span)) = (connection_id, ctx.span(id)) {
+            span.extensions_mut().insert(SpanConnectionId(field.value));
+        }
+    }
+
+    fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+        let Some(logger) = LOGGER.get() else {
+            return;
+        };
+
+        let mut visitor = FieldVisitor::default();
+        event.record(&mut visitor);
+
+        let connection_id = ctx.event_scope(event).and_then(|scope| {
+            scope
+                .filter_map(|span| span.extensions().get::<SpanConnectionId>().map(|id| id.0.clone()))
+                .next()
+        });
+
+        let metadata = event.metadata();
+        let record = QuicLogRecord {
+            level: QuicLogLevel::from(metadata.level()),
+            target: metadata.target().to_string(),
+            message: visitor.message.unwrap_or_default(),
+            fields: visitor.fields,
+            connection_id,
+            timestamp_ms: chrono::Utc::now().timestamp_millis(),
+        };
+
+        (logger.callback.read().unwrap())(record);
+    }
+}
+
+#[derive(Default)]
+struct FieldVisitor {
+    message: Option<String>,
+    fields: Vec<QuicLogField>,
+}
+
+impl FieldVisitor {
+    fn push(&mut self, field: &Field, value: String) {
+        if field.name() == "message" {
+            self.message = Some(value);
+        } else {
+            self.fields.push(QuicLogField {
+                name: field.name().to_string(),
+                value,
+            });
+        }
+    }
+}
+
+impl Visit for FieldVisitor {
+    fn record_str(&mut self, field: &Field, value: &str) {
+        self.push(field, value.to_string());
+    }
+
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        self.push(field, format!("{:?}", value));
+    }
+}
@@ -1,6 +1,9 @@
 //! Certificate and TLS related types
 
 use flutter_rust_bridge::frb;
+use crate::errors::QuicError;
+use rustls_pemfile::Item;
+use rustls_pki_types::{PrivateKeyDer, PrivatePkcs1KeyDer, PrivatePkcs8KeyDer, PrivateSec1KeyDer};
 
 /// Certificate chain for TLS
 #[frb]
@@ -8,8 +11,234 @@ pub struct CertificateChain {
     pub certificates: Vec<Vec<u8>>,
 }
 
+impl CertificateChain {
+    /// Load every `CERTIFICATE` block from PEM data, leaf certificate first
+    ///
+    /// Other block types (keys, CRLs) are skipped.
+    pub fn from_pem(pem: &[u8]) -> Result<Self, QuicError> {
+        let certificates: Vec<Vec<u8>> = read_pem_items(pem)?
+            .into_iter()
+            .filter_map(|block| match block.item {
+                Some(Item::X509Certificate(cert)) => Some(cert.to_vec()),
+                _ => None,
+            })
+            .collect();
+
+        if certificates.is_empty() {
+            return Err(QuicError::Tls("No CERTIFICATE blocks found in PEM data".to_string()));
+        }
+
+        Ok(Self { certificates })
+    }
+}
+
+/// Encoding of a private key's DER bytes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrivateKeyFormat {
+    /// PKCS#8 `PrivateKeyInfo` (`BEGIN PRIVATE KEY`)
+    Pkcs8,
+    /// SEC1 EC key (`BEGIN EC PRIVATE KEY`)
+    Sec1,
+    /// PKCS#1 RSA key (`BEGIN RSA PRIVATE KEY`)
+    Pkcs1,
+}
+
 /// Private key for TLS
 #[frb]
 pub struct PrivateKey {
     pub key_data: Vec<u8>,
-} 
\ No newline at end of file
+    pub format: PrivateKeyFormat,
+}
+
+impl PrivateKey {
+    /// Load the private key from PEM data
+    ///
+    /// Accepts unencrypted PKCS#8, SEC1 and PKCS#1 (RSA) keys. The data must contain
+    /// exactly one key; certificates in the same file are skipped.
+    pub fn from_pem(pem: &[u8]) -> Result<Self, QuicError> {
+        let mut keys = Vec::new();
+        for block in read_pem_items(pem)? {
+            let (key_data, format) = match block.item {
+                Some(Item::Pkcs8Key(key)) => (key.secret_pkcs8_der().to_vec(), PrivateKeyFormat::Pkcs8),
+                Some(Item::Sec1Key(key)) => (key.secret_sec1_der().to_vec(), PrivateKeyFormat::Sec1),
+                Some(Item::Pkcs1Key(key)) => (key.secret_pkcs1_der().to_vec(), PrivateKeyFormat::Pkcs1),
+                _ if block.encrypted => {
+                    return Err(QuicError::Tls(format!(
+                        "PEM block {} is an encrypted private key; decrypt it first", block.number
+                    )));
+                }
+                _ if block.label.ends_with("PRIVATE KEY") => {
+                    return Err(QuicError::Tls(format!(
+                        "PEM block {} is an unsupported {} block", block.number, block.label
+                    )));
+                }
+                _ => continue,
+            };
+            keys.push((block.number, Self { key_data, format }));
+        }
+        let mut keys = keys.into_iter();
+
+        let (_, key) = keys.next()
+            .ok_or_else(|| QuicError::Tls("No PRIVATE KEY, EC PRIVATE KEY or RSA PRIVATE KEY block found in PEM data".to_string()))?;
+
+        if let Some((block, _)) = keys.next() {
+            return Err(QuicError::Tls(format!(
+                "PEM block {} is a second private key; expected exactly one", block
+            )));
+        }
+
+        Ok(key)
+    }
+
+    /// Convert to the rustls key type without guessing the encoding
+    pub(crate) fn to_der(&self) -> PrivateKeyDer<'static> {
+        let key_data = self.key_data.clone();
+        match self.format {
+            PrivateKeyFormat::Pkcs8 => PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(key_data)),
+            PrivateKeyFormat::Sec1 => PrivateKeyDer::Sec1(PrivateSec1KeyDer::from(key_data)),
+            PrivateKeyFormat::Pkcs1 => PrivateKeyDer::Pkcs1(PrivatePkcs1KeyDer::from(key_data)),
+        }
+    }
+}
+
+/// Key algorithm for generated certificates
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CertificateKeyAlgorithm {
+    EcdsaP256,
+    EcdsaP384,
+    Ed25519,
+}
+
+/// Self-signed certificate with its key and SHA-256 fingerprint
+#[frb]
+pub struct SelfSignedCertificate {
+    pub certificate_chain: CertificateChain,
+    pub private_key: PrivateKey,
+    /// Lowercase hex SHA-256 digest of the DER certificate, for client-side pinning
+    pub sha256_fingerprint: String,
+}
+
+/// Generate a self-signed certificate for local development servers
+///
+/// # Arguments
+/// * `subject_alt_names` - DNS names and IP addresses, e.g. `["localhost", "127.0.0.1"]`
+/// * `validity_days` - Days the certificate stays valid, starting now
+/// * `algorithm` - Key algorithm of the generated key pair
+pub fn generate_self_signed(
+    subject_alt_names: Vec<String>,
+    validity_days: u32,
+    algorithm: CertificateKeyAlgorithm,
+) -> Result<SelfSignedCertificate, QuicError> {
+    if subject_alt_names.is_empty() {
+        return Err(QuicError::Tls("At least one subject alternative name is required".to_string()));
+    }
+
+    let signature_algorithm = match algorithm {
+        CertificateKeyAlgorithm::EcdsaP256 => &rcgen::PKCS_ECDSA_P256_SHA256,
+        CertificateKeyAlgorithm::EcdsaP384 => &rcgen::PKCS_ECDSA_P384_SHA384,
+        CertificateKeyAlgorithm::Ed25519 => &rcgen::PKCS_ED25519,
+    };
+    let key_pair = rcgen::KeyPair::generate_for(signature_algorithm)
+        .map_err(|e| QuicError::Tls(format!("Failed to generate {:?} key pair: {}", algorithm, e)))?;
+
+    // IP addresses become IP SANs, everything else a DNS SAN
+    let mut params = rcgen::CertificateParams::new(subject_alt_names)
+        .map_err(|e| QuicError::Tls(format!("Invalid subject alternative name: {}", e)))?;
+    let now = time::OffsetDateTime::now_utc();
+    params.not_before = now;
+    params.not_after = now + time::Duration::days(i64::from(validity_days));
+
+    let certificate = params.self_signed(&key_pair)
+        .map_err(|e| QuicError::Tls(format!("Failed to sign certificate: {}", e)))?;
+
+    let der = certificate.der().to_vec();
+    let sha256_fingerprint = ring::digest::digest(&ring::digest::SHA256, &der)
+        .as_ref()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect();
+
+    Ok(SelfSignedCertificate {
+        certificate_chain: CertificateChain { certificates: vec![der] },
+        private_key: PrivateKey {
+            key_data: key_pair.serialize_der(),
+            format: PrivateKeyFormat::Pkcs8,
+        },
+        sha256_fingerprint,
+    })
+}
+
+/// Load the first private key and its certificate chain from a PKCS#12 (`.p12`/`.pfx`) bundle
+pub fn load_pkcs12(data: &[u8], password: &str) -> Result<(CertificateChain, PrivateKey), QuicError> {
+    let keystore = p12_keystore::KeyStore::from_pkcs12(data, password)
+        .map_err(|e| QuicError::Tls(format!("Failed to decode PKCS#12 bundle: {}", e)))?;
+
+    let (alias, key_chain) = keystore.private_key_chain()
+        .ok_or_else(|| QuicError::Tls("PKCS#12 bundle contains no private key".to_string()))?;
+
+    if key_chain.chain().is_empty() {
+        return Err(QuicError::Tls(format!(
+            "PKCS#12 entry '{}' has no certificates", alias
+        )));
+    }
+
+    let chain = CertificateChain {
+        certificates: key_chain.chain()
+            .iter()
+            .map(|cert| cert.as_der().to_vec())
+            .collect(),
+    };
+    // PKCS#12 stores keys as PKCS#8 PrivateKeyInfo
+    let key = PrivateKey {
+        key_data: key_chain.key().to_vec(),
+        format: PrivateKeyFormat::Pkcs8,
+    };
+
+    Ok((chain, key))
+}
+
+/// A PEM block with its 1-based position among all blocks in the data
+struct PemBlock {
+    number: usize,
+    label: String,
+    encrypted: bool,
+    /// The decoded block, or `None` for block types rustls does not support
+    item: Option<Item>,
+}
+
+/// Parse all PEM blocks, numbering them in file order including unsupported ones
+fn read_pem_items(pem: &[u8]) -> Result<Vec<PemBlock>, QuicError> {
+    const BEGIN: &[u8] = b"-----BEGIN ";
+    const END: &[u8] = b"-----END ";
+
+    let mut blocks = Vec::new();
+    let mut rest = pem;
+    while let Some(start) = find(rest, BEGIN) {
+        let section = &rest[start..];
+        // A block ends after its END line; a missing END is left to the parser to report
+        let len = find(section, END)
+            .map(|end| end + find(&section[end..], b"\n").map_or(section.len() - end, |eol| eol + 1))
+            .unwrap_or(section.len());
+        let (section, remainder) = section.split_at(len);
+        rest = remainder;
+
+        let number = blocks.len() + 1;
+        let label_end = find(&section[BEGIN.len()..], b"-----").unwrap_or(0);
+        let label = String::from_utf8_lossy(&section[BEGIN.len()..BEGIN.len() + label_end]).into_owned();
+        // PKCS#8 encryption uses its own label, legacy OpenSSL encryption a header
+        let encrypted = label.starts_with("ENCRYPTED") || find(section, b"Proc-Type: 4,ENCRYPTED").is_some();
+        let item = if encrypted {
+            None
+        } else {
+            rustls_pemfile::read_one(&mut &section[..])
+                .map_err(|e| QuicError::Tls(format!("Failed to parse PEM block {}: {}", number, e)))?
+        };
+        blocks.push(PemBlock { number, label, encrypted, item });
+    }
+
+    Ok(blocks)
+}
+
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack.windows(needle.len()).position(|window| window == needle)
+}